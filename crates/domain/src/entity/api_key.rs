@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+
+/// An API key issued to a client. Only the hash of the raw key is ever persisted.
+#[derive(Debug, Clone, Getters, Setters)]
+pub struct ApiKey {
+    #[getset(get = "pub")]
+    id: String,
+    #[getset(get = "pub")]
+    client_id: String,
+    #[getset(get = "pub")]
+    key_hash: String,
+    #[getset(get = "pub", set = "pub")]
+    revoked_at: Option<DateTime<Utc>>,
+    #[getset(get = "pub")]
+    created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    pub fn new(
+        id: String,
+        client_id: String,
+        key_hash: String,
+        revoked_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            client_id,
+            key_hash,
+            revoked_at,
+            created_at,
+        }
+    }
+
+    /// Returns `true` while the key has not been revoked.
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_active_reflects_revocation() {
+        let created_at = chrono::Utc::now();
+        let mut key = ApiKey::new(
+            "key-id".to_owned(),
+            "client-id".to_owned(),
+            "hash".to_owned(),
+            None,
+            created_at,
+        );
+        assert!(key.is_active());
+
+        key.set_revoked_at(Some(created_at));
+        assert!(!key.is_active());
+    }
+}
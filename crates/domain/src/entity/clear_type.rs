@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter, Result};
 
+use super::judgement::Judgement;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ClearType {
     Fail,
@@ -18,3 +20,43 @@ impl Display for ClearType {
         }
     }
 }
+
+impl ClearType {
+    /// Whether a run with these judgement counts can earn this clear type. `AllPerfect` allows
+    /// nothing but perfects and `FullCombo` allows no misses; failing or clearing fits any counts.
+    pub fn is_consistent_with(self, judgement: &Judgement) -> bool {
+        match self {
+            ClearType::AllPerfect => {
+                *judgement.great() == 0 && *judgement.good() == 0 && *judgement.miss() == 0
+            }
+            ClearType::FullCombo => *judgement.miss() == 0,
+            ClearType::Fail | ClearType::Clear => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_perfect_rejects_any_non_perfect() {
+        assert!(ClearType::AllPerfect.is_consistent_with(&Judgement::new(1000, 0, 0, 0)));
+        assert!(!ClearType::AllPerfect.is_consistent_with(&Judgement::new(999, 1, 0, 0)));
+        assert!(!ClearType::AllPerfect.is_consistent_with(&Judgement::new(999, 0, 1, 0)));
+        assert!(!ClearType::AllPerfect.is_consistent_with(&Judgement::new(999, 0, 0, 1)));
+    }
+
+    #[test]
+    fn full_combo_rejects_misses() {
+        assert!(ClearType::FullCombo.is_consistent_with(&Judgement::new(900, 80, 20, 0)));
+        assert!(!ClearType::FullCombo.is_consistent_with(&Judgement::new(900, 80, 19, 1)));
+    }
+
+    #[test]
+    fn fail_and_clear_accept_any_counts() {
+        let judgement = Judgement::new(0, 0, 0, 1000);
+        assert!(ClearType::Fail.is_consistent_with(&judgement));
+        assert!(ClearType::Clear.is_consistent_with(&judgement));
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+
+use super::client_role::ClientRole;
+
+/// A machine or service registered to call the API, such as a single cabinet or card station.
+/// Its API keys are rotated independently while the client identity stays stable.
+#[derive(Debug, Clone, Getters, Setters)]
+pub struct Client {
+    #[getset(get = "pub")]
+    id: String,
+    #[getset(get = "pub")]
+    role: ClientRole,
+    #[getset(get = "pub")]
+    label: String,
+    #[getset(get = "pub", set = "pub")]
+    revoked_at: Option<DateTime<Utc>>,
+    #[getset(get = "pub")]
+    created_at: DateTime<Utc>,
+}
+
+impl Client {
+    pub fn new(
+        id: String,
+        role: ClientRole,
+        label: String,
+        revoked_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            role,
+            label,
+            revoked_at,
+            created_at,
+        }
+    }
+
+    /// Builds a client that has not been persisted yet. The id is assigned by storage.
+    pub fn new_temporary(role: ClientRole, label: String) -> Self {
+        Self {
+            id: "".to_string(),
+            role,
+            label,
+            revoked_at: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Returns `true` while the client has not been revoked.
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_temporary_is_active_without_id() {
+        let mut client = Client::new_temporary(ClientRole::Station, "Station #2".to_owned());
+
+        assert!(client.id().is_empty());
+        assert_eq!(*client.role(), ClientRole::Station);
+        assert!(client.is_active());
+
+        client.set_revoked_at(Some(chrono::Utc::now()));
+        assert!(!client.is_active());
+    }
+}
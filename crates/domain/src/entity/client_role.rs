@@ -0,0 +1,22 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// Kind of machine or service that talks to the API. Mirrors the `app`, `station`, `web` and
+/// `admin` tags in `docs/openapi.yaml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClientRole {
+    Cabinet,
+    Station,
+    Web,
+    Admin,
+}
+
+impl Display for ClientRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ClientRole::Cabinet => write!(f, "CABINET"),
+            ClientRole::Station => write!(f, "STATION"),
+            ClientRole::Web => write!(f, "WEB"),
+            ClientRole::Admin => write!(f, "ADMIN"),
+        }
+    }
+}
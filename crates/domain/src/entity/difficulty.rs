@@ -1,12 +1,23 @@
-use std::fmt::{Display, Formatter, Result};
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
 
-#[derive(Debug, Clone, Copy)]
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Difficulty {
     Easy,
     Normal,
     Hard,
 }
 
+#[derive(Debug, Error)]
+pub enum DifficultyError {
+    #[error("Unsupported difficulty: {0}")]
+    Unsupported(String),
+}
+
 impl Display for Difficulty {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -16,3 +27,33 @@ impl Display for Difficulty {
         }
     }
 }
+
+/// Parses the lowercase names used on the wire (`easy`, `normal`, `hard`). The uppercase
+/// [`Display`] form is accepted as well.
+impl FromStr for Difficulty {
+    type Err = DifficultyError;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(DifficultyError::Unsupported(value.to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_accepts_wire_and_display_forms() {
+        assert_eq!("hard".parse::<Difficulty>().unwrap(), Difficulty::Hard);
+        assert_eq!("EASY".parse::<Difficulty>().unwrap(), Difficulty::Easy);
+        assert!(matches!(
+            "expert".parse::<Difficulty>(),
+            Err(DifficultyError::Unsupported(value)) if value == "expert"
+        ));
+    }
+}
@@ -1,11 +1,22 @@
-use std::fmt::{Display, Formatter, Result};
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
+
+use thiserror::Error;
 
 // TODO: Add the rest of the genres
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Genre {
     ORIGINAL,
 }
 
+#[derive(Debug, Error)]
+pub enum GenreError {
+    #[error("Unsupported genre: {0}")]
+    Unsupported(String),
+}
+
 impl Display for Genre {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -13,3 +24,30 @@ impl Display for Genre {
         }
     }
 }
+
+/// Parses the [`Display`] form, ignoring case.
+impl FromStr for Genre {
+    type Err = GenreError;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.to_ascii_uppercase().as_str() {
+            "ORIGINAL" => Ok(Genre::ORIGINAL),
+            _ => Err(GenreError::Unsupported(value.to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_round_trips_display() {
+        assert_eq!(
+            Genre::ORIGINAL.to_string().parse::<Genre>().unwrap(),
+            Genre::ORIGINAL
+        );
+        assert_eq!("original".parse::<Genre>().unwrap(), Genre::ORIGINAL);
+        assert!("VARIETY".parse::<Genre>().is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+/// Letter grade shown for a score. Variants are declared from worst to best, so grades compare
+/// the same way their scores do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Grade {
+    D,
+    C,
+    B,
+    A,
+    AA,
+    S,
+    SS,
+    SSS,
+}
+
+#[derive(Debug, Error)]
+pub enum GradeError {
+    #[error("Unsupported grade: {0}")]
+    Unsupported(String),
+}
+
+impl Grade {
+    /// Every grade from best to worst, i.e. in the order [`Grade::from_score`] checks them.
+    pub const ALL: [Grade; 8] = [
+        Grade::SSS,
+        Grade::SS,
+        Grade::S,
+        Grade::AA,
+        Grade::A,
+        Grade::B,
+        Grade::C,
+        Grade::D,
+    ];
+
+    /// Lowest score that earns this grade.
+    pub fn min_score(self) -> u32 {
+        match self {
+            Grade::SSS => 1_075_000,
+            Grade::SS => 1_050_000,
+            Grade::S => 1_000_000,
+            Grade::AA => 950_000,
+            Grade::A => 900_000,
+            Grade::B => 800_000,
+            Grade::C => 700_000,
+            Grade::D => 0,
+        }
+    }
+
+    pub fn from_score(score: u32) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|grade| score >= grade.min_score())
+            .unwrap_or(Grade::D)
+    }
+}
+
+impl Display for Grade {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Grade::SSS => write!(f, "SSS"),
+            Grade::SS => write!(f, "SS"),
+            Grade::S => write!(f, "S"),
+            Grade::AA => write!(f, "AA"),
+            Grade::A => write!(f, "A"),
+            Grade::B => write!(f, "B"),
+            Grade::C => write!(f, "C"),
+            Grade::D => write!(f, "D"),
+        }
+    }
+}
+
+/// Parses the [`Display`] form. Lowercase letters are accepted as well.
+impl FromStr for Grade {
+    type Err = GradeError;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|grade| grade.to_string().eq_ignore_ascii_case(value))
+            .ok_or_else(|| GradeError::Unsupported(value.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_score_uses_the_highest_reached_threshold() {
+        assert_eq!(Grade::from_score(1_100_000), Grade::SSS);
+        assert_eq!(Grade::from_score(1_075_000), Grade::SSS);
+        assert_eq!(Grade::from_score(1_074_999), Grade::SS);
+        assert_eq!(Grade::from_score(1_000_000), Grade::S);
+        assert_eq!(Grade::from_score(949_999), Grade::A);
+        assert_eq!(Grade::from_score(0), Grade::D);
+    }
+
+    #[test]
+    fn grades_order_like_their_scores() {
+        assert!(Grade::SSS > Grade::SS);
+        assert!(Grade::AA > Grade::A);
+        assert!(Grade::C > Grade::D);
+        let mut sorted = Grade::ALL;
+        sorted.sort_by(|a, b| b.cmp(a));
+        assert_eq!(sorted, Grade::ALL);
+    }
+
+    #[test]
+    fn from_str_accepts_any_case() {
+        assert_eq!("sss".parse::<Grade>().unwrap(), Grade::SSS);
+        assert_eq!("AA".parse::<Grade>().unwrap(), Grade::AA);
+        assert!(matches!(
+            "AAA".parse::<Grade>(),
+            Err(GradeError::Unsupported(value)) if value == "AAA"
+        ));
+    }
+}
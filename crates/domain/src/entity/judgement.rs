@@ -0,0 +1,45 @@
+use getset::Getters;
+
+/// How many notes of a play landed in each timing window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Getters)]
+pub struct Judgement {
+    #[getset(get = "pub")]
+    perfect: u32,
+    #[getset(get = "pub")]
+    great: u32,
+    #[getset(get = "pub")]
+    good: u32,
+    #[getset(get = "pub")]
+    miss: u32,
+}
+
+impl Judgement {
+    pub fn new(perfect: u32, great: u32, good: u32, miss: u32) -> Self {
+        Self {
+            perfect,
+            great,
+            good,
+            miss,
+        }
+    }
+
+    /// Returns the number of judged notes.
+    pub fn total(&self) -> u32 {
+        self.perfect
+            .saturating_add(self.great)
+            .saturating_add(self.good)
+            .saturating_add(self.miss)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_sums_every_window() {
+        assert_eq!(Judgement::new(900, 80, 15, 5).total(), 1000);
+        assert_eq!(Judgement::default().total(), 0);
+        assert_eq!(Judgement::new(u32::MAX, 1, 0, 0).total(), u32::MAX);
+    }
+}
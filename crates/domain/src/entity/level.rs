@@ -3,7 +3,7 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 use anyhow::Result;
 use thiserror::Error;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Level(u32, u32);
 
 #[derive(Debug, Error)]
@@ -38,6 +38,22 @@ impl TryFrom<(u32, u32)> for Level {
     }
 }
 
+/// Builds a level from its decimal form, e.g. `13.7`, as exposed by `/sync`. Values with more
+/// than one fractional digit are rejected rather than rounded.
+impl TryFrom<f64> for Level {
+    type Error = LevelError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        let scaled = value * 10.0;
+        if !scaled.is_finite() || scaled < 0.0 || (scaled - scaled.round()).abs() > 1e-6 {
+            return Err(LevelError::InvalidValue);
+        }
+
+        let scaled = scaled.round() as u32;
+        Level::new(scaled / 10, scaled % 10)
+    }
+}
+
 impl Display for Level {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         if self.1 < 5 {
@@ -64,6 +80,25 @@ mod tests {
         assert!(matches!(Level::new(10, 12), Err(LevelError::InvalidValue)));
     }
 
+    #[test]
+    fn try_from_decimal_splits_components() {
+        let level = Level::try_from(13.7).expect("should construct");
+        assert_eq!(level.components(), (13, 7));
+
+        assert!(matches!(
+            Level::try_from(13.75),
+            Err(LevelError::InvalidValue)
+        ));
+        assert!(matches!(
+            Level::try_from(-1.0),
+            Err(LevelError::InvalidValue)
+        ));
+        assert!(matches!(
+            Level::try_from(0.5),
+            Err(LevelError::InvalidValue)
+        ));
+    }
+
     #[test]
     fn display_uses_plus_suffix_for_upper_half() {
         let upper_half = Level::new(14, 7).expect("should construct");
@@ -1,10 +1,21 @@
+pub mod api_key;
 pub mod clear_type;
+pub mod client;
+pub mod client_role;
 pub mod difficulty;
 pub mod genre;
+pub mod grade;
+pub mod judgement;
 pub mod level;
 pub mod music;
+pub mod play;
 pub mod rating;
 pub mod record;
+pub mod rival;
+pub mod season;
 pub mod sheet;
+pub mod submission_result;
 pub mod user;
 pub mod user_play_option;
+pub mod user_progress;
+pub mod xp_campaign;
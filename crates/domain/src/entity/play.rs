@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use getset::Getters;
+
+use super::{clear_type::ClearType, judgement::Judgement};
+
+/// A single submitted play. Unlike [`Record`](super::record::Record), which only keeps the best
+/// result per sheet, plays are append-only and are never updated.
+#[derive(Debug, Clone, Getters)]
+pub struct Play {
+    #[getset(get = "pub")]
+    id: String,
+    #[getset(get = "pub")]
+    user_id: String,
+    #[getset(get = "pub")]
+    sheet_id: String,
+    /// The cabinet the play was submitted from.
+    #[getset(get = "pub")]
+    client_id: String,
+    #[getset(get = "pub")]
+    score: u32,
+    #[getset(get = "pub")]
+    clear_type: ClearType,
+    #[getset(get = "pub")]
+    judgement: Judgement,
+    #[getset(get = "pub")]
+    played_at: DateTime<Utc>,
+    /// Client-generated id used to recognise retried submissions. Plays logged before ids were
+    /// required have none.
+    #[getset(get = "pub")]
+    submission_id: Option<String>,
+}
+
+#[allow(clippy::too_many_arguments)]
+impl Play {
+    pub fn new(
+        id: String,
+        user_id: String,
+        sheet_id: String,
+        client_id: String,
+        score: u32,
+        clear_type: ClearType,
+        judgement: Judgement,
+        played_at: DateTime<Utc>,
+        submission_id: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            sheet_id,
+            client_id,
+            score,
+            clear_type,
+            judgement,
+            played_at,
+            submission_id,
+        }
+    }
+
+    /// Builds a play that has not been persisted yet. The id is assigned by storage.
+    pub fn new_from_submission(
+        submission_id: String,
+        user_id: String,
+        sheet_id: String,
+        client_id: String,
+        score: u32,
+        clear_type: ClearType,
+        judgement: Judgement,
+        played_at: DateTime<Utc>,
+    ) -> Self {
+        Self::new(
+            String::new(),
+            user_id,
+            sheet_id,
+            client_id,
+            score,
+            clear_type,
+            judgement,
+            played_at,
+            Some(submission_id),
+        )
+    }
+}
@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use getset::{Getters, Setters};
 
-use super::clear_type::ClearType;
+use super::{clear_type::ClearType, grade::Grade, judgement::Judgement};
 
 #[derive(Debug, Clone, Getters, Setters)]
 pub struct Record {
@@ -15,6 +15,12 @@ pub struct Record {
     score: u32,
     #[getset(get = "pub", set = "pub")]
     clear_type: ClearType,
+    /// Judgement counts of the run that set the best score.
+    #[getset(get = "pub", set = "pub")]
+    judgement: Judgement,
+    /// Max combo of the run that set the best score.
+    #[getset(get = "pub", set = "pub")]
+    max_combo: u32,
     #[getset(get = "pub", set = "pub")]
     play_count: u32,
     #[getset(get = "pub", set = "pub")]
@@ -29,6 +35,8 @@ impl Record {
         sheet_id: String,
         score: u32,
         clear_type: ClearType,
+        judgement: Judgement,
+        max_combo: u32,
         play_count: u32,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -38,6 +46,8 @@ impl Record {
             sheet_id,
             score,
             clear_type,
+            judgement,
+            max_combo,
             play_count,
             updated_at,
         }
@@ -48,6 +58,8 @@ impl Record {
         sheet_id: String,
         score: u32,
         clear_type: ClearType,
+        judgement: Judgement,
+        max_combo: u32,
         updated_at: DateTime<Utc>,
     ) -> Self {
         Self::new(
@@ -56,26 +68,39 @@ impl Record {
             sheet_id,
             score,
             clear_type,
+            judgement,
+            max_combo,
             1,
             updated_at,
         )
     }
 
+    /// Folds a new run into the record. The judgement and max combo follow the best score, while
+    /// the clear type is tracked independently.
     pub fn apply_submission(
         &mut self,
         score: u32,
         clear_type: ClearType,
+        judgement: Judgement,
+        max_combo: u32,
         updated_at: DateTime<Utc>,
     ) {
         self.set_play_count(self.play_count() + 1);
         if score > *self.score() {
             self.set_score(score);
+            self.set_judgement(judgement);
+            self.set_max_combo(max_combo);
         }
         if is_better_clear(clear_type, *self.clear_type()) {
             self.set_clear_type(clear_type);
         }
         self.set_updated_at(updated_at);
     }
+
+    /// Grade of the best score.
+    pub fn grade(&self) -> Grade {
+        Grade::from_score(self.score)
+    }
 }
 
 fn is_better_clear(new: ClearType, current: ClearType) -> bool {
@@ -90,3 +115,59 @@ fn clear_type_rank(clear_type: ClearType) -> u8 {
         ClearType::AllPerfect => 3,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn record() -> Record {
+        Record::new_from_submission(
+            "user-1".to_owned(),
+            "sheet-1".to_owned(),
+            950_000,
+            ClearType::FullCombo,
+            Judgement::new(900, 90, 10, 0),
+            1000,
+            Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn apply_submission_keeps_judgement_of_best_run() {
+        let mut record = record();
+
+        record.apply_submission(
+            900_000,
+            ClearType::Clear,
+            Judgement::new(800, 100, 50, 50),
+            400,
+            Utc.with_ymd_and_hms(2025, 11, 2, 0, 0, 0).unwrap(),
+        );
+
+        assert_eq!(*record.score(), 950_000);
+        assert_eq!(*record.judgement(), Judgement::new(900, 90, 10, 0));
+        assert_eq!(*record.max_combo(), 1000);
+        assert_eq!(*record.clear_type(), ClearType::FullCombo);
+        assert_eq!(*record.play_count(), 2);
+    }
+
+    #[test]
+    fn apply_submission_replaces_judgement_on_new_best() {
+        let mut record = record();
+
+        record.apply_submission(
+            990_000,
+            ClearType::Clear,
+            Judgement::new(980, 10, 5, 5),
+            700,
+            Utc.with_ymd_and_hms(2025, 11, 2, 0, 0, 0).unwrap(),
+        );
+
+        assert_eq!(*record.score(), 990_000);
+        assert_eq!(*record.judgement(), Judgement::new(980, 10, 5, 5));
+        assert_eq!(*record.max_combo(), 700);
+        assert_eq!(*record.clear_type(), ClearType::FullCombo);
+    }
+}
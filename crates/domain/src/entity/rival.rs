@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use getset::Getters;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RivalError {
+    #[error("a user cannot register themselves as a rival")]
+    SelfRival,
+}
+
+/// A player `user_id` follows so that their scores can be compared sheet by sheet.
+#[derive(Debug, Clone, Getters)]
+pub struct Rival {
+    #[getset(get = "pub")]
+    user_id: String,
+    #[getset(get = "pub")]
+    rival_id: String,
+    #[getset(get = "pub")]
+    created_at: DateTime<Utc>,
+}
+
+impl Rival {
+    pub fn new(user_id: String, rival_id: String, created_at: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            rival_id,
+            created_at,
+        }
+    }
+
+    /// Builds a rival that has not been registered yet. The registration time is assigned by
+    /// storage.
+    pub fn new_temporary(user_id: String, rival_id: String) -> Result<Self, RivalError> {
+        if user_id == rival_id {
+            return Err(RivalError::SelfRival);
+        }
+
+        Ok(Self::new(user_id, rival_id, Utc::now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::user::{USER1, USER2};
+
+    #[test]
+    fn new_temporary_rejects_the_user_themselves() {
+        assert_eq!(
+            Rival::new_temporary(USER1.id.to_owned(), USER1.id.to_owned()).unwrap_err(),
+            RivalError::SelfRival
+        );
+
+        let rival = Rival::new_temporary(USER1.id.to_owned(), USER2.id.to_owned()).unwrap();
+        assert_eq!(rival.user_id(), USER1.id);
+        assert_eq!(rival.rival_id(), USER2.id);
+    }
+}
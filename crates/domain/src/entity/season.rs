@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use getset::Getters;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SeasonError {
+    #[error("a season must end after it starts")]
+    EmptyPeriod,
+}
+
+/// Which series of seasons a season belongs to. Operators schedule each series separately, so a
+/// weekly and a monthly season may run at the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeasonPeriod {
+    Weekly,
+    Monthly,
+}
+
+/// A ranking that is kept per season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeasonBoard {
+    /// Sum of each user's best score per sheet within the season.
+    TotalScore,
+    /// Number of plays within the season.
+    Plays,
+}
+
+/// A season scheduled by an operator. `archived_at` is set once its final standings have been
+/// stored.
+#[derive(Debug, Clone, Getters)]
+pub struct Season {
+    #[getset(get = "pub")]
+    id: String,
+    #[getset(get = "pub")]
+    period: SeasonPeriod,
+    #[getset(get = "pub")]
+    starts_at: DateTime<Utc>,
+    #[getset(get = "pub")]
+    ends_at: DateTime<Utc>,
+    #[getset(get = "pub")]
+    archived_at: Option<DateTime<Utc>>,
+}
+
+impl Season {
+    pub fn new(
+        id: String,
+        period: SeasonPeriod,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            period,
+            starts_at,
+            ends_at,
+            archived_at,
+        }
+    }
+
+    /// Builds a season that has not been persisted yet. The id is assigned by storage.
+    pub fn new_temporary(
+        period: SeasonPeriod,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Result<Self, SeasonError> {
+        if ends_at <= starts_at {
+            return Err(SeasonError::EmptyPeriod);
+        }
+
+        Ok(Self::new(String::new(), period, starts_at, ends_at, None))
+    }
+
+    /// The season includes its start but not its end.
+    pub fn is_active_at(&self, at: DateTime<Utc>) -> bool {
+        self.starts_at <= at && at < self.ends_at
+    }
+
+    /// Whether every play of the season has been made by `at`.
+    pub fn has_ended_at(&self, at: DateTime<Utc>) -> bool {
+        self.ends_at <= at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::datetime::timestamp;
+
+    #[test]
+    fn new_temporary_rejects_seasons_that_end_before_they_start() {
+        let start = timestamp(2025, 11, 17, 0, 0, 0);
+        let end = timestamp(2025, 11, 24, 0, 0, 0);
+
+        assert_eq!(
+            Season::new_temporary(SeasonPeriod::Weekly, end, start).unwrap_err(),
+            SeasonError::EmptyPeriod
+        );
+        assert_eq!(
+            Season::new_temporary(SeasonPeriod::Weekly, start, start).unwrap_err(),
+            SeasonError::EmptyPeriod
+        );
+    }
+
+    #[test]
+    fn is_active_at_excludes_the_end() {
+        let start = timestamp(2025, 11, 17, 0, 0, 0);
+        let end = timestamp(2025, 11, 24, 0, 0, 0);
+        let season = Season::new_temporary(SeasonPeriod::Weekly, start, end).unwrap();
+
+        assert!(season.is_active_at(start));
+        assert!(!season.is_active_at(end));
+        assert!(season.has_ended_at(end));
+        assert!(!season.has_ended_at(timestamp(2025, 11, 23, 23, 59, 59)));
+    }
+}
@@ -14,8 +14,15 @@ pub struct Sheet {
     level: Level,
     #[getset(get = "pub")]
     notes_designer: String,
+    /// Number of judged notes. Zero for sheets registered before note counts were tracked.
+    #[getset(get = "pub")]
+    note_count: u32,
+    /// Highest score attainable on the sheet. Zero when not yet known.
+    #[getset(get = "pub")]
+    max_score: u32,
 }
 
+#[allow(clippy::too_many_arguments)]
 impl Sheet {
     pub fn new(
         id: String,
@@ -23,6 +30,8 @@ impl Sheet {
         difficulty: Difficulty,
         level: Level,
         notes_designer: String,
+        note_count: u32,
+        max_score: u32,
     ) -> Self {
         Self {
             id,
@@ -30,6 +39,8 @@ impl Sheet {
             difficulty,
             level,
             notes_designer,
+            note_count,
+            max_score,
         }
     }
 }
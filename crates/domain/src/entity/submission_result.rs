@@ -0,0 +1,41 @@
+use getset::Getters;
+
+use super::record::Record;
+
+/// The levels a user moved between because of one submission batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Getters)]
+pub struct LevelUp {
+    #[getset(get = "pub")]
+    from: u32,
+    #[getset(get = "pub")]
+    to: u32,
+}
+
+impl LevelUp {
+    pub fn new(from: u32, to: u32) -> Self {
+        Self { from, to }
+    }
+}
+
+/// What a submission was answered with: the record its sheet was left at and the level-up of
+/// its batch. Kept so that a retried request gets the original answer, not the record as later
+/// plays left it.
+#[derive(Debug, Clone, Getters)]
+pub struct SubmissionResult {
+    #[getset(get = "pub")]
+    submission_id: String,
+    #[getset(get = "pub")]
+    record: Record,
+    #[getset(get = "pub")]
+    level_up: Option<LevelUp>,
+}
+
+impl SubmissionResult {
+    pub fn new(submission_id: String, record: Record, level_up: Option<LevelUp>) -> Self {
+        Self {
+            submission_id,
+            record,
+            level_up,
+        }
+    }
+}
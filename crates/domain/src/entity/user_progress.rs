@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use getset::Getters;
+
+/// Snapshots younger than this many days are kept daily; older ones are thinned to one per week.
+pub const DAILY_RETENTION_DAYS: i64 = 180;
+
+/// A user's rating and XP as of the end of a day (UTC).
+#[derive(Debug, Clone, Getters)]
+pub struct UserProgress {
+    #[getset(get = "pub")]
+    user_id: String,
+    #[getset(get = "pub")]
+    day: NaiveDate,
+    #[getset(get = "pub")]
+    rating: u32,
+    #[getset(get = "pub")]
+    xp: u32,
+}
+
+impl UserProgress {
+    pub fn new(user_id: String, day: NaiveDate, rating: u32, xp: u32) -> Self {
+        Self {
+            user_id,
+            day,
+            rating,
+            xp,
+        }
+    }
+}
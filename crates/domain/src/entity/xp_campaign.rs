@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use getset::Getters;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum XpCampaignError {
+    #[error("the multiplier must be at least 100 percent, got {0}")]
+    MultiplierBelowBase(u32),
+    #[error("a campaign must end after it starts")]
+    EmptyPeriod,
+}
+
+/// A period during which every submission earns `multiplier_percent` percent of its usual XP,
+/// such as a double XP weekend at 200.
+#[derive(Debug, Clone, Getters)]
+pub struct XpCampaign {
+    #[getset(get = "pub")]
+    id: String,
+    #[getset(get = "pub")]
+    name: String,
+    #[getset(get = "pub")]
+    multiplier_percent: u32,
+    #[getset(get = "pub")]
+    starts_at: DateTime<Utc>,
+    #[getset(get = "pub")]
+    ends_at: DateTime<Utc>,
+}
+
+impl XpCampaign {
+    pub fn new(
+        id: String,
+        name: String,
+        multiplier_percent: u32,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            multiplier_percent,
+            starts_at,
+            ends_at,
+        }
+    }
+
+    /// Builds a campaign that has not been persisted yet. The id is assigned by storage.
+    pub fn new_temporary(
+        name: String,
+        multiplier_percent: u32,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Result<Self, XpCampaignError> {
+        if multiplier_percent < 100 {
+            return Err(XpCampaignError::MultiplierBelowBase(multiplier_percent));
+        }
+        if ends_at <= starts_at {
+            return Err(XpCampaignError::EmptyPeriod);
+        }
+
+        Ok(Self::new(
+            String::new(),
+            name,
+            multiplier_percent,
+            starts_at,
+            ends_at,
+        ))
+    }
+
+    /// The period includes its start but not its end.
+    pub fn is_active_at(&self, at: DateTime<Utc>) -> bool {
+        self.starts_at <= at && at < self.ends_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::datetime::timestamp;
+
+    #[test]
+    fn new_temporary_rejects_invalid_campaigns() {
+        let start = timestamp(2025, 11, 22, 0, 0, 0);
+        let end = timestamp(2025, 11, 24, 0, 0, 0);
+
+        assert_eq!(
+            XpCampaign::new_temporary("Slow".to_owned(), 50, start, end).unwrap_err(),
+            XpCampaignError::MultiplierBelowBase(50)
+        );
+        assert_eq!(
+            XpCampaign::new_temporary("Backwards".to_owned(), 200, end, start).unwrap_err(),
+            XpCampaignError::EmptyPeriod
+        );
+    }
+
+    #[test]
+    fn is_active_at_excludes_the_end() {
+        let start = timestamp(2025, 11, 22, 0, 0, 0);
+        let end = timestamp(2025, 11, 24, 0, 0, 0);
+        let campaign = XpCampaign::new_temporary("Double XP".to_owned(), 200, start, end).unwrap();
+
+        assert!(campaign.is_active_at(start));
+        assert!(!campaign.is_active_at(end));
+        assert!(!campaign.is_active_at(timestamp(2025, 11, 21, 23, 59, 59)));
+    }
+}
@@ -0,0 +1,22 @@
+use std::future::Future;
+
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::api_key::ApiKey;
+
+#[derive(Debug, Error)]
+pub enum ApiKeyRepositoryError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+pub trait ApiKeyRepository: Send + Sync {
+    /// Looks up a key by the hash of its raw value. Revoked keys are returned as well so callers
+    /// can distinguish them from unknown keys.
+    fn find_by_key_hash(
+        &self,
+        key_hash: &str,
+    ) -> impl Future<Output = Result<Option<ApiKey>, ApiKeyRepositoryError>> + Send;
+}
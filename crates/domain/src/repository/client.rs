@@ -0,0 +1,40 @@
+use std::future::Future;
+
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::client::Client;
+
+#[derive(Debug, Error)]
+pub enum ClientRepositoryError {
+    #[error("Client not found: {0}")]
+    NotFound(String),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+pub trait ClientRepository: Send + Sync {
+    /// Persists a new client together with its first API key in a single unit of work.
+    fn create(
+        &self,
+        client: Client,
+        key_hash: String,
+    ) -> impl Future<Output = Result<Client, ClientRepositoryError>> + Send;
+    fn find_by_id(
+        &self,
+        client_id: &str,
+    ) -> impl Future<Output = Result<Option<Client>, ClientRepositoryError>> + Send;
+    /// Revokes every active key of the client and issues a new one with the given hash. Both
+    /// steps must happen atomically so the client is never left without a usable key.
+    fn rotate_key(
+        &self,
+        client_id: &str,
+        key_hash: String,
+    ) -> impl Future<Output = Result<(), ClientRepositoryError>> + Send;
+    /// Marks the client and all of its keys as revoked and returns the updated client.
+    fn revoke(
+        &self,
+        client_id: &str,
+    ) -> impl Future<Output = Result<Client, ClientRepositoryError>> + Send;
+}
@@ -1,33 +1,99 @@
+use std::future::Future;
+
 use crate::repository::{
+    api_key::{ApiKeyRepository, MockApiKeyRepository},
+    client::{ClientRepository, MockClientRepository},
     music::{MockMusicRepository, MusicRepository},
+    play::{MockPlayRepository, PlayRepository},
     record::{MockRecordRepository, RecordRepository},
+    rival::{MockRivalRepository, RivalRepository},
+    season::{MockSeasonRepository, SeasonRepository},
     user::{MockUserRepository, UserRepository},
+    xp_campaign::{MockXpCampaignRepository, XpCampaignRepository},
 };
 
+pub mod api_key;
+pub mod client;
 pub mod music;
+pub mod play;
+pub mod ranking;
 pub mod record;
+pub mod rival;
+pub mod season;
 pub mod user;
+pub mod xp_campaign;
 
 pub trait Repositories {
     type UserRepositoryImpl: UserRepository;
     type RecordRepositoryImpl: RecordRepository;
     type MusicRepositoryImpl: MusicRepository;
+    type ApiKeyRepositoryImpl: ApiKeyRepository;
+    type ClientRepositoryImpl: ClientRepository;
+    type PlayRepositoryImpl: PlayRepository;
+    type XpCampaignRepositoryImpl: XpCampaignRepository;
+    type SeasonRepositoryImpl: SeasonRepository;
+    type RivalRepositoryImpl: RivalRepository;
+    type UnitOfWork<'a>: UnitOfWork
+    where
+        Self: 'a;
+
+    fn user(&self) -> &Self::UserRepositoryImpl;
+    fn record(&self) -> &Self::RecordRepositoryImpl;
+    fn music(&self) -> &Self::MusicRepositoryImpl;
+    fn api_key(&self) -> &Self::ApiKeyRepositoryImpl;
+    fn client(&self) -> &Self::ClientRepositoryImpl;
+    fn play(&self) -> &Self::PlayRepositoryImpl;
+    fn xp_campaign(&self) -> &Self::XpCampaignRepositoryImpl;
+    fn season(&self) -> &Self::SeasonRepositoryImpl;
+    fn rival(&self) -> &Self::RivalRepositoryImpl;
+
+    /// Opens a unit of work whose writes become visible together on
+    /// [`UnitOfWork::commit`].
+    fn begin(&self) -> impl Future<Output = anyhow::Result<Self::UnitOfWork<'_>>> + Send;
+}
+
+/// The repositories that take part in a transaction. Dropping a unit of work without committing
+/// it rolls back everything written through it.
+pub trait UnitOfWork: Send + Sync {
+    type UserRepositoryImpl: UserRepository;
+    type RecordRepositoryImpl: RecordRepository;
+    type MusicRepositoryImpl: MusicRepository;
+    type PlayRepositoryImpl: PlayRepository;
 
     fn user(&self) -> &Self::UserRepositoryImpl;
     fn record(&self) -> &Self::RecordRepositoryImpl;
     fn music(&self) -> &Self::MusicRepositoryImpl;
+    fn play(&self) -> &Self::PlayRepositoryImpl;
+
+    fn commit(self) -> impl Future<Output = anyhow::Result<()>> + Send;
 }
 
+/// Every repository mocked. Tests start from [`Default`] and replace only the mocks they set
+/// expectations on.
+#[derive(Default)]
 pub struct MockRepositories {
     pub user: MockUserRepository,
     pub record: MockRecordRepository,
     pub music: MockMusicRepository,
+    pub api_key: MockApiKeyRepository,
+    pub client: MockClientRepository,
+    pub play: MockPlayRepository,
+    pub xp_campaign: MockXpCampaignRepository,
+    pub season: MockSeasonRepository,
+    pub rival: MockRivalRepository,
 }
 
 impl Repositories for MockRepositories {
     type UserRepositoryImpl = MockUserRepository;
     type RecordRepositoryImpl = MockRecordRepository;
     type MusicRepositoryImpl = MockMusicRepository;
+    type ApiKeyRepositoryImpl = MockApiKeyRepository;
+    type ClientRepositoryImpl = MockClientRepository;
+    type PlayRepositoryImpl = MockPlayRepository;
+    type XpCampaignRepositoryImpl = MockXpCampaignRepository;
+    type SeasonRepositoryImpl = MockSeasonRepository;
+    type RivalRepositoryImpl = MockRivalRepository;
+    type UnitOfWork<'a> = &'a MockRepositories;
 
     fn user(&self) -> &Self::UserRepositoryImpl {
         &self.user
@@ -40,4 +106,61 @@ impl Repositories for MockRepositories {
     fn music(&self) -> &Self::MusicRepositoryImpl {
         &self.music
     }
+
+    fn api_key(&self) -> &Self::ApiKeyRepositoryImpl {
+        &self.api_key
+    }
+
+    fn client(&self) -> &Self::ClientRepositoryImpl {
+        &self.client
+    }
+
+    fn play(&self) -> &Self::PlayRepositoryImpl {
+        &self.play
+    }
+
+    fn xp_campaign(&self) -> &Self::XpCampaignRepositoryImpl {
+        &self.xp_campaign
+    }
+
+    fn season(&self) -> &Self::SeasonRepositoryImpl {
+        &self.season
+    }
+
+    fn rival(&self) -> &Self::RivalRepositoryImpl {
+        &self.rival
+    }
+
+    async fn begin(&self) -> anyhow::Result<Self::UnitOfWork<'_>> {
+        Ok(self)
+    }
+}
+
+/// Mocks have nothing to roll back, so the unit of work simply shares the expectations set on
+/// [`MockRepositories`].
+impl UnitOfWork for &MockRepositories {
+    type UserRepositoryImpl = MockUserRepository;
+    type RecordRepositoryImpl = MockRecordRepository;
+    type MusicRepositoryImpl = MockMusicRepository;
+    type PlayRepositoryImpl = MockPlayRepository;
+
+    fn user(&self) -> &Self::UserRepositoryImpl {
+        &self.user
+    }
+
+    fn record(&self) -> &Self::RecordRepositoryImpl {
+        &self.record
+    }
+
+    fn music(&self) -> &Self::MusicRepositoryImpl {
+        &self.music
+    }
+
+    fn play(&self) -> &Self::PlayRepositoryImpl {
+        &self.play
+    }
+
+    async fn commit(self) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
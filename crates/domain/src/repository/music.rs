@@ -1,12 +1,26 @@
-use std::future::Future;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
+};
 
+use chrono::{DateTime, Utc};
 use mockall::automock;
 use thiserror::Error;
 
-use crate::entity::{music::Music, sheet::Sheet};
+use crate::entity::{difficulty::Difficulty, music::Music, sheet::Sheet};
 
 #[derive(Debug, Error)]
 pub enum MusicRepositoryError {
+    #[error("Music not found: {0}")]
+    MusicNotFound(String),
+    #[error("Sheet not found: {0}")]
+    SheetNotFound(String),
+    /// Raised when a music already has an active sheet of the same difficulty.
+    #[error("Sheet already exists for music {music_id} at difficulty {difficulty}")]
+    SheetAlreadyExists {
+        music_id: String,
+        difficulty: Difficulty,
+    },
     #[error(transparent)]
     InternalError(#[from] anyhow::Error),
 }
@@ -23,9 +37,156 @@ impl MusicWithSheets {
     }
 }
 
+#[derive(Debug)]
+pub struct MusicTombstone {
+    pub id: String,
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl MusicTombstone {
+    pub fn new(id: String, deleted_at: DateTime<Utc>) -> Self {
+        Self { id, deleted_at }
+    }
+}
+
+#[derive(Debug)]
+pub struct SheetTombstone {
+    pub id: String,
+    pub music_id: String,
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl SheetTombstone {
+    pub fn new(id: String, music_id: String, deleted_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            music_id,
+            deleted_at,
+        }
+    }
+}
+
+/// Catalog changes after a point in time.
+#[derive(Debug)]
+pub struct CatalogDelta {
+    /// Live musics that changed themselves or through one of their sheets. Each carries all of
+    /// its live sheets, so a client can replace its copy of the music wholesale.
+    pub musics: Vec<MusicWithSheets>,
+    pub removed_musics: Vec<MusicTombstone>,
+    pub removed_sheets: Vec<SheetTombstone>,
+    /// The point up to which the delta is complete, to be passed as the next `since`. Taken from
+    /// the database clock of the snapshot the delta was read from; equals the requested `since`
+    /// when nothing changed.
+    pub synced_at: DateTime<Utc>,
+}
+
+/// Locates a row of a catalog manifest: an entry and, for sheet rows, the sheet within that entry.
+/// Both indices are zero-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CatalogRow {
+    pub entry: usize,
+    pub sheet: Option<usize>,
+}
+
+impl CatalogRow {
+    pub fn music(entry: usize) -> Self {
+        Self { entry, sheet: None }
+    }
+
+    pub fn sheet(entry: usize, sheet: usize) -> Self {
+        Self {
+            entry,
+            sheet: Some(sheet),
+        }
+    }
+}
+
+impl Display for CatalogRow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.sheet {
+            Some(sheet) => write!(f, "[{}].sheets[{}]", self.entry, sheet),
+            None => write!(f, "[{}].music", self.entry),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CatalogRowError {
+    pub row: CatalogRow,
+    pub message: String,
+}
+
+impl CatalogRowError {
+    pub fn new(row: CatalogRow, message: String) -> Self {
+        Self { row, message }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CatalogImportReport {
+    /// Musics written, or that would have been written on a dry run.
+    pub musics: usize,
+    /// Sheets written, or that would have been written on a dry run.
+    pub sheets: usize,
+    pub failures: Vec<CatalogRowError>,
+    pub committed: bool,
+}
+
 #[automock]
 pub trait MusicRepository: Send + Sync {
+    /// Lists every music that has not been deleted, together with its remaining sheets.
     fn list_with_sheets(
         &self,
     ) -> impl Future<Output = Result<Vec<MusicWithSheets>, MusicRepositoryError>> + Send;
+    /// Lists musics and sheets added, changed or archived strictly after `since`. Reads from a
+    /// snapshot of its own, so it cannot be called inside a unit of work.
+    fn list_changed_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> impl Future<Output = Result<CatalogDelta, MusicRepositoryError>> + Send;
+    /// Returns the live sheets among `sheet_ids`. Unknown and archived ids are left out.
+    fn find_sheets_by_ids(
+        &self,
+        sheet_ids: &[String],
+    ) -> impl Future<Output = Result<Vec<Sheet>, MusicRepositoryError>> + Send;
+    /// Persists a new music. An empty id lets storage assign one.
+    fn create_music(
+        &self,
+        music: Music,
+    ) -> impl Future<Output = Result<Music, MusicRepositoryError>> + Send;
+    fn update_music(
+        &self,
+        music: Music,
+    ) -> impl Future<Output = Result<Music, MusicRepositoryError>> + Send;
+    /// Archives the music and all of its sheets. Rows are kept so that records referencing the
+    /// sheets survive; archived entries simply disappear from listings.
+    fn delete_music(
+        &self,
+        music_id: &str,
+    ) -> impl Future<Output = Result<(), MusicRepositoryError>> + Send;
+    /// Persists a new sheet. An empty id lets storage assign one.
+    fn create_sheet(
+        &self,
+        sheet: Sheet,
+    ) -> impl Future<Output = Result<Sheet, MusicRepositoryError>> + Send;
+    fn update_sheet(
+        &self,
+        sheet: Sheet,
+    ) -> impl Future<Output = Result<Sheet, MusicRepositoryError>> + Send;
+    /// Archives a single sheet instead of deleting it, so that the `records` foreign key does
+    /// not cascade and wipe player scores.
+    fn delete_sheet(
+        &self,
+        sheet_id: &str,
+    ) -> impl Future<Output = Result<(), MusicRepositoryError>> + Send;
+    /// Upserts the entries by id inside a single transaction, restoring archived rows that
+    /// reappear. Each sheet is attached to the music of its entry. A failing row is reported
+    /// rather than aborting the import, and the transaction is only committed when every row
+    /// succeeded and `dry_run` is false. Inside a unit of work the import runs in a savepoint, and
+    /// committing it leaves the outcome to the unit of work.
+    fn import_catalog(
+        &self,
+        entries: Vec<MusicWithSheets>,
+        dry_run: bool,
+    ) -> impl Future<Output = Result<CatalogImportReport, MusicRepositoryError>> + Send;
 }
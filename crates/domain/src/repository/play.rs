@@ -0,0 +1,136 @@
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use thiserror::Error;
+
+use crate::{
+    entity::{level::Level, play::Play, submission_result::SubmissionResult},
+    repository::{ranking::RankingWindow, record::TotalScoreRankingRow},
+};
+
+#[derive(Debug, Error)]
+pub enum PlayRepositoryError {
+    #[error("User not found: {0}")]
+    UserNotFound(String),
+    #[error("Sheet not found: {0}")]
+    SheetNotFound(String),
+    #[error("Submission already recorded: {0}")]
+    DuplicateSubmission(String),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[derive(Debug)]
+pub struct PlayPage {
+    pub plays: Vec<Play>,
+    /// Number of plays the user has in total, regardless of the requested page.
+    pub total: u64,
+}
+
+impl PlayPage {
+    pub fn new(plays: Vec<Play>, total: u64) -> Self {
+        Self { plays, total }
+    }
+}
+
+/// Score of a single play together with the level of its sheet, for the recent rating frame.
+#[derive(Debug)]
+pub struct RecentPlayScore {
+    pub sheet_id: String,
+    pub level: Level,
+    pub score: u32,
+}
+
+impl RecentPlayScore {
+    pub fn new(sheet_id: String, level: Level, score: u32) -> Self {
+        Self {
+            sheet_id,
+            level,
+            score,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlayCountRankingRow {
+    pub rank: u32,
+    pub user_id: String,
+    pub display_name: String,
+    pub plays: u64,
+}
+
+impl PlayCountRankingRow {
+    pub fn new(rank: u32, user_id: String, display_name: String, plays: u64) -> Self {
+        Self {
+            rank,
+            user_id,
+            display_name,
+            plays,
+        }
+    }
+}
+
+#[automock]
+pub trait PlayRepository: Send + Sync {
+    /// Appends plays to the log in a single statement. Fails with `DuplicateSubmission` if any
+    /// play reuses a submission id that is already stored.
+    fn append(
+        &self,
+        plays: Vec<Play>,
+    ) -> impl Future<Output = Result<(), PlayRepositoryError>> + Send;
+
+    /// Returns the subset of `submission_ids` that the user has already submitted.
+    fn find_submitted_ids(
+        &self,
+        user_id: &str,
+        submission_ids: &[String],
+    ) -> impl Future<Output = Result<Vec<String>, PlayRepositoryError>> + Send;
+
+    /// Stores what each submission was answered with, for replays of the same request.
+    fn save_results(
+        &self,
+        user_id: &str,
+        results: Vec<SubmissionResult>,
+    ) -> impl Future<Output = Result<(), PlayRepositoryError>> + Send;
+
+    /// Returns the stored results of those `submission_ids` the user has submitted. Plays logged
+    /// before results were kept have none.
+    fn find_results(
+        &self,
+        user_id: &str,
+        submission_ids: &[String],
+    ) -> impl Future<Output = Result<Vec<SubmissionResult>, PlayRepositoryError>> + Send;
+
+    /// Returns a page of the user's plays, newest first.
+    fn find_by_user_id(
+        &self,
+        user_id: &str,
+        limit: u64,
+        offset: u64,
+    ) -> impl Future<Output = Result<PlayPage, PlayRepositoryError>> + Send;
+
+    /// Newest first, excluding plays of test musics.
+    fn find_recent_scores(
+        &self,
+        user_id: &str,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<RecentPlayScore>, PlayRepositoryError>> + Send;
+
+    /// Ranks public users by the sum of their best score per sheet among plays made between
+    /// `from` (inclusive) and `to` (exclusive), and returns the requested window.
+    fn find_public_total_score_ranking_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        window: &RankingWindow,
+    ) -> impl Future<Output = Result<Vec<TotalScoreRankingRow>, PlayRepositoryError>> + Send;
+
+    /// Ranks public users by how many plays they made, optionally only between `from`
+    /// (inclusive) and `to` (exclusive), and returns the requested window.
+    fn find_public_play_count_ranking(
+        &self,
+        between: Option<(DateTime<Utc>, DateTime<Utc>)>,
+        window: &RankingWindow,
+    ) -> impl Future<Output = Result<Vec<PlayCountRankingRow>, PlayRepositoryError>> + Send;
+}
@@ -0,0 +1,29 @@
+use crate::entity::{difficulty::Difficulty, genre::Genre, level::Level};
+
+/// The slice of a ranking a query should return. Ranks are always computed over the whole ranking,
+/// and tied entries share a rank.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RankingWindow {
+    /// `limit` entries after skipping the first `offset`.
+    Page { offset: u64, limit: u64 },
+    /// The entry of `user_id` with up to `neighbors` entries on either side. Empty when the user
+    /// does not appear in the ranking.
+    Around { user_id: String, neighbors: u64 },
+}
+
+/// Restricts a record ranking to the sheets that match every given criterion. Level bounds are
+/// inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RankingFilter {
+    pub difficulty: Option<Difficulty>,
+    pub genre: Option<Genre>,
+    pub min_level: Option<Level>,
+    pub max_level: Option<Level>,
+}
+
+impl RankingFilter {
+    /// True when no criterion is set, so the ranking covers every sheet.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
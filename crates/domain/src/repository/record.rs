@@ -1,9 +1,18 @@
-use std::future::Future;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
+    str::FromStr,
+};
 
 use mockall::automock;
 use thiserror::Error;
 
-use crate::entity::{level::Level, record::Record};
+use crate::{
+    entity::{
+        clear_type::ClearType, difficulty::Difficulty, grade::Grade, level::Level, record::Record,
+    },
+    repository::ranking::{RankingFilter, RankingWindow},
+};
 
 #[derive(Debug, Error)]
 pub enum RecordRepositoryError {
@@ -19,35 +28,66 @@ pub enum RecordRepositoryError {
     InternalError(#[from] anyhow::Error),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RecordWithMetadata {
     pub record: Record,
+    pub difficulty: Difficulty,
     pub level: Level,
     pub is_test: bool,
 }
 
 impl RecordWithMetadata {
-    pub fn new(record: Record, level: Level, is_test: bool) -> Self {
+    pub fn new(record: Record, difficulty: Difficulty, level: Level, is_test: bool) -> Self {
         Self {
             record,
+            difficulty,
             level,
             is_test,
         }
     }
 }
 
+/// A submission folded into a record, together with the sheet rating of its score. The rating is
+/// stored alongside the record so that the user's best sheets can be found without recomputing
+/// every record.
+#[derive(Debug, Clone)]
+pub struct RecordUpsert {
+    pub record: Record,
+    pub rating: u32,
+}
+
+impl RecordUpsert {
+    pub fn new(record: Record, rating: u32) -> Self {
+        Self { record, rating }
+    }
+}
+
 /// Represents a scoreboard row for a single sheet. Implementations must ensure rows are sorted in
 /// descending order by `score` before handing them to the domain layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SheetRating {
+    pub sheet_id: String,
+    pub rating: u32,
+}
+
+impl SheetRating {
+    pub fn new(sheet_id: String, rating: u32) -> Self {
+        Self { sheet_id, rating }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SheetScoreRankingRow {
+    pub rank: u32,
     pub user_id: String,
     pub display_name: String,
     pub score: u32,
 }
 
 impl SheetScoreRankingRow {
-    pub fn new(user_id: String, display_name: String, score: u32) -> Self {
+    pub fn new(rank: u32, user_id: String, display_name: String, score: u32) -> Self {
         Self {
+            rank,
             user_id,
             display_name,
             score,
@@ -59,14 +99,16 @@ impl SheetScoreRankingRow {
 /// stable SUM(...) and exclude non-public users at the persistence layer.
 #[derive(Debug, Clone)]
 pub struct TotalScoreRankingRow {
+    pub rank: u32,
     pub user_id: String,
     pub display_name: String,
     pub total_score: u64,
 }
 
 impl TotalScoreRankingRow {
-    pub fn new(user_id: String, display_name: String, total_score: u64) -> Self {
+    pub fn new(rank: u32, user_id: String, display_name: String, total_score: u64) -> Self {
         Self {
+            rank,
             user_id,
             display_name,
             total_score,
@@ -74,6 +116,114 @@ impl TotalScoreRankingRow {
     }
 }
 
+/// Number of cleared sheets per user. A sheet counts once its record has any lamp above failed.
+#[derive(Debug, Clone)]
+pub struct ClearCountRankingRow {
+    pub rank: u32,
+    pub user_id: String,
+    pub display_name: String,
+    pub clears: u64,
+}
+
+impl ClearCountRankingRow {
+    pub fn new(rank: u32, user_id: String, display_name: String, clears: u64) -> Self {
+        Self {
+            rank,
+            user_id,
+            display_name,
+            clears,
+        }
+    }
+}
+
+/// Restricts a record listing to the records that match every given criterion.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordFilter {
+    pub sheet: RankingFilter,
+    pub clear_type: Option<ClearType>,
+    pub music_id: Option<String>,
+    pub min_grade: Option<Grade>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RecordSort {
+    Score,
+    Level,
+    #[default]
+    UpdatedAt,
+    PlayCount,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Position after the last record of a page: the sort key of that record and its id, which breaks
+/// ties. Rendered as `<key>.<record id>` so that clients can pass it back verbatim.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordCursor {
+    /// The score, level (times ten), play count or `updated_at` in microseconds since the epoch.
+    pub key: i64,
+    pub record_id: String,
+}
+
+#[derive(Debug, Error)]
+#[error("Invalid record cursor: {0}")]
+pub struct RecordCursorError(String);
+
+impl Display for RecordCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}.{}", self.key, self.record_id)
+    }
+}
+
+impl FromStr for RecordCursor {
+    type Err = RecordCursorError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || RecordCursorError(value.to_owned());
+        let (key, record_id) = value.split_once('.').ok_or_else(invalid)?;
+        if record_id.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            key: key.parse().map_err(|_| invalid())?,
+            record_id: record_id.to_owned(),
+        })
+    }
+}
+
+/// Which of a user's records to list, in what order, and how many. Without a `limit` every
+/// matching record is returned.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordQuery {
+    pub filter: RecordFilter,
+    pub sort: RecordSort,
+    pub order: SortOrder,
+    pub after: Option<RecordCursor>,
+    pub limit: Option<u64>,
+}
+
+/// A record together with its sheet and the title of the sheet's music.
+#[derive(Debug, Clone)]
+pub struct RecordDetail {
+    pub record: Record,
+    pub music_id: String,
+    pub music_title: String,
+    pub difficulty: Difficulty,
+    pub level: Level,
+}
+
+#[derive(Debug)]
+pub struct RecordDetailPage {
+    pub records: Vec<RecordDetail>,
+    /// Set when more records match the query than fit on this page.
+    pub next_cursor: Option<RecordCursor>,
+}
+
 #[automock]
 pub trait RecordRepository: Send + Sync {
     fn find_by_user_id(
@@ -85,6 +235,14 @@ pub trait RecordRepository: Send + Sync {
         user_id: &str,
     ) -> impl Future<Output = Result<Vec<RecordWithMetadata>, RecordRepositoryError>> + Send;
 
+    /// Lists the user's records matching `query` with their sheet and music title, ordered by the
+    /// requested key and then by record id.
+    fn find_details_by_user_id(
+        &self,
+        user_id: &str,
+        query: &RecordQuery,
+    ) -> impl Future<Output = Result<RecordDetailPage, RecordRepositoryError>> + Send;
+
     /// Loads records for the specified user and sheet IDs. More efficient than loading all records
     /// when only a subset is needed. Returns an empty vector if none of the specified sheet IDs
     /// have records.
@@ -94,35 +252,70 @@ pub trait RecordRepository: Send + Sync {
         sheet_ids: &[String],
     ) -> impl Future<Output = Result<Vec<Record>, RecordRepositoryError>> + Send;
 
-    /// Persists a new record aggregate. Callers must guarantee that the record identifier is
-    /// unique; the repository will generate an error if the tuple `(user_id, sheet_id)` already
-    /// exists.
-    fn insert(
+    /// Inserts or merges the given records in a single statement and returns the stored rows.
+    /// Existing rows are merged the same way as [`Record::apply_submission`]: play counts add up,
+    /// the best score carries its judgement, max combo and rating, and the best clear type wins.
+    /// Each `(user_id, sheet_id)` may appear at most once per call.
+    fn upsert_many(
         &self,
-        record: Record,
-    ) -> impl Future<Output = Result<Record, RecordRepositoryError>> + Send;
+        records: Vec<RecordUpsert>,
+    ) -> impl Future<Output = Result<Vec<Record>, RecordRepositoryError>> + Send;
 
-    /// Persists changes to an existing record aggregate, matching rows by the primary key.
-    fn update(
+    /// Returns the user's highest stored sheet ratings in descending order, excluding sheets of
+    /// test musics.
+    fn find_top_sheet_ratings(
         &self,
-        record: Record,
-    ) -> impl Future<Output = Result<Record, RecordRepositoryError>> + Send;
+        user_id: &str,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<u32>, RecordRepositoryError>> + Send;
 
     /// Returns the sum of record scores across the entire catalog. Implementations must default to
     /// zero when no records are present to keep the aggregation stable for dashboards.
+    /// Overwrites the cached sheet ratings of the user's records, e.g. after the rating formula
+    /// changed. Unlike [`upsert_many`](Self::upsert_many) this may lower a rating.
+    fn update_ratings(
+        &self,
+        user_id: &str,
+        ratings: Vec<SheetRating>,
+    ) -> impl Future<Output = Result<(), RecordRepositoryError>> + Send;
+
+    /// Lists the users holding a record on any of the sheets, ordered by id, e.g. to re-rate them
+    /// after a sheet's level changed.
+    fn find_user_ids_by_sheet_ids(
+        &self,
+        sheet_ids: &[String],
+    ) -> impl Future<Output = Result<Vec<String>, RecordRepositoryError>> + Send;
+
     fn sum_scores(&self) -> impl Future<Output = Result<u64, RecordRepositoryError>> + Send;
 
-    /// Retrieves the highest scores for the supplied sheet. Persistence adapters must filter out
-    /// non-public users in this query because visibility flags are enforced by the users table.
+    /// Ranks the scores on the supplied sheet and returns the requested window. Persistence
+    /// adapters must filter out non-public users in this query because visibility flags are
+    /// enforced by the users table.
     fn find_public_high_scores_by_sheet(
         &self,
         sheet_id: &str,
-        limit: u64,
+        window: &RankingWindow,
     ) -> impl Future<Output = Result<Vec<SheetScoreRankingRow>, RecordRepositoryError>> + Send;
 
-    /// Aggregates total record scores per public user and returns the top rows in descending order.
+    /// Aggregates total record scores per public user, ranks them in descending order and returns
+    /// the requested window.
     fn find_public_total_score_ranking(
         &self,
-        limit: u64,
+        window: &RankingWindow,
     ) -> impl Future<Output = Result<Vec<TotalScoreRankingRow>, RecordRepositoryError>> + Send;
+
+    /// Like [`Self::find_public_total_score_ranking`], but only sums records on sheets that match
+    /// `filter`. Users without such records are left out.
+    fn find_public_filtered_total_score_ranking(
+        &self,
+        filter: &RankingFilter,
+        window: &RankingWindow,
+    ) -> impl Future<Output = Result<Vec<TotalScoreRankingRow>, RecordRepositoryError>> + Send;
+
+    /// Ranks public users by how many sheets matching `filter` they have cleared.
+    fn find_public_clear_count_ranking(
+        &self,
+        filter: &RankingFilter,
+        window: &RankingWindow,
+    ) -> impl Future<Output = Result<Vec<ClearCountRankingRow>, RecordRepositoryError>> + Send;
 }
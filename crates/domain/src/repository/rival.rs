@@ -0,0 +1,36 @@
+use std::future::Future;
+
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::rival::Rival;
+
+#[derive(Debug, Error)]
+pub enum RivalRepositoryError {
+    #[error("User not found: {0}")]
+    UserNotFound(String),
+    #[error("Rival {rival_id} is already registered by user {user_id}")]
+    AlreadyRegistered { user_id: String, rival_id: String },
+    #[error("Rival {rival_id} is not registered by user {user_id}")]
+    NotFound { user_id: String, rival_id: String },
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+pub trait RivalRepository: Send + Sync {
+    fn create(
+        &self,
+        rival: Rival,
+    ) -> impl Future<Output = Result<Rival, RivalRepositoryError>> + Send;
+    /// The rivals `user_id` registered, oldest registration first.
+    fn find_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Vec<Rival>, RivalRepositoryError>> + Send;
+    fn delete(
+        &self,
+        user_id: &str,
+        rival_id: &str,
+    ) -> impl Future<Output = Result<(), RivalRepositoryError>> + Send;
+}
@@ -0,0 +1,96 @@
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use thiserror::Error;
+
+use crate::{
+    entity::season::{Season, SeasonBoard, SeasonPeriod},
+    repository::ranking::RankingWindow,
+};
+
+#[derive(Debug, Error)]
+pub enum SeasonRepositoryError {
+    #[error("Season not found: {0}")]
+    NotFound(String),
+    #[error("a {0:?} season already covers part of that period")]
+    Overlapping(SeasonPeriod),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+/// A user's final placement on one board of a finished season.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeasonStanding {
+    pub board: SeasonBoard,
+    pub rank: u32,
+    pub user_id: String,
+    pub display_name: String,
+    /// The total score or play count, depending on the board.
+    pub value: u64,
+}
+
+impl SeasonStanding {
+    pub fn new(
+        board: SeasonBoard,
+        rank: u32,
+        user_id: String,
+        display_name: String,
+        value: u64,
+    ) -> Self {
+        Self {
+            board,
+            rank,
+            user_id,
+            display_name,
+            value,
+        }
+    }
+}
+
+#[automock]
+pub trait SeasonRepository: Send + Sync {
+    /// Stores a new season, rejecting it with [`SeasonRepositoryError::Overlapping`] when another
+    /// season of the same period overlaps it.
+    fn create(
+        &self,
+        season: Season,
+    ) -> impl Future<Output = Result<Season, SeasonRepositoryError>> + Send;
+
+    /// Every season, latest start first.
+    fn find_all(&self) -> impl Future<Output = Result<Vec<Season>, SeasonRepositoryError>> + Send;
+
+    fn find_by_id(
+        &self,
+        season_id: &str,
+    ) -> impl Future<Output = Result<Option<Season>, SeasonRepositoryError>> + Send;
+
+    /// The season of `period` whose period contains `at`.
+    fn find_active_at(
+        &self,
+        period: SeasonPeriod,
+        at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Option<Season>, SeasonRepositoryError>> + Send;
+
+    /// The archived season of `period` that ended last.
+    fn find_latest_archived(
+        &self,
+        period: SeasonPeriod,
+    ) -> impl Future<Output = Result<Option<Season>, SeasonRepositoryError>> + Send;
+
+    /// Stores the final standings and marks the season archived, all at once. A season that is
+    /// already archived is returned untouched, so concurrent callers cannot duplicate standings.
+    fn archive(
+        &self,
+        season_id: &str,
+        standings: Vec<SeasonStanding>,
+    ) -> impl Future<Output = Result<Season, SeasonRepositoryError>> + Send;
+
+    /// The archived standings of one board, ordered by rank.
+    fn find_standings(
+        &self,
+        season_id: &str,
+        board: SeasonBoard,
+        window: &RankingWindow,
+    ) -> impl Future<Output = Result<Vec<SeasonStanding>, SeasonRepositoryError>> + Send;
+}
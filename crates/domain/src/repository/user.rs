@@ -1,9 +1,13 @@
 use std::future::Future;
 
+use chrono::NaiveDate;
 use mockall::automock;
 use thiserror::Error;
 
-use crate::entity::{user::User, user_play_option::UserPlayOption};
+use crate::{
+    entity::{user::User, user_play_option::UserPlayOption, user_progress::UserProgress},
+    repository::ranking::RankingWindow,
+};
 
 #[derive(Debug, Error)]
 pub enum UserRepositoryError {
@@ -17,6 +21,19 @@ pub enum UserRepositoryError {
     InternalError(#[from] anyhow::Error),
 }
 
+/// A user together with their position in a ranking.
+#[derive(Debug)]
+pub struct RankedUser {
+    pub rank: u32,
+    pub user: User,
+}
+
+impl RankedUser {
+    pub fn new(rank: u32, user: User) -> Self {
+        Self { rank, user }
+    }
+}
+
 #[automock]
 pub trait UserRepository: Send + Sync {
     fn create(&self, user: User) -> impl Future<Output = Result<User, UserRepositoryError>> + Send;
@@ -26,10 +43,12 @@ pub trait UserRepository: Send + Sync {
     ) -> impl Future<Output = Result<Option<User>, UserRepositoryError>> + Send;
     /// Executes an atomic credit increment for the aggregate and returns the persisted value.
     /// Implementations must delegate the increment to the storage backend to avoid lost updates
-    /// when multiple cabinets consume credits concurrently.
+    /// when multiple cabinets consume credits concurrently, and log `client_id` as the cabinet
+    /// that consumed the credit.
     fn increment_credits(
         &self,
         user_id: &str,
+        client_id: &str,
     ) -> impl Future<Output = Result<u32, UserRepositoryError>> + Send;
 
     fn find_by_id(
@@ -37,9 +56,20 @@ pub trait UserRepository: Send + Sync {
         user_id: &str,
     ) -> impl Future<Output = Result<Option<User>, UserRepositoryError>> + Send;
 
+    /// Like [`UserRepository::find_by_id`], but locks the user's row until the surrounding unit
+    /// of work ends. Read-modify-write updates of xp and rating load the user through it so that
+    /// concurrent updates queue up instead of overwriting each other.
+    fn find_by_id_for_update(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Option<User>, UserRepositoryError>> + Send;
+
     fn save(&self, user: User) -> impl Future<Output = Result<User, UserRepositoryError>> + Send;
 
     /// Returns the total number of persisted user aggregates.
+    fn find_all_ids(&self)
+    -> impl Future<Output = Result<Vec<String>, UserRepositoryError>> + Send;
+
     fn count_all(&self) -> impl Future<Output = Result<u64, UserRepositoryError>> + Send;
 
     /// Sums the `credits` field across all user aggregates. Implementations must default to zero
@@ -58,15 +88,36 @@ pub trait UserRepository: Send + Sync {
 
     /// Fetches public user aggregates ordered by rating in descending order. Implementations must
     /// apply the visibility filter and return at most `limit` rows.
-    fn find_public_top_by_rating(
+    /// Stores the snapshot for its day, replacing one recorded earlier that day.
+    fn save_progress(
         &self,
-        limit: u64,
-    ) -> impl Future<Output = Result<Vec<User>, UserRepositoryError>> + Send;
+        progress: UserProgress,
+    ) -> impl Future<Output = Result<(), UserRepositoryError>> + Send;
 
-    /// Fetches public user aggregates ordered by XP in descending order. Implementations must
-    /// apply the visibility filter and return at most `limit` rows.
-    fn find_public_top_by_xp(
+    /// Deletes the user's snapshots before `before` except the last one of each ISO week.
+    fn thin_progress(
+        &self,
+        user_id: &str,
+        before: NaiveDate,
+    ) -> impl Future<Output = Result<(), UserRepositoryError>> + Send;
+
+    /// Snapshots between `from` and `to` inclusive, oldest first.
+    fn find_progress(
+        &self,
+        user_id: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> impl Future<Output = Result<Vec<UserProgress>, UserRepositoryError>> + Send;
+
+    fn find_public_rating_ranking(
+        &self,
+        window: &RankingWindow,
+    ) -> impl Future<Output = Result<Vec<RankedUser>, UserRepositoryError>> + Send;
+
+    /// Ranks public users by XP in descending order and returns the requested window.
+    /// Implementations must apply the visibility filter before ranking.
+    fn find_public_xp_ranking(
         &self,
-        limit: u64,
-    ) -> impl Future<Output = Result<Vec<User>, UserRepositoryError>> + Send;
+        window: &RankingWindow,
+    ) -> impl Future<Output = Result<Vec<RankedUser>, UserRepositoryError>> + Send;
 }
@@ -0,0 +1,36 @@
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use thiserror::Error;
+
+use crate::entity::xp_campaign::XpCampaign;
+
+#[derive(Debug, Error)]
+pub enum XpCampaignRepositoryError {
+    #[error("XP campaign not found: {0}")]
+    NotFound(String),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+#[automock]
+pub trait XpCampaignRepository: Send + Sync {
+    fn create(
+        &self,
+        campaign: XpCampaign,
+    ) -> impl Future<Output = Result<XpCampaign, XpCampaignRepositoryError>> + Send;
+    /// Every campaign, latest start first.
+    fn find_all(
+        &self,
+    ) -> impl Future<Output = Result<Vec<XpCampaign>, XpCampaignRepositoryError>> + Send;
+    /// Campaigns whose period contains `at`.
+    fn find_active_at(
+        &self,
+        at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<XpCampaign>, XpCampaignRepositoryError>> + Send;
+    fn delete(
+        &self,
+        campaign_id: &str,
+    ) -> impl Future<Output = Result<(), XpCampaignRepositoryError>> + Send;
+}
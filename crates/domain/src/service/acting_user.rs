@@ -0,0 +1,171 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Shortest secret accepted for signing acting user tokens, matching the HMAC-SHA256 block of
+/// entropy an operator is expected to generate.
+pub const MIN_SECRET_BYTES: usize = 32;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ActingUserTokenError {
+    #[error("Acting user tokens are not accepted because no signing secret is configured")]
+    Disabled,
+    #[error("Acting user token signing secret must be at least {MIN_SECRET_BYTES} bytes")]
+    WeakSecret,
+    #[error("Malformed acting user token")]
+    Malformed,
+    #[error("Acting user token signature does not match")]
+    BadSignature,
+    #[error("Acting user token has expired")]
+    Expired,
+}
+
+/// Signs and verifies the tokens a web backend mints for its signed-in users. A token reads
+/// `<user id>.<expiry as unix seconds>.<hex HMAC-SHA256 of the first two parts>`, so only a
+/// holder of the shared secret can name the user a web request acts for.
+///
+/// The `Default` signer has no secret and rejects every token.
+#[derive(Clone, Default)]
+pub struct ActingUserSigner {
+    secret: Option<Vec<u8>>,
+}
+
+impl ActingUserSigner {
+    pub fn new(secret: impl Into<Vec<u8>>) -> Result<Self, ActingUserTokenError> {
+        let secret = secret.into();
+        if secret.len() < MIN_SECRET_BYTES {
+            return Err(ActingUserTokenError::WeakSecret);
+        }
+        Ok(Self {
+            secret: Some(secret),
+        })
+    }
+
+    pub fn sign(
+        &self,
+        user_id: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<String, ActingUserTokenError> {
+        let payload = format!("{user_id}.{}", expires_at.timestamp());
+        let mut mac = self.mac()?;
+        mac.update(payload.as_bytes());
+        Ok(format!(
+            "{payload}.{}",
+            hex::encode(mac.finalize().into_bytes())
+        ))
+    }
+
+    /// Returns the user a token names once its signature checks out and it has not expired.
+    pub fn verify(&self, token: &str, now: DateTime<Utc>) -> Result<String, ActingUserTokenError> {
+        let mut mac = self.mac()?;
+        let (payload, signature) = token
+            .rsplit_once('.')
+            .ok_or(ActingUserTokenError::Malformed)?;
+        let (user_id, expires_at) = payload
+            .split_once('.')
+            .ok_or(ActingUserTokenError::Malformed)?;
+        let expires_at: i64 = expires_at
+            .parse()
+            .map_err(|_| ActingUserTokenError::Malformed)?;
+        let signature = hex::decode(signature).map_err(|_| ActingUserTokenError::Malformed)?;
+
+        mac.update(payload.as_bytes());
+        mac.verify_slice(&signature)
+            .map_err(|_| ActingUserTokenError::BadSignature)?;
+        if user_id.is_empty() {
+            return Err(ActingUserTokenError::Malformed);
+        }
+        if now.timestamp() >= expires_at {
+            return Err(ActingUserTokenError::Expired);
+        }
+        Ok(user_id.to_owned())
+    }
+
+    fn mac(&self) -> Result<HmacSha256, ActingUserTokenError> {
+        let secret = self
+            .secret
+            .as_deref()
+            .ok_or(ActingUserTokenError::Disabled)?;
+        Ok(HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::testing::datetime::sample_timestamp;
+
+    const SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+    const USER_ID: &str = "11111111-1111-1111-1111-111111111111";
+
+    fn signer() -> ActingUserSigner {
+        ActingUserSigner::new(SECRET).unwrap()
+    }
+
+    #[test]
+    fn verify_returns_the_signed_user() {
+        let now = sample_timestamp();
+        let token = signer().sign(USER_ID, now + Duration::minutes(5)).unwrap();
+
+        assert_eq!(signer().verify(&token, now), Ok(USER_ID.to_owned()));
+    }
+
+    #[test]
+    fn verify_rejects_tokens_naming_another_user() {
+        let now = sample_timestamp();
+        let token = signer().sign(USER_ID, now + Duration::minutes(5)).unwrap();
+        let forged = token.replacen(USER_ID, "22222222-2222-2222-2222-222222222222", 1);
+
+        assert_eq!(
+            signer().verify(&forged, now),
+            Err(ActingUserTokenError::BadSignature)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_tokens_from_another_secret() {
+        let now = sample_timestamp();
+        let other = ActingUserSigner::new(b"fedcba9876543210fedcba9876543210".to_vec()).unwrap();
+        let token = other.sign(USER_ID, now + Duration::minutes(5)).unwrap();
+
+        assert_eq!(
+            signer().verify(&token, now),
+            Err(ActingUserTokenError::BadSignature)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_expired_and_malformed_tokens() {
+        let now = sample_timestamp();
+        let token = signer().sign(USER_ID, now).unwrap();
+
+        assert_eq!(
+            signer().verify(&token, now),
+            Err(ActingUserTokenError::Expired)
+        );
+        assert_eq!(
+            signer().verify(USER_ID, now),
+            Err(ActingUserTokenError::Malformed)
+        );
+    }
+
+    #[test]
+    fn signer_without_secret_rejects_everything() {
+        let now = sample_timestamp();
+        let token = signer().sign(USER_ID, now + Duration::minutes(5)).unwrap();
+
+        assert_eq!(
+            ActingUserSigner::default().verify(&token, now),
+            Err(ActingUserTokenError::Disabled)
+        );
+        assert_eq!(
+            ActingUserSigner::new(b"short".to_vec()).err(),
+            Some(ActingUserTokenError::WeakSecret)
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// Number of random bytes behind a generated API key. Hex encoding doubles the visible length.
+const API_KEY_BYTES: usize = 32;
+
+/// Generates a new raw API key. The raw value is shown to the operator once and never stored.
+pub fn generate_api_key() -> String {
+    let mut bytes = [0u8; API_KEY_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Hashes a raw API key into the hex-encoded SHA-256 digest stored in `api_keys.key_hash`.
+pub fn hash_api_key(raw_key: &str) -> String {
+    hex::encode(Sha256::digest(raw_key.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_api_key_is_deterministic_hex() {
+        let hash = hash_api_key("secret");
+        assert_eq!(hash, hash_api_key("secret"));
+        assert_eq!(hash.len(), 64);
+        assert_ne!(hash, hash_api_key("other"));
+    }
+
+    #[test]
+    fn generate_api_key_returns_distinct_hex_keys() {
+        let first = generate_api_key();
+        let second = generate_api_key();
+
+        assert_eq!(first.len(), API_KEY_BYTES * 2);
+        assert!(first.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(first, second);
+    }
+}
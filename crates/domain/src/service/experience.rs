@@ -1,3 +1,5 @@
+use crate::entity::{clear_type::ClearType, level::Level};
+
 /// Calculates the XP granted for a single record submission.
 /// Formula: max(1, (score - 900000) / 1000).
 pub fn xp_for_score(score: u32) -> u32 {
@@ -15,3 +17,136 @@ where
         .into_iter()
         .fold(0u32, |acc, score| acc.saturating_add(xp_for_score(score)))
 }
+
+/// Scales `xp` by a campaign multiplier given in percent, rounding down.
+pub fn apply_multiplier(xp: u32, multiplier_percent: u32) -> u32 {
+    let scaled = u64::from(xp) * u64::from(multiplier_percent) / 100;
+    scaled.min(u64::from(u32::MAX)) as u32
+}
+
+/// Bonus XP granted on top of [`xp_for_score`].
+///
+/// A full combo earns `full_combo_bonus` and an all perfect earns `all_perfect_bonus` instead.
+/// Any clear earns `level_bonus` per integer level of the sheet, and the first clear of a sheet
+/// earns `first_clear_bonus` once. Every bonus is zero by default, so out of the box a
+/// submission earns exactly its score-based XP.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct XpPolicy {
+    full_combo_bonus: u32,
+    all_perfect_bonus: u32,
+    level_bonus: u32,
+    first_clear_bonus: u32,
+}
+
+impl XpPolicy {
+    pub fn new(
+        full_combo_bonus: u32,
+        all_perfect_bonus: u32,
+        level_bonus: u32,
+        first_clear_bonus: u32,
+    ) -> Self {
+        Self {
+            full_combo_bonus,
+            all_perfect_bonus,
+            level_bonus,
+            first_clear_bonus,
+        }
+    }
+
+    pub fn full_combo_bonus(&self) -> u32 {
+        self.full_combo_bonus
+    }
+
+    pub fn all_perfect_bonus(&self) -> u32 {
+        self.all_perfect_bonus
+    }
+
+    pub fn level_bonus(&self) -> u32 {
+        self.level_bonus
+    }
+
+    pub fn first_clear_bonus(&self) -> u32 {
+        self.first_clear_bonus
+    }
+
+    /// XP for one submission before any campaign multiplier. `first_clear` tells whether this is
+    /// the first time the user clears the sheet; it is ignored for failed runs.
+    pub fn xp_for(
+        &self,
+        score: u32,
+        clear_type: ClearType,
+        level: &Level,
+        first_clear: bool,
+    ) -> u32 {
+        let mut xp = xp_for_score(score);
+        if clear_type == ClearType::Fail {
+            return xp;
+        }
+
+        let clear_bonus = match clear_type {
+            ClearType::AllPerfect => self.all_perfect_bonus,
+            ClearType::FullCombo => self.full_combo_bonus,
+            ClearType::Fail | ClearType::Clear => 0,
+        };
+        let (integer, _) = level.components();
+        xp = xp
+            .saturating_add(clear_bonus)
+            .saturating_add(self.level_bonus.saturating_mul(integer));
+        if first_clear {
+            xp = xp.saturating_add(self.first_clear_bonus);
+        }
+        xp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(integer: u32) -> Level {
+        Level::new(integer, 5).unwrap()
+    }
+
+    #[test]
+    fn default_policy_grants_score_xp_only() {
+        let policy = XpPolicy::default();
+
+        assert_eq!(
+            policy.xp_for(1_000_000, ClearType::AllPerfect, &level(14), true),
+            100
+        );
+    }
+
+    #[test]
+    fn xp_for_adds_clear_level_and_first_clear_bonuses() {
+        let policy = XpPolicy::new(20, 50, 2, 30);
+
+        // 100 from the score, 50 for the all perfect, 2 × 13 for the level and 30 for the first clear.
+        assert_eq!(
+            policy.xp_for(1_000_000, ClearType::AllPerfect, &level(13), true),
+            206
+        );
+        assert_eq!(
+            policy.xp_for(950_000, ClearType::FullCombo, &level(13), false),
+            96
+        );
+        assert_eq!(
+            policy.xp_for(950_000, ClearType::Clear, &level(10), false),
+            70
+        );
+    }
+
+    #[test]
+    fn xp_for_gives_failed_runs_no_bonus() {
+        let policy = XpPolicy::new(20, 50, 2, 30);
+
+        assert_eq!(policy.xp_for(850_000, ClearType::Fail, &level(13), true), 1);
+    }
+
+    #[test]
+    fn apply_multiplier_rounds_down() {
+        assert_eq!(apply_multiplier(101, 200), 202);
+        assert_eq!(apply_multiplier(101, 150), 151);
+        assert_eq!(apply_multiplier(u32::MAX, 200), u32::MAX);
+    }
+}
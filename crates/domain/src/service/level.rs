@@ -0,0 +1,157 @@
+use thiserror::Error;
+
+/// XP needed to advance from level 1 to level 2 when no curve is configured.
+pub const DEFAULT_BASE_XP: u32 = 100;
+
+/// Extra XP each further level costs on top of the previous one by default.
+pub const DEFAULT_XP_STEP: u32 = 20;
+
+/// Highest reachable level by default.
+pub const DEFAULT_MAX_LEVEL: u32 = 200;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LevelCurveError {
+    #[error("a level must cost at least one XP")]
+    ZeroBaseXp,
+    #[error("the maximum level must be at least 1")]
+    ZeroMaxLevel,
+}
+
+/// Where a user stands on the level curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerLevel {
+    pub level: u32,
+    /// XP earned since the current level was reached.
+    pub xp_into_level: u32,
+    /// XP the current level takes to clear, or `None` at the maximum level.
+    pub xp_for_next: Option<u32>,
+}
+
+/// How a user's XP maps to a player level.
+///
+/// Everyone starts at level 1. Clearing level `n` costs `base_xp + xp_step * (n - 1)` XP, so each
+/// level takes a little longer than the last, until `max_level` is reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelCurve {
+    base_xp: u32,
+    xp_step: u32,
+    max_level: u32,
+}
+
+impl Default for LevelCurve {
+    fn default() -> Self {
+        Self {
+            base_xp: DEFAULT_BASE_XP,
+            xp_step: DEFAULT_XP_STEP,
+            max_level: DEFAULT_MAX_LEVEL,
+        }
+    }
+}
+
+impl LevelCurve {
+    pub fn new(base_xp: u32, xp_step: u32, max_level: u32) -> Result<Self, LevelCurveError> {
+        if base_xp == 0 {
+            return Err(LevelCurveError::ZeroBaseXp);
+        }
+        if max_level == 0 {
+            return Err(LevelCurveError::ZeroMaxLevel);
+        }
+
+        Ok(Self {
+            base_xp,
+            xp_step,
+            max_level,
+        })
+    }
+
+    pub fn base_xp(&self) -> u32 {
+        self.base_xp
+    }
+
+    pub fn xp_step(&self) -> u32 {
+        self.xp_step
+    }
+
+    pub fn max_level(&self) -> u32 {
+        self.max_level
+    }
+
+    pub fn level_for(&self, xp: u32) -> PlayerLevel {
+        let mut level = 1;
+        let mut remaining = u64::from(xp);
+        while level < self.max_level {
+            let cost = self.cost_of(level);
+            if remaining < cost {
+                return PlayerLevel {
+                    level,
+                    xp_into_level: remaining as u32,
+                    xp_for_next: Some(cost.min(u64::from(u32::MAX)) as u32),
+                };
+            }
+            remaining -= cost;
+            level += 1;
+        }
+
+        PlayerLevel {
+            level,
+            xp_into_level: remaining as u32,
+            xp_for_next: None,
+        }
+    }
+
+    fn cost_of(&self, level: u32) -> u64 {
+        u64::from(self.base_xp) + u64::from(self.xp_step) * u64::from(level - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_for_starts_at_one() {
+        let level = LevelCurve::default().level_for(0);
+
+        assert_eq!(
+            level,
+            PlayerLevel {
+                level: 1,
+                xp_into_level: 0,
+                xp_for_next: Some(100),
+            }
+        );
+    }
+
+    #[test]
+    fn level_for_walks_the_growing_costs() {
+        let curve = LevelCurve::default();
+
+        // Levels 1, 2 and 3 cost 100, 120 and 140.
+        assert_eq!(curve.level_for(99).level, 1);
+        assert_eq!(curve.level_for(100).level, 2);
+        let level = curve.level_for(365);
+        assert_eq!(level.level, 4);
+        assert_eq!(level.xp_into_level, 5);
+        assert_eq!(level.xp_for_next, Some(160));
+    }
+
+    #[test]
+    fn level_for_stops_at_the_maximum() {
+        let curve = LevelCurve::new(10, 0, 3).unwrap();
+
+        let level = curve.level_for(1_000);
+
+        assert_eq!(level.level, 3);
+        assert_eq!(level.xp_into_level, 980);
+        assert_eq!(level.xp_for_next, None);
+    }
+
+    #[test]
+    fn new_rejects_degenerate_curves() {
+        assert_eq!(LevelCurve::new(0, 10, 50), Err(LevelCurveError::ZeroBaseXp));
+        assert_eq!(
+            LevelCurve::new(100, 10, 0),
+            Err(LevelCurveError::ZeroMaxLevel)
+        );
+    }
+}
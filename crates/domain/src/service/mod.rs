@@ -1,2 +1,6 @@
+pub mod acting_user;
+pub mod api_key;
 pub mod experience;
+pub mod level;
 pub mod rating;
+pub mod score_validation;
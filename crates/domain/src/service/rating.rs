@@ -1,65 +1,262 @@
+use thiserror::Error;
+
 use crate::{
     entity::{level::Level, rating::Rating},
     repository::record::RecordWithMetadata,
 };
 
-pub fn calculate_user_rating(records: &[RecordWithMetadata]) -> Rating {
-    let mut values: Vec<u32> = records
-        .iter()
-        .filter(|entry| !entry.is_test)
-        .map(|entry| calculate_sheet_rating(&entry.level, *entry.record.score()))
-        .collect();
+/// Score-bonus anchors used when no curve is configured. Scores between two anchors are
+/// interpolated linearly; scores outside the table are clamped to its ends.
+pub const DEFAULT_BONUS_CURVE: [(u32, i32); 9] = [
+    (700_000, -200),
+    (750_000, -150),
+    (800_000, -100),
+    (850_000, -50),
+    (900_000, 0),
+    (950_000, 50),
+    (1_000_000, 100),
+    (1_050_000, 150),
+    (1_090_000, 200),
+];
 
-    if values.is_empty() {
-        return Rating::default();
-    }
+/// Number of best sheet ratings averaged into a user's rating by default.
+pub const DEFAULT_BEST_COUNT: usize = 3;
 
-    values.sort_unstable_by(|a, b| b.cmp(a));
-    let count = values.len().min(3);
-    let total: u32 = values.into_iter().take(count).sum();
-    Rating::new(total / count as u32)
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RatingPolicyError {
+    #[error("at least one best sheet must count towards the rating")]
+    NoBestSheets,
+    #[error("the bonus curve needs at least one anchor")]
+    EmptyBonusCurve,
+    #[error("bonus curve anchors must be sorted by strictly increasing score")]
+    UnorderedBonusCurve,
 }
 
-fn calculate_sheet_rating(level: &Level, score: u32) -> u32 {
-    let (integer, decimal) = level.components();
-    let base = integer * 100 + decimal * 10;
-    let bonus = compute_score_bonus(score);
-    let total = base as i64 + bonus as i64;
-    if total < 0 { 0 } else { total as u32 }
+/// How sheet and user ratings are derived from scores.
+///
+/// A user's rating is the average of their best `best_count` sheet ratings together with the
+/// sheet ratings of their `recent_count` most recent plays. The recent frame is disabled when
+/// `recent_count` is zero, which is the default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RatingPolicy {
+    best_count: usize,
+    recent_count: usize,
+    bonus_curve: Vec<(u32, i32)>,
 }
 
-fn compute_score_bonus(score: u32) -> i32 {
-    const ANCHORS: [(u32, i32); 9] = [
-        (700_000, -200),
-        (750_000, -150),
-        (800_000, -100),
-        (850_000, -50),
-        (900_000, 0),
-        (950_000, 50),
-        (1_000_000, 100),
-        (1_050_000, 150),
-        (1_090_000, 200),
-    ];
-
-    if score <= ANCHORS[0].0 {
-        return ANCHORS[0].1;
+impl Default for RatingPolicy {
+    fn default() -> Self {
+        Self {
+            best_count: DEFAULT_BEST_COUNT,
+            recent_count: 0,
+            bonus_curve: DEFAULT_BONUS_CURVE.to_vec(),
+        }
+    }
+}
+
+impl RatingPolicy {
+    pub fn new(
+        best_count: usize,
+        recent_count: usize,
+        bonus_curve: Vec<(u32, i32)>,
+    ) -> Result<Self, RatingPolicyError> {
+        if best_count == 0 {
+            return Err(RatingPolicyError::NoBestSheets);
+        }
+        if bonus_curve.is_empty() {
+            return Err(RatingPolicyError::EmptyBonusCurve);
+        }
+        if bonus_curve.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            return Err(RatingPolicyError::UnorderedBonusCurve);
+        }
+
+        Ok(Self {
+            best_count,
+            recent_count,
+            bonus_curve,
+        })
+    }
+
+    pub fn best_count(&self) -> usize {
+        self.best_count
+    }
+
+    pub fn recent_count(&self) -> usize {
+        self.recent_count
+    }
+
+    pub fn bonus_curve(&self) -> &[(u32, i32)] {
+        &self.bonus_curve
     }
 
-    if score >= ANCHORS[ANCHORS.len() - 1].0 {
-        return ANCHORS[ANCHORS.len() - 1].1;
+    pub fn sheet_rating(&self, level: &Level, score: u32) -> u32 {
+        let (integer, decimal) = level.components();
+        let base = integer * 100 + decimal * 10;
+        let bonus = self.score_bonus(score);
+        let total = base as i64 + bonus as i64;
+        if total < 0 { 0 } else { total as u32 }
     }
 
-    for window in ANCHORS.windows(2) {
-        let lower = window[0];
-        let upper = window[1];
-        if (lower.0..=upper.0).contains(&score) {
-            let range = (upper.0 - lower.0) as i64;
-            let position = (score - lower.0) as i64;
-            let diff = (upper.1 - lower.1) as i64;
-            let bonus = lower.1 as i64 + diff * position / range;
-            return bonus as i32;
+    /// Combines sheet ratings into a user rating. Only the best values of `best` matter, so
+    /// callers that already know the user's top sheets can pass just those instead of every
+    /// record. `recent` is taken as-is, newest first, and ignored when the recent frame is off.
+    pub fn user_rating(
+        &self,
+        best: impl IntoIterator<Item = u32>,
+        recent: impl IntoIterator<Item = u32>,
+    ) -> Rating {
+        let mut best: Vec<u32> = best.into_iter().collect();
+        best.sort_unstable_by(|a, b| b.cmp(a));
+
+        let counted: Vec<u32> = best
+            .into_iter()
+            .take(self.best_count)
+            .chain(recent.into_iter().take(self.recent_count))
+            .collect();
+
+        if counted.is_empty() {
+            return Rating::default();
+        }
+
+        let total: u64 = counted.iter().map(|&value| value as u64).sum();
+        Rating::new((total / counted.len() as u64) as u32)
+    }
+
+    /// Rates every non-test record with this policy and combines them with `recent`.
+    pub fn user_rating_from_records(
+        &self,
+        records: &[RecordWithMetadata],
+        recent: impl IntoIterator<Item = u32>,
+    ) -> Rating {
+        self.user_rating(
+            records
+                .iter()
+                .filter(|entry| !entry.is_test)
+                .map(|entry| self.sheet_rating(&entry.level, *entry.record.score())),
+            recent,
+        )
+    }
+
+    fn score_bonus(&self, score: u32) -> i32 {
+        let curve = &self.bonus_curve;
+        let first = curve[0];
+        let last = curve[curve.len() - 1];
+
+        if score <= first.0 {
+            return first.1;
+        }
+
+        if score >= last.0 {
+            return last.1;
+        }
+
+        for window in curve.windows(2) {
+            let lower = window[0];
+            let upper = window[1];
+            if (lower.0..=upper.0).contains(&score) {
+                let range = (upper.0 - lower.0) as i64;
+                let position = (score - lower.0) as i64;
+                let diff = (upper.1 - lower.1) as i64;
+                let bonus = lower.1 as i64 + diff * position / range;
+                return bonus as i32;
+            }
         }
+
+        first.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::entity::{
+        clear_type::ClearType, difficulty::Difficulty, judgement::Judgement, record::Record,
+    };
+
+    fn entry(sheet_id: &str, level: (u32, u32), score: u32, is_test: bool) -> RecordWithMetadata {
+        let record = Record::new_from_submission(
+            "user-1".to_owned(),
+            sheet_id.to_owned(),
+            score,
+            ClearType::Clear,
+            Judgement::default(),
+            0,
+            Utc.with_ymd_and_hms(2025, 11, 18, 0, 0, 0).unwrap(),
+        );
+        RecordWithMetadata::new(
+            record,
+            Difficulty::Hard,
+            Level::try_from(level).unwrap(),
+            is_test,
+        )
+    }
+
+    #[test]
+    fn sheet_rating_interpolates_between_anchors() {
+        let level = Level::new(13, 7).unwrap();
+
+        let policy = RatingPolicy::default();
+
+        assert_eq!(policy.sheet_rating(&level, 1_000_000), 1470);
+        assert_eq!(policy.sheet_rating(&level, 1_070_000), 1545);
+        assert_eq!(policy.sheet_rating(&Level::new(1, 0).unwrap(), 0), 0);
     }
 
-    ANCHORS[0].1
+    #[test]
+    fn top_ratings_alone_reproduce_full_calculation() {
+        let records = vec![
+            entry("sheet-1", (13, 7), 1_000_000, false),
+            entry("sheet-2", (12, 0), 980_000, false),
+            entry("sheet-3", (14, 0), 900_000, false),
+            entry("sheet-4", (10, 0), 800_000, false),
+            entry("sheet-5", (15, 0), 1_090_000, true),
+        ];
+
+        let policy = RatingPolicy::default();
+        let full = policy.user_rating_from_records(&records, []);
+        let top = policy.user_rating([1470, 1400, 1280], []);
+
+        assert_eq!(full.value(), top.value());
+        assert_eq!(full.value(), 1383);
+    }
+
+    #[test]
+    fn recent_frame_is_averaged_with_best_sheets() {
+        let policy = RatingPolicy::new(2, 2, DEFAULT_BONUS_CURVE.to_vec()).unwrap();
+
+        // Best two of three sheets plus the two newest plays; older plays fall out of the frame.
+        let rating = policy.user_rating([1500, 1000, 1400], [1300, 1200, 100]);
+
+        assert_eq!(rating.value(), (1500 + 1400 + 1300 + 1200) / 4);
+    }
+
+    #[test]
+    fn custom_bonus_curve_replaces_default_anchors() {
+        let policy = RatingPolicy::new(3, 0, vec![(900_000, 0), (1_000_000, 300)]).unwrap();
+        let level = Level::new(10, 0).unwrap();
+
+        assert_eq!(policy.sheet_rating(&level, 800_000), 1000);
+        assert_eq!(policy.sheet_rating(&level, 950_000), 1150);
+        assert_eq!(policy.sheet_rating(&level, 1_090_000), 1300);
+    }
+
+    #[test]
+    fn new_rejects_invalid_policies() {
+        let curve = DEFAULT_BONUS_CURVE.to_vec();
+
+        assert_eq!(
+            RatingPolicy::new(0, 0, curve.clone()),
+            Err(RatingPolicyError::NoBestSheets)
+        );
+        assert_eq!(
+            RatingPolicy::new(3, 0, Vec::new()),
+            Err(RatingPolicyError::EmptyBonusCurve)
+        );
+        assert_eq!(
+            RatingPolicy::new(3, 0, vec![(900_000, 0), (900_000, 10)]),
+            Err(RatingPolicyError::UnorderedBonusCurve)
+        );
+    }
 }
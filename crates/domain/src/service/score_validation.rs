@@ -0,0 +1,133 @@
+use thiserror::Error;
+
+use crate::entity::{judgement::Judgement, sheet::Sheet};
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ScoreValidationError {
+    #[error("score {score} exceeds the maximum of {max_score}")]
+    ScoreExceedsMaximum { score: u32, max_score: u32 },
+    #[error("judgement total {total} does not match the note count of {note_count}")]
+    NoteCountMismatch { total: u32, note_count: u32 },
+    #[error("max combo {max_combo} exceeds the note count of {note_count}")]
+    ComboExceedsNoteCount { max_combo: u32, note_count: u32 },
+}
+
+/// Checks that a submitted run is possible on `sheet`: the score may not exceed the sheet's
+/// maximum, every note must have been judged exactly once and the combo cannot outnumber the
+/// notes. Bounds the sheet does not know yet (zero) are not checked.
+pub fn validate_submission(
+    sheet: &Sheet,
+    score: u32,
+    judgement: &Judgement,
+    max_combo: u32,
+) -> Result<(), ScoreValidationError> {
+    let max_score = *sheet.max_score();
+    if max_score > 0 && score > max_score {
+        return Err(ScoreValidationError::ScoreExceedsMaximum { score, max_score });
+    }
+
+    let note_count = *sheet.note_count();
+    let total = judgement.total();
+    if note_count > 0 && total != note_count {
+        return Err(ScoreValidationError::NoteCountMismatch { total, note_count });
+    }
+    if note_count > 0 && max_combo > note_count {
+        return Err(ScoreValidationError::ComboExceedsNoteCount {
+            max_combo,
+            note_count,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{difficulty::Difficulty, level::Level};
+
+    fn sheet(note_count: u32, max_score: u32) -> Sheet {
+        Sheet::new(
+            "sheet-1".to_owned(),
+            "music-1".to_owned(),
+            Difficulty::Easy,
+            Level::new(10, 0).unwrap(),
+            "Designer".to_owned(),
+            note_count,
+            max_score,
+        )
+    }
+
+    #[test]
+    fn accepts_plausible_run() {
+        let result = validate_submission(
+            &sheet(1000, 1_010_000),
+            1_010_000,
+            &Judgement::new(1000, 0, 0, 0),
+            1000,
+        );
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn rejects_score_above_maximum() {
+        let result = validate_submission(
+            &sheet(1000, 1_010_000),
+            1_010_001,
+            &Judgement::new(1000, 0, 0, 0),
+            1000,
+        );
+        assert_eq!(
+            result,
+            Err(ScoreValidationError::ScoreExceedsMaximum {
+                score: 1_010_001,
+                max_score: 1_010_000
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_judgement_total_mismatch() {
+        let result = validate_submission(
+            &sheet(1000, 1_010_000),
+            900_000,
+            &Judgement::new(900, 50, 30, 10),
+            500,
+        );
+        assert_eq!(
+            result,
+            Err(ScoreValidationError::NoteCountMismatch {
+                total: 990,
+                note_count: 1000
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_combo_above_note_count() {
+        let result = validate_submission(
+            &sheet(1000, 1_010_000),
+            1_010_000,
+            &Judgement::new(1000, 0, 0, 0),
+            1001,
+        );
+        assert_eq!(
+            result,
+            Err(ScoreValidationError::ComboExceedsNoteCount {
+                max_combo: 1001,
+                note_count: 1000
+            })
+        );
+    }
+
+    #[test]
+    fn skips_unknown_bounds() {
+        let result = validate_submission(
+            &sheet(0, 0),
+            u32::MAX,
+            &Judgement::new(1, 2, 3, 4),
+            u32::MAX,
+        );
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -0,0 +1,18 @@
+use chrono::{Duration, Utc};
+
+use crate::service::acting_user::ActingUserSigner;
+
+/// Secret shared by [`acting_user_signer`] and [`acting_user_token`].
+pub const ACTING_USER_SECRET: &[u8] = b"test-acting-user-secret-0123456789";
+
+/// Returns the signer a test server needs to accept [`acting_user_token`].
+pub fn acting_user_signer() -> ActingUserSigner {
+    ActingUserSigner::new(ACTING_USER_SECRET).expect("fixture secret is long enough")
+}
+
+/// Mints a token naming `user_id` that stays valid for the rest of the test run.
+pub fn acting_user_token(user_id: &str) -> String {
+    acting_user_signer()
+        .sign(user_id, Utc::now() + Duration::hours(1))
+        .expect("fixture signer has a secret")
+}
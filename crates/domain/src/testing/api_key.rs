@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+
+use super::datetime::sample_timestamp;
+use crate::{
+    entity::{api_key::ApiKey, client::Client, client_role::ClientRole},
+    repository::{api_key::MockApiKeyRepository, client::MockClientRepository},
+    service::api_key::hash_api_key,
+};
+
+/// A registered client together with the raw value of its current API key.
+pub struct ApiKeySample {
+    pub id: &'static str,
+    pub client_id: &'static str,
+    pub raw_key: &'static str,
+    pub role: ClientRole,
+    pub label: &'static str,
+}
+
+impl ApiKeySample {
+    /// Builds an `ApiKey` whose hash matches `raw_key`.
+    pub fn build(&self, revoked_at: Option<DateTime<Utc>>) -> ApiKey {
+        ApiKey::new(
+            self.id.to_owned(),
+            self.client_id.to_owned(),
+            hash_api_key(self.raw_key),
+            revoked_at,
+            sample_timestamp(),
+        )
+    }
+
+    /// Builds the `Client` owning the key.
+    pub fn client(&self, revoked_at: Option<DateTime<Utc>>) -> Client {
+        Client::new(
+            self.client_id.to_owned(),
+            self.role,
+            self.label.to_owned(),
+            revoked_at,
+            sample_timestamp(),
+        )
+    }
+}
+
+pub const CABINET_KEY: ApiKeySample = ApiKeySample {
+    id: "7a1f0c52-8c4e-4a4b-9f5e-0d6c2b1e9a01",
+    client_id: "5c9e2d41-3b7a-4f60-8e1d-2a4b6c8d0e01",
+    raw_key: "cabinet-test-key",
+    role: ClientRole::Cabinet,
+    label: "Cabinet #1",
+};
+
+pub const STATION_KEY: ApiKeySample = ApiKeySample {
+    id: "7a1f0c52-8c4e-4a4b-9f5e-0d6c2b1e9a02",
+    client_id: "5c9e2d41-3b7a-4f60-8e1d-2a4b6c8d0e02",
+    raw_key: "station-test-key",
+    role: ClientRole::Station,
+    label: "Station #1",
+};
+
+pub const WEB_KEY: ApiKeySample = ApiKeySample {
+    id: "7a1f0c52-8c4e-4a4b-9f5e-0d6c2b1e9a03",
+    client_id: "5c9e2d41-3b7a-4f60-8e1d-2a4b6c8d0e03",
+    raw_key: "web-test-key",
+    role: ClientRole::Web,
+    label: "xlair.dev",
+};
+
+pub const ADMIN_KEY: ApiKeySample = ApiKeySample {
+    id: "7a1f0c52-8c4e-4a4b-9f5e-0d6c2b1e9a04",
+    client_id: "5c9e2d41-3b7a-4f60-8e1d-2a4b6c8d0e04",
+    raw_key: "admin-test-key",
+    role: ClientRole::Admin,
+    label: "Operator",
+};
+
+const SAMPLES: [ApiKeySample; 4] = [CABINET_KEY, STATION_KEY, WEB_KEY, ADMIN_KEY];
+
+/// Returns a repository mock that resolves every sample key above. Unknown hashes resolve to
+/// `None`.
+pub fn api_key_repository() -> MockApiKeyRepository {
+    let mut repo = MockApiKeyRepository::new();
+    repo.expect_find_by_key_hash().returning(|key_hash| {
+        let found = SAMPLES
+            .into_iter()
+            .find(|sample| hash_api_key(sample.raw_key) == key_hash)
+            .map(|sample| sample.build(None));
+        Box::pin(async move { Ok(found) })
+    });
+    repo
+}
+
+/// Returns a repository mock that resolves the clients owning the sample keys above. Only
+/// `find_by_id` is stubbed; tests exercising mutations set their own expectations.
+pub fn client_repository() -> MockClientRepository {
+    let mut repo = MockClientRepository::new();
+    repo.expect_find_by_id().returning(|client_id| {
+        let found = SAMPLES
+            .into_iter()
+            .find(|sample| sample.client_id == client_id)
+            .map(|sample| sample.client(None));
+        Box::pin(async move { Ok(found) })
+    });
+    repo
+}
@@ -3,5 +3,7 @@
 //! These helpers are exposed via the `test-support` feature so that crates depending on `domain`
 //! can compose deterministic aggregates without repeating literals.
 
+pub mod acting_user;
+pub mod api_key;
 pub mod datetime;
 pub mod user;
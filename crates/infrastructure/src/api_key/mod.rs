@@ -0,0 +1,30 @@
+mod read;
+
+use std::sync::Arc;
+
+use domain::{
+    entity::api_key::ApiKey,
+    repository::api_key::{ApiKeyRepository, ApiKeyRepositoryError},
+};
+use sea_orm::DbConn;
+use tracing::instrument;
+
+pub struct ApiKeyRepositoryImpl {
+    db: Arc<DbConn>,
+}
+
+impl ApiKeyRepositoryImpl {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+impl ApiKeyRepository for ApiKeyRepositoryImpl {
+    #[instrument(skip(self, key_hash))]
+    async fn find_by_key_hash(
+        &self,
+        key_hash: &str,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        read::find_by_key_hash(self.db.as_ref(), key_hash).await
+    }
+}
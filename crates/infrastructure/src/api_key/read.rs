@@ -0,0 +1,60 @@
+use std::convert::TryFrom;
+
+use anyhow::Error as AnyError;
+use domain::{entity::api_key::ApiKey, repository::api_key::ApiKeyRepositoryError};
+use sea_orm::{ColumnTrait, DbConn, EntityTrait, QueryFilter};
+use tracing::{debug, error};
+
+use crate::entities;
+
+pub async fn find_by_key_hash(
+    db: &DbConn,
+    key_hash: &str,
+) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+    debug!("Querying API key via SeaORM");
+    let model = entities::api_keys::Entity::find()
+        .filter(entities::api_keys::Column::KeyHash.eq(key_hash))
+        .one(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to query API key");
+            ApiKeyRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    model.map(ApiKey::try_from).transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use sea_orm::{DatabaseBackend, MockDatabase, prelude::Uuid};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn find_by_key_hash_converts_model() {
+        let id = Uuid::parse_str("ffffffff-ffff-ffff-ffff-ffffffffffff").expect("valid uuid");
+        let client_id =
+            Uuid::parse_str("eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee").expect("valid uuid");
+        let created_at = Utc.with_ymd_and_hms(2025, 11, 10, 0, 0, 0).unwrap();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![entities::api_keys::Model {
+                id,
+                key_hash: "hash".to_owned(),
+                revoked_at: Some(created_at.into()),
+                created_at: created_at.into(),
+                client_id,
+            }]])
+            .into_connection();
+
+        let key = find_by_key_hash(&db, "hash")
+            .await
+            .unwrap()
+            .expect("key should be found");
+
+        assert_eq!(key.id(), &id.to_string());
+        assert_eq!(key.client_id(), &client_id.to_string());
+        assert_eq!(*key.revoked_at(), Some(created_at));
+        assert!(!key.is_active());
+    }
+}
@@ -0,0 +1,16 @@
+use anyhow::Error as AnyError;
+use domain::repository::client::ClientRepositoryError;
+use sea_orm::{DbErr, prelude::Uuid};
+use tracing::{debug, error};
+
+pub fn parse_client_uuid(client_id: &str) -> Result<Uuid, ClientRepositoryError> {
+    Uuid::parse_str(client_id).map_err(|err| {
+        debug!(error = %err, "Failed to parse client id");
+        ClientRepositoryError::NotFound(client_id.to_owned())
+    })
+}
+
+pub fn convert_db_error(err: DbErr, context: &'static str) -> ClientRepositoryError {
+    error!(error = %err, "{context}");
+    ClientRepositoryError::InternalError(AnyError::from(err))
+}
@@ -0,0 +1,61 @@
+mod adapter;
+mod read;
+mod write;
+
+use std::sync::Arc;
+
+use domain::{
+    entity::client::Client,
+    repository::client::{ClientRepository, ClientRepositoryError},
+};
+use read::find_by_id as query_by_id;
+use sea_orm::DbConn;
+use tracing::{debug, info, instrument};
+use write::{create_client, revoke_client, rotate_key as mutate_rotate_key};
+
+pub struct ClientRepositoryImpl {
+    db: Arc<DbConn>,
+}
+
+impl ClientRepositoryImpl {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+impl ClientRepository for ClientRepositoryImpl {
+    #[instrument(skip(self, client, key_hash), fields(role = %client.role()))]
+    async fn create(
+        &self,
+        client: Client,
+        key_hash: String,
+    ) -> Result<Client, ClientRepositoryError> {
+        debug!("Persisting client via SeaORM");
+        let created = create_client(self.db.as_ref(), client, key_hash).await?;
+        info!(client_id = %created.id(), "Client persisted by repository");
+        Ok(created)
+    }
+
+    #[instrument(skip(self), fields(client_id = %client_id))]
+    async fn find_by_id(&self, client_id: &str) -> Result<Option<Client>, ClientRepositoryError> {
+        query_by_id(self.db.as_ref(), client_id).await
+    }
+
+    #[instrument(skip(self, key_hash), fields(client_id = %client_id))]
+    async fn rotate_key(
+        &self,
+        client_id: &str,
+        key_hash: String,
+    ) -> Result<(), ClientRepositoryError> {
+        mutate_rotate_key(self.db.as_ref(), client_id, key_hash).await?;
+        info!("Client key rotated by repository");
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(client_id = %client_id))]
+    async fn revoke(&self, client_id: &str) -> Result<Client, ClientRepositoryError> {
+        let client = revoke_client(self.db.as_ref(), client_id).await?;
+        info!("Client revoked by repository");
+        Ok(client)
+    }
+}
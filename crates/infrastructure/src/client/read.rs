@@ -0,0 +1,68 @@
+use std::convert::TryFrom;
+
+use domain::{entity::client::Client, repository::client::ClientRepositoryError};
+use sea_orm::{ConnectionTrait, EntityTrait};
+use tracing::debug;
+
+use super::adapter::{convert_db_error, parse_client_uuid};
+use crate::entities;
+
+pub async fn find_by_id<C: ConnectionTrait>(
+    db: &C,
+    client_id: &str,
+) -> Result<Option<Client>, ClientRepositoryError> {
+    let uuid = parse_client_uuid(client_id)?;
+
+    debug!(client_id = %uuid, "Querying client via SeaORM");
+    let model = entities::clients::Entity::find_by_id(uuid)
+        .one(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query client by id"))?;
+
+    model.map(Client::try_from).transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use domain::{entity::client_role::ClientRole, testing::api_key::CABINET_KEY};
+    use sea_orm::{DatabaseBackend, MockDatabase, prelude::Uuid};
+
+    use super::*;
+    use crate::entities::sea_orm_active_enums::ClientRole as DbClientRole;
+
+    #[tokio::test]
+    async fn find_by_id_converts_model() {
+        let id = Uuid::parse_str(CABINET_KEY.client_id).expect("valid uuid");
+        let created_at = Utc.with_ymd_and_hms(2025, 11, 11, 0, 0, 0).unwrap();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![entities::clients::Model {
+                id,
+                role: DbClientRole::Cabinet,
+                label: CABINET_KEY.label.to_owned(),
+                revoked_at: None,
+                created_at: created_at.into(),
+            }]])
+            .into_connection();
+
+        let client = find_by_id(&db, CABINET_KEY.client_id)
+            .await
+            .unwrap()
+            .expect("client should be found");
+
+        assert_eq!(client.id(), CABINET_KEY.client_id);
+        assert_eq!(*client.role(), ClientRole::Cabinet);
+        assert!(client.is_active());
+    }
+
+    #[tokio::test]
+    async fn find_by_id_rejects_malformed_id() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+        let err = find_by_id(&db, "not-a-uuid")
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, ClientRepositoryError::NotFound(id) if id == "not-a-uuid"));
+    }
+}
@@ -0,0 +1,141 @@
+use std::convert::TryFrom;
+
+use chrono::Utc;
+use domain::{entity::client::Client, repository::client::ClientRepositoryError};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, QueryFilter, TransactionTrait,
+    prelude::Uuid, sea_query::Expr,
+};
+use tracing::debug;
+
+use super::adapter::{convert_db_error, parse_client_uuid};
+use crate::entities;
+
+pub async fn create_client(
+    db: &DbConn,
+    client: Client,
+    key_hash: String,
+) -> Result<Client, ClientRepositoryError> {
+    let txn = db
+        .begin()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to begin client transaction"))?;
+
+    let active: entities::clients::ActiveModel = client.into();
+    let model = active
+        .insert(&txn)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to insert client"))?;
+
+    insert_key(&txn, model.id, key_hash).await?;
+
+    txn.commit()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to commit client transaction"))?;
+
+    debug!(client_id = %model.id, "Client persisted with initial key");
+    Client::try_from(model)
+}
+
+pub async fn rotate_key(
+    db: &DbConn,
+    client_id: &str,
+    key_hash: String,
+) -> Result<(), ClientRepositoryError> {
+    let uuid = parse_client_uuid(client_id)?;
+    let txn = db
+        .begin()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to begin key rotation transaction"))?;
+
+    let exists = entities::clients::Entity::find_by_id(uuid)
+        .one(&txn)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query client for key rotation"))?
+        .is_some();
+    if !exists {
+        debug!("Client not found for supplied id");
+        return Err(ClientRepositoryError::NotFound(client_id.to_owned()));
+    }
+
+    revoke_active_keys(&txn, uuid).await?;
+    insert_key(&txn, uuid, key_hash).await?;
+
+    txn.commit()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to commit key rotation transaction"))?;
+
+    debug!(client_id = %uuid, "Client key rotated");
+    Ok(())
+}
+
+pub async fn revoke_client(db: &DbConn, client_id: &str) -> Result<Client, ClientRepositoryError> {
+    let uuid = parse_client_uuid(client_id)?;
+    let txn = db
+        .begin()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to begin client revocation transaction"))?;
+
+    let model = entities::clients::Entity::update_many()
+        .col_expr(
+            entities::clients::Column::RevokedAt,
+            Expr::value(Utc::now()),
+        )
+        .filter(entities::clients::Column::Id.eq(uuid))
+        .exec_with_returning(&txn)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to revoke client"))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            debug!("Client not found for supplied id");
+            ClientRepositoryError::NotFound(client_id.to_owned())
+        })?;
+
+    revoke_active_keys(&txn, uuid).await?;
+
+    txn.commit()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to commit client revocation transaction"))?;
+
+    debug!(client_id = %uuid, "Client and its keys revoked");
+    Client::try_from(model)
+}
+
+async fn insert_key<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    client_id: Uuid,
+    key_hash: String,
+) -> Result<(), ClientRepositoryError> {
+    let key = entities::api_keys::ActiveModel {
+        key_hash: ActiveValue::Set(key_hash),
+        client_id: ActiveValue::Set(client_id),
+        ..Default::default()
+    };
+
+    entities::api_keys::Entity::insert(key)
+        .exec_without_returning(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to insert API key"))?;
+
+    Ok(())
+}
+
+async fn revoke_active_keys<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    client_id: Uuid,
+) -> Result<(), ClientRepositoryError> {
+    let result = entities::api_keys::Entity::update_many()
+        .col_expr(
+            entities::api_keys::Column::RevokedAt,
+            Expr::value(Utc::now()),
+        )
+        .filter(entities::api_keys::Column::ClientId.eq(client_id))
+        .filter(entities::api_keys::Column::RevokedAt.is_null())
+        .exec(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to revoke API keys"))?;
+
+    debug!(client_id = %client_id, revoked = result.rows_affected, "Active API keys revoked");
+    Ok(())
+}
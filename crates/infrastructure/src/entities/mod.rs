@@ -2,9 +2,19 @@
 
 pub mod prelude;
 
+pub mod api_keys;
+pub mod clients;
+pub mod credit_logs;
 pub mod musics;
+pub mod plays;
 pub mod records;
+pub mod rivals;
 pub mod sea_orm_active_enums;
+pub mod season_standings;
+pub mod seasons;
 pub mod sheets;
+pub mod submission_results;
 pub mod user_play_options;
+pub mod user_progress;
 pub mod users;
+pub mod xp_campaigns;
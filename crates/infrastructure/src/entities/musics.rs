@@ -15,6 +15,8 @@ pub struct Model {
     pub jacket: String,
     pub registration_date: DateTimeWithTimeZone,
     pub is_test: bool,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+    pub updated_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
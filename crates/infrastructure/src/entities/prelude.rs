@@ -1,6 +1,11 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.16
 
 pub use super::{
-    musics::Entity as Musics, records::Entity as Records, sheets::Entity as Sheets,
-    user_play_options::Entity as UserPlayOptions, users::Entity as Users,
+    api_keys::Entity as ApiKeys, clients::Entity as Clients, credit_logs::Entity as CreditLogs,
+    musics::Entity as Musics, plays::Entity as Plays, records::Entity as Records,
+    rivals::Entity as Rivals, season_standings::Entity as SeasonStandings,
+    seasons::Entity as Seasons, sheets::Entity as Sheets,
+    submission_results::Entity as SubmissionResults, user_play_options::Entity as UserPlayOptions,
+    user_progress::Entity as UserProgress, users::Entity as Users,
+    xp_campaigns::Entity as XpCampaigns,
 };
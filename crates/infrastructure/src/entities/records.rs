@@ -15,6 +15,12 @@ pub struct Model {
     pub clear_type: ClearType,
     pub play_count: i32,
     pub updated_at: DateTimeWithTimeZone,
+    pub judge_perfect: i32,
+    pub judge_great: i32,
+    pub judge_good: i32,
+    pub judge_miss: i32,
+    pub max_combo: i32,
+    pub rating: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -15,6 +15,18 @@ pub enum ClearType {
     AllPerfect,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "client_role")]
+pub enum ClientRole {
+    #[sea_orm(string_value = "cabinet")]
+    Cabinet,
+    #[sea_orm(string_value = "station")]
+    Station,
+    #[sea_orm(string_value = "web")]
+    Web,
+    #[sea_orm(string_value = "admin")]
+    Admin,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "difficulty")]
 pub enum Difficulty {
     #[sea_orm(string_value = "easy")]
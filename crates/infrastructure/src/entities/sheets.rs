@@ -13,6 +13,10 @@ pub struct Model {
     pub difficulty: Difficulty,
     pub level: i32,
     pub notes_designer: String,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+    pub updated_at: DateTimeWithTimeZone,
+    pub note_count: i32,
+    pub max_score: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -25,6 +29,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Musics,
+    #[sea_orm(has_many = "super::plays::Entity")]
+    Plays,
     #[sea_orm(has_many = "super::records::Entity")]
     Records,
 }
@@ -35,6 +41,12 @@ impl Related<super::musics::Entity> for Entity {
     }
 }
 
+impl Related<super::plays::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Plays.def()
+    }
+}
+
 impl Related<super::records::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Records.def()
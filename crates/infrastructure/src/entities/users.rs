@@ -21,8 +21,30 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::credit_logs::Entity")]
+    CreditLogs,
+    #[sea_orm(has_many = "super::plays::Entity")]
+    Plays,
     #[sea_orm(has_many = "super::records::Entity")]
     Records,
+    #[sea_orm(has_many = "super::season_standings::Entity")]
+    SeasonStandings,
+    #[sea_orm(has_many = "super::submission_results::Entity")]
+    SubmissionResults,
+    #[sea_orm(has_many = "super::user_progress::Entity")]
+    UserProgress,
+}
+
+impl Related<super::credit_logs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CreditLogs.def()
+    }
+}
+
+impl Related<super::plays::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Plays.def()
+    }
 }
 
 impl Related<super::records::Entity> for Entity {
@@ -31,4 +53,22 @@ impl Related<super::records::Entity> for Entity {
     }
 }
 
+impl Related<super::season_standings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SeasonStandings.def()
+    }
+}
+
+impl Related<super::submission_results::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SubmissionResults.def()
+    }
+}
+
+impl Related<super::user_progress::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserProgress.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
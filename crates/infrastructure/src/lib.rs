@@ -1,30 +1,63 @@
 use std::sync::Arc;
 
 use domain::repository::Repositories;
+use sea_orm::{DbConn, TransactionTrait};
 use tracing::{error, info, instrument};
 
+pub mod api_key;
+pub mod client;
 pub mod entities;
 pub mod model;
 pub mod music;
+pub mod play;
+mod ranking;
 pub mod record;
+pub mod rival;
+pub mod season;
+mod unit_of_work;
 pub mod user;
+pub mod xp_campaign;
+
+pub use unit_of_work::UnitOfWorkImpl;
 
 pub struct RepositoriesImpl {
+    db: Arc<DbConn>,
     user: user::UserRepositoryImpl,
     record: record::RecordRepositoryImpl,
     music: music::MusicRepositoryImpl,
+    api_key: api_key::ApiKeyRepositoryImpl,
+    client: client::ClientRepositoryImpl,
+    play: play::PlayRepositoryImpl,
+    xp_campaign: xp_campaign::XpCampaignRepositoryImpl,
+    season: season::SeasonRepositoryImpl,
+    rival: rival::RivalRepositoryImpl,
 }
 
 impl RepositoriesImpl {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        db: Arc<DbConn>,
         user: user::UserRepositoryImpl,
         record: record::RecordRepositoryImpl,
         music: music::MusicRepositoryImpl,
+        api_key: api_key::ApiKeyRepositoryImpl,
+        client: client::ClientRepositoryImpl,
+        play: play::PlayRepositoryImpl,
+        xp_campaign: xp_campaign::XpCampaignRepositoryImpl,
+        season: season::SeasonRepositoryImpl,
+        rival: rival::RivalRepositoryImpl,
     ) -> Self {
         Self {
+            db,
             user,
             record,
             music,
+            api_key,
+            client,
+            play,
+            xp_campaign,
+            season,
+            rival,
         }
     }
 
@@ -48,11 +81,24 @@ impl RepositoriesImpl {
         let user_repo = user::UserRepositoryImpl::new(db.clone());
         let record_repo = record::RecordRepositoryImpl::new(db.clone());
         let music_repo = music::MusicRepositoryImpl::new(db.clone());
+        let api_key_repo = api_key::ApiKeyRepositoryImpl::new(db.clone());
+        let client_repo = client::ClientRepositoryImpl::new(db.clone());
+        let play_repo = play::PlayRepositoryImpl::new(db.clone());
+        let xp_campaign_repo = xp_campaign::XpCampaignRepositoryImpl::new(db.clone());
+        let season_repo = season::SeasonRepositoryImpl::new(db.clone());
+        let rival_repo = rival::RivalRepositoryImpl::new(db.clone());
 
         Self {
+            db,
             user: user_repo,
             record: record_repo,
             music: music_repo,
+            api_key: api_key_repo,
+            client: client_repo,
+            play: play_repo,
+            xp_campaign: xp_campaign_repo,
+            season: season_repo,
+            rival: rival_repo,
         }
     }
 }
@@ -61,6 +107,13 @@ impl Repositories for RepositoriesImpl {
     type UserRepositoryImpl = user::UserRepositoryImpl;
     type RecordRepositoryImpl = record::RecordRepositoryImpl;
     type MusicRepositoryImpl = music::MusicRepositoryImpl;
+    type ApiKeyRepositoryImpl = api_key::ApiKeyRepositoryImpl;
+    type ClientRepositoryImpl = client::ClientRepositoryImpl;
+    type PlayRepositoryImpl = play::PlayRepositoryImpl;
+    type XpCampaignRepositoryImpl = xp_campaign::XpCampaignRepositoryImpl;
+    type SeasonRepositoryImpl = season::SeasonRepositoryImpl;
+    type RivalRepositoryImpl = rival::RivalRepositoryImpl;
+    type UnitOfWork<'a> = UnitOfWorkImpl;
 
     fn user(&self) -> &Self::UserRepositoryImpl {
         &self.user
@@ -73,4 +126,37 @@ impl Repositories for RepositoriesImpl {
     fn music(&self) -> &Self::MusicRepositoryImpl {
         &self.music
     }
+
+    fn api_key(&self) -> &Self::ApiKeyRepositoryImpl {
+        &self.api_key
+    }
+
+    fn client(&self) -> &Self::ClientRepositoryImpl {
+        &self.client
+    }
+
+    fn play(&self) -> &Self::PlayRepositoryImpl {
+        &self.play
+    }
+
+    fn xp_campaign(&self) -> &Self::XpCampaignRepositoryImpl {
+        &self.xp_campaign
+    }
+
+    fn season(&self) -> &Self::SeasonRepositoryImpl {
+        &self.season
+    }
+
+    fn rival(&self) -> &Self::RivalRepositoryImpl {
+        &self.rival
+    }
+
+    #[instrument(name = "infrastructure.repositories.begin", skip(self))]
+    async fn begin(&self) -> anyhow::Result<Self::UnitOfWork<'_>> {
+        let txn = self.db.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to begin unit of work");
+            anyhow::Error::from(err)
+        })?;
+        Ok(UnitOfWorkImpl::new(txn))
+    }
 }
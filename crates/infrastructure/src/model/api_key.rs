@@ -0,0 +1,18 @@
+use chrono::Utc;
+use domain::{entity::api_key::ApiKey, repository::api_key::ApiKeyRepositoryError};
+
+use crate::entities::api_keys::Model as ApiKeyModel;
+
+impl TryFrom<ApiKeyModel> for ApiKey {
+    type Error = ApiKeyRepositoryError;
+
+    fn try_from(model: ApiKeyModel) -> Result<Self, Self::Error> {
+        Ok(ApiKey::new(
+            model.id.to_string(),
+            model.client_id.to_string(),
+            model.key_hash,
+            model.revoked_at.map(|value| value.with_timezone(&Utc)),
+            model.created_at.with_timezone(&Utc),
+        ))
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::Utc;
+use domain::{
+    entity::{client::Client, client_role::ClientRole as DomainClientRole},
+    repository::client::ClientRepositoryError,
+};
+use sea_orm::{ActiveValue, prelude::Uuid};
+
+use crate::entities::{
+    clients::{ActiveModel as ClientActiveModel, Model as ClientModel},
+    sea_orm_active_enums::ClientRole as DbClientRole,
+};
+
+impl TryFrom<ClientModel> for Client {
+    type Error = ClientRepositoryError;
+
+    fn try_from(model: ClientModel) -> Result<Self, Self::Error> {
+        Ok(Client::new(
+            model.id.to_string(),
+            model.role.into(),
+            model.label,
+            model.revoked_at.map(|value| value.with_timezone(&Utc)),
+            model.created_at.with_timezone(&Utc),
+        ))
+    }
+}
+
+impl From<Client> for ClientActiveModel {
+    fn from(client: Client) -> Self {
+        // a client without id has not been persisted yet, so storage assigns id and created_at
+        let (id, created_at) = if client.id().is_empty() {
+            (ActiveValue::NotSet, ActiveValue::NotSet)
+        } else {
+            (
+                ActiveValue::Set(Uuid::parse_str(client.id()).unwrap_or_else(|_| Uuid::nil())),
+                ActiveValue::Set((*client.created_at()).into()),
+            )
+        };
+
+        ClientActiveModel {
+            id,
+            role: ActiveValue::Set((*client.role()).into()),
+            label: ActiveValue::Set(client.label().to_owned()),
+            revoked_at: ActiveValue::Set(client.revoked_at().map(Into::into)),
+            created_at,
+        }
+    }
+}
+
+impl From<DbClientRole> for DomainClientRole {
+    fn from(value: DbClientRole) -> Self {
+        match value {
+            DbClientRole::Cabinet => DomainClientRole::Cabinet,
+            DbClientRole::Station => DomainClientRole::Station,
+            DbClientRole::Web => DomainClientRole::Web,
+            DbClientRole::Admin => DomainClientRole::Admin,
+        }
+    }
+}
+
+impl From<DomainClientRole> for DbClientRole {
+    fn from(value: DomainClientRole) -> Self {
+        match value {
+            DomainClientRole::Cabinet => DbClientRole::Cabinet,
+            DomainClientRole::Station => DbClientRole::Station,
+            DomainClientRole::Web => DbClientRole::Web,
+            DomainClientRole::Admin => DbClientRole::Admin,
+        }
+    }
+}
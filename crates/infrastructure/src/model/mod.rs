@@ -1,3 +1,10 @@
+pub mod api_key;
+pub mod client;
+pub mod play;
 pub mod record;
+pub mod rival;
+pub mod season;
 pub mod user;
 pub mod user_play_option;
+pub mod user_progress;
+pub mod xp_campaign;
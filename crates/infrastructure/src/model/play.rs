@@ -0,0 +1,95 @@
+use std::convert::TryFrom;
+
+use anyhow::Error as AnyError;
+use chrono::Utc;
+use domain::{
+    entity::{
+        judgement::Judgement,
+        play::Play,
+        record::Record,
+        submission_result::{LevelUp, SubmissionResult},
+    },
+    repository::play::PlayRepositoryError,
+};
+
+use crate::entities::{
+    plays::Model as PlayModel, submission_results::Model as SubmissionResultModel,
+};
+
+/// Converts database play model to domain entity.
+///
+/// # Errors
+/// Returns `InternalError` if the database contains a negative score or judgement count.
+impl TryFrom<PlayModel> for Play {
+    type Error = PlayRepositoryError;
+
+    fn try_from(model: PlayModel) -> Result<Self, Self::Error> {
+        let judgement = Judgement::new(
+            convert_count(model.judge_perfect, "judge_perfect")?,
+            convert_count(model.judge_great, "judge_great")?,
+            convert_count(model.judge_good, "judge_good")?,
+            convert_count(model.judge_miss, "judge_miss")?,
+        );
+
+        Ok(Play::new(
+            model.id.to_string(),
+            model.user_id.to_string(),
+            model.sheet_id.to_string(),
+            model.client_id.to_string(),
+            convert_count(model.score, "score")?,
+            model.clear_type.into(),
+            judgement,
+            model.played_at.with_timezone(&Utc),
+            model.submission_id.map(|id| id.to_string()),
+        ))
+    }
+}
+
+/// Converts a stored submission result to the domain entity. The level-up is only kept when both
+/// levels are present.
+///
+/// # Errors
+/// Returns `InternalError` if the database contains a negative count or level.
+impl TryFrom<SubmissionResultModel> for SubmissionResult {
+    type Error = PlayRepositoryError;
+
+    fn try_from(model: SubmissionResultModel) -> Result<Self, Self::Error> {
+        let judgement = Judgement::new(
+            convert_count(model.judge_perfect, "judge_perfect")?,
+            convert_count(model.judge_great, "judge_great")?,
+            convert_count(model.judge_good, "judge_good")?,
+            convert_count(model.judge_miss, "judge_miss")?,
+        );
+        let record = Record::new(
+            model.record_id.to_string(),
+            model.user_id.to_string(),
+            model.sheet_id.to_string(),
+            convert_count(model.score, "score")?,
+            model.clear_type.into(),
+            judgement,
+            convert_count(model.max_combo, "max_combo")?,
+            convert_count(model.play_count, "play_count")?,
+            model.record_updated_at.with_timezone(&Utc),
+        );
+        let level_up = match (model.level_from, model.level_to) {
+            (Some(from), Some(to)) => Some(LevelUp::new(
+                convert_count(from, "level_from")?,
+                convert_count(to, "level_to")?,
+            )),
+            _ => None,
+        };
+
+        Ok(SubmissionResult::new(
+            model.submission_id.to_string(),
+            record,
+            level_up,
+        ))
+    }
+}
+
+fn convert_count(value: i32, column: &'static str) -> Result<u32, PlayRepositoryError> {
+    u32::try_from(value).map_err(|err| {
+        tracing::warn!(error = %err, value, column, "Play column from database must be non-negative");
+        PlayRepositoryError::InternalError(AnyError::from(err))
+    })
+}
@@ -2,7 +2,7 @@ use std::convert::TryFrom;
 
 use anyhow::Error as AnyError;
 use domain::{
-    entity::{clear_type::ClearType as DomainClearType, record::Record},
+    entity::{clear_type::ClearType as DomainClearType, judgement::Judgement, record::Record},
     repository::record::RecordRepositoryError,
 };
 
@@ -13,7 +13,8 @@ use crate::entities::{
 /// Converts database record model to domain entity.
 ///
 /// # Errors
-/// Returns `InternalError` if the database contains invalid data (negative score or play_count).
+/// Returns `InternalError` if the database contains invalid data (negative score, play_count,
+/// judgement count or max_combo).
 /// This conversion assumes database integrity constraints ensure valid data.
 impl TryFrom<RecordModel> for Record {
     type Error = RecordRepositoryError;
@@ -30,6 +31,13 @@ impl TryFrom<RecordModel> for Record {
             tracing::warn!(error = %err, value = model.play_count, "Play count from database must be non-negative");
             RecordRepositoryError::InternalError(AnyError::from(err))
         })?;
+        let judgement = Judgement::new(
+            convert_count(model.judge_perfect, "judge_perfect")?,
+            convert_count(model.judge_great, "judge_great")?,
+            convert_count(model.judge_good, "judge_good")?,
+            convert_count(model.judge_miss, "judge_miss")?,
+        );
+        let max_combo = convert_count(model.max_combo, "max_combo")?;
         let updated_at = model.updated_at.with_timezone(&chrono::Utc);
 
         Ok(Record::new(
@@ -38,12 +46,21 @@ impl TryFrom<RecordModel> for Record {
             sheet_id,
             score,
             model.clear_type.into(),
+            judgement,
+            max_combo,
             play_count,
             updated_at,
         ))
     }
 }
 
+fn convert_count(value: i32, column: &'static str) -> Result<u32, RecordRepositoryError> {
+    u32::try_from(value).map_err(|err| {
+        tracing::warn!(error = %err, value, column, "Record column from database must be non-negative");
+        RecordRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
 impl From<DbClearType> for DomainClearType {
     fn from(value: DbClearType) -> Self {
         match value {
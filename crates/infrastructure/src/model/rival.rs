@@ -0,0 +1,14 @@
+use chrono::Utc;
+use domain::entity::rival::Rival;
+
+use crate::entities::rivals::Model as RivalModel;
+
+impl From<RivalModel> for Rival {
+    fn from(model: RivalModel) -> Self {
+        Rival::new(
+            model.user_id.to_string(),
+            model.rival_id.to_string(),
+            model.created_at.with_timezone(&Utc),
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use chrono::Utc;
+use domain::{entity::season::Season, repository::season::SeasonRepositoryError};
+use sea_orm::ActiveValue;
+
+use crate::{
+    entities::seasons::{ActiveModel as SeasonActiveModel, Model as SeasonModel},
+    season::{period_from_db, period_to_db},
+};
+
+impl TryFrom<SeasonModel> for Season {
+    type Error = SeasonRepositoryError;
+
+    fn try_from(model: SeasonModel) -> Result<Self, Self::Error> {
+        Ok(Season::new(
+            model.id.to_string(),
+            period_from_db(&model.period)?,
+            model.starts_at.with_timezone(&Utc),
+            model.ends_at.with_timezone(&Utc),
+            model.archived_at.map(|at| at.with_timezone(&Utc)),
+        ))
+    }
+}
+
+impl From<Season> for SeasonActiveModel {
+    fn from(season: Season) -> Self {
+        // seasons are only ever inserted unarchived, so storage always assigns the id
+        SeasonActiveModel {
+            id: ActiveValue::NotSet,
+            period: ActiveValue::Set(period_to_db(*season.period()).to_owned()),
+            starts_at: ActiveValue::Set((*season.starts_at()).into()),
+            ends_at: ActiveValue::Set((*season.ends_at()).into()),
+            archived_at: ActiveValue::NotSet,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use anyhow::Error as AnyError;
+use domain::{entity::user_progress::UserProgress, repository::user::UserRepositoryError};
+
+use crate::entities::user_progress::Model as UserProgressModel;
+
+impl TryFrom<UserProgressModel> for UserProgress {
+    type Error = UserRepositoryError;
+
+    fn try_from(model: UserProgressModel) -> Result<Self, Self::Error> {
+        let rating = u32::try_from(model.rating).map_err(|err| {
+            tracing::warn!(error = %err, value = model.rating, "Rating from database must be non-negative");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+        Ok(Self::new(
+            model.user_id.to_string(),
+            model.day,
+            rating,
+            model.xp as u32,
+        ))
+    }
+}
@@ -0,0 +1,45 @@
+use anyhow::anyhow;
+use chrono::Utc;
+use domain::{entity::xp_campaign::XpCampaign, repository::xp_campaign::XpCampaignRepositoryError};
+use sea_orm::ActiveValue;
+
+use crate::entities::xp_campaigns::{
+    ActiveModel as XpCampaignActiveModel, Model as XpCampaignModel,
+};
+
+impl TryFrom<XpCampaignModel> for XpCampaign {
+    type Error = XpCampaignRepositoryError;
+
+    fn try_from(model: XpCampaignModel) -> Result<Self, Self::Error> {
+        let multiplier_percent = u32::try_from(model.multiplier_percent).map_err(|_| {
+            XpCampaignRepositoryError::InternalError(anyhow!(
+                "negative multiplier stored for XP campaign {}",
+                model.id
+            ))
+        })?;
+
+        Ok(XpCampaign::new(
+            model.id.to_string(),
+            model.name,
+            multiplier_percent,
+            model.starts_at.with_timezone(&Utc),
+            model.ends_at.with_timezone(&Utc),
+        ))
+    }
+}
+
+impl From<XpCampaign> for XpCampaignActiveModel {
+    fn from(campaign: XpCampaign) -> Self {
+        // campaigns are only ever inserted, so storage always assigns id and created_at
+        XpCampaignActiveModel {
+            id: ActiveValue::NotSet,
+            name: ActiveValue::Set(campaign.name().to_owned()),
+            multiplier_percent: ActiveValue::Set(
+                i32::try_from(*campaign.multiplier_percent()).unwrap_or(i32::MAX),
+            ),
+            starts_at: ActiveValue::Set((*campaign.starts_at()).into()),
+            ends_at: ActiveValue::Set((*campaign.ends_at()).into()),
+            created_at: ActiveValue::NotSet,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, str::FromStr};
 
 use anyhow::{Error as AnyError, anyhow};
 use chrono::Utc;
@@ -6,12 +6,17 @@ use domain::{
     entity::{difficulty::Difficulty, genre::Genre, level::Level, music::Music, sheet::Sheet},
     repository::music::MusicRepositoryError,
 };
-use sea_orm::prelude::Decimal;
-use tracing::warn;
+use sea_orm::{
+    ActiveValue, DbErr,
+    error::SqlErr,
+    prelude::{Decimal, Uuid},
+};
+use tracing::{debug, error, warn};
 
 use crate::entities::{
-    musics::Model as MusicModel, sea_orm_active_enums::Difficulty as DbDifficulty,
-    sheets::Model as SheetModel,
+    musics::{ActiveModel as MusicActiveModel, Model as MusicModel},
+    sea_orm_active_enums::Difficulty as DbDifficulty,
+    sheets::{ActiveModel as SheetActiveModel, Model as SheetModel},
 };
 
 pub fn convert_music(model: MusicModel) -> Result<Music, MusicRepositoryError> {
@@ -39,9 +44,11 @@ pub fn convert_sheets(models: Vec<SheetModel>) -> Result<Vec<Sheet>, MusicReposi
     Ok(sheets)
 }
 
-fn convert_sheet(model: SheetModel) -> Result<Sheet, MusicRepositoryError> {
+pub fn convert_sheet(model: SheetModel) -> Result<Sheet, MusicRepositoryError> {
     let difficulty = convert_difficulty(model.difficulty);
     let level = convert_level(model.level)?;
+    let note_count = convert_bound(model.note_count, "note_count")?;
+    let max_score = convert_bound(model.max_score, "max_score")?;
 
     Ok(Sheet::new(
         model.id.to_string(),
@@ -49,6 +56,8 @@ fn convert_sheet(model: SheetModel) -> Result<Sheet, MusicRepositoryError> {
         difficulty,
         level,
         model.notes_designer,
+        note_count,
+        max_score,
     ))
 }
 
@@ -94,10 +103,201 @@ fn convert_level(raw_level: i32) -> Result<Level, MusicRepositoryError> {
     })
 }
 
-fn convert_difficulty(value: DbDifficulty) -> Difficulty {
+fn convert_bound(value: i32, column: &'static str) -> Result<u32, MusicRepositoryError> {
+    u32::try_from(value).map_err(|err| {
+        warn!(error = %err, value, column, "Sheet bound from database must be non-negative");
+        MusicRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
+fn bound_to_db(value: u32) -> Result<i32, MusicRepositoryError> {
+    i32::try_from(value).map_err(|err| {
+        warn!(error = %err, value, "Sheet bound exceeds database range");
+        MusicRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
+pub fn convert_difficulty(value: DbDifficulty) -> Difficulty {
     match value {
         DbDifficulty::Easy => Difficulty::Easy,
         DbDifficulty::Normal => Difficulty::Normal,
         DbDifficulty::Hard => Difficulty::Hard,
     }
 }
+
+pub fn difficulty_to_db(value: Difficulty) -> DbDifficulty {
+    match value {
+        Difficulty::Easy => DbDifficulty::Easy,
+        Difficulty::Normal => DbDifficulty::Normal,
+        Difficulty::Hard => DbDifficulty::Hard,
+    }
+}
+
+pub fn genre_to_db(value: Genre) -> i32 {
+    match value {
+        Genre::ORIGINAL => 0,
+    }
+}
+
+/// Stores levels as `integer * 10 + decimal`, the inverse of [`convert_level`].
+pub fn level_to_db(level: &Level) -> i32 {
+    let (integer, decimal) = level.components();
+    (integer * 10 + decimal) as i32
+}
+
+fn bpm_to_db(bpm: f32) -> Result<Decimal, MusicRepositoryError> {
+    // f32's Display yields the shortest round-tripping form, so 140.1 does not turn into
+    // 140.100006103515625.
+    Decimal::from_str(&bpm.to_string())
+        .map(|value| value.round_dp(3))
+        .map_err(|err| {
+            warn!(error = %err, value = bpm, "Failed to convert BPM to decimal");
+            MusicRepositoryError::InternalError(AnyError::from(err))
+        })
+}
+
+/// Parses a stored id. Empty ids stand for rows that storage has yet to create.
+fn parse_id(
+    id: &str,
+    not_found: impl FnOnce(String) -> MusicRepositoryError,
+) -> Result<ActiveValue<Uuid>, MusicRepositoryError> {
+    if id.is_empty() {
+        return Ok(ActiveValue::NotSet);
+    }
+
+    Uuid::parse_str(id).map(ActiveValue::Set).map_err(|err| {
+        debug!(error = %err, "Failed to parse catalog id");
+        not_found(id.to_owned())
+    })
+}
+
+pub fn parse_music_uuid(music_id: &str) -> Result<Uuid, MusicRepositoryError> {
+    Uuid::parse_str(music_id).map_err(|err| {
+        debug!(error = %err, "Failed to parse music id");
+        MusicRepositoryError::MusicNotFound(music_id.to_owned())
+    })
+}
+
+pub fn parse_sheet_uuid(sheet_id: &str) -> Result<Uuid, MusicRepositoryError> {
+    Uuid::parse_str(sheet_id).map_err(|err| {
+        debug!(error = %err, "Failed to parse sheet id");
+        MusicRepositoryError::SheetNotFound(sheet_id.to_owned())
+    })
+}
+
+pub fn music_active_model(music: &Music) -> Result<MusicActiveModel, MusicRepositoryError> {
+    Ok(MusicActiveModel {
+        id: parse_id(music.id(), MusicRepositoryError::MusicNotFound)?,
+        title: ActiveValue::Set(music.title().to_owned()),
+        artist: ActiveValue::Set(music.artist().to_owned()),
+        bpm: ActiveValue::Set(bpm_to_db(*music.bpm())?),
+        genre: ActiveValue::Set(genre_to_db(*music.genre())),
+        jacket: ActiveValue::Set(music.jacket_image_url().to_owned()),
+        registration_date: ActiveValue::Set((*music.registration_date()).into()),
+        is_test: ActiveValue::Set(*music.is_test()),
+        deleted_at: ActiveValue::NotSet,
+        // Stamped by the database clock, see `stamp_catalog_updated_at`.
+        updated_at: ActiveValue::NotSet,
+    })
+}
+
+pub fn sheet_active_model(sheet: &Sheet) -> Result<SheetActiveModel, MusicRepositoryError> {
+    Ok(SheetActiveModel {
+        id: parse_id(sheet.id(), MusicRepositoryError::SheetNotFound)?,
+        music_id: ActiveValue::Set(parse_music_uuid(sheet.music_id())?),
+        difficulty: ActiveValue::Set(difficulty_to_db(*sheet.difficulty())),
+        level: ActiveValue::Set(level_to_db(sheet.level())),
+        notes_designer: ActiveValue::Set(sheet.notes_designer().to_owned()),
+        note_count: ActiveValue::Set(bound_to_db(*sheet.note_count())?),
+        max_score: ActiveValue::Set(bound_to_db(*sheet.max_score())?),
+        deleted_at: ActiveValue::NotSet,
+        // Stamped by the database clock, see `stamp_catalog_updated_at`.
+        updated_at: ActiveValue::NotSet,
+    })
+}
+
+/// Maps constraint violations raised while writing a sheet onto domain errors.
+pub fn convert_sheet_write_error(err: DbErr, sheet: &Sheet) -> MusicRepositoryError {
+    match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => {
+            warn!(music_id = %sheet.music_id(), difficulty = %sheet.difficulty(), "Sheet difficulty already taken");
+            MusicRepositoryError::SheetAlreadyExists {
+                music_id: sheet.music_id().to_owned(),
+                difficulty: *sheet.difficulty(),
+            }
+        }
+        Some(SqlErr::ForeignKeyConstraintViolation(_)) => {
+            warn!(music_id = %sheet.music_id(), "Sheet references unknown music");
+            MusicRepositoryError::MusicNotFound(sheet.music_id().to_owned())
+        }
+        _ => {
+            error!(error = %err, "Failed to write sheet");
+            MusicRepositoryError::InternalError(AnyError::from(err))
+        }
+    }
+}
+
+pub fn convert_db_error(err: DbErr, context: &'static str) -> MusicRepositoryError {
+    error!(error = %err, "{context}");
+    MusicRepositoryError::InternalError(AnyError::from(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn music_active_model_round_trips_values() {
+        let music = Music::new(
+            String::new(),
+            "Song".to_owned(),
+            "Artist".to_owned(),
+            140.1,
+            Genre::ORIGINAL,
+            "jackets/song.png".to_owned(),
+            Utc.with_ymd_and_hms(2025, 10, 1, 12, 0, 0).unwrap(),
+            false,
+        );
+
+        let active = music_active_model(&music).expect("should convert");
+
+        assert!(matches!(active.id, ActiveValue::NotSet));
+        assert_eq!(
+            active.bpm,
+            ActiveValue::Set(Decimal::from_str("140.1").unwrap())
+        );
+        assert_eq!(active.genre, ActiveValue::Set(0));
+    }
+
+    #[test]
+    fn sheet_active_model_encodes_level_and_rejects_bad_music_id() {
+        let sheet = Sheet::new(
+            String::new(),
+            "ffffffff-ffff-ffff-ffff-ffffffffffff".to_owned(),
+            Difficulty::Hard,
+            Level::new(13, 7).unwrap(),
+            "Designer".to_owned(),
+            1000,
+            1_010_000,
+        );
+        let active = sheet_active_model(&sheet).expect("should convert");
+        assert_eq!(active.level, ActiveValue::Set(137));
+        assert_eq!(active.difficulty, ActiveValue::Set(DbDifficulty::Hard));
+
+        let orphan = Sheet::new(
+            String::new(),
+            "music-1".to_owned(),
+            Difficulty::Easy,
+            Level::new(3, 0).unwrap(),
+            "Designer".to_owned(),
+            1000,
+            1_010_000,
+        );
+        assert!(matches!(
+            sheet_active_model(&orphan),
+            Err(MusicRepositoryError::MusicNotFound(id)) if id == "music-1"
+        ));
+    }
+}
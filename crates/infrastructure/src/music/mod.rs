@@ -1,23 +1,36 @@
 mod adapter;
 mod read;
+mod write;
 
 use std::sync::Arc;
 
-use domain::repository::music::{MusicRepository, MusicRepositoryError, MusicWithSheets};
-use sea_orm::DbConn;
+pub(crate) use adapter::{convert_difficulty, difficulty_to_db, genre_to_db, level_to_db};
+use chrono::{DateTime, Utc};
+use domain::{
+    entity::{music::Music, sheet::Sheet},
+    repository::music::{
+        CatalogDelta, CatalogImportReport, MusicRepository, MusicRepositoryError, MusicWithSheets,
+    },
+};
+use sea_orm::{ConnectionTrait, DbConn, TransactionTrait};
 use tracing::{debug, info, instrument};
 
-pub struct MusicRepositoryImpl {
-    db: Arc<DbConn>,
+/// Generic over the connection so catalog writes can share a transaction opened by
+/// [`UnitOfWorkImpl`](crate::UnitOfWorkImpl). Writes that need a transaction of their own run in
+/// a savepoint there.
+pub struct MusicRepositoryImpl<C = DbConn> {
+    db: Arc<C>,
 }
 
-impl MusicRepositoryImpl {
-    pub fn new(db: Arc<DbConn>) -> Self {
+impl<C> MusicRepositoryImpl<C> {
+    pub fn new(db: Arc<C>) -> Self {
         Self { db }
     }
 }
 
-impl MusicRepository for MusicRepositoryImpl {
+impl<C: ConnectionTrait + TransactionTrait + Send + Sync> MusicRepository
+    for MusicRepositoryImpl<C>
+{
     #[instrument(skip(self))]
     async fn list_with_sheets(&self) -> Result<Vec<MusicWithSheets>, MusicRepositoryError> {
         debug!("Loading music metadata via SeaORM");
@@ -25,4 +38,88 @@ impl MusicRepository for MusicRepositoryImpl {
         info!(count = musics.len(), "Music metadata loaded");
         Ok(musics)
     }
+
+    #[instrument(skip(self), fields(since = %since))]
+    async fn list_changed_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<CatalogDelta, MusicRepositoryError> {
+        let delta = read::list_changed_since(self.db.as_ref(), since).await?;
+        info!(
+            musics = delta.musics.len(),
+            removed_musics = delta.removed_musics.len(),
+            removed_sheets = delta.removed_sheets.len(),
+            "Catalog changes loaded"
+        );
+        Ok(delta)
+    }
+
+    #[instrument(skip(self, sheet_ids), fields(count = sheet_ids.len()))]
+    async fn find_sheets_by_ids(
+        &self,
+        sheet_ids: &[String],
+    ) -> Result<Vec<Sheet>, MusicRepositoryError> {
+        let sheets = read::find_sheets_by_ids(self.db.as_ref(), sheet_ids).await?;
+        info!(count = sheets.len(), "Sheets loaded by ids");
+        Ok(sheets)
+    }
+
+    #[instrument(skip(self, music), fields(title = %music.title()))]
+    async fn create_music(&self, music: Music) -> Result<Music, MusicRepositoryError> {
+        let created = write::create_music(self.db.as_ref(), music).await?;
+        info!(music_id = %created.id(), "Music persisted by repository");
+        Ok(created)
+    }
+
+    #[instrument(skip(self, music), fields(music_id = %music.id()))]
+    async fn update_music(&self, music: Music) -> Result<Music, MusicRepositoryError> {
+        let updated = write::update_music(self.db.as_ref(), music).await?;
+        info!("Music updated by repository");
+        Ok(updated)
+    }
+
+    #[instrument(skip(self), fields(music_id = %music_id))]
+    async fn delete_music(&self, music_id: &str) -> Result<(), MusicRepositoryError> {
+        write::delete_music(self.db.as_ref(), music_id).await?;
+        info!("Music archived by repository");
+        Ok(())
+    }
+
+    #[instrument(skip(self, sheet), fields(music_id = %sheet.music_id(), difficulty = %sheet.difficulty()))]
+    async fn create_sheet(&self, sheet: Sheet) -> Result<Sheet, MusicRepositoryError> {
+        let created = write::create_sheet(self.db.as_ref(), sheet).await?;
+        info!(sheet_id = %created.id(), "Sheet persisted by repository");
+        Ok(created)
+    }
+
+    #[instrument(skip(self, sheet), fields(sheet_id = %sheet.id()))]
+    async fn update_sheet(&self, sheet: Sheet) -> Result<Sheet, MusicRepositoryError> {
+        let updated = write::update_sheet(self.db.as_ref(), sheet).await?;
+        info!("Sheet updated by repository");
+        Ok(updated)
+    }
+
+    #[instrument(skip(self), fields(sheet_id = %sheet_id))]
+    async fn delete_sheet(&self, sheet_id: &str) -> Result<(), MusicRepositoryError> {
+        write::delete_sheet(self.db.as_ref(), sheet_id).await?;
+        info!("Sheet archived by repository");
+        Ok(())
+    }
+
+    #[instrument(skip(self, entries), fields(entries = entries.len(), dry_run))]
+    async fn import_catalog(
+        &self,
+        entries: Vec<MusicWithSheets>,
+        dry_run: bool,
+    ) -> Result<CatalogImportReport, MusicRepositoryError> {
+        let report = write::import_catalog(self.db.as_ref(), entries, dry_run).await?;
+        info!(
+            musics = report.musics,
+            sheets = report.sheets,
+            failures = report.failures.len(),
+            committed = report.committed,
+            "Catalog imported by repository"
+        );
+        Ok(report)
+    }
 }
@@ -1,19 +1,202 @@
+use std::collections::BTreeSet;
+
 use anyhow::Error as AnyError;
-use domain::repository::music::{MusicRepositoryError, MusicWithSheets};
-use sea_orm::{DbConn, EntityTrait, QueryOrder};
+use chrono::{DateTime, Utc};
+use domain::{
+    entity::sheet::Sheet,
+    repository::music::{
+        CatalogDelta, MusicRepositoryError, MusicTombstone, MusicWithSheets, SheetTombstone,
+    },
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, IsolationLevel, QueryFilter,
+    QueryOrder, Select, Statement, TransactionTrait,
+    prelude::{DateTimeWithTimeZone, Uuid},
+};
 use tracing::{debug, error};
 
 use super::adapter;
 use crate::entities;
 
-/// Collects every music alongside its sheets.
+/// Collects every live music alongside its live sheets. Archived rows are skipped.
 ///
 /// # Implicit dependencies
 /// - Relies on the `fk_sheets_music` foreign key relation in the database to ensure that each sheet
 ///   references an existing music entry.
-pub async fn list_with_sheets(db: &DbConn) -> Result<Vec<MusicWithSheets>, MusicRepositoryError> {
+pub async fn list_with_sheets<C: ConnectionTrait>(
+    db: &C,
+) -> Result<Vec<MusicWithSheets>, MusicRepositoryError> {
     debug!("Querying musics with related sheets");
-    let models = entities::musics::Entity::find()
+    fetch_with_live_sheets(db, entities::musics::Entity::find()).await
+}
+
+/// Loads the live sheets among `sheet_ids`. Ids that are not UUIDs cannot exist and are skipped.
+pub async fn find_sheets_by_ids<C: ConnectionTrait>(
+    db: &C,
+    sheet_ids: &[String],
+) -> Result<Vec<Sheet>, MusicRepositoryError> {
+    let uuids: Vec<Uuid> = sheet_ids
+        .iter()
+        .filter_map(|id| Uuid::parse_str(id).ok())
+        .collect();
+    if uuids.is_empty() {
+        debug!("No well-formed sheet ids supplied");
+        return Ok(Vec::new());
+    }
+
+    let models = entities::sheets::Entity::find()
+        .filter(entities::sheets::Column::Id.is_in(uuids))
+        .filter(entities::sheets::Column::DeletedAt.is_null())
+        .all(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to fetch sheets by ids");
+            MusicRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    adapter::convert_sheets(models)
+}
+
+#[derive(Debug, FromQueryResult)]
+struct SnapshotClock {
+    now: DateTimeWithTimeZone,
+    oldest_in_flight: Option<DateTimeWithTimeZone>,
+}
+
+/// Reads the snapshot's clock together with the start of the oldest other transaction still open
+/// on this database. Such a transaction may yet commit rows stamped with its own start time.
+const SNAPSHOT_CLOCK_SQL: &str = r#"
+SELECT
+    now() AS now,
+    (
+        SELECT min(xact_start)
+        FROM pg_stat_activity
+        WHERE datname = current_database()
+          AND pid <> pg_backend_pid()
+          AND xact_start IS NOT NULL
+    ) AS oldest_in_flight
+"#;
+
+/// Builds the catalog delta after `since` from the `updated_at` stamps. Archived rows are turned
+/// into tombstones, while any live music touched directly or through a sheet is returned whole.
+///
+/// Every read runs in one REPEATABLE READ transaction, so musics and sheets come from the same
+/// snapshot. Rows are stamped with the database clock at the start of their transaction, so a row
+/// the snapshot cannot see yet is stamped no earlier than the oldest transaction then in flight.
+/// The next cursor is therefore the snapshot clock moved back to that transaction's start; rows
+/// stamped after it are sent again next time and clients de-duplicate them by id.
+pub async fn list_changed_since<C: ConnectionTrait + TransactionTrait>(
+    db: &C,
+    since: DateTime<Utc>,
+) -> Result<CatalogDelta, MusicRepositoryError> {
+    debug!(%since, "Querying catalog changes");
+    let txn = db
+        .begin_with_config(Some(IsolationLevel::RepeatableRead), None)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to open catalog snapshot");
+            MusicRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    let clock = SnapshotClock::find_by_statement(Statement::from_string(
+        txn.get_database_backend(),
+        SNAPSHOT_CLOCK_SQL,
+    ))
+    .one(&txn)
+    .await
+    .map_err(|err| {
+        error!(error = %err, "Failed to read snapshot clock");
+        MusicRepositoryError::InternalError(AnyError::from(err))
+    })?
+    .ok_or_else(|| MusicRepositoryError::InternalError(anyhow::anyhow!("now() returned no row")))?;
+    let snapshot_at = clock.now.with_timezone(&Utc);
+    let settled_at = clock.oldest_in_flight.map_or(snapshot_at, |started| {
+        snapshot_at.min(started.with_timezone(&Utc))
+    });
+
+    let changed_musics = entities::musics::Entity::find()
+        .filter(entities::musics::Column::UpdatedAt.gt(since))
+        .all(&txn)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to fetch changed musics");
+            MusicRepositoryError::InternalError(AnyError::from(err))
+        })?;
+    let changed_sheets = entities::sheets::Entity::find()
+        .filter(entities::sheets::Column::UpdatedAt.gt(since))
+        .all(&txn)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to fetch changed sheets");
+            MusicRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    // An unchanged cursor is always safe, and keeps the ETag of an empty delta stable.
+    let synced_at = if changed_musics.is_empty() && changed_sheets.is_empty() {
+        since
+    } else {
+        settled_at.max(since)
+    };
+
+    let mut touched = BTreeSet::new();
+    let mut removed_musics = Vec::new();
+    for music in &changed_musics {
+        match music.deleted_at {
+            Some(deleted_at) => removed_musics.push(MusicTombstone::new(
+                music.id.to_string(),
+                deleted_at.with_timezone(&Utc),
+            )),
+            None => {
+                touched.insert(music.id);
+            }
+        }
+    }
+    let mut removed_sheets = Vec::new();
+    for sheet in &changed_sheets {
+        match sheet.deleted_at {
+            Some(deleted_at) => removed_sheets.push(SheetTombstone::new(
+                sheet.id.to_string(),
+                sheet.music_id.to_string(),
+                deleted_at.with_timezone(&Utc),
+            )),
+            None => {
+                touched.insert(sheet.music_id);
+            }
+        }
+    }
+
+    let musics = if touched.is_empty() {
+        Vec::new()
+    } else {
+        let query =
+            entities::musics::Entity::find().filter(entities::musics::Column::Id.is_in(touched));
+        fetch_with_live_sheets(&txn, query).await?
+    };
+    txn.commit().await.map_err(|err| {
+        error!(error = %err, "Failed to close catalog snapshot");
+        MusicRepositoryError::InternalError(AnyError::from(err))
+    })?;
+
+    debug!(
+        musics = musics.len(),
+        removed_musics = removed_musics.len(),
+        removed_sheets = removed_sheets.len(),
+        "Catalog changes collected"
+    );
+    Ok(CatalogDelta {
+        musics,
+        removed_musics,
+        removed_sheets,
+        synced_at,
+    })
+}
+
+async fn fetch_with_live_sheets<C: ConnectionTrait>(
+    db: &C,
+    query: Select<entities::musics::Entity>,
+) -> Result<Vec<MusicWithSheets>, MusicRepositoryError> {
+    let models = query
+        .filter(entities::musics::Column::DeletedAt.is_null())
         .order_by_asc(entities::musics::Column::RegistrationDate)
         .find_with_related(entities::sheets::Entity)
         .all(db)
@@ -26,9 +209,167 @@ pub async fn list_with_sheets(db: &DbConn) -> Result<Vec<MusicWithSheets>, Music
     let mut musics = Vec::with_capacity(models.len());
     for (music_model, sheet_models) in models {
         let music = adapter::convert_music(music_model)?;
-        let sheets = adapter::convert_sheets(sheet_models)?;
+        let sheets = adapter::convert_sheets(
+            sheet_models
+                .into_iter()
+                .filter(|sheet| sheet.deleted_at.is_none())
+                .collect(),
+        )?;
         musics.push(MusicWithSheets::new(music, sheets));
     }
 
     Ok(musics)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::TimeZone;
+    use sea_orm::{DatabaseBackend, MockDatabase, Value, prelude::Uuid};
+
+    use super::*;
+    use crate::entities::sea_orm_active_enums::Difficulty as DbDifficulty;
+
+    fn snapshot_clock(
+        now: DateTime<Utc>,
+        oldest_in_flight: Option<DateTime<Utc>>,
+    ) -> Vec<BTreeMap<&'static str, Value>> {
+        vec![BTreeMap::from([
+            ("now", Value::from(DateTimeWithTimeZone::from(now))),
+            (
+                "oldest_in_flight",
+                Value::from(oldest_in_flight.map(DateTimeWithTimeZone::from)),
+            ),
+        ])]
+    }
+
+    #[tokio::test]
+    async fn list_changed_since_reports_archived_sheets_as_tombstones() {
+        let since = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let archived_at = Utc.with_ymd_and_hms(2025, 1, 2, 9, 30, 0).unwrap();
+        let sheet = entities::sheets::Model {
+            id: Uuid::from_u128(2),
+            music_id: Uuid::from_u128(1),
+            difficulty: DbDifficulty::Hard,
+            level: 125,
+            notes_designer: "Designer".to_owned(),
+            note_count: 1000,
+            max_score: 1_010_000,
+            deleted_at: Some(archived_at.into()),
+            updated_at: archived_at.into(),
+        };
+        let snapshot_at = Utc.with_ymd_and_hms(2025, 1, 2, 10, 0, 0).unwrap();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([snapshot_clock(snapshot_at, None)])
+            .append_query_results([Vec::<entities::musics::Model>::new()])
+            .append_query_results([vec![sheet]])
+            .into_connection();
+
+        let delta = list_changed_since(&db, since)
+            .await
+            .expect("query should succeed");
+
+        assert!(delta.musics.is_empty());
+        assert!(delta.removed_musics.is_empty());
+        assert_eq!(delta.removed_sheets.len(), 1);
+        assert_eq!(delta.removed_sheets[0].id, Uuid::from_u128(2).to_string());
+        assert_eq!(
+            delta.removed_sheets[0].music_id,
+            Uuid::from_u128(1).to_string()
+        );
+        // Later than the newest stamp, so rows committed late with an older stamp are covered.
+        assert_eq!(delta.synced_at, snapshot_at);
+    }
+
+    #[tokio::test]
+    async fn list_changed_since_keeps_cursor_when_nothing_changed() {
+        let since = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([snapshot_clock(
+                Utc.with_ymd_and_hms(2025, 1, 2, 10, 0, 0).unwrap(),
+                None,
+            )])
+            .append_query_results([Vec::<entities::musics::Model>::new()])
+            .append_query_results([Vec::<entities::sheets::Model>::new()])
+            .into_connection();
+
+        let delta = list_changed_since(&db, since)
+            .await
+            .expect("query should succeed");
+
+        assert!(delta.musics.is_empty());
+        assert_eq!(delta.synced_at, since);
+    }
+
+    #[tokio::test]
+    async fn list_changed_since_steps_cursor_back_to_oldest_open_transaction() {
+        let since = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let archived_at = Utc.with_ymd_and_hms(2025, 1, 2, 9, 30, 0).unwrap();
+        let import_started_at = Utc.with_ymd_and_hms(2025, 1, 2, 9, 0, 0).unwrap();
+        let sheet = entities::sheets::Model {
+            id: Uuid::from_u128(2),
+            music_id: Uuid::from_u128(1),
+            difficulty: DbDifficulty::Hard,
+            level: 125,
+            notes_designer: "Designer".to_owned(),
+            note_count: 1000,
+            max_score: 1_010_000,
+            deleted_at: Some(archived_at.into()),
+            updated_at: archived_at.into(),
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([snapshot_clock(
+                Utc.with_ymd_and_hms(2025, 1, 2, 10, 0, 0).unwrap(),
+                Some(import_started_at),
+            )])
+            .append_query_results([Vec::<entities::musics::Model>::new()])
+            .append_query_results([vec![sheet]])
+            .into_connection();
+
+        let delta = list_changed_since(&db, since)
+            .await
+            .expect("query should succeed");
+
+        // The open import stamps its rows with its own start, before the snapshot clock.
+        assert_eq!(delta.synced_at, import_started_at);
+    }
+
+    #[tokio::test]
+    async fn find_sheets_by_ids_skips_malformed_ids_without_querying() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+        let sheets = find_sheets_by_ids(&db, &["not-a-uuid".to_owned()])
+            .await
+            .expect("query should succeed");
+
+        assert!(sheets.is_empty());
+        assert!(db.into_transaction_log().is_empty());
+    }
+
+    #[tokio::test]
+    async fn find_sheets_by_ids_converts_bounds() {
+        let sheet = entities::sheets::Model {
+            id: Uuid::from_u128(2),
+            music_id: Uuid::from_u128(1),
+            difficulty: DbDifficulty::Normal,
+            level: 95,
+            notes_designer: "Designer".to_owned(),
+            note_count: 812,
+            max_score: 1_010_000,
+            deleted_at: None,
+            updated_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap().into(),
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![sheet]])
+            .into_connection();
+
+        let sheets = find_sheets_by_ids(&db, &[Uuid::from_u128(2).to_string()])
+            .await
+            .expect("query should succeed");
+
+        assert_eq!(sheets.len(), 1);
+        assert_eq!(*sheets[0].note_count(), 812);
+        assert_eq!(*sheets[0].max_score(), 1_010_000);
+    }
+}
@@ -0,0 +1,552 @@
+use chrono::Utc;
+use domain::{
+    entity::{music::Music, sheet::Sheet},
+    repository::music::{
+        CatalogImportReport, CatalogRow, CatalogRowError, MusicRepositoryError, MusicWithSheets,
+    },
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DatabaseTransaction, DbErr,
+    EntityTrait, IntoActiveModel, QueryFilter, TransactionTrait, Value,
+    prelude::Uuid,
+    sea_query::{Alias, BinOper, Expr, OnConflict, SimpleExpr},
+};
+use tracing::debug;
+
+use super::adapter::{
+    convert_db_error, convert_music, convert_sheet, convert_sheet_write_error, music_active_model,
+    parse_music_uuid, parse_sheet_uuid, sheet_active_model,
+};
+use crate::entities;
+
+pub async fn create_music<C: ConnectionTrait>(
+    db: &C,
+    music: Music,
+) -> Result<Music, MusicRepositoryError> {
+    let model = music_active_model(&music)?
+        .insert(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to insert music"))?;
+
+    debug!(music_id = %model.id, "Music persisted");
+    convert_music(model)
+}
+
+pub async fn update_music<C: ConnectionTrait>(
+    db: &C,
+    music: Music,
+) -> Result<Music, MusicRepositoryError> {
+    let uuid = parse_music_uuid(music.id())?;
+    ensure_live_music(db, uuid, music.id()).await?;
+
+    let model = music_active_model(&music)?
+        .update(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to update music"))?;
+
+    debug!(music_id = %model.id, "Music updated");
+    convert_music(model)
+}
+
+pub async fn delete_music<C: ConnectionTrait + TransactionTrait>(
+    db: &C,
+    music_id: &str,
+) -> Result<(), MusicRepositoryError> {
+    let uuid = parse_music_uuid(music_id)?;
+    let now = Utc::now();
+    let txn = db
+        .begin()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to begin music archive transaction"))?;
+
+    let archived = entities::musics::Entity::update_many()
+        .col_expr(entities::musics::Column::DeletedAt, Expr::value(now))
+        .filter(entities::musics::Column::Id.eq(uuid))
+        .filter(entities::musics::Column::DeletedAt.is_null())
+        .exec(&txn)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to archive music"))?;
+
+    if archived.rows_affected == 0 {
+        debug!("Music not found for supplied id");
+        return Err(MusicRepositoryError::MusicNotFound(music_id.to_owned()));
+    }
+
+    let sheets = entities::sheets::Entity::update_many()
+        .col_expr(entities::sheets::Column::DeletedAt, Expr::value(now))
+        .filter(entities::sheets::Column::MusicId.eq(uuid))
+        .filter(entities::sheets::Column::DeletedAt.is_null())
+        .exec(&txn)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to archive sheets of music"))?;
+
+    txn.commit()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to commit music archive transaction"))?;
+
+    debug!(music_id = %uuid, sheets = sheets.rows_affected, "Music archived");
+    Ok(())
+}
+
+pub async fn create_sheet<C: ConnectionTrait>(
+    db: &C,
+    sheet: Sheet,
+) -> Result<Sheet, MusicRepositoryError> {
+    let active = sheet_active_model(&sheet)?;
+    ensure_live_music(db, *active.music_id.as_ref(), sheet.music_id()).await?;
+
+    let model = active
+        .insert(db)
+        .await
+        .map_err(|err| convert_sheet_write_error(err, &sheet))?;
+
+    debug!(sheet_id = %model.id, "Sheet persisted");
+    convert_sheet(model)
+}
+
+pub async fn update_sheet<C: ConnectionTrait>(
+    db: &C,
+    sheet: Sheet,
+) -> Result<Sheet, MusicRepositoryError> {
+    let uuid = parse_sheet_uuid(sheet.id())?;
+    let active = sheet_active_model(&sheet)?;
+    ensure_live_music(db, *active.music_id.as_ref(), sheet.music_id()).await?;
+
+    let current = entities::sheets::Entity::find_by_id(uuid)
+        .filter(entities::sheets::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query sheet"))?
+        .ok_or_else(|| {
+            debug!("Sheet not found for supplied id");
+            MusicRepositoryError::SheetNotFound(sheet.id().to_owned())
+        })?;
+
+    let mut target = current.into_active_model();
+    target.music_id = active.music_id;
+    target.difficulty = active.difficulty;
+    target.level = active.level;
+    target.notes_designer = active.notes_designer;
+    target.note_count = active.note_count;
+    target.max_score = active.max_score;
+
+    let model = target
+        .update(db)
+        .await
+        .map_err(|err| convert_sheet_write_error(err, &sheet))?;
+
+    debug!(sheet_id = %model.id, "Sheet updated");
+    convert_sheet(model)
+}
+
+pub async fn delete_sheet<C: ConnectionTrait>(
+    db: &C,
+    sheet_id: &str,
+) -> Result<(), MusicRepositoryError> {
+    let uuid = parse_sheet_uuid(sheet_id)?;
+
+    let now = Utc::now();
+    let archived = entities::sheets::Entity::update_many()
+        .col_expr(entities::sheets::Column::DeletedAt, Expr::value(now))
+        .filter(entities::sheets::Column::Id.eq(uuid))
+        .filter(entities::sheets::Column::DeletedAt.is_null())
+        .exec(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to archive sheet"))?;
+
+    if archived.rows_affected == 0 {
+        debug!("Sheet not found for supplied id");
+        return Err(MusicRepositoryError::SheetNotFound(sheet_id.to_owned()));
+    }
+
+    debug!(sheet_id = %uuid, "Sheet archived");
+    Ok(())
+}
+
+pub async fn import_catalog<C: ConnectionTrait + TransactionTrait>(
+    db: &C,
+    entries: Vec<MusicWithSheets>,
+    dry_run: bool,
+) -> Result<CatalogImportReport, MusicRepositoryError> {
+    let txn = db
+        .begin()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to begin catalog import transaction"))?;
+    let mut report = CatalogImportReport::default();
+
+    for (entry_index, entry) in entries.into_iter().enumerate() {
+        let savepoint = begin_savepoint(&txn).await?;
+        let upserted = upsert_music(&savepoint, &entry.music).await;
+        let music_id = match finish_savepoint(savepoint, upserted).await? {
+            Ok(music_id) => music_id,
+            Err(err) => {
+                // Sheets of a music that could not be written would only fail on the foreign key.
+                report.failures.push(CatalogRowError::new(
+                    CatalogRow::music(entry_index),
+                    err.to_string(),
+                ));
+                continue;
+            }
+        };
+        report.musics += 1;
+
+        for (sheet_index, sheet) in entry.sheets.iter().enumerate() {
+            let savepoint = begin_savepoint(&txn).await?;
+            let upserted = upsert_sheet(&savepoint, sheet, music_id).await;
+            match finish_savepoint(savepoint, upserted).await? {
+                Ok(()) => report.sheets += 1,
+                Err(err) => report.failures.push(CatalogRowError::new(
+                    CatalogRow::sheet(entry_index, sheet_index),
+                    err.to_string(),
+                )),
+            }
+        }
+    }
+
+    if dry_run || !report.failures.is_empty() {
+        txn.rollback()
+            .await
+            .map_err(|err| convert_db_error(err, "Failed to roll back catalog import"))?;
+        debug!(
+            dry_run,
+            failures = report.failures.len(),
+            "Catalog import rolled back"
+        );
+        return Ok(report);
+    }
+
+    txn.commit()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to commit catalog import"))?;
+    report.committed = true;
+
+    debug!(
+        musics = report.musics,
+        sheets = report.sheets,
+        "Catalog import committed"
+    );
+    Ok(report)
+}
+
+async fn upsert_music(
+    txn: &DatabaseTransaction,
+    music: &Music,
+) -> Result<Uuid, MusicRepositoryError> {
+    use entities::musics::Column;
+
+    const COLUMNS: [Column; 8] = [
+        Column::Title,
+        Column::Artist,
+        Column::Bpm,
+        Column::Genre,
+        Column::Jacket,
+        Column::RegistrationDate,
+        Column::IsTest,
+        Column::DeletedAt,
+    ];
+
+    let mut active = music_active_model(music)?;
+    active.deleted_at = ActiveValue::Set(None);
+    let known_id = active.id.clone().into_value();
+
+    let inserted = entities::musics::Entity::insert(active)
+        .on_conflict(
+            OnConflict::column(Column::Id)
+                .update_columns(COLUMNS)
+                .action_and_where(differs_from_excluded::<entities::musics::Entity>(&COLUMNS))
+                .to_owned(),
+        )
+        .exec_with_returning(txn)
+        .await;
+
+    match (inserted, known_id) {
+        (Ok(model), _) => Ok(model.id),
+        // An unchanged row is left alone and returns nothing, but then its id was supplied.
+        (Err(DbErr::RecordNotFound(_)), Some(Value::Uuid(Some(id)))) => Ok(*id),
+        (Err(err), _) => Err(convert_db_error(err, "Failed to upsert music")),
+    }
+}
+
+async fn upsert_sheet(
+    txn: &DatabaseTransaction,
+    sheet: &Sheet,
+    music_id: Uuid,
+) -> Result<(), MusicRepositoryError> {
+    use entities::sheets::Column;
+
+    const COLUMNS: [Column; 7] = [
+        Column::MusicId,
+        Column::Difficulty,
+        Column::Level,
+        Column::NotesDesigner,
+        Column::NoteCount,
+        Column::MaxScore,
+        Column::DeletedAt,
+    ];
+
+    let mut active = sheet_active_model(sheet)?;
+    active.music_id = ActiveValue::Set(music_id);
+    active.deleted_at = ActiveValue::Set(None);
+
+    let inserted = entities::sheets::Entity::insert(active)
+        .on_conflict(
+            OnConflict::column(Column::Id)
+                .update_columns(COLUMNS)
+                .action_and_where(differs_from_excluded::<entities::sheets::Entity>(&COLUMNS))
+                .to_owned(),
+        )
+        .exec_with_returning(txn)
+        .await;
+
+    match inserted {
+        // An unchanged row is left alone and returns nothing.
+        Ok(_) | Err(DbErr::RecordNotFound(_)) => Ok(()),
+        Err(err) => Err(convert_sheet_write_error(err, sheet)),
+    }
+}
+
+/// Restricts an upsert's update to rows whose columns actually change, so re-importing an
+/// unchanged manifest does not restamp `updated_at` and push every row into the next sync.
+fn differs_from_excluded<E: EntityTrait>(columns: &[E::Column]) -> SimpleExpr {
+    let current = columns
+        .iter()
+        .map(|column| Expr::col((E::default(), *column)).into());
+    let excluded = columns
+        .iter()
+        .map(|column| Expr::col((Alias::new("excluded"), *column)).into());
+    Expr::tuple(current).binary(BinOper::Custom("IS DISTINCT FROM"), Expr::tuple(excluded))
+}
+
+/// Rows are written inside savepoints so that a failing row does not poison the surrounding
+/// transaction and the remaining rows can still be checked.
+async fn begin_savepoint(
+    txn: &DatabaseTransaction,
+) -> Result<DatabaseTransaction, MusicRepositoryError> {
+    txn.begin()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to create savepoint"))
+}
+
+/// Releases the savepoint when the row was written and rolls back to it otherwise. The outer
+/// result only fails when the savepoint itself could not be finished.
+async fn finish_savepoint<T>(
+    savepoint: DatabaseTransaction,
+    result: Result<T, MusicRepositoryError>,
+) -> Result<Result<T, MusicRepositoryError>, MusicRepositoryError> {
+    match result {
+        Ok(value) => {
+            savepoint
+                .commit()
+                .await
+                .map_err(|err| convert_db_error(err, "Failed to release savepoint"))?;
+            Ok(Ok(value))
+        }
+        Err(err) => {
+            savepoint
+                .rollback()
+                .await
+                .map_err(|err| convert_db_error(err, "Failed to roll back savepoint"))?;
+            Ok(Err(err))
+        }
+    }
+}
+
+/// Archived musics keep their row, so the foreign key alone does not stop writes against them.
+async fn ensure_live_music<C: ConnectionTrait>(
+    db: &C,
+    music_id: Uuid,
+    raw_music_id: &str,
+) -> Result<(), MusicRepositoryError> {
+    let exists = entities::musics::Entity::find_by_id(music_id)
+        .filter(entities::musics::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query music"))?
+        .is_some();
+
+    if !exists {
+        debug!("Music not found for supplied id");
+        return Err(MusicRepositoryError::MusicNotFound(raw_music_id.to_owned()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use domain::entity::{difficulty::Difficulty, genre::Genre, level::Level};
+    use sea_orm::{
+        DatabaseBackend, MockDatabase, MockExecResult, RuntimeErr, Transaction, prelude::Decimal,
+    };
+
+    use super::*;
+    use crate::entities::sea_orm_active_enums::Difficulty as DbDifficulty;
+
+    const MUSIC_ID: &str = "ffffffff-ffff-ffff-ffff-ffffffffffff";
+
+    fn music_model() -> entities::musics::Model {
+        entities::musics::Model {
+            id: Uuid::parse_str(MUSIC_ID).unwrap(),
+            title: "Song".to_owned(),
+            artist: "Artist".to_owned(),
+            bpm: Decimal::new(1500, 1),
+            genre: 0,
+            jacket: "jacket.png".to_owned(),
+            registration_date: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap().into(),
+            is_test: false,
+            deleted_at: None,
+            updated_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap().into(),
+        }
+    }
+
+    fn sheet_model(difficulty: DbDifficulty) -> entities::sheets::Model {
+        entities::sheets::Model {
+            id: Uuid::nil(),
+            music_id: Uuid::parse_str(MUSIC_ID).unwrap(),
+            difficulty,
+            level: 80,
+            notes_designer: "Designer".to_owned(),
+            note_count: 1000,
+            max_score: 1_010_000,
+            deleted_at: None,
+            updated_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap().into(),
+        }
+    }
+
+    fn entry(difficulties: &[Difficulty]) -> MusicWithSheets {
+        let music = Music::new(
+            MUSIC_ID.to_owned(),
+            "Song".to_owned(),
+            "Artist".to_owned(),
+            150.0,
+            Genre::ORIGINAL,
+            "jacket.png".to_owned(),
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            false,
+        );
+        let sheets = difficulties
+            .iter()
+            .map(|difficulty| {
+                Sheet::new(
+                    String::new(),
+                    MUSIC_ID.to_owned(),
+                    *difficulty,
+                    Level::new(8, 0).unwrap(),
+                    "Designer".to_owned(),
+                    1000,
+                    1_010_000,
+                )
+            })
+            .collect();
+        MusicWithSheets::new(music, sheets)
+    }
+
+    #[tokio::test]
+    async fn import_catalog_dry_run_counts_rows_without_committing() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![music_model()]])
+            .append_query_results([vec![sheet_model(DbDifficulty::Easy)]])
+            .into_connection();
+
+        let report = import_catalog(&db, vec![entry(&[Difficulty::Easy])], true)
+            .await
+            .expect("import should run");
+
+        assert_eq!(report.musics, 1);
+        assert_eq!(report.sheets, 1);
+        assert!(report.failures.is_empty());
+        assert!(!report.committed);
+    }
+
+    #[tokio::test]
+    async fn import_catalog_leaves_unchanged_rows_untouched() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<entities::musics::Model>::new()])
+            .append_query_results([Vec::<entities::sheets::Model>::new()])
+            .into_connection();
+
+        let report = import_catalog(&db, vec![entry(&[Difficulty::Easy])], true)
+            .await
+            .expect("import should run");
+
+        assert_eq!(report.musics, 1);
+        assert_eq!(report.sheets, 1);
+        assert!(report.failures.is_empty());
+
+        let statements = db
+            .into_transaction_log()
+            .into_iter()
+            .flat_map(|transaction: Transaction| transaction.statements().to_vec())
+            .filter(|statement| statement.sql.contains("ON CONFLICT"))
+            .collect::<Vec<_>>();
+        assert_eq!(statements.len(), 2);
+        assert!(statements[0].sql.contains(
+            r#"WHERE ("musics"."title", "musics"."artist", "musics"."bpm", "musics"."genre", "musics"."jacket", "musics"."registration_date", "musics"."is_test", "musics"."deleted_at") IS DISTINCT FROM ("excluded"."title""#
+        ));
+        assert!(
+            statements[1]
+                .sql
+                .contains(r#"IS DISTINCT FROM ("excluded"."music_id""#)
+        );
+    }
+
+    #[tokio::test]
+    async fn import_catalog_reports_failing_rows_and_rolls_back() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![music_model()]])
+            .append_query_errors([DbErr::Query(RuntimeErr::Internal(
+                "connection reset".to_owned(),
+            ))])
+            .append_query_results([vec![sheet_model(DbDifficulty::Hard)]])
+            .into_connection();
+
+        let report = import_catalog(
+            &db,
+            vec![entry(&[Difficulty::Easy, Difficulty::Hard])],
+            false,
+        )
+        .await
+        .expect("import should run");
+
+        assert_eq!(report.musics, 1);
+        assert_eq!(report.sheets, 1);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].row, CatalogRow::sheet(0, 0));
+        assert!(!report.committed);
+    }
+
+    #[tokio::test]
+    async fn delete_sheet_reports_missing_rows() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let err = delete_sheet(&db, MUSIC_ID).await.expect_err("should fail");
+
+        assert!(matches!(err, MusicRepositoryError::SheetNotFound(id) if id == MUSIC_ID));
+    }
+
+    #[tokio::test]
+    async fn create_sheet_rejects_archived_music() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<entities::musics::Model>::new()])
+            .into_connection();
+        let sheet = Sheet::new(
+            String::new(),
+            MUSIC_ID.to_owned(),
+            Difficulty::Normal,
+            Level::new(8, 0).unwrap(),
+            "Designer".to_owned(),
+            1000,
+            1_010_000,
+        );
+
+        let err = create_sheet(&db, sheet).await.expect_err("should fail");
+
+        assert!(matches!(err, MusicRepositoryError::MusicNotFound(id) if id == MUSIC_ID));
+    }
+}
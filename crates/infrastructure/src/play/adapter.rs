@@ -0,0 +1,163 @@
+use anyhow::Error as AnyError;
+use domain::{
+    entity::{level::Level, play::Play, submission_result::SubmissionResult},
+    repository::play::PlayRepositoryError,
+};
+use sea_orm::{ActiveValue, DbErr, prelude::Uuid};
+use tracing::{debug, error, warn};
+
+use crate::entities::{
+    plays::ActiveModel as PlayActiveModel, sea_orm_active_enums::ClearType as DbClearType,
+    submission_results::ActiveModel as SubmissionResultActiveModel,
+};
+
+/// Builds an `ActiveModel` for inserts, delegating `id` generation to the database default
+/// (`gen_random_uuid()` defined in `m20251114_000010_create_plays_table`).
+pub fn active_model_for_insert(play: &Play) -> Result<PlayActiveModel, PlayRepositoryError> {
+    let client_uuid = Uuid::parse_str(play.client_id()).map_err(|err| {
+        debug!(error = %err, "Failed to parse client id");
+        PlayRepositoryError::InternalError(AnyError::from(err))
+    })?;
+    let judgement = play.judgement();
+
+    Ok(PlayActiveModel {
+        id: ActiveValue::NotSet,
+        user_id: ActiveValue::Set(parse_user_uuid(play.user_id())?),
+        sheet_id: ActiveValue::Set(parse_sheet_uuid(play.sheet_id())?),
+        client_id: ActiveValue::Set(client_uuid),
+        score: ActiveValue::Set(convert_count(*play.score())?),
+        clear_type: ActiveValue::Set(DbClearType::from(*play.clear_type())),
+        judge_perfect: ActiveValue::Set(convert_count(*judgement.perfect())?),
+        judge_great: ActiveValue::Set(convert_count(*judgement.great())?),
+        judge_good: ActiveValue::Set(convert_count(*judgement.good())?),
+        judge_miss: ActiveValue::Set(convert_count(*judgement.miss())?),
+        played_at: ActiveValue::Set((*play.played_at()).into()),
+        submission_id: ActiveValue::Set(
+            play.submission_id()
+                .as_deref()
+                .map(parse_submission_uuid)
+                .transpose()?,
+        ),
+    })
+}
+
+/// Builds the stored answer of one submission. The record comes straight from the upsert, so
+/// malformed ids here are a bug.
+pub fn result_active_model(
+    user_uuid: Uuid,
+    result: &SubmissionResult,
+) -> Result<SubmissionResultActiveModel, PlayRepositoryError> {
+    let record = result.record();
+    let judgement = record.judgement();
+    let parse_id = |id: &str| {
+        Uuid::parse_str(id).map_err(|err| {
+            warn!(error = %err, "Failed to parse stored record id");
+            PlayRepositoryError::InternalError(AnyError::from(err))
+        })
+    };
+
+    Ok(SubmissionResultActiveModel {
+        submission_id: ActiveValue::Set(parse_submission_uuid(result.submission_id())?),
+        user_id: ActiveValue::Set(user_uuid),
+        record_id: ActiveValue::Set(parse_id(record.id())?),
+        sheet_id: ActiveValue::Set(parse_id(record.sheet_id())?),
+        score: ActiveValue::Set(convert_count(*record.score())?),
+        clear_type: ActiveValue::Set(DbClearType::from(*record.clear_type())),
+        judge_perfect: ActiveValue::Set(convert_count(*judgement.perfect())?),
+        judge_great: ActiveValue::Set(convert_count(*judgement.great())?),
+        judge_good: ActiveValue::Set(convert_count(*judgement.good())?),
+        judge_miss: ActiveValue::Set(convert_count(*judgement.miss())?),
+        max_combo: ActiveValue::Set(convert_count(*record.max_combo())?),
+        play_count: ActiveValue::Set(convert_count(*record.play_count())?),
+        record_updated_at: ActiveValue::Set((*record.updated_at()).into()),
+        level_from: ActiveValue::Set(
+            result
+                .level_up()
+                .map(|level_up| convert_count(*level_up.from()))
+                .transpose()?,
+        ),
+        level_to: ActiveValue::Set(
+            result
+                .level_up()
+                .map(|level_up| convert_count(*level_up.to()))
+                .transpose()?,
+        ),
+    })
+}
+
+pub fn parse_user_uuid(user_id: &str) -> Result<Uuid, PlayRepositoryError> {
+    Uuid::parse_str(user_id).map_err(|err| {
+        debug!(error = %err, "Failed to parse user id");
+        PlayRepositoryError::UserNotFound(user_id.to_owned())
+    })
+}
+
+fn parse_sheet_uuid(sheet_id: &str) -> Result<Uuid, PlayRepositoryError> {
+    Uuid::parse_str(sheet_id).map_err(|err| {
+        debug!(error = %err, "Failed to parse sheet id");
+        PlayRepositoryError::SheetNotFound(sheet_id.to_owned())
+    })
+}
+
+/// Submission ids are validated at the API boundary, so a malformed one here is a bug.
+pub fn parse_submission_uuid(submission_id: &str) -> Result<Uuid, PlayRepositoryError> {
+    Uuid::parse_str(submission_id).map_err(|err| {
+        warn!(error = %err, "Failed to parse submission id");
+        PlayRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
+/// Sheet levels are stored as tenths, e.g. `137` for 13.7.
+pub fn convert_level(raw_level: i32) -> Result<Level, PlayRepositoryError> {
+    let level = u32::try_from(raw_level)
+        .map_err(AnyError::from)
+        .and_then(|value| Level::new(value / 10, value % 10).map_err(AnyError::from));
+    level.map_err(|err| {
+        warn!(error = %err, value = raw_level, "Invalid sheet level");
+        PlayRepositoryError::InternalError(err)
+    })
+}
+
+pub fn convert_score(raw_score: i32) -> Result<u32, PlayRepositoryError> {
+    u32::try_from(raw_score).map_err(|err| {
+        warn!(error = %err, value = raw_score, "Negative play score");
+        PlayRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
+fn convert_count(value: u32) -> Result<i32, PlayRepositoryError> {
+    i32::try_from(value).map_err(|err| {
+        warn!(error = %err, value, "Play value exceeds database range");
+        PlayRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
+/// Maps foreign key violations back to the offending id. A batch shares one user, but may span
+/// several sheets, so the sheet is only reported when the batch has exactly one.
+pub fn convert_insert_error(err: DbErr, plays: &[Play]) -> PlayRepositoryError {
+    let message = err.to_string();
+    let first = plays.first();
+    if message.contains("fk_plays_user") {
+        let user_id = first
+            .map(|play| play.user_id().to_owned())
+            .unwrap_or_default();
+        warn!(user_id = %user_id, "User not found for foreign key constraint");
+        return PlayRepositoryError::UserNotFound(user_id);
+    }
+
+    if message.contains("fk_plays_sheet") {
+        let sheet_id = match plays {
+            [play] => play.sheet_id().to_owned(),
+            _ => String::new(),
+        };
+        warn!(sheet_id = %sheet_id, "Sheet not found for foreign key constraint");
+        return PlayRepositoryError::SheetNotFound(sheet_id);
+    }
+
+    convert_db_error(err, "Failed to insert plays")
+}
+
+pub fn convert_db_error(err: DbErr, context: &'static str) -> PlayRepositoryError {
+    error!(error = %err, "{context}");
+    PlayRepositoryError::InternalError(AnyError::from(err))
+}
@@ -0,0 +1,146 @@
+mod adapter;
+mod read;
+mod write;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use domain::{
+    entity::{play::Play, submission_result::SubmissionResult},
+    repository::{
+        play::{
+            PlayCountRankingRow, PlayPage, PlayRepository, PlayRepositoryError, RecentPlayScore,
+        },
+        ranking::RankingWindow,
+        record::TotalScoreRankingRow,
+    },
+};
+use read::{
+    plays_by_user, public_play_count_ranking, public_total_score_ranking_between,
+    recent_scores_by_user, results_by_submission_ids, submitted_ids,
+};
+use sea_orm::{ConnectionTrait, DbConn};
+use tracing::{debug, info, instrument};
+use write::{insert_plays, insert_results};
+
+pub struct PlayRepositoryImpl<C = DbConn> {
+    db: Arc<C>,
+}
+
+impl<C> PlayRepositoryImpl<C> {
+    pub fn new(db: Arc<C>) -> Self {
+        Self { db }
+    }
+}
+
+impl<C: ConnectionTrait + Send + Sync> PlayRepository for PlayRepositoryImpl<C> {
+    #[instrument(skip(self, plays), fields(count = plays.len()))]
+    async fn append(&self, plays: Vec<Play>) -> Result<(), PlayRepositoryError> {
+        debug!("Appending plays via SeaORM");
+        insert_plays(self.db.as_ref(), plays).await?;
+        info!("Plays appended successfully");
+        Ok(())
+    }
+
+    #[instrument(skip(self, submission_ids), fields(user_id = %user_id, count = submission_ids.len()))]
+    async fn find_submitted_ids(
+        &self,
+        user_id: &str,
+        submission_ids: &[String],
+    ) -> Result<Vec<String>, PlayRepositoryError> {
+        debug!("Looking up submitted plays via SeaORM");
+        let ids = submitted_ids(self.db.as_ref(), user_id, submission_ids).await?;
+        info!(count = ids.len(), "Submitted plays resolved successfully");
+        Ok(ids)
+    }
+
+    #[instrument(skip(self, results), fields(user_id = %user_id, count = results.len()))]
+    async fn save_results(
+        &self,
+        user_id: &str,
+        results: Vec<SubmissionResult>,
+    ) -> Result<(), PlayRepositoryError> {
+        debug!("Storing submission results via SeaORM");
+        insert_results(self.db.as_ref(), user_id, results).await?;
+        info!("Submission results stored successfully");
+        Ok(())
+    }
+
+    #[instrument(skip(self, submission_ids), fields(user_id = %user_id, count = submission_ids.len()))]
+    async fn find_results(
+        &self,
+        user_id: &str,
+        submission_ids: &[String],
+    ) -> Result<Vec<SubmissionResult>, PlayRepositoryError> {
+        debug!("Looking up submission results via SeaORM");
+        let results = results_by_submission_ids(self.db.as_ref(), user_id, submission_ids).await?;
+        info!(
+            count = results.len(),
+            "Submission results resolved successfully"
+        );
+        Ok(results)
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id, limit, offset))]
+    async fn find_by_user_id(
+        &self,
+        user_id: &str,
+        limit: u64,
+        offset: u64,
+    ) -> Result<PlayPage, PlayRepositoryError> {
+        debug!("Fetching plays via SeaORM");
+        let page = plays_by_user(self.db.as_ref(), user_id, limit, offset).await?;
+        info!(
+            count = page.plays.len(),
+            total = page.total,
+            "Plays fetched successfully"
+        );
+        Ok(page)
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id, limit))]
+    async fn find_recent_scores(
+        &self,
+        user_id: &str,
+        limit: u64,
+    ) -> Result<Vec<RecentPlayScore>, PlayRepositoryError> {
+        debug!("Fetching recent play scores via SeaORM");
+        let scores = recent_scores_by_user(self.db.as_ref(), user_id, limit).await?;
+        info!(
+            count = scores.len(),
+            "Recent play scores fetched successfully"
+        );
+        Ok(scores)
+    }
+
+    #[instrument(skip(self), fields(%from, %to))]
+    async fn find_public_total_score_ranking_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        window: &RankingWindow,
+    ) -> Result<Vec<TotalScoreRankingRow>, PlayRepositoryError> {
+        debug!("Fetching period total score ranking via SeaORM");
+        let rows = public_total_score_ranking_between(self.db.as_ref(), from, to, window).await?;
+        info!(
+            count = rows.len(),
+            "Period total score ranking fetched successfully"
+        );
+        Ok(rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_public_play_count_ranking(
+        &self,
+        between: Option<(DateTime<Utc>, DateTime<Utc>)>,
+        window: &RankingWindow,
+    ) -> Result<Vec<PlayCountRankingRow>, PlayRepositoryError> {
+        debug!("Fetching play count ranking via SeaORM");
+        let rows = public_play_count_ranking(self.db.as_ref(), between, window).await?;
+        info!(
+            count = rows.len(),
+            "Play count ranking fetched successfully"
+        );
+        Ok(rows)
+    }
+}
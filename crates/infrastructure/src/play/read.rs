@@ -0,0 +1,454 @@
+use std::convert::TryFrom;
+
+use anyhow::Error as AnyError;
+use bigdecimal::ToPrimitive;
+use chrono::{DateTime, Utc};
+use domain::{
+    entity::{play::Play, submission_result::SubmissionResult},
+    repository::{
+        play::{PlayCountRankingRow, PlayPage, PlayRepositoryError, RecentPlayScore},
+        ranking::RankingWindow,
+        record::TotalScoreRankingRow,
+    },
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, JoinType, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait, Value, prelude::Uuid,
+    sqlx::types::BigDecimal,
+};
+use tracing::{debug, error};
+
+use super::adapter::{
+    convert_db_error, convert_level, convert_score, parse_submission_uuid, parse_user_uuid,
+};
+use crate::{entities, ranking::window_statement};
+
+#[derive(Debug, FromQueryResult)]
+struct RecentScoreRow {
+    sheet_id: Uuid,
+    level: i32,
+    score: i32,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct TotalScoreRow {
+    user_id: Uuid,
+    display_name: String,
+    total_score: BigDecimal,
+    rank: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct PlayCountRow {
+    user_id: Uuid,
+    display_name: String,
+    plays: i64,
+    rank: i64,
+}
+
+/// Ranks public users by the sum of their best score per sheet among the plays made in a period.
+/// Ties are listed by display name.
+const TOTAL_SCORE_BETWEEN_SQL: &str = r#"SELECT "users"."id" AS "user_id", "users"."display_name", CAST(SUM("best"."score") AS numeric) AS "total_score", RANK() OVER (ORDER BY SUM("best"."score") DESC) AS "rank", ROW_NUMBER() OVER (ORDER BY SUM("best"."score") DESC, "users"."display_name" ASC, "users"."id" ASC) AS "position" FROM (SELECT "user_id", "sheet_id", MAX("score") AS "score" FROM "plays" WHERE "played_at" >= $1 AND "played_at" < $2 GROUP BY "user_id", "sheet_id") AS "best" INNER JOIN "users" ON "users"."id" = "best"."user_id" WHERE "users"."is_public" = TRUE GROUP BY "users"."id", "users"."display_name""#;
+
+pub async fn public_total_score_ranking_between<C: ConnectionTrait>(
+    db: &C,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    window: &RankingWindow,
+) -> Result<Vec<TotalScoreRankingRow>, PlayRepositoryError> {
+    let statement = window_statement(
+        db.get_database_backend(),
+        TOTAL_SCORE_BETWEEN_SQL,
+        vec![from.into(), to.into()],
+        window,
+        parse_user_uuid,
+    )?;
+    let rows = TotalScoreRow::find_by_statement(statement)
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to fetch period total score ranking"))?;
+
+    rows.into_iter()
+        .map(|row| {
+            let total_score = row.total_score.to_u64().ok_or_else(|| {
+                error!("Period total score does not fit in u64");
+                PlayRepositoryError::InternalError(AnyError::msg(
+                    "Failed to convert total score to u64",
+                ))
+            })?;
+            Ok(TotalScoreRankingRow::new(
+                convert_rank(row.rank)?,
+                row.user_id.to_string(),
+                row.display_name,
+                total_score,
+            ))
+        })
+        .collect()
+}
+
+pub async fn public_play_count_ranking<C: ConnectionTrait>(
+    db: &C,
+    between: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    window: &RankingWindow,
+) -> Result<Vec<PlayCountRankingRow>, PlayRepositoryError> {
+    let (period_filter, values): (&str, Vec<Value>) = match between {
+        Some((from, to)) => (
+            r#" AND "plays"."played_at" >= $1 AND "plays"."played_at" < $2"#,
+            vec![from.into(), to.into()],
+        ),
+        None => ("", Vec::new()),
+    };
+    // Ties are listed by display name.
+    let ranked_sql = format!(
+        r#"SELECT "users"."id" AS "user_id", "users"."display_name", COUNT(*) AS "plays", RANK() OVER (ORDER BY COUNT(*) DESC) AS "rank", ROW_NUMBER() OVER (ORDER BY COUNT(*) DESC, "users"."display_name" ASC, "users"."id" ASC) AS "position" FROM "plays" INNER JOIN "users" ON "users"."id" = "plays"."user_id" WHERE "users"."is_public" = TRUE{period_filter} GROUP BY "users"."id", "users"."display_name""#
+    );
+    let statement = window_statement(
+        db.get_database_backend(),
+        &ranked_sql,
+        values,
+        window,
+        parse_user_uuid,
+    )?;
+    let rows = PlayCountRow::find_by_statement(statement)
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to fetch play count ranking"))?;
+
+    rows.into_iter()
+        .map(|row| {
+            let plays = u64::try_from(row.plays).map_err(|err| {
+                error!(error = %err, "Failed to convert play count to u64");
+                PlayRepositoryError::InternalError(AnyError::from(err))
+            })?;
+            Ok(PlayCountRankingRow::new(
+                convert_rank(row.rank)?,
+                row.user_id.to_string(),
+                row.display_name,
+                plays,
+            ))
+        })
+        .collect()
+}
+
+fn convert_rank(rank: i64) -> Result<u32, PlayRepositoryError> {
+    u32::try_from(rank).map_err(|err| {
+        error!(error = %err, "Failed to convert rank to u32");
+        PlayRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
+pub async fn plays_by_user<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    limit: u64,
+    offset: u64,
+) -> Result<PlayPage, PlayRepositoryError> {
+    let uuid = parse_user_uuid(user_id)?;
+
+    let user = entities::users::Entity::find_by_id(uuid)
+        .one(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to verify user existence"))?;
+    if user.is_none() {
+        debug!("User not found while querying plays");
+        return Err(PlayRepositoryError::UserNotFound(user_id.to_owned()));
+    }
+
+    let query = entities::plays::Entity::find().filter(entities::plays::Column::UserId.eq(uuid));
+    let total = query
+        .clone()
+        .count(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to count plays"))?;
+
+    let models = query
+        .order_by_desc(entities::plays::Column::PlayedAt)
+        .order_by_desc(entities::plays::Column::Id)
+        .limit(limit)
+        .offset(offset)
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to fetch plays"))?;
+
+    let plays = models
+        .into_iter()
+        .map(Play::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(PlayPage::new(plays, total))
+}
+
+pub async fn submitted_ids<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    submission_ids: &[String],
+) -> Result<Vec<String>, PlayRepositoryError> {
+    if submission_ids.is_empty() {
+        debug!("No submission ids to look up");
+        return Ok(Vec::new());
+    }
+
+    let uuid = parse_user_uuid(user_id)?;
+    let submission_uuids = submission_ids
+        .iter()
+        .map(|id| parse_submission_uuid(id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let models = entities::plays::Entity::find()
+        .filter(entities::plays::Column::UserId.eq(uuid))
+        .filter(entities::plays::Column::SubmissionId.is_in(submission_uuids))
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query submitted plays"))?;
+
+    Ok(models
+        .into_iter()
+        .filter_map(|model| model.submission_id.map(|id| id.to_string()))
+        .collect())
+}
+
+pub async fn results_by_submission_ids<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    submission_ids: &[String],
+) -> Result<Vec<SubmissionResult>, PlayRepositoryError> {
+    if submission_ids.is_empty() {
+        debug!("No submission results to look up");
+        return Ok(Vec::new());
+    }
+
+    let uuid = parse_user_uuid(user_id)?;
+    let submission_uuids = submission_ids
+        .iter()
+        .map(|id| parse_submission_uuid(id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    entities::submission_results::Entity::find()
+        .filter(entities::submission_results::Column::UserId.eq(uuid))
+        .filter(entities::submission_results::Column::SubmissionId.is_in(submission_uuids))
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query submission results"))?
+        .into_iter()
+        .map(SubmissionResult::try_from)
+        .collect()
+}
+
+pub async fn recent_scores_by_user<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    limit: u64,
+) -> Result<Vec<RecentPlayScore>, PlayRepositoryError> {
+    let uuid = parse_user_uuid(user_id)?;
+
+    let rows = entities::plays::Entity::find()
+        .select_only()
+        .column(entities::plays::Column::SheetId)
+        .column(entities::sheets::Column::Level)
+        .column(entities::plays::Column::Score)
+        .join(JoinType::InnerJoin, entities::plays::Relation::Sheets.def())
+        .join(
+            JoinType::InnerJoin,
+            entities::sheets::Relation::Musics.def(),
+        )
+        .filter(entities::plays::Column::UserId.eq(uuid))
+        .filter(entities::musics::Column::IsTest.eq(false))
+        .order_by_desc(entities::plays::Column::PlayedAt)
+        .order_by_desc(entities::plays::Column::Id)
+        .limit(limit)
+        .into_model::<RecentScoreRow>()
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to fetch recent plays"))?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(RecentPlayScore::new(
+                row.sheet_id.to_string(),
+                convert_level(row.level)?,
+                convert_score(row.score)?,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{TimeZone, Utc};
+    use domain::{entity::clear_type::ClearType, testing::api_key::CABINET_KEY};
+    use sea_orm::{DatabaseBackend, MockDatabase, prelude::Uuid, sea_query::Value};
+
+    use super::*;
+    use crate::entities::sea_orm_active_enums::ClearType as DbClearType;
+
+    const USER_ID: &str = "bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb";
+
+    fn user_model(id: Uuid) -> entities::users::Model {
+        let now = Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap();
+        entities::users::Model {
+            id,
+            card: "CARD-0001".to_owned(),
+            display_name: "Alice".to_owned(),
+            rating: 0,
+            xp: 0,
+            credits: 0,
+            is_public: true,
+            is_admin: false,
+            created_at: now.into(),
+            updated_at: now.into(),
+        }
+    }
+
+    fn count_row(value: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([("num_items".to_owned(), Value::BigInt(Some(value)))])
+    }
+
+    #[tokio::test]
+    async fn plays_by_user_converts_rows_and_total() {
+        let user_id = Uuid::parse_str(USER_ID).expect("valid uuid");
+        let played_at = Utc.with_ymd_and_hms(2025, 11, 14, 12, 0, 0).unwrap();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![user_model(user_id)]])
+            .append_query_results([vec![count_row(3)]])
+            .append_query_results([vec![entities::plays::Model {
+                id: Uuid::from_u128(1),
+                user_id,
+                sheet_id: Uuid::from_u128(2),
+                client_id: Uuid::parse_str(CABINET_KEY.client_id).expect("valid uuid"),
+                score: 987_000,
+                clear_type: DbClearType::FullCombo,
+                judge_perfect: 900,
+                judge_great: 100,
+                judge_good: 0,
+                judge_miss: 0,
+                played_at: played_at.into(),
+                submission_id: None,
+            }]])
+            .into_connection();
+
+        let page = plays_by_user(&db, USER_ID, 1, 0).await.unwrap();
+
+        assert_eq!(page.total, 3);
+        assert_eq!(page.plays.len(), 1);
+        let play = &page.plays[0];
+        assert_eq!(play.client_id(), CABINET_KEY.client_id);
+        assert_eq!(*play.clear_type(), ClearType::FullCombo);
+        assert_eq!(play.judgement().total(), 1000);
+        assert_eq!(*play.played_at(), played_at);
+    }
+
+    #[tokio::test]
+    async fn plays_by_user_reports_missing_user() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<entities::users::Model>::new()])
+            .into_connection();
+
+        let err = plays_by_user(&db, USER_ID, 20, 0)
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, PlayRepositoryError::UserNotFound(id) if id == USER_ID));
+    }
+
+    #[tokio::test]
+    async fn submitted_ids_returns_stored_ids() {
+        let user_id = Uuid::parse_str(USER_ID).expect("valid uuid");
+        let submission_id = Uuid::from_u128(10);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![entities::plays::Model {
+                id: Uuid::from_u128(1),
+                user_id,
+                sheet_id: Uuid::from_u128(2),
+                client_id: Uuid::parse_str(CABINET_KEY.client_id).expect("valid uuid"),
+                score: 987_000,
+                clear_type: DbClearType::Clear,
+                judge_perfect: 900,
+                judge_great: 80,
+                judge_good: 20,
+                judge_miss: 0,
+                played_at: Utc.with_ymd_and_hms(2025, 11, 17, 12, 0, 0).unwrap().into(),
+                submission_id: Some(submission_id),
+            }]])
+            .into_connection();
+
+        let ids = submitted_ids(
+            &db,
+            USER_ID,
+            &[submission_id.to_string(), Uuid::from_u128(11).to_string()],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(ids, vec![submission_id.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn submitted_ids_skips_query_for_empty_input() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+        let ids = submitted_ids(&db, USER_ID, &[]).await.unwrap();
+
+        assert!(ids.is_empty());
+        assert!(db.into_transaction_log().is_empty());
+    }
+
+    #[tokio::test]
+    async fn recent_scores_by_user_converts_levels() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![BTreeMap::from([
+                (
+                    "sheet_id".to_owned(),
+                    Value::Uuid(Some(Box::new(Uuid::from_u128(3)))),
+                ),
+                ("level".to_owned(), Value::Int(Some(137))),
+                ("score".to_owned(), Value::Int(Some(1_000_000))),
+            ])]])
+            .into_connection();
+
+        let scores = recent_scores_by_user(&db, USER_ID, 10).await.unwrap();
+
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].sheet_id, Uuid::from_u128(3).to_string());
+        assert_eq!(scores[0].level.components(), (13, 7));
+        assert_eq!(scores[0].score, 1_000_000);
+        let log = db.into_transaction_log();
+        let sql = &log[0].statements()[0].sql;
+        assert!(sql.contains(r#""musics"."is_test" = $2"#));
+        assert!(sql.contains(r#"ORDER BY "plays"."played_at" DESC"#));
+    }
+
+    #[tokio::test]
+    async fn public_play_count_ranking_filters_the_period() {
+        let user_id = Uuid::parse_str(USER_ID).expect("valid uuid");
+        let from = Utc.with_ymd_and_hms(2025, 11, 17, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2025, 11, 24, 0, 0, 0).unwrap();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![BTreeMap::from([
+                ("user_id".to_owned(), Value::Uuid(Some(Box::new(user_id)))),
+                (
+                    "display_name".to_owned(),
+                    Value::String(Some(Box::new("Alice".to_owned()))),
+                ),
+                ("plays".to_owned(), Value::BigInt(Some(12))),
+                ("rank".to_owned(), Value::BigInt(Some(1))),
+            ])]])
+            .into_connection();
+
+        let window = RankingWindow::Page {
+            offset: 0,
+            limit: 20,
+        };
+        let rows = public_play_count_ranking(&db, Some((from, to)), &window)
+            .await
+            .unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].plays, 12);
+        assert_eq!(rows[0].rank, 1);
+        let log = db.into_transaction_log();
+        let sql = format!("{log:?}");
+        assert!(sql.contains(r#"\"plays\".\"played_at\" >= $1"#));
+    }
+}
@@ -0,0 +1,204 @@
+use domain::{
+    entity::{play::Play, submission_result::SubmissionResult},
+    repository::play::PlayRepositoryError,
+};
+use sea_orm::{ConnectionTrait, EntityTrait, sea_query::OnConflict};
+use tracing::{debug, warn};
+
+use super::adapter::{
+    active_model_for_insert, convert_db_error, convert_insert_error, parse_user_uuid,
+    result_active_model,
+};
+use crate::entities;
+
+pub async fn insert_plays<C: ConnectionTrait>(
+    db: &C,
+    plays: Vec<Play>,
+) -> Result<(), PlayRepositoryError> {
+    if plays.is_empty() {
+        debug!("No plays to append");
+        return Ok(());
+    }
+
+    let models = plays
+        .iter()
+        .map(active_model_for_insert)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Submission ids are unique per user. Colliding rows are skipped rather than failing the
+    // statement, so the surrounding transaction stays usable, and reported below.
+    let inserted = entities::plays::Entity::insert_many(models)
+        .on_conflict(
+            OnConflict::columns([
+                entities::plays::Column::UserId,
+                entities::plays::Column::SubmissionId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(db)
+        .await
+        .map_err(|err| convert_insert_error(err, &plays))?;
+
+    if inserted < plays.len() as u64 {
+        let submission_id = match plays.as_slice() {
+            [play] => play.submission_id().clone().unwrap_or_default(),
+            _ => String::new(),
+        };
+        warn!(submission_id = %submission_id, "Submission id already recorded");
+        return Err(PlayRepositoryError::DuplicateSubmission(submission_id));
+    }
+
+    Ok(())
+}
+
+pub async fn insert_results<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    results: Vec<SubmissionResult>,
+) -> Result<(), PlayRepositoryError> {
+    if results.is_empty() {
+        debug!("No submission results to store");
+        return Ok(());
+    }
+
+    let user_uuid = parse_user_uuid(user_id)?;
+    let models = results
+        .iter()
+        .map(|result| result_active_model(user_uuid, result))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    entities::submission_results::Entity::insert_many(models)
+        .exec_without_returning(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to insert submission results"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use domain::{
+        entity::{
+            clear_type::ClearType, judgement::Judgement, record::Record, submission_result::LevelUp,
+        },
+        testing::api_key::CABINET_KEY,
+    };
+    use sea_orm::{DatabaseBackend, DbErr, MockDatabase, MockExecResult, RuntimeErr};
+
+    use super::*;
+
+    const USER_ID: &str = "bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb";
+    const SHEET_ID: &str = "cccccccc-cccc-cccc-cccc-cccccccccccc";
+
+    fn submitted(sheet_id: &str) -> Play {
+        Play::new_from_submission(
+            "dddddddd-dddd-dddd-dddd-dddddddddddd".to_owned(),
+            USER_ID.to_owned(),
+            sheet_id.to_owned(),
+            CABINET_KEY.client_id.to_owned(),
+            987_000,
+            ClearType::Clear,
+            Judgement::new(900, 80, 15, 5),
+            Utc.with_ymd_and_hms(2025, 11, 14, 12, 0, 0).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn insert_plays_skips_empty_batches() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+        insert_plays(&db, Vec::new()).await.unwrap();
+
+        assert!(db.into_transaction_log().is_empty());
+    }
+
+    #[tokio::test]
+    async fn insert_plays_writes_one_statement() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        insert_plays(&db, vec![submitted(SHEET_ID), submitted(SHEET_ID)])
+            .await
+            .unwrap();
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        assert!(
+            log[0].statements()[0]
+                .sql
+                .ends_with(r#"ON CONFLICT ("user_id", "submission_id") DO NOTHING"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn insert_plays_maps_sheet_foreign_key_violation() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_errors([DbErr::Exec(RuntimeErr::Internal(
+                "violates foreign key constraint \"fk_plays_sheet\"".to_owned(),
+            ))])
+            .into_connection();
+
+        let err = insert_plays(&db, vec![submitted(SHEET_ID)])
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, PlayRepositoryError::SheetNotFound(id) if id == SHEET_ID));
+    }
+
+    #[tokio::test]
+    async fn insert_plays_reports_skipped_rows_as_duplicate_submission() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let err = insert_plays(&db, vec![submitted(SHEET_ID)])
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(
+            err,
+            PlayRepositoryError::DuplicateSubmission(id) if id == "dddddddd-dddd-dddd-dddd-dddddddddddd"
+        ));
+    }
+
+    #[tokio::test]
+    async fn insert_results_writes_one_statement() {
+        let record = Record::new(
+            "eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee".to_owned(),
+            USER_ID.to_owned(),
+            SHEET_ID.to_owned(),
+            987_000,
+            ClearType::Clear,
+            Judgement::new(900, 80, 15, 5),
+            900,
+            2,
+            Utc.with_ymd_and_hms(2025, 11, 14, 12, 0, 0).unwrap(),
+        );
+        let result = SubmissionResult::new(
+            "dddddddd-dddd-dddd-dddd-dddddddddddd".to_owned(),
+            record,
+            Some(LevelUp::new(4, 5)),
+        );
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        insert_results(&db, USER_ID, vec![result]).await.unwrap();
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        assert!(format!("{:?}", log[0]).contains("submission_results"));
+    }
+}
@@ -0,0 +1,103 @@
+use domain::repository::ranking::RankingWindow;
+use sea_orm::{DbBackend, Statement, Value, prelude::Uuid};
+
+/// Wraps a ranked query so that only the requested window is returned.
+///
+/// `ranked_sql` must select `user_id`, a `rank` from `RANK() OVER (...)` and a gapless `position`
+/// from `ROW_NUMBER() OVER (...)` with the same leading order, so ties share a rank while the
+/// window still has a stable order. `values` are bound to `ranked_sql`; the window's own
+/// parameters are numbered after them.
+pub fn window_statement<E>(
+    backend: DbBackend,
+    ranked_sql: &str,
+    mut values: Vec<Value>,
+    window: &RankingWindow,
+    parse_user_uuid: impl FnOnce(&str) -> Result<Uuid, E>,
+) -> Result<Statement, E> {
+    let sql = match window {
+        RankingWindow::Page { offset, limit } => {
+            values.push(to_bigint(*limit).into());
+            values.push(to_bigint(*offset).into());
+            format!(
+                r#"WITH "ranked" AS ({ranked_sql}) SELECT * FROM "ranked" ORDER BY "position" LIMIT ${} OFFSET ${}"#,
+                values.len() - 1,
+                values.len()
+            )
+        }
+        RankingWindow::Around { user_id, neighbors } => {
+            values.push(parse_user_uuid(user_id)?.into());
+            values.push(to_bigint(*neighbors).into());
+            format!(
+                r#"WITH "ranked" AS ({ranked_sql}), "target" AS (SELECT "position" FROM "ranked" WHERE "user_id" = ${}) SELECT "ranked".* FROM "ranked", "target" WHERE "ranked"."position" BETWEEN "target"."position" - ${n} AND "target"."position" + ${n} ORDER BY "ranked"."position""#,
+                values.len() - 1,
+                n = values.len()
+            )
+        }
+    };
+
+    Ok(Statement::from_sql_and_values(backend, sql, values))
+}
+
+/// Window sizes beyond `i64::MAX` select everything anyway, so they are clamped.
+fn to_bigint(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    const RANKED: &str =
+        r#"SELECT "user_id", 1 AS "rank", 1 AS "position" FROM "t" WHERE "k" = $1"#;
+
+    #[test]
+    fn window_statement_pages_after_the_ranked_values() {
+        let statement = window_statement(
+            DbBackend::Postgres,
+            RANKED,
+            vec![Value::from(7)],
+            &RankingWindow::Page {
+                offset: 40,
+                limit: 20,
+            },
+            |_| -> Result<Uuid, Infallible> { unreachable!() },
+        )
+        .unwrap();
+
+        assert!(
+            statement
+                .sql
+                .ends_with(r#"ORDER BY "position" LIMIT $2 OFFSET $3"#)
+        );
+        let values = statement.values.unwrap().0;
+        assert_eq!(values[1..], [Value::from(20i64), Value::from(40i64)]);
+    }
+
+    #[test]
+    fn window_statement_centres_on_the_user() {
+        let user_id = Uuid::from_u128(9);
+
+        let statement = window_statement(
+            DbBackend::Postgres,
+            RANKED,
+            vec![Value::from(7)],
+            &RankingWindow::Around {
+                user_id: user_id.to_string(),
+                neighbors: 3,
+            },
+            Uuid::parse_str,
+        )
+        .unwrap();
+
+        assert!(statement.sql.contains(r#"WHERE "user_id" = $2"#));
+        assert!(
+            statement
+                .sql
+                .contains(r#"BETWEEN "target"."position" - $3 AND "target"."position" + $3"#)
+        );
+        let values = statement.values.unwrap().0;
+        assert_eq!(values[1..], [Value::from(user_id), Value::from(3i64)]);
+    }
+}
@@ -1,7 +1,7 @@
 use anyhow::Error as AnyError;
 use domain::{
-    entity::{level::Level, record::Record},
-    repository::record::RecordRepositoryError,
+    entity::level::Level,
+    repository::record::{RecordRepositoryError, RecordUpsert},
 };
 use sea_orm::{ActiveValue, DbErr, prelude::Uuid};
 
@@ -9,47 +9,30 @@ use crate::entities::{
     records::ActiveModel as RecordActiveModel, sea_orm_active_enums::ClearType as DbClearType,
 };
 
-/// Builds an `ActiveModel` for inserts, delegating `id` generation to the
-/// database default (`gen_random_uuid()` defined in
-/// `m20251007_000004_create_records_table`).
-pub fn active_model_for_insert(
-    record: &Record,
+/// Builds an `ActiveModel` for upserts, delegating `id` generation to the database default
+/// (`gen_random_uuid()` defined in `m20251007_000004_create_records_table`). On conflict the id
+/// of the existing row is kept.
+pub fn active_model_for_upsert(
+    upsert: &RecordUpsert,
 ) -> Result<RecordActiveModel, RecordRepositoryError> {
-    let record_id = if record.id().is_empty() {
-        ActiveValue::NotSet
-    } else {
-        ActiveValue::Set(parse_record_uuid(record.id())?)
-    };
-
+    let record = &upsert.record;
     let user_uuid = parse_user_uuid(record.user_id())?;
     let sheet_uuid = parse_sheet_uuid(record.sheet_id())?;
 
     Ok(RecordActiveModel {
-        id: record_id,
+        id: ActiveValue::NotSet,
         user_id: ActiveValue::Set(user_uuid),
         sheet_id: ActiveValue::Set(sheet_uuid),
         score: ActiveValue::Set(convert_score(*record.score())?),
         clear_type: ActiveValue::Set(DbClearType::from(*record.clear_type())),
         play_count: ActiveValue::Set(convert_play_count(*record.play_count())?),
         updated_at: ActiveValue::Set((*record.updated_at()).into()),
-    })
-}
-
-pub fn active_model_for_update(
-    record: &Record,
-) -> Result<RecordActiveModel, RecordRepositoryError> {
-    let record_id = parse_record_uuid(record.id())?;
-    let user_uuid = parse_user_uuid(record.user_id())?;
-    let sheet_uuid = parse_sheet_uuid(record.sheet_id())?;
-
-    Ok(RecordActiveModel {
-        id: ActiveValue::Unchanged(record_id),
-        user_id: ActiveValue::Unchanged(user_uuid),
-        sheet_id: ActiveValue::Unchanged(sheet_uuid),
-        score: ActiveValue::Set(convert_score(*record.score())?),
-        clear_type: ActiveValue::Set(DbClearType::from(*record.clear_type())),
-        play_count: ActiveValue::Set(convert_play_count(*record.play_count())?),
-        updated_at: ActiveValue::Set((*record.updated_at()).into()),
+        judge_perfect: ActiveValue::Set(convert_count(*record.judgement().perfect())?),
+        judge_great: ActiveValue::Set(convert_count(*record.judgement().great())?),
+        judge_good: ActiveValue::Set(convert_count(*record.judgement().good())?),
+        judge_miss: ActiveValue::Set(convert_count(*record.judgement().miss())?),
+        max_combo: ActiveValue::Set(convert_count(*record.max_combo())?),
+        rating: ActiveValue::Set(convert_count(upsert.rating)?),
     })
 }
 
@@ -67,13 +50,6 @@ pub fn parse_sheet_uuid(sheet_id: &str) -> Result<Uuid, RecordRepositoryError> {
     })
 }
 
-fn parse_record_uuid(record_id: &str) -> Result<Uuid, RecordRepositoryError> {
-    Uuid::parse_str(record_id).map_err(|err| {
-        tracing::debug!(error = %err, "Failed to parse record id");
-        RecordRepositoryError::InternalError(AnyError::from(err))
-    })
-}
-
 pub fn convert_level(raw_level: i32) -> Result<Level, RecordRepositoryError> {
     if raw_level < 0 {
         tracing::warn!(value = raw_level, "Level must be non-negative");
@@ -104,6 +80,13 @@ fn convert_score(score: u32) -> Result<i32, RecordRepositoryError> {
     })
 }
 
+pub fn convert_count(value: u32) -> Result<i32, RecordRepositoryError> {
+    i32::try_from(value).map_err(|err| {
+        tracing::warn!(error = %err, value, "Judgement count, max combo or rating exceeds database range");
+        RecordRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
 fn convert_play_count(play_count: u32) -> Result<i32, RecordRepositoryError> {
     i32::try_from(play_count).map_err(|err| {
         tracing::warn!(error = %err, "Play count exceeds database range");
@@ -111,28 +94,28 @@ fn convert_play_count(play_count: u32) -> Result<i32, RecordRepositoryError> {
     })
 }
 
-pub fn convert_insert_error(err: DbErr, user_id: &str, sheet_id: &str) -> RecordRepositoryError {
+/// Maps foreign key violations back to the offending id. A batch shares one user, but may span
+/// several sheets, so the sheet is only reported when the batch has exactly one.
+pub fn convert_upsert_error(err: DbErr, records: &[RecordUpsert]) -> RecordRepositoryError {
     let message = err.to_string();
     if message.contains("fk_records_user") {
+        let user_id = records
+            .first()
+            .map(|upsert| upsert.record.user_id().to_owned())
+            .unwrap_or_default();
         tracing::warn!(user_id = %user_id, "User not found for foreign key constraint");
-        return RecordRepositoryError::UserNotFound(user_id.to_owned());
+        return RecordRepositoryError::UserNotFound(user_id);
     }
 
     if message.contains("fk_records_sheet") {
+        let sheet_id = match records {
+            [upsert] => upsert.record.sheet_id().to_owned(),
+            _ => String::new(),
+        };
         tracing::warn!(sheet_id = %sheet_id, "Sheet not found for foreign key constraint");
-        return RecordRepositoryError::SheetNotFound(sheet_id.to_owned());
+        return RecordRepositoryError::SheetNotFound(sheet_id);
     }
 
-    tracing::error!(error = %err, "Failed to insert record");
+    tracing::error!(error = %err, "Failed to upsert records");
     RecordRepositoryError::InternalError(AnyError::from(err))
 }
-
-pub fn convert_update_error(err: DbErr, record_id: &str) -> RecordRepositoryError {
-    if matches!(err, DbErr::RecordNotUpdated) {
-        tracing::debug!(record_id = %record_id, "Record not updated; underlying row may be missing");
-        RecordRepositoryError::InternalError(AnyError::from(err))
-    } else {
-        tracing::error!(error = %err, record_id = %record_id, "Failed to update record");
-        RecordRepositoryError::InternalError(AnyError::from(err))
-    }
-}
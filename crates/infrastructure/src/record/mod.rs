@@ -6,29 +6,35 @@ use std::sync::Arc;
 
 use domain::{
     entity::record::Record,
-    repository::record::{
-        RecordRepository, RecordRepositoryError, RecordWithMetadata, SheetScoreRankingRow,
-        TotalScoreRankingRow,
+    repository::{
+        ranking::{RankingFilter, RankingWindow},
+        record::{
+            ClearCountRankingRow, RecordDetailPage, RecordQuery, RecordRepository,
+            RecordRepositoryError, RecordUpsert, RecordWithMetadata, SheetRating,
+            SheetScoreRankingRow, TotalScoreRankingRow,
+        },
     },
 };
 use read::{
-    public_high_scores_by_sheet, public_total_score_ranking, records_by_user,
+    public_clear_count_ranking, public_filtered_total_score_ranking, public_high_scores_by_sheet,
+    public_total_score_ranking, record_details_by_user, records_by_user,
     records_by_user_and_sheet_ids, records_with_metadata_by_user, sum_scores as query_sum_scores,
+    top_sheet_ratings_by_user, user_ids_by_sheet_ids,
 };
-use sea_orm::DbConn;
+use sea_orm::{ConnectionTrait, DbConn};
 use tracing::{debug, info, instrument};
 
-pub struct RecordRepositoryImpl {
-    db: Arc<DbConn>,
+pub struct RecordRepositoryImpl<C = DbConn> {
+    db: Arc<C>,
 }
 
-impl RecordRepositoryImpl {
-    pub fn new(db: Arc<DbConn>) -> Self {
+impl<C> RecordRepositoryImpl<C> {
+    pub fn new(db: Arc<C>) -> Self {
         Self { db }
     }
 }
 
-impl RecordRepository for RecordRepositoryImpl {
+impl<C: ConnectionTrait + Send + Sync> RecordRepository for RecordRepositoryImpl<C> {
     #[instrument(skip(self), fields(user_id = %user_id))]
     async fn find_by_user_id(&self, user_id: &str) -> Result<Vec<Record>, RecordRepositoryError> {
         debug!("Fetching records via SeaORM");
@@ -51,6 +57,22 @@ impl RecordRepository for RecordRepositoryImpl {
         Ok(result)
     }
 
+    #[instrument(skip(self, query), fields(user_id = %user_id))]
+    async fn find_details_by_user_id(
+        &self,
+        user_id: &str,
+        query: &RecordQuery,
+    ) -> Result<RecordDetailPage, RecordRepositoryError> {
+        debug!("Fetching record details via SeaORM");
+        let page = record_details_by_user(self.db.as_ref(), user_id, query).await?;
+        info!(
+            count = page.records.len(),
+            has_more = page.next_cursor.is_some(),
+            "Record details fetched successfully"
+        );
+        Ok(page)
+    }
+
     #[instrument(
         skip(self),
         fields(user_id = %user_id, sheet_count = sheet_ids.len())
@@ -69,20 +91,49 @@ impl RecordRepository for RecordRepositoryImpl {
         Ok(records)
     }
 
-    #[instrument(skip(self, record), fields(user_id = %record.user_id(), sheet_id = %record.sheet_id()))]
-    async fn insert(&self, record: Record) -> Result<Record, RecordRepositoryError> {
-        debug!("Persisting record via SeaORM");
-        let inserted = write::insert_record(self.db.as_ref(), record).await?;
-        info!(record_id = %inserted.id(), "Record inserted successfully");
-        Ok(inserted)
+    #[instrument(skip(self, records), fields(count = records.len()))]
+    async fn upsert_many(
+        &self,
+        records: Vec<RecordUpsert>,
+    ) -> Result<Vec<Record>, RecordRepositoryError> {
+        debug!("Upserting records via SeaORM");
+        let stored = write::upsert_records(self.db.as_ref(), records).await?;
+        info!(count = stored.len(), "Records upserted successfully");
+        Ok(stored)
     }
 
-    #[instrument(skip(self, record), fields(record_id = %record.id()))]
-    async fn update(&self, record: Record) -> Result<Record, RecordRepositoryError> {
-        debug!("Updating record via SeaORM");
-        let updated = write::update_record(self.db.as_ref(), record).await?;
-        info!(record_id = %updated.id(), "Record updated successfully");
-        Ok(updated)
+    #[instrument(skip(self), fields(user_id = %user_id, limit))]
+    async fn find_top_sheet_ratings(
+        &self,
+        user_id: &str,
+        limit: u64,
+    ) -> Result<Vec<u32>, RecordRepositoryError> {
+        top_sheet_ratings_by_user(self.db.as_ref(), user_id, limit).await
+    }
+
+    #[instrument(skip(self, ratings), fields(user_id = %user_id, count = ratings.len()))]
+    async fn update_ratings(
+        &self,
+        user_id: &str,
+        ratings: Vec<SheetRating>,
+    ) -> Result<(), RecordRepositoryError> {
+        debug!("Updating record ratings via SeaORM");
+        write::update_record_ratings(self.db.as_ref(), user_id, ratings).await?;
+        info!("Record ratings updated successfully");
+        Ok(())
+    }
+
+    #[instrument(skip(self, sheet_ids), fields(sheet_count = sheet_ids.len()))]
+    async fn find_user_ids_by_sheet_ids(
+        &self,
+        sheet_ids: &[String],
+    ) -> Result<Vec<String>, RecordRepositoryError> {
+        let user_ids = user_ids_by_sheet_ids(self.db.as_ref(), sheet_ids).await?;
+        info!(
+            count = user_ids.len(),
+            "Users by sheet IDs fetched successfully"
+        );
+        Ok(user_ids)
     }
 
     #[instrument(skip(self))]
@@ -90,14 +141,14 @@ impl RecordRepository for RecordRepositoryImpl {
         query_sum_scores(self.db.as_ref()).await
     }
 
-    #[instrument(skip(self), fields(sheet_id = %sheet_id, limit))]
+    #[instrument(skip(self), fields(sheet_id = %sheet_id))]
     async fn find_public_high_scores_by_sheet(
         &self,
         sheet_id: &str,
-        limit: u64,
+        window: &RankingWindow,
     ) -> Result<Vec<SheetScoreRankingRow>, RecordRepositoryError> {
         debug!("Fetching public sheet ranking via SeaORM");
-        let result = public_high_scores_by_sheet(self.db.as_ref(), sheet_id, limit).await?;
+        let result = public_high_scores_by_sheet(self.db.as_ref(), sheet_id, window).await?;
         info!(
             count = result.len(),
             "Public sheet ranking fetched successfully"
@@ -105,17 +156,40 @@ impl RecordRepository for RecordRepositoryImpl {
         Ok(result)
     }
 
-    #[instrument(skip(self), fields(limit))]
+    #[instrument(skip(self))]
     async fn find_public_total_score_ranking(
         &self,
-        limit: u64,
+        window: &RankingWindow,
     ) -> Result<Vec<TotalScoreRankingRow>, RecordRepositoryError> {
         debug!("Fetching public total score ranking via SeaORM");
-        let result = public_total_score_ranking(self.db.as_ref(), limit).await?;
+        let result = public_total_score_ranking(self.db.as_ref(), window).await?;
         info!(
             count = result.len(),
             "Public total score ranking fetched successfully"
         );
         Ok(result)
     }
+
+    #[instrument(skip(self))]
+    async fn find_public_filtered_total_score_ranking(
+        &self,
+        filter: &RankingFilter,
+        window: &RankingWindow,
+    ) -> Result<Vec<TotalScoreRankingRow>, RecordRepositoryError> {
+        let result = public_filtered_total_score_ranking(self.db.as_ref(), filter, window).await?;
+        info!(
+            count = result.len(),
+            "Filtered total score ranking fetched successfully"
+        );
+        Ok(result)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_public_clear_count_ranking(
+        &self,
+        filter: &RankingFilter,
+        window: &RankingWindow,
+    ) -> Result<Vec<ClearCountRankingRow>, RecordRepositoryError> {
+        public_clear_count_ranking(self.db.as_ref(), filter, window).await
+    }
 }
@@ -5,22 +5,32 @@ use std::{
 
 use anyhow::Error as AnyError;
 use bigdecimal::{Signed, ToPrimitive};
+use chrono::DateTime;
 use domain::{
-    entity::record::Record,
-    repository::record::{
-        RecordRepositoryError, RecordWithMetadata, SheetScoreRankingRow, TotalScoreRankingRow,
+    entity::{difficulty::Difficulty, record::Record},
+    repository::{
+        ranking::{RankingFilter, RankingWindow},
+        record::{
+            ClearCountRankingRow, RecordCursor, RecordDetail, RecordDetailPage, RecordQuery,
+            RecordRepositoryError, RecordSort, RecordWithMetadata, SheetScoreRankingRow, SortOrder,
+            TotalScoreRankingRow,
+        },
     },
 };
 use sea_orm::{
-    ColumnTrait, DbConn, EntityTrait, FromQueryResult, JoinType, QueryFilter, QueryOrder,
-    QuerySelect, RelationTrait,
+    ActiveEnum, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, FromQueryResult, JoinType,
+    QueryFilter, QueryOrder, QueryResult, QuerySelect, RelationTrait, Statement, Value,
     prelude::Uuid,
     sea_query::{Alias, Expr},
     sqlx::types::BigDecimal,
 };
 use tracing::{debug, error, info, warn};
 
-use crate::entities::{self, prelude::Records};
+use crate::{
+    entities::{self, prelude::Records, sea_orm_active_enums::ClearType as DbClearType},
+    music::{difficulty_to_db, genre_to_db, level_to_db},
+    ranking::window_statement,
+};
 
 #[derive(Debug, FromQueryResult)]
 struct SheetScoreRow {
@@ -30,6 +40,8 @@ struct SheetScoreRow {
     display_name: String,
     #[sea_orm(column_name = "score")]
     score: i32,
+    #[sea_orm(column_name = "rank")]
+    rank: i64,
 }
 
 #[derive(Debug, FromQueryResult)]
@@ -40,10 +52,20 @@ struct TotalScoreRow {
     display_name: String,
     #[sea_orm(column_name = "total_score")]
     total_score: BigDecimal,
+    #[sea_orm(column_name = "rank")]
+    rank: i64,
 }
 
-pub async fn records_by_user(
-    db: &DbConn,
+#[derive(Debug, FromQueryResult)]
+struct ClearCountRow {
+    user_id: Uuid,
+    display_name: String,
+    clears: i64,
+    rank: i64,
+}
+
+pub async fn records_by_user<C: ConnectionTrait>(
+    db: &C,
     user_id: &str,
 ) -> Result<Vec<Record>, RecordRepositoryError> {
     debug!("Resolving user before loading records");
@@ -62,8 +84,8 @@ pub async fn records_by_user(
     models.into_iter().map(Record::try_from).collect()
 }
 
-pub async fn records_by_user_and_sheet_ids(
-    db: &DbConn,
+pub async fn records_by_user_and_sheet_ids<C: ConnectionTrait>(
+    db: &C,
     user_id: &str,
     sheet_ids: &[String],
 ) -> Result<Vec<Record>, RecordRepositoryError> {
@@ -94,15 +116,167 @@ pub async fn records_by_user_and_sheet_ids(
     models.into_iter().map(Record::try_from).collect()
 }
 
-pub async fn records_with_metadata_by_user(
-    db: &DbConn,
+pub async fn records_with_metadata_by_user<C: ConnectionTrait>(
+    db: &C,
     user_id: &str,
 ) -> Result<Vec<RecordWithMetadata>, RecordRepositoryError> {
     let uuid = ensure_user_exists(db, user_id).await?;
     records_with_metadata(db, uuid).await
 }
 
-pub async fn ensure_user_exists(db: &DbConn, user_id: &str) -> Result<Uuid, RecordRepositoryError> {
+/// Lists the user's records joined with their sheet and music. Pages are cut by keyset: the
+/// query fetches one row more than `limit` to tell whether another page follows.
+pub async fn record_details_by_user<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    query: &RecordQuery,
+) -> Result<RecordDetailPage, RecordRepositoryError> {
+    let uuid = ensure_user_exists(db, user_id).await?;
+    let empty = || RecordDetailPage {
+        records: Vec::new(),
+        next_cursor: None,
+    };
+
+    let mut values: Vec<Value> = vec![uuid.into()];
+    let mut conditions = sheet_filter_conditions(&query.filter.sheet, &mut values);
+    let mut push = |condition: &str, value: Value| {
+        values.push(value);
+        conditions.push_str(&format!(" AND {condition} ${}", values.len()));
+    };
+    if let Some(clear_type) = query.filter.clear_type {
+        push(
+            r#""records"."clear_type"::text ="#,
+            DbClearType::from(clear_type).to_value().into(),
+        );
+    }
+    if let Some(music_id) = &query.filter.music_id {
+        let Ok(music_uuid) = Uuid::parse_str(music_id) else {
+            debug!(music_id = %music_id, "Malformed music id matches no records");
+            return Ok(empty());
+        };
+        push(r#""sheets"."music_id" ="#, music_uuid.into());
+    }
+    if let Some(min_grade) = query.filter.min_grade {
+        push(
+            r#""records"."score" >="#,
+            i64::from(min_grade.min_score()).into(),
+        );
+    }
+
+    let sort_column = match query.sort {
+        RecordSort::Score => r#""records"."score""#,
+        RecordSort::Level => r#""sheets"."level""#,
+        RecordSort::UpdatedAt => r#""records"."updated_at""#,
+        RecordSort::PlayCount => r#""records"."play_count""#,
+    };
+    let (direction, comparison) = match query.order {
+        SortOrder::Ascending => ("ASC", ">"),
+        SortOrder::Descending => ("DESC", "<"),
+    };
+    if let Some(cursor) = &query.after {
+        let key: Value = match query.sort {
+            RecordSort::UpdatedAt => match DateTime::from_timestamp_micros(cursor.key) {
+                Some(updated_at) => updated_at.into(),
+                None => {
+                    debug!(key = cursor.key, "Cursor timestamp out of range");
+                    return Ok(empty());
+                }
+            },
+            _ => cursor.key.into(),
+        };
+        let Ok(record_uuid) = Uuid::parse_str(&cursor.record_id) else {
+            debug!(record_id = %cursor.record_id, "Malformed cursor record id");
+            return Ok(empty());
+        };
+        values.push(key);
+        values.push(record_uuid.into());
+        conditions.push_str(&format!(
+            r#" AND ({sort_column}, "records"."id") {comparison} (${}, ${})"#,
+            values.len() - 1,
+            values.len()
+        ));
+    }
+    let mut sql = format!(
+        r#"SELECT "records".*, "sheets"."music_id" AS "detail_music_id", "musics"."title" AS "detail_music_title", "sheets"."difficulty"::text AS "detail_difficulty", "sheets"."level" AS "detail_level" FROM "records" {SHEET_JOINS} WHERE "records"."user_id" = $1{conditions} ORDER BY {sort_column} {direction}, "records"."id" {direction}"#
+    );
+    if let Some(limit) = query.limit {
+        values.push(limit.saturating_add(1).into());
+        sql.push_str(&format!(" LIMIT ${}", values.len()));
+    }
+
+    debug!(?query, "Fetching record details via SeaORM");
+    let rows = db
+        .query_all(Statement::from_sql_and_values(
+            db.get_database_backend(),
+            sql,
+            values,
+        ))
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to fetch record details");
+            RecordRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    let mut records = rows
+        .iter()
+        .map(convert_detail_row)
+        .collect::<Result<Vec<_>, _>>()?;
+    let next_cursor = match query.limit {
+        Some(limit) if records.len() as u64 > limit => {
+            records.truncate(limit as usize);
+            records.last().map(|last| cursor_after(last, query.sort))
+        }
+        _ => None,
+    };
+
+    Ok(RecordDetailPage {
+        records,
+        next_cursor,
+    })
+}
+
+fn convert_detail_row(row: &QueryResult) -> Result<RecordDetail, RecordRepositoryError> {
+    let read_error = |err: DbErr| {
+        error!(error = %err, "Failed to read record detail row");
+        RecordRepositoryError::InternalError(AnyError::from(err))
+    };
+    let model = entities::records::Model::from_query_result(row, "").map_err(read_error)?;
+    let music_id: Uuid = row.try_get("", "detail_music_id").map_err(read_error)?;
+    let music_title: String = row.try_get("", "detail_music_title").map_err(read_error)?;
+    let difficulty: String = row.try_get("", "detail_difficulty").map_err(read_error)?;
+    let level: i32 = row.try_get("", "detail_level").map_err(read_error)?;
+
+    Ok(RecordDetail {
+        record: Record::try_from(model)?,
+        music_id: music_id.to_string(),
+        music_title,
+        difficulty: difficulty.parse::<Difficulty>().map_err(|err| {
+            error!(error = %err, "Unexpected sheet difficulty");
+            RecordRepositoryError::InternalError(AnyError::from(err))
+        })?,
+        level: crate::record::adapter::convert_level(level)?,
+    })
+}
+
+/// Builds the cursor that resumes right after `last`, keyed the way `sort` orders the query.
+fn cursor_after(last: &RecordDetail, sort: RecordSort) -> RecordCursor {
+    let record = &last.record;
+    let key = match sort {
+        RecordSort::Score => i64::from(*record.score()),
+        RecordSort::Level => i64::from(level_to_db(&last.level)),
+        RecordSort::UpdatedAt => record.updated_at().timestamp_micros(),
+        RecordSort::PlayCount => i64::from(*record.play_count()),
+    };
+    RecordCursor {
+        key,
+        record_id: record.id().clone(),
+    }
+}
+
+pub async fn ensure_user_exists<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+) -> Result<Uuid, RecordRepositoryError> {
     let uuid = crate::record::adapter::parse_user_uuid(user_id)?;
 
     let user_exists = entities::users::Entity::find_by_id(uuid)
@@ -123,8 +297,8 @@ pub async fn ensure_user_exists(db: &DbConn, user_id: &str) -> Result<Uuid, Reco
 
 /// Loads records alongside sheet/music metadata. Relies on the `fk_records_sheet` and
 /// `fk_sheets_music` constraints to guarantee referential integrity across tables.
-async fn records_with_metadata(
-    db: &DbConn,
+async fn records_with_metadata<C: ConnectionTrait>(
+    db: &C,
     user_uuid: Uuid,
 ) -> Result<Vec<RecordWithMetadata>, RecordRepositoryError> {
     let records_and_sheets = entities::records::Entity::find()
@@ -181,16 +355,48 @@ async fn records_with_metadata(
         })?;
 
         let level = crate::record::adapter::convert_level(sheet.level)?;
+        let difficulty = crate::music::convert_difficulty(sheet.difficulty);
         let record = Record::try_from(record_model)?;
-        result.push(RecordWithMetadata::new(record, level, is_test));
+        result.push(RecordWithMetadata::new(record, difficulty, level, is_test));
     }
 
     Ok(result)
 }
 
+pub async fn user_ids_by_sheet_ids<C: ConnectionTrait>(
+    db: &C,
+    sheet_ids: &[String],
+) -> Result<Vec<String>, RecordRepositoryError> {
+    if sheet_ids.is_empty() {
+        debug!("No sheet IDs provided");
+        return Ok(Vec::new());
+    }
+
+    let mut sheet_uuids = Vec::with_capacity(sheet_ids.len());
+    for sheet_id in sheet_ids {
+        sheet_uuids.push(crate::record::adapter::parse_sheet_uuid(sheet_id)?);
+    }
+
+    let user_ids = Records::find()
+        .select_only()
+        .column(entities::records::Column::UserId)
+        .distinct()
+        .filter(entities::records::Column::SheetId.is_in(sheet_uuids))
+        .order_by_asc(entities::records::Column::UserId)
+        .into_tuple::<Uuid>()
+        .all(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to fetch users by sheet IDs");
+            RecordRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    Ok(user_ids.into_iter().map(|id| id.to_string()).collect())
+}
+
 /// Aggregates record scores across all users. Casts SUM(...) to NUMERIC to
 /// stabilize Postgres' return type regardless of column width.
-pub async fn sum_scores(db: &DbConn) -> Result<u64, RecordRepositoryError> {
+pub async fn sum_scores<C: ConnectionTrait>(db: &C) -> Result<u64, RecordRepositoryError> {
     debug!("Summing record scores via SeaORM");
     let sum = entities::records::Entity::find()
         .select_only()
@@ -226,33 +432,75 @@ pub async fn sum_scores(db: &DbConn) -> Result<u64, RecordRepositoryError> {
     Ok(sum)
 }
 
-pub async fn public_high_scores_by_sheet(
-    db: &DbConn,
-    sheet_id: &str,
+/// Reads the cached per-record ratings, ordered by the `idx_records_user_rating` index.
+pub async fn top_sheet_ratings_by_user<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
     limit: u64,
+) -> Result<Vec<u32>, RecordRepositoryError> {
+    let uuid = crate::record::adapter::parse_user_uuid(user_id)?;
+
+    let ratings: Vec<i32> = entities::records::Entity::find()
+        .select_only()
+        .column(entities::records::Column::Rating)
+        .join(
+            JoinType::InnerJoin,
+            entities::records::Relation::Sheets.def(),
+        )
+        .join(
+            JoinType::InnerJoin,
+            entities::sheets::Relation::Musics.def(),
+        )
+        .filter(entities::records::Column::UserId.eq(uuid))
+        .filter(entities::musics::Column::IsTest.eq(false))
+        .order_by_desc(entities::records::Column::Rating)
+        .limit(limit)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to fetch top sheet ratings");
+            RecordRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    ratings
+        .into_iter()
+        .map(|rating| {
+            u32::try_from(rating).map_err(|err| {
+                error!(error = %err, "Failed to convert sheet rating to u32");
+                RecordRepositoryError::InternalError(AnyError::from(err))
+            })
+        })
+        .collect()
+}
+
+/// Ranks public scores on the sheet. Equal scores share a rank; within a tie the earlier score is
+/// listed first.
+const SHEET_RANKING_SQL: &str = r#"SELECT "records"."user_id", "users"."display_name", "records"."score", RANK() OVER (ORDER BY "records"."score" DESC) AS "rank", ROW_NUMBER() OVER (ORDER BY "records"."score" DESC, "records"."updated_at" ASC, "records"."user_id" ASC) AS "position" FROM "records" INNER JOIN "users" ON "users"."id" = "records"."user_id" WHERE "records"."sheet_id" = $1 AND "users"."is_public" = TRUE"#;
+
+/// Ranks public users by the sum of their best scores. Ties are listed by display name.
+const TOTAL_SCORE_RANKING_SQL: &str = r#"SELECT "users"."id" AS "user_id", "users"."display_name", CAST(SUM("records"."score") AS numeric) AS "total_score", RANK() OVER (ORDER BY SUM("records"."score") DESC) AS "rank", ROW_NUMBER() OVER (ORDER BY SUM("records"."score") DESC, "users"."display_name" ASC, "users"."id" ASC) AS "position" FROM "records" INNER JOIN "users" ON "users"."id" = "records"."user_id" WHERE "users"."is_public" = TRUE GROUP BY "users"."id", "users"."display_name""#;
+
+pub async fn public_high_scores_by_sheet<C: ConnectionTrait>(
+    db: &C,
+    sheet_id: &str,
+    window: &RankingWindow,
 ) -> Result<Vec<SheetScoreRankingRow>, RecordRepositoryError> {
     let sheet_uuid = crate::record::adapter::parse_sheet_uuid(sheet_id)?;
 
     debug!(
         sheet_id = %sheet_uuid,
-        limit,
+        ?window,
         "Fetching public high scores for sheet via SeaORM"
     );
-    let rows = entities::records::Entity::find()
-        .select_only()
-        .column_as(entities::records::Column::UserId, "user_id")
-        .column_as(entities::users::Column::DisplayName, "display_name")
-        .column_as(entities::records::Column::Score, "score")
-        .join(
-            JoinType::InnerJoin,
-            entities::records::Relation::Users.def(),
-        )
-        .filter(entities::records::Column::SheetId.eq(sheet_uuid))
-        .filter(entities::users::Column::IsPublic.eq(true))
-        .order_by_desc(entities::records::Column::Score)
-        .order_by_asc(entities::records::Column::UpdatedAt)
-        .limit(limit)
-        .into_model::<SheetScoreRow>()
+    let statement = window_statement(
+        db.get_database_backend(),
+        SHEET_RANKING_SQL,
+        vec![sheet_uuid.into()],
+        window,
+        crate::record::adapter::parse_user_uuid,
+    )?;
+    let rows = SheetScoreRow::find_by_statement(statement)
         .all(db)
         .await
         .map_err(|err| {
@@ -272,6 +520,7 @@ pub async fn public_high_scores_by_sheet(
             RecordRepositoryError::InternalError(AnyError::from(err))
         })?;
         result.push(SheetScoreRankingRow::new(
+            convert_rank(row.rank)?,
             row.user_id.to_string(),
             row.display_name,
             score,
@@ -286,32 +535,47 @@ pub async fn public_high_scores_by_sheet(
     Ok(result)
 }
 
-pub async fn public_total_score_ranking(
-    db: &DbConn,
-    limit: u64,
+pub async fn public_total_score_ranking<C: ConnectionTrait>(
+    db: &C,
+    window: &RankingWindow,
 ) -> Result<Vec<TotalScoreRankingRow>, RecordRepositoryError> {
-    debug!(limit, "Fetching public total score ranking via SeaORM");
-    let rows = entities::records::Entity::find()
-        .select_only()
-        .column_as(entities::users::Column::Id, "user_id")
-        .column_as(entities::users::Column::DisplayName, "display_name")
-        .column_as(
-            Expr::col(entities::records::Column::Score)
-                .sum()
-                .cast_as(Alias::new("numeric")),
-            "total_score",
-        )
-        .join(
-            JoinType::InnerJoin,
-            entities::records::Relation::Users.def(),
-        )
-        .filter(entities::users::Column::IsPublic.eq(true))
-        .group_by(entities::users::Column::Id)
-        .group_by(entities::users::Column::DisplayName)
-        .order_by_desc(Expr::col(Alias::new("total_score")))
-        .order_by_asc(entities::users::Column::DisplayName)
-        .limit(limit)
-        .into_model::<TotalScoreRow>()
+    debug!(?window, "Fetching public total score ranking via SeaORM");
+    total_score_ranking(db, TOTAL_SCORE_RANKING_SQL, Vec::new(), window).await
+}
+
+pub async fn public_filtered_total_score_ranking<C: ConnectionTrait>(
+    db: &C,
+    filter: &RankingFilter,
+    window: &RankingWindow,
+) -> Result<Vec<TotalScoreRankingRow>, RecordRepositoryError> {
+    debug!(
+        ?filter,
+        ?window,
+        "Fetching filtered total score ranking via SeaORM"
+    );
+    let mut values = Vec::new();
+    let conditions = sheet_filter_conditions(filter, &mut values);
+    // Ties are listed by display name, as in the unfiltered ranking.
+    let sql = format!(
+        r#"SELECT "users"."id" AS "user_id", "users"."display_name", CAST(SUM("records"."score") AS numeric) AS "total_score", RANK() OVER (ORDER BY SUM("records"."score") DESC) AS "rank", ROW_NUMBER() OVER (ORDER BY SUM("records"."score") DESC, "users"."display_name" ASC, "users"."id" ASC) AS "position" FROM "records" INNER JOIN "users" ON "users"."id" = "records"."user_id" {SHEET_JOINS} WHERE "users"."is_public" = TRUE{conditions} GROUP BY "users"."id", "users"."display_name""#
+    );
+    total_score_ranking(db, &sql, values, window).await
+}
+
+async fn total_score_ranking<C: ConnectionTrait>(
+    db: &C,
+    ranked_sql: &str,
+    values: Vec<Value>,
+    window: &RankingWindow,
+) -> Result<Vec<TotalScoreRankingRow>, RecordRepositoryError> {
+    let statement = window_statement(
+        db.get_database_backend(),
+        ranked_sql,
+        values,
+        window,
+        crate::record::adapter::parse_user_uuid,
+    )?;
+    let rows = TotalScoreRow::find_by_statement(statement)
         .all(db)
         .await
         .map_err(|err| {
@@ -332,6 +596,7 @@ pub async fn public_total_score_ranking(
             err
         })?;
         result.push(TotalScoreRankingRow::new(
+            convert_rank(row.rank)?,
             row.user_id.to_string(),
             row.display_name,
             total_score,
@@ -345,11 +610,102 @@ pub async fn public_total_score_ranking(
     Ok(result)
 }
 
+pub async fn public_clear_count_ranking<C: ConnectionTrait>(
+    db: &C,
+    filter: &RankingFilter,
+    window: &RankingWindow,
+) -> Result<Vec<ClearCountRankingRow>, RecordRepositoryError> {
+    debug!(?filter, ?window, "Fetching clear count ranking via SeaORM");
+    let mut values = Vec::new();
+    let conditions = sheet_filter_conditions(filter, &mut values);
+    // Ties are listed by display name.
+    let sql = format!(
+        r#"SELECT "users"."id" AS "user_id", "users"."display_name", COUNT(*) AS "clears", RANK() OVER (ORDER BY COUNT(*) DESC) AS "rank", ROW_NUMBER() OVER (ORDER BY COUNT(*) DESC, "users"."display_name" ASC, "users"."id" ASC) AS "position" FROM "records" INNER JOIN "users" ON "users"."id" = "records"."user_id" {SHEET_JOINS} WHERE "users"."is_public" = TRUE AND "records"."clear_type" <> 'failed'{conditions} GROUP BY "users"."id", "users"."display_name""#
+    );
+    let statement = window_statement(
+        db.get_database_backend(),
+        &sql,
+        values,
+        window,
+        crate::record::adapter::parse_user_uuid,
+    )?;
+    let rows = ClearCountRow::find_by_statement(statement)
+        .all(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to fetch clear count ranking");
+            RecordRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    let result = rows
+        .into_iter()
+        .map(|row| {
+            let clears = u64::try_from(row.clears).map_err(|err| {
+                error!(error = %err, "Failed to convert clear count to u64");
+                RecordRepositoryError::InternalError(AnyError::from(err))
+            })?;
+            Ok(ClearCountRankingRow::new(
+                convert_rank(row.rank)?,
+                row.user_id.to_string(),
+                row.display_name,
+                clears,
+            ))
+        })
+        .collect::<Result<Vec<_>, RecordRepositoryError>>()?;
+
+    info!(
+        count = result.len(),
+        "Clear count ranking fetched successfully"
+    );
+    Ok(result)
+}
+
+const SHEET_JOINS: &str = r#"INNER JOIN "sheets" ON "sheets"."id" = "records"."sheet_id" INNER JOIN "musics" ON "musics"."id" = "sheets"."music_id""#;
+
+/// Turns `filter` into conditions on [`SHEET_JOINS`], each prefixed with `AND`, and binds their
+/// values after the ones already in `values`.
+fn sheet_filter_conditions(filter: &RankingFilter, values: &mut Vec<Value>) -> String {
+    let mut conditions = String::new();
+    let mut push = |condition: &str, value: Value| {
+        values.push(value);
+        conditions.push_str(&format!(" AND {condition} ${}", values.len()));
+    };
+
+    if let Some(difficulty) = filter.difficulty {
+        push(
+            r#""sheets"."difficulty"::text ="#,
+            difficulty_to_db(difficulty).to_value().into(),
+        );
+    }
+    if let Some(genre) = filter.genre {
+        push(r#""musics"."genre" ="#, genre_to_db(genre).into());
+    }
+    if let Some(min_level) = &filter.min_level {
+        push(r#""sheets"."level" >="#, level_to_db(min_level).into());
+    }
+    if let Some(max_level) = &filter.max_level {
+        push(r#""sheets"."level" <="#, level_to_db(max_level).into());
+    }
+    conditions
+}
+
+fn convert_rank(rank: i64) -> Result<u32, RecordRepositoryError> {
+    u32::try_from(rank).map_err(|err| {
+        error!(error = %err, "Failed to convert rank to u32");
+        RecordRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
     use bigdecimal::BigDecimal;
+    use chrono::TimeZone;
+    use domain::{
+        entity::{clear_type::ClearType, genre::Genre, grade::Grade, level::Level},
+        repository::record::RecordFilter,
+    };
     use sea_orm::{DatabaseBackend, MockDatabase, prelude::Uuid, sea_query::Value};
 
     use super::*;
@@ -361,8 +717,14 @@ mod tests {
         BTreeMap::from([(label.to_owned(), mapped_value)])
     }
 
-    fn sheet_row(user_id: Uuid, display_name: &str, score: i32) -> BTreeMap<String, Value> {
+    fn sheet_row(
+        rank: i64,
+        user_id: Uuid,
+        display_name: &str,
+        score: i32,
+    ) -> BTreeMap<String, Value> {
         BTreeMap::from([
+            ("rank".to_owned(), Value::BigInt(Some(rank))),
             ("user_id".to_owned(), Value::Uuid(Some(Box::new(user_id)))),
             (
                 "display_name".to_owned(),
@@ -373,11 +735,13 @@ mod tests {
     }
 
     fn total_score_row(
+        rank: i64,
         user_id: Uuid,
         display_name: &str,
         total_score: i64,
     ) -> BTreeMap<String, Value> {
         BTreeMap::from([
+            ("rank".to_owned(), Value::BigInt(Some(rank))),
             ("user_id".to_owned(), Value::Uuid(Some(Box::new(user_id)))),
             (
                 "display_name".to_owned(),
@@ -415,33 +779,266 @@ mod tests {
         let sheet_id = Uuid::parse_str("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa").expect("valid uuid");
         let user_id = Uuid::parse_str("bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb").expect("valid uuid");
         let db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([vec![sheet_row(user_id, "Alice", 987_654)]])
+            .append_query_results([vec![
+                sheet_row(1, user_id, "Alice", 987_654),
+                sheet_row(1, Uuid::from_u128(2), "Bob", 987_654),
+            ]])
             .into_connection();
 
-        let result = public_high_scores_by_sheet(&db, &sheet_id.to_string(), 20)
+        let window = RankingWindow::Page {
+            offset: 0,
+            limit: 20,
+        };
+        let result = public_high_scores_by_sheet(&db, &sheet_id.to_string(), &window)
             .await
             .unwrap();
 
-        assert_eq!(result.len(), 1);
+        assert_eq!(result.len(), 2);
         let entry = &result[0];
+        assert_eq!(entry.rank, 1);
         assert_eq!(entry.user_id, user_id.to_string());
         assert_eq!(entry.display_name, "Alice");
         assert_eq!(entry.score, 987_654);
+        // Equal scores share a rank.
+        assert_eq!(result[1].rank, 1);
     }
 
     #[tokio::test]
     async fn public_total_score_ranking_converts_rows() {
         let user_id = Uuid::parse_str("cccccccc-cccc-cccc-cccc-cccccccccccc").expect("valid uuid");
         let db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([vec![total_score_row(user_id, "Bob", 1_234_567)]])
+            .append_query_results([vec![total_score_row(12, user_id, "Bob", 1_234_567)]])
             .into_connection();
 
-        let result = public_total_score_ranking(&db, 20).await.unwrap();
+        let window = RankingWindow::Around {
+            user_id: user_id.to_string(),
+            neighbors: 5,
+        };
+        let result = public_total_score_ranking(&db, &window).await.unwrap();
 
         assert_eq!(result.len(), 1);
         let entry = &result[0];
+        assert_eq!(entry.rank, 12);
         assert_eq!(entry.user_id, user_id.to_string());
         assert_eq!(entry.display_name, "Bob");
         assert_eq!(entry.total_score, 1_234_567);
     }
+
+    #[tokio::test]
+    async fn public_total_score_ranking_breaks_name_ties_by_user_id() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![
+                total_score_row(1, Uuid::from_u128(1), "Alice", 1_000_000),
+                total_score_row(1, Uuid::from_u128(2), "Alice", 1_000_000),
+            ]])
+            .into_connection();
+
+        let window = RankingWindow::Page {
+            offset: 0,
+            limit: 20,
+        };
+        let result = public_total_score_ranking(&db, &window).await.unwrap();
+
+        assert_eq!(result[0].user_id, Uuid::from_u128(1).to_string());
+        assert_eq!(result[1].user_id, Uuid::from_u128(2).to_string());
+        // Tied players with the same name still get a stable position, so pages never overlap.
+        let log = db.into_transaction_log();
+        assert!(
+            log[0].statements()[0]
+                .sql
+                .contains(r#""users"."display_name" ASC, "users"."id" ASC) AS "position""#)
+        );
+    }
+
+    #[tokio::test]
+    async fn top_sheet_ratings_by_user_filters_test_musics() {
+        let user_id = Uuid::from_u128(7);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![
+                BTreeMap::from([("rating".to_owned(), Value::Int(Some(1470)))]),
+                BTreeMap::from([("rating".to_owned(), Value::Int(Some(1400)))]),
+            ]])
+            .into_connection();
+
+        let ratings = top_sheet_ratings_by_user(&db, &user_id.to_string(), 3)
+            .await
+            .unwrap();
+
+        assert_eq!(ratings, vec![1470, 1400]);
+        let log = db.into_transaction_log();
+        let sql = &log[0].statements()[0].sql;
+        assert!(sql.contains(r#""musics"."is_test" = $2"#));
+        assert!(sql.contains(r#"ORDER BY "records"."rating" DESC"#));
+    }
+
+    #[tokio::test]
+    async fn public_clear_count_ranking_applies_every_filter() {
+        let user_id = Uuid::from_u128(5);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![BTreeMap::from([
+                ("rank".to_owned(), Value::BigInt(Some(1))),
+                ("user_id".to_owned(), Value::Uuid(Some(Box::new(user_id)))),
+                (
+                    "display_name".to_owned(),
+                    Value::String(Some(Box::new("Alice".to_owned()))),
+                ),
+                ("clears".to_owned(), Value::BigInt(Some(17))),
+            ])]])
+            .into_connection();
+
+        let filter = RankingFilter {
+            difficulty: Some(Difficulty::Hard),
+            genre: Some(Genre::ORIGINAL),
+            min_level: Some(Level::new(13, 5).unwrap()),
+            max_level: None,
+        };
+        let window = RankingWindow::Page {
+            offset: 0,
+            limit: 20,
+        };
+        let result = public_clear_count_ranking(&db, &filter, &window)
+            .await
+            .unwrap();
+
+        assert_eq!(result[0].clears, 17);
+        let log = db.into_transaction_log();
+        let statement = &log[0].statements()[0];
+        assert!(
+            statement
+                .sql
+                .contains(r#""sheets"."difficulty"::text = $1"#)
+        );
+        assert!(statement.sql.contains(r#""musics"."genre" = $2"#));
+        assert!(statement.sql.contains(r#""sheets"."level" >= $3"#));
+        assert!(statement.sql.contains("LIMIT $4 OFFSET $5"));
+        let values = &statement.values.as_ref().unwrap().0;
+        assert_eq!(values[0], Value::from("hard"));
+        assert_eq!(values[2], Value::from(135));
+    }
+
+    fn detail_row(record_id: u128, score: i32, level: i32) -> BTreeMap<String, Value> {
+        let updated_at = chrono::Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap();
+        let int = |value: i32| Value::Int(Some(value));
+        BTreeMap::from([
+            (
+                "id".to_owned(),
+                Value::Uuid(Some(Box::new(Uuid::from_u128(record_id)))),
+            ),
+            (
+                "user_id".to_owned(),
+                Value::Uuid(Some(Box::new(Uuid::from_u128(7)))),
+            ),
+            (
+                "sheet_id".to_owned(),
+                Value::Uuid(Some(Box::new(Uuid::from_u128(100 + record_id)))),
+            ),
+            ("score".to_owned(), int(score)),
+            (
+                "clear_type".to_owned(),
+                Value::String(Some(Box::new("full_combo".to_owned()))),
+            ),
+            ("play_count".to_owned(), int(3)),
+            (
+                "updated_at".to_owned(),
+                Value::ChronoDateTimeWithTimeZone(Some(Box::new(updated_at.into()))),
+            ),
+            ("judge_perfect".to_owned(), int(900)),
+            ("judge_great".to_owned(), int(100)),
+            ("judge_good".to_owned(), int(0)),
+            ("judge_miss".to_owned(), int(0)),
+            ("max_combo".to_owned(), int(1000)),
+            ("rating".to_owned(), int(1400)),
+            (
+                "detail_music_id".to_owned(),
+                Value::Uuid(Some(Box::new(Uuid::from_u128(9)))),
+            ),
+            (
+                "detail_music_title".to_owned(),
+                Value::String(Some(Box::new("Song".to_owned()))),
+            ),
+            (
+                "detail_difficulty".to_owned(),
+                Value::String(Some(Box::new("hard".to_owned()))),
+            ),
+            ("detail_level".to_owned(), int(level)),
+        ])
+    }
+
+    #[tokio::test]
+    async fn record_details_by_user_pages_by_keyset() {
+        let user_id = Uuid::from_u128(7);
+        let now = chrono::Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap();
+        let user = entities::users::Model {
+            id: user_id,
+            card: "CARD-0001".to_owned(),
+            display_name: "Alice".to_owned(),
+            rating: 0,
+            xp: 0,
+            credits: 0,
+            is_public: true,
+            is_admin: false,
+            created_at: now.into(),
+            updated_at: now.into(),
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![user]])
+            .append_query_results([vec![
+                detail_row(3, 1_060_000, 137),
+                detail_row(2, 1_000_000, 130),
+                detail_row(1, 990_000, 120),
+            ]])
+            .into_connection();
+
+        let query = RecordQuery {
+            filter: RecordFilter {
+                clear_type: Some(ClearType::FullCombo),
+                min_grade: Some(Grade::S),
+                ..Default::default()
+            },
+            sort: RecordSort::Score,
+            order: SortOrder::Descending,
+            after: Some(RecordCursor {
+                key: 1_070_000,
+                record_id: Uuid::from_u128(4).to_string(),
+            }),
+            limit: Some(2),
+        };
+        let page = record_details_by_user(&db, &user_id.to_string(), &query)
+            .await
+            .unwrap();
+
+        assert_eq!(page.records.len(), 2);
+        assert_eq!(page.records[0].music_title, "Song");
+        assert_eq!(page.records[0].difficulty, Difficulty::Hard);
+        assert_eq!(page.records[0].level, Level::new(13, 7).unwrap());
+        assert_eq!(
+            page.next_cursor,
+            Some(RecordCursor {
+                key: 1_000_000,
+                record_id: Uuid::from_u128(2).to_string(),
+            })
+        );
+        let log = db.into_transaction_log();
+        let statement = &log[1].statements()[0];
+        assert!(
+            statement
+                .sql
+                .contains(r#""records"."clear_type"::text = $2"#)
+        );
+        assert!(statement.sql.contains(r#""records"."score" >= $3"#));
+        assert!(
+            statement
+                .sql
+                .contains(r#"("records"."score", "records"."id") < ($4, $5)"#)
+        );
+        assert!(
+            statement
+                .sql
+                .contains(r#"ORDER BY "records"."score" DESC, "records"."id" DESC LIMIT $6"#)
+        );
+        let values = &statement.values.as_ref().unwrap().0;
+        assert_eq!(values[1], Value::from("full_combo"));
+        assert_eq!(values[2], Value::from(1_000_000i64));
+        assert_eq!(values[5], Value::from(3u64));
+    }
 }
@@ -1,29 +1,241 @@
 use std::convert::TryFrom;
 
-use domain::{entity::record::Record, repository::record::RecordRepositoryError};
-use sea_orm::{ActiveModelTrait, DbConn};
+use anyhow::Error as AnyError;
+use domain::{
+    entity::record::Record,
+    repository::record::{RecordRepositoryError, RecordUpsert, SheetRating},
+};
+use sea_orm::{
+    ConnectionTrait, EntityTrait, Statement, Value,
+    sea_query::{Expr, OnConflict, SimpleExpr},
+};
+use tracing::{debug, error};
 
-use crate::record::adapter::{
-    active_model_for_insert, active_model_for_update, convert_insert_error, convert_update_error,
+use crate::{
+    entities::records::{Column, Entity},
+    record::adapter::{
+        active_model_for_upsert, convert_count, convert_upsert_error, parse_sheet_uuid,
+        parse_user_uuid,
+    },
 };
 
-pub async fn insert_record(db: &DbConn, record: Record) -> Result<Record, RecordRepositoryError> {
-    let user_id = record.user_id().to_owned();
-    let sheet_id = record.sheet_id().to_owned();
-    let active = active_model_for_insert(&record)?;
+/// Inserts every record in one statement, merging conflicting rows with the same rules as
+/// `Record::apply_submission`. All expressions read the pre-update row, so the judgement columns
+/// compare against the old score rather than the merged one.
+pub async fn upsert_records<C: ConnectionTrait>(
+    db: &C,
+    records: Vec<RecordUpsert>,
+) -> Result<Vec<Record>, RecordRepositoryError> {
+    if records.is_empty() {
+        debug!("No records to upsert");
+        return Ok(Vec::new());
+    }
+
+    let models = records
+        .iter()
+        .map(active_model_for_upsert)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let on_conflict = OnConflict::columns([Column::UserId, Column::SheetId])
+        .values([
+            (
+                Column::PlayCount,
+                Expr::cust(r#""records"."play_count" + "excluded"."play_count""#),
+            ),
+            (
+                Column::Score,
+                Expr::cust(r#"GREATEST("records"."score", "excluded"."score")"#),
+            ),
+            (
+                Column::ClearType,
+                // The enum is declared from `failed` to `perfect`, so its order is the clear rank.
+                Expr::cust(r#"GREATEST("records"."clear_type", "excluded"."clear_type")"#),
+            ),
+            (Column::JudgePerfect, from_better_score("judge_perfect")),
+            (Column::JudgeGreat, from_better_score("judge_great")),
+            (Column::JudgeGood, from_better_score("judge_good")),
+            (Column::JudgeMiss, from_better_score("judge_miss")),
+            (Column::MaxCombo, from_better_score("max_combo")),
+            (
+                Column::Rating,
+                Expr::cust(r#"GREATEST("records"."rating", "excluded"."rating")"#),
+            ),
+            (Column::UpdatedAt, Expr::cust(r#""excluded"."updated_at""#)),
+        ])
+        .to_owned();
 
-    match active.insert(db).await {
-        Ok(model) => Ok(Record::try_from(model)?),
-        Err(err) => Err(convert_insert_error(err, &user_id, &sheet_id)),
+    let models = Entity::insert_many(models)
+        .on_conflict(on_conflict)
+        .exec_with_returning_many(db)
+        .await
+        .map_err(|err| convert_upsert_error(err, &records))?;
+
+    models
+        .into_iter()
+        .map(Record::try_from)
+        .collect::<Result<Vec<_>, _>>()
+}
+
+fn from_better_score(column: &str) -> SimpleExpr {
+    Expr::cust(format!(
+        r#"CASE WHEN "excluded"."score" > "records"."score" THEN "excluded"."{column}" ELSE "records"."{column}" END"#
+    ))
+}
+
+/// Rewrites the ratings of the user's records in one `UPDATE ... FROM (VALUES ...)` statement.
+/// Sheets the user has no record for are ignored.
+pub async fn update_record_ratings<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    ratings: Vec<SheetRating>,
+) -> Result<(), RecordRepositoryError> {
+    if ratings.is_empty() {
+        debug!("No record ratings to update");
+        return Ok(());
+    }
+
+    let mut values: Vec<Value> = vec![parse_user_uuid(user_id)?.into()];
+    let mut rows = Vec::with_capacity(ratings.len());
+    for rating in &ratings {
+        values.push(parse_sheet_uuid(&rating.sheet_id)?.into());
+        values.push(convert_count(rating.rating)?.into());
+        rows.push(format!(
+            "(CAST(${} AS uuid), CAST(${} AS integer))",
+            values.len() - 1,
+            values.len()
+        ));
     }
+
+    let sql = format!(
+        r#"UPDATE "records" SET "rating" = "v"."rating" FROM (VALUES {}) AS "v"("sheet_id", "rating") WHERE "records"."user_id" = $1 AND "records"."sheet_id" = "v"."sheet_id""#,
+        rows.join(", ")
+    );
+    db.execute(Statement::from_sql_and_values(
+        db.get_database_backend(),
+        sql,
+        values,
+    ))
+    .await
+    .map_err(|err| {
+        error!(error = %err, "Failed to update record ratings");
+        RecordRepositoryError::InternalError(AnyError::from(err))
+    })?;
+
+    Ok(())
 }
 
-pub async fn update_record(db: &DbConn, record: Record) -> Result<Record, RecordRepositoryError> {
-    let record_id = record.id().to_owned();
-    let active = active_model_for_update(&record)?;
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use domain::entity::{clear_type::ClearType, judgement::Judgement};
+    use sea_orm::{
+        DatabaseBackend, DbErr, MockDatabase, MockExecResult, RuntimeErr, prelude::Uuid,
+    };
+
+    use super::*;
+    use crate::entities::{self, sea_orm_active_enums::ClearType as DbClearType};
+
+    const USER_ID: &str = "bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb";
+    const SHEET_ID: &str = "cccccccc-cccc-cccc-cccc-cccccccccccc";
+
+    fn upsert(sheet_id: &str) -> RecordUpsert {
+        let record = Record::new_from_submission(
+            USER_ID.to_owned(),
+            sheet_id.to_owned(),
+            1_000_000,
+            ClearType::FullCombo,
+            Judgement::new(900, 80, 20, 0),
+            1000,
+            Utc.with_ymd_and_hms(2025, 11, 18, 12, 0, 0).unwrap(),
+        );
+        RecordUpsert::new(record, 1470)
+    }
+
+    #[tokio::test]
+    async fn upsert_records_skips_empty_batches() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+        let stored = upsert_records(&db, Vec::new()).await.unwrap();
+
+        assert!(stored.is_empty());
+        assert!(db.into_transaction_log().is_empty());
+    }
+
+    #[tokio::test]
+    async fn upsert_records_merges_in_one_statement() {
+        let updated_at = Utc.with_ymd_and_hms(2025, 11, 18, 12, 0, 0).unwrap();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![entities::records::Model {
+                id: Uuid::from_u128(1),
+                user_id: Uuid::parse_str(USER_ID).unwrap(),
+                sheet_id: Uuid::parse_str(SHEET_ID).unwrap(),
+                score: 1_000_000,
+                clear_type: DbClearType::FullCombo,
+                play_count: 4,
+                updated_at: updated_at.into(),
+                judge_perfect: 900,
+                judge_great: 80,
+                judge_good: 20,
+                judge_miss: 0,
+                max_combo: 1000,
+                rating: 1470,
+            }]])
+            .into_connection();
+
+        let stored = upsert_records(&db, vec![upsert(SHEET_ID)]).await.unwrap();
+
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].id(), &Uuid::from_u128(1).to_string());
+        assert_eq!(*stored[0].play_count(), 4);
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        let sql = &log[0].statements()[0].sql;
+        assert!(sql.contains(r#"ON CONFLICT ("user_id", "sheet_id") DO UPDATE"#));
+        assert!(sql.contains(r#""play_count" = "records"."play_count" + "excluded"."play_count""#));
+        assert!(sql.contains("RETURNING"));
+    }
+
+    #[tokio::test]
+    async fn upsert_records_maps_sheet_foreign_key_violation() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors([DbErr::Query(RuntimeErr::Internal(
+                "violates foreign key constraint \"fk_records_sheet\"".to_owned(),
+            ))])
+            .into_connection();
+
+        let err = upsert_records(&db, vec![upsert(SHEET_ID)])
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, RecordRepositoryError::SheetNotFound(id) if id == SHEET_ID));
+    }
+
+    #[tokio::test]
+    async fn update_record_ratings_binds_every_sheet() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        update_record_ratings(
+            &db,
+            USER_ID,
+            vec![
+                SheetRating::new(SHEET_ID.to_owned(), 1470),
+                SheetRating::new("dddddddd-dddd-dddd-dddd-dddddddddddd".to_owned(), 1200),
+            ],
+        )
+        .await
+        .unwrap();
 
-    match active.update(db).await {
-        Ok(model) => Ok(Record::try_from(model)?),
-        Err(err) => Err(convert_update_error(err, &record_id)),
+        let log = db.into_transaction_log();
+        let statement = &log[0].statements()[0];
+        assert!(statement.sql.contains(
+            "(CAST($2 AS uuid), CAST($3 AS integer)), (CAST($4 AS uuid), CAST($5 AS integer))"
+        ));
+        assert_eq!(statement.values.as_ref().unwrap().0.len(), 5);
     }
 }
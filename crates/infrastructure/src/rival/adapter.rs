@@ -0,0 +1,37 @@
+use anyhow::Error as AnyError;
+use domain::{entity::rival::Rival, repository::rival::RivalRepositoryError};
+use sea_orm::{DbErr, error::SqlErr, prelude::Uuid};
+use tracing::{debug, error, warn};
+
+pub fn parse_user_uuid(user_id: &str) -> Result<Uuid, RivalRepositoryError> {
+    Uuid::parse_str(user_id).map_err(|err| {
+        debug!(error = %err, "Failed to parse user id");
+        RivalRepositoryError::UserNotFound(user_id.to_owned())
+    })
+}
+
+/// Maps constraint violations raised while registering a rival onto domain errors.
+pub fn convert_rival_insert_error(err: DbErr, rival: &Rival) -> RivalRepositoryError {
+    match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => {
+            warn!(rival_id = %rival.rival_id(), "Rival already registered");
+            RivalRepositoryError::AlreadyRegistered {
+                user_id: rival.user_id().to_owned(),
+                rival_id: rival.rival_id().to_owned(),
+            }
+        }
+        Some(SqlErr::ForeignKeyConstraintViolation(_)) => {
+            warn!(rival_id = %rival.rival_id(), "Rival references unknown user");
+            RivalRepositoryError::UserNotFound(rival.rival_id().to_owned())
+        }
+        _ => {
+            error!(error = %err, "Failed to insert rival");
+            RivalRepositoryError::InternalError(AnyError::from(err))
+        }
+    }
+}
+
+pub fn convert_db_error(err: DbErr, context: &'static str) -> RivalRepositoryError {
+    error!(error = %err, "{context}");
+    RivalRepositoryError::InternalError(AnyError::from(err))
+}
@@ -0,0 +1,46 @@
+mod adapter;
+mod read;
+mod write;
+
+use std::sync::Arc;
+
+use domain::{
+    entity::rival::Rival,
+    repository::rival::{RivalRepository, RivalRepositoryError},
+};
+use read::rivals_by_user;
+use sea_orm::{ConnectionTrait, DbConn};
+use tracing::{debug, info, instrument};
+use write::{create_rival, delete_rival};
+
+pub struct RivalRepositoryImpl<C = DbConn> {
+    db: Arc<C>,
+}
+
+impl<C> RivalRepositoryImpl<C> {
+    pub fn new(db: Arc<C>) -> Self {
+        Self { db }
+    }
+}
+
+impl<C: ConnectionTrait + Send + Sync> RivalRepository for RivalRepositoryImpl<C> {
+    #[instrument(skip(self, rival), fields(user_id = %rival.user_id(), rival_id = %rival.rival_id()))]
+    async fn create(&self, rival: Rival) -> Result<Rival, RivalRepositoryError> {
+        debug!("Persisting rival via SeaORM");
+        let created = create_rival(self.db.as_ref(), rival).await?;
+        info!("Rival persisted by repository");
+        Ok(created)
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id))]
+    async fn find_by_user_id(&self, user_id: &str) -> Result<Vec<Rival>, RivalRepositoryError> {
+        rivals_by_user(self.db.as_ref(), user_id).await
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id, rival_id = %rival_id))]
+    async fn delete(&self, user_id: &str, rival_id: &str) -> Result<(), RivalRepositoryError> {
+        delete_rival(self.db.as_ref(), user_id, rival_id).await?;
+        info!("Rival deleted by repository");
+        Ok(())
+    }
+}
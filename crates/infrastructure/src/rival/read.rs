@@ -0,0 +1,49 @@
+use domain::{entity::rival::Rival, repository::rival::RivalRepositoryError};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+
+use super::adapter::{convert_db_error, parse_user_uuid};
+use crate::entities;
+
+pub async fn rivals_by_user<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+) -> Result<Vec<Rival>, RivalRepositoryError> {
+    let user_uuid = parse_user_uuid(user_id)?;
+    let models = entities::rivals::Entity::find()
+        .filter(entities::rivals::Column::UserId.eq(user_uuid))
+        .order_by_asc(entities::rivals::Column::CreatedAt)
+        .order_by_asc(entities::rivals::Column::RivalId)
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query rivals"))?;
+
+    Ok(models.into_iter().map(Rival::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::testing::{
+        datetime::timestamp,
+        user::{USER1, USER2},
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase, prelude::Uuid};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn rivals_by_user_converts_models() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![entities::rivals::Model {
+                user_id: Uuid::parse_str(USER1.id).unwrap(),
+                rival_id: Uuid::parse_str(USER2.id).unwrap(),
+                created_at: timestamp(2025, 11, 22, 0, 0, 0).into(),
+            }]])
+            .into_connection();
+
+        let rivals = rivals_by_user(&db, USER1.id).await.unwrap();
+
+        assert_eq!(rivals.len(), 1);
+        assert_eq!(rivals[0].rival_id(), USER2.id);
+        assert_eq!(*rivals[0].created_at(), timestamp(2025, 11, 22, 0, 0, 0));
+    }
+}
@@ -0,0 +1,72 @@
+use domain::{entity::rival::Rival, repository::rival::RivalRepositoryError};
+use sea_orm::{ActiveModelTrait, ActiveValue, ConnectionTrait, EntityTrait};
+use tracing::debug;
+
+use super::adapter::{convert_db_error, convert_rival_insert_error, parse_user_uuid};
+use crate::entities;
+
+pub async fn create_rival<C: ConnectionTrait>(
+    db: &C,
+    rival: Rival,
+) -> Result<Rival, RivalRepositoryError> {
+    // storage assigns the registration time
+    let active = entities::rivals::ActiveModel {
+        user_id: ActiveValue::Set(parse_user_uuid(rival.user_id())?),
+        rival_id: ActiveValue::Set(parse_user_uuid(rival.rival_id())?),
+        created_at: ActiveValue::NotSet,
+    };
+    let model = active
+        .insert(db)
+        .await
+        .map_err(|err| convert_rival_insert_error(err, &rival))?;
+
+    debug!(rival_id = %model.rival_id, "Rival persisted");
+    Ok(Rival::from(model))
+}
+
+pub async fn delete_rival<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    rival_id: &str,
+) -> Result<(), RivalRepositoryError> {
+    let not_found = || RivalRepositoryError::NotFound {
+        user_id: user_id.to_owned(),
+        rival_id: rival_id.to_owned(),
+    };
+    let user_uuid = parse_user_uuid(user_id)?;
+    let rival_uuid = parse_user_uuid(rival_id).map_err(|_| not_found())?;
+    let result = entities::rivals::Entity::delete_by_id((user_uuid, rival_uuid))
+        .exec(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to delete rival"))?;
+
+    if result.rows_affected == 0 {
+        debug!("Rival not registered for supplied ids");
+        return Err(not_found());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::testing::user::{USER1, USER2};
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn delete_rival_reports_missing_rows() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let err = delete_rival(&db, USER1.id, USER2.id)
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, RivalRepositoryError::NotFound { .. }));
+    }
+}
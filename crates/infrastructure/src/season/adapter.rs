@@ -0,0 +1,64 @@
+use anyhow::{Error as AnyError, anyhow};
+use domain::{
+    entity::season::{SeasonBoard, SeasonPeriod},
+    repository::season::SeasonRepositoryError,
+};
+use sea_orm::{DbErr, error::SqlErr, prelude::Uuid};
+use tracing::{error, warn};
+
+pub fn parse_season_uuid(season_id: &str) -> Result<Uuid, SeasonRepositoryError> {
+    Uuid::parse_str(season_id).map_err(|err| {
+        error!(error = %err, "Failed to parse season id");
+        SeasonRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
+pub fn parse_user_uuid(user_id: &str) -> Result<Uuid, SeasonRepositoryError> {
+    Uuid::parse_str(user_id).map_err(|err| {
+        error!(error = %err, "Failed to parse user id");
+        SeasonRepositoryError::InternalError(AnyError::from(err))
+    })
+}
+
+pub fn period_to_db(period: SeasonPeriod) -> &'static str {
+    match period {
+        SeasonPeriod::Weekly => "weekly",
+        SeasonPeriod::Monthly => "monthly",
+    }
+}
+
+pub fn period_from_db(period: &str) -> Result<SeasonPeriod, SeasonRepositoryError> {
+    match period {
+        "weekly" => Ok(SeasonPeriod::Weekly),
+        "monthly" => Ok(SeasonPeriod::Monthly),
+        other => Err(SeasonRepositoryError::InternalError(anyhow!(
+            "unknown season period stored: {other}"
+        ))),
+    }
+}
+
+pub fn board_to_db(board: SeasonBoard) -> &'static str {
+    match board {
+        SeasonBoard::TotalScore => "total_score",
+        SeasonBoard::Plays => "plays",
+    }
+}
+
+/// Maps the unique `(period, starts_at)` index onto the overlap it implies.
+pub fn convert_season_write_error(err: DbErr, period: SeasonPeriod) -> SeasonRepositoryError {
+    match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => {
+            warn!(?period, "Season start already taken");
+            SeasonRepositoryError::Overlapping(period)
+        }
+        _ => {
+            error!(error = %err, "Failed to write season");
+            SeasonRepositoryError::InternalError(AnyError::from(err))
+        }
+    }
+}
+
+pub fn convert_db_error(err: DbErr, context: &'static str) -> SeasonRepositoryError {
+    error!(error = %err, "{context}");
+    SeasonRepositoryError::InternalError(AnyError::from(err))
+}
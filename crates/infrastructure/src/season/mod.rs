@@ -0,0 +1,92 @@
+mod adapter;
+mod read;
+mod write;
+
+use std::sync::Arc;
+
+pub(crate) use adapter::{period_from_db, period_to_db};
+use chrono::{DateTime, Utc};
+use domain::{
+    entity::season::{Season, SeasonBoard, SeasonPeriod},
+    repository::{
+        ranking::RankingWindow,
+        season::{SeasonRepository, SeasonRepositoryError, SeasonStanding},
+    },
+};
+use read::{all_seasons, latest_archived_season, season_active_at, season_by_id, standings};
+use sea_orm::{ConnectionTrait, DbConn, TransactionTrait};
+use tracing::{debug, info, instrument};
+use write::{archive_season, create_season};
+
+pub struct SeasonRepositoryImpl<C = DbConn> {
+    db: Arc<C>,
+}
+
+impl<C> SeasonRepositoryImpl<C> {
+    pub fn new(db: Arc<C>) -> Self {
+        Self { db }
+    }
+}
+
+impl<C: ConnectionTrait + TransactionTrait + Send + Sync> SeasonRepository
+    for SeasonRepositoryImpl<C>
+{
+    #[instrument(skip(self, season), fields(period = ?season.period()))]
+    async fn create(&self, season: Season) -> Result<Season, SeasonRepositoryError> {
+        debug!("Persisting season via SeaORM");
+        let created = create_season(self.db.as_ref(), season).await?;
+        info!(season_id = %created.id(), "Season persisted by repository");
+        Ok(created)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_all(&self) -> Result<Vec<Season>, SeasonRepositoryError> {
+        all_seasons(self.db.as_ref()).await
+    }
+
+    #[instrument(skip(self), fields(season_id = %season_id))]
+    async fn find_by_id(&self, season_id: &str) -> Result<Option<Season>, SeasonRepositoryError> {
+        season_by_id(self.db.as_ref(), season_id).await
+    }
+
+    #[instrument(skip(self), fields(%at))]
+    async fn find_active_at(
+        &self,
+        period: SeasonPeriod,
+        at: DateTime<Utc>,
+    ) -> Result<Option<Season>, SeasonRepositoryError> {
+        season_active_at(self.db.as_ref(), period, at).await
+    }
+
+    #[instrument(skip(self))]
+    async fn find_latest_archived(
+        &self,
+        period: SeasonPeriod,
+    ) -> Result<Option<Season>, SeasonRepositoryError> {
+        latest_archived_season(self.db.as_ref(), period).await
+    }
+
+    #[instrument(skip(self, standings), fields(season_id = %season_id, count = standings.len()))]
+    async fn archive(
+        &self,
+        season_id: &str,
+        standings: Vec<SeasonStanding>,
+    ) -> Result<Season, SeasonRepositoryError> {
+        debug!("Archiving season via SeaORM");
+        let season = archive_season(self.db.as_ref(), season_id, standings).await?;
+        info!("Season archived by repository");
+        Ok(season)
+    }
+
+    #[instrument(skip(self), fields(season_id = %season_id))]
+    async fn find_standings(
+        &self,
+        season_id: &str,
+        board: SeasonBoard,
+        window: &RankingWindow,
+    ) -> Result<Vec<SeasonStanding>, SeasonRepositoryError> {
+        let rows = standings(self.db.as_ref(), season_id, board, window).await?;
+        debug!(count = rows.len(), "Season standings fetched");
+        Ok(rows)
+    }
+}
@@ -0,0 +1,223 @@
+use anyhow::Error as AnyError;
+use chrono::{DateTime, Utc};
+use domain::{
+    entity::season::{Season, SeasonBoard, SeasonPeriod},
+    repository::{
+        ranking::RankingWindow,
+        season::{SeasonRepositoryError, SeasonStanding},
+    },
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, PaginatorTrait, QueryFilter,
+    QueryOrder, prelude::Uuid,
+};
+use tracing::error;
+
+use super::adapter::{
+    board_to_db, convert_db_error, parse_season_uuid, parse_user_uuid, period_to_db,
+};
+use crate::{entities, ranking::window_statement};
+
+#[derive(Debug, FromQueryResult)]
+struct StandingRow {
+    user_id: Uuid,
+    display_name: String,
+    value: i64,
+    rank: i32,
+}
+
+/// Archived ranks are kept as stored; ties are listed by display name.
+const STANDINGS_SQL: &str = r#"SELECT "user_id", "display_name", "value", "rank", ROW_NUMBER() OVER (ORDER BY "rank" ASC, "display_name" ASC) AS "position" FROM "season_standings" WHERE "season_id" = $1 AND "board" = $2"#;
+
+pub async fn all_seasons<C: ConnectionTrait>(db: &C) -> Result<Vec<Season>, SeasonRepositoryError> {
+    let models = entities::seasons::Entity::find()
+        .order_by_desc(entities::seasons::Column::StartsAt)
+        .order_by_asc(entities::seasons::Column::Period)
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query seasons"))?;
+
+    models.into_iter().map(Season::try_from).collect()
+}
+
+pub async fn season_by_id<C: ConnectionTrait>(
+    db: &C,
+    season_id: &str,
+) -> Result<Option<Season>, SeasonRepositoryError> {
+    let model = entities::seasons::Entity::find_by_id(parse_season_uuid(season_id)?)
+        .one(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to fetch season"))?;
+
+    model.map(Season::try_from).transpose()
+}
+
+pub async fn season_active_at<C: ConnectionTrait>(
+    db: &C,
+    period: SeasonPeriod,
+    at: DateTime<Utc>,
+) -> Result<Option<Season>, SeasonRepositoryError> {
+    let model = entities::seasons::Entity::find()
+        .filter(entities::seasons::Column::Period.eq(period_to_db(period)))
+        .filter(entities::seasons::Column::StartsAt.lte(at))
+        .filter(entities::seasons::Column::EndsAt.gt(at))
+        .one(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to fetch active season"))?;
+
+    model.map(Season::try_from).transpose()
+}
+
+pub async fn latest_archived_season<C: ConnectionTrait>(
+    db: &C,
+    period: SeasonPeriod,
+) -> Result<Option<Season>, SeasonRepositoryError> {
+    let model = entities::seasons::Entity::find()
+        .filter(entities::seasons::Column::Period.eq(period_to_db(period)))
+        .filter(entities::seasons::Column::ArchivedAt.is_not_null())
+        .order_by_desc(entities::seasons::Column::EndsAt)
+        .one(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to fetch latest archived season"))?;
+
+    model.map(Season::try_from).transpose()
+}
+
+/// Seasons of `period` that share any instant with `starts_at..ends_at`.
+pub async fn overlapping_season_exists<C: ConnectionTrait>(
+    db: &C,
+    period: SeasonPeriod,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+) -> Result<bool, SeasonRepositoryError> {
+    let count = entities::seasons::Entity::find()
+        .filter(entities::seasons::Column::Period.eq(period_to_db(period)))
+        .filter(entities::seasons::Column::StartsAt.lt(ends_at))
+        .filter(entities::seasons::Column::EndsAt.gt(starts_at))
+        .count(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to check for overlapping seasons"))?;
+
+    Ok(count > 0)
+}
+
+pub async fn standings<C: ConnectionTrait>(
+    db: &C,
+    season_id: &str,
+    board: SeasonBoard,
+    window: &RankingWindow,
+) -> Result<Vec<SeasonStanding>, SeasonRepositoryError> {
+    let statement = window_statement(
+        db.get_database_backend(),
+        STANDINGS_SQL,
+        vec![
+            parse_season_uuid(season_id)?.into(),
+            board_to_db(board).into(),
+        ],
+        window,
+        parse_user_uuid,
+    )?;
+    let rows = StandingRow::find_by_statement(statement)
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to fetch season standings"))?;
+
+    rows.into_iter()
+        .map(|row| {
+            let rank = u32::try_from(row.rank).map_err(|err| {
+                error!(error = %err, "Failed to convert stored rank to u32");
+                SeasonRepositoryError::InternalError(AnyError::from(err))
+            })?;
+            let value = u64::try_from(row.value).map_err(|err| {
+                error!(error = %err, "Failed to convert stored standing value to u64");
+                SeasonRepositoryError::InternalError(AnyError::from(err))
+            })?;
+            Ok(SeasonStanding::new(
+                board,
+                rank,
+                row.user_id.to_string(),
+                row.display_name,
+                value,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use domain::testing::datetime::timestamp;
+    use sea_orm::{DatabaseBackend, MockDatabase, Value};
+
+    use super::*;
+
+    const SEASON_ID: &str = "55555555-5555-5555-5555-555555555555";
+    const USER_ID: &str = "11111111-1111-1111-1111-111111111111";
+
+    #[tokio::test]
+    async fn season_active_at_filters_by_period_and_instant() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![entities::seasons::Model {
+                id: Uuid::parse_str(SEASON_ID).expect("valid uuid"),
+                period: "monthly".to_owned(),
+                starts_at: timestamp(2025, 11, 1, 0, 0, 0).into(),
+                ends_at: timestamp(2025, 12, 1, 0, 0, 0).into(),
+                archived_at: None,
+            }]])
+            .into_connection();
+
+        let season = season_active_at(&db, SeasonPeriod::Monthly, timestamp(2025, 11, 20, 0, 0, 0))
+            .await
+            .unwrap()
+            .expect("season should be found");
+
+        assert_eq!(season.id(), SEASON_ID);
+        assert_eq!(*season.period(), SeasonPeriod::Monthly);
+        let log = db.into_transaction_log();
+        let statement = &log[0].statements()[0];
+        assert!(statement.sql.contains(r#""seasons"."ends_at" > $3"#));
+        assert!(format!("{:?}", statement.values).contains("monthly"));
+    }
+
+    #[tokio::test]
+    async fn standings_reads_the_requested_board() {
+        let user_id = Uuid::parse_str(USER_ID).expect("valid uuid");
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![BTreeMap::from([
+                ("user_id".to_owned(), Value::Uuid(Some(Box::new(user_id)))),
+                (
+                    "display_name".to_owned(),
+                    Value::String(Some(Box::new("Alice".to_owned()))),
+                ),
+                ("value".to_owned(), Value::BigInt(Some(30))),
+                ("rank".to_owned(), Value::Int(Some(2))),
+            ])]])
+            .into_connection();
+
+        let rows = standings(
+            &db,
+            SEASON_ID,
+            SeasonBoard::Plays,
+            &RankingWindow::Page {
+                offset: 0,
+                limit: 20,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![SeasonStanding::new(
+                SeasonBoard::Plays,
+                2,
+                USER_ID.to_owned(),
+                "Alice".to_owned(),
+                30,
+            )]
+        );
+        let log = format!("{:?}", db.into_transaction_log());
+        assert!(log.contains("String(Some(\"plays\"))"));
+    }
+}
@@ -0,0 +1,289 @@
+use anyhow::Error as AnyError;
+use chrono::Utc;
+use domain::{
+    entity::season::Season,
+    repository::season::{SeasonRepositoryError, SeasonStanding},
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    QuerySelect, Statement, TransactionTrait,
+    prelude::DateTimeWithTimeZone,
+    sea_query::{Expr, OnConflict},
+};
+use tracing::{debug, error};
+
+use super::{
+    adapter::{
+        board_to_db, convert_db_error, convert_season_write_error, parse_season_uuid,
+        parse_user_uuid,
+    },
+    read::overlapping_season_exists,
+};
+use crate::entities;
+
+/// Rows per standings insert. Each row binds six parameters, which keeps a chunk well below the
+/// 65535 bind parameters Postgres accepts in one statement.
+const STANDINGS_CHUNK_SIZE: usize = 1000;
+
+/// Inserts the season unless it overlaps another season of its period. The table lock keeps a
+/// concurrent create from slipping in between the check and the insert; it only blocks writers.
+pub async fn create_season<C: ConnectionTrait + TransactionTrait>(
+    db: &C,
+    season: Season,
+) -> Result<Season, SeasonRepositoryError> {
+    let txn = db
+        .begin()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to begin season create transaction"))?;
+
+    txn.execute(Statement::from_string(
+        txn.get_database_backend(),
+        r#"LOCK TABLE "seasons" IN SHARE ROW EXCLUSIVE MODE"#,
+    ))
+    .await
+    .map_err(|err| convert_db_error(err, "Failed to lock seasons"))?;
+
+    if overlapping_season_exists(
+        &txn,
+        *season.period(),
+        *season.starts_at(),
+        *season.ends_at(),
+    )
+    .await?
+    {
+        debug!("Season overlaps an existing season");
+        return Err(SeasonRepositoryError::Overlapping(*season.period()));
+    }
+
+    let period = *season.period();
+    let active: entities::seasons::ActiveModel = season.into();
+    let model = active
+        .insert(&txn)
+        .await
+        .map_err(|err| convert_season_write_error(err, period))?;
+
+    txn.commit()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to commit season create transaction"))?;
+
+    debug!(season_id = %model.id, "Season persisted");
+    Season::try_from(model)
+}
+
+/// Stores the standings and marks the season archived in one transaction, so a failure part way
+/// leaves no standings behind an unarchived season. The season row is locked first, which makes a
+/// concurrent archive of the same season wait and then find it already archived.
+pub async fn archive_season<C: ConnectionTrait + TransactionTrait>(
+    db: &C,
+    season_id: &str,
+    standings: Vec<SeasonStanding>,
+) -> Result<Season, SeasonRepositoryError> {
+    let season_uuid = parse_season_uuid(season_id)?;
+    let txn = db
+        .begin()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to begin season archive transaction"))?;
+
+    let model = entities::seasons::Entity::find_by_id(season_uuid)
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to lock season"))?
+        .ok_or_else(|| {
+            debug!("Season not found for supplied id");
+            SeasonRepositoryError::NotFound(season_id.to_owned())
+        })?;
+    let season = Season::try_from(model)?;
+    if season.archived_at().is_some() {
+        debug!("Season already archived");
+        return Ok(season);
+    }
+
+    let rows = standings
+        .into_iter()
+        .map(|standing| {
+            let rank = i32::try_from(standing.rank).map_err(|err| {
+                error!(error = %err, "Rank exceeds database range");
+                SeasonRepositoryError::InternalError(AnyError::from(err))
+            })?;
+            let value = i64::try_from(standing.value).map_err(|err| {
+                error!(error = %err, "Standing value exceeds database range");
+                SeasonRepositoryError::InternalError(AnyError::from(err))
+            })?;
+            Ok(entities::season_standings::ActiveModel {
+                season_id: ActiveValue::Set(season_uuid),
+                board: ActiveValue::Set(board_to_db(standing.board).to_owned()),
+                user_id: ActiveValue::Set(parse_user_uuid(&standing.user_id)?),
+                rank: ActiveValue::Set(rank),
+                display_name: ActiveValue::Set(standing.display_name),
+                value: ActiveValue::Set(value),
+            })
+        })
+        .collect::<Result<Vec<_>, SeasonRepositoryError>>()?;
+    for chunk in rows.chunks(STANDINGS_CHUNK_SIZE) {
+        entities::season_standings::Entity::insert_many(chunk.to_vec())
+            .on_conflict(
+                OnConflict::columns([
+                    entities::season_standings::Column::SeasonId,
+                    entities::season_standings::Column::Board,
+                    entities::season_standings::Column::UserId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(&txn)
+            .await
+            .map_err(|err| convert_db_error(err, "Failed to insert season standings"))?;
+    }
+
+    let archived_at = Utc::now();
+    entities::seasons::Entity::update_many()
+        .col_expr(
+            entities::seasons::Column::ArchivedAt,
+            Expr::value(DateTimeWithTimeZone::from(archived_at)),
+        )
+        .filter(entities::seasons::Column::Id.eq(season_uuid))
+        .exec(&txn)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to mark season archived"))?;
+
+    txn.commit()
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to commit season archive transaction"))?;
+
+    debug!(season_id = %season.id(), "Season archived");
+    Ok(Season::new(
+        season.id().clone(),
+        *season.period(),
+        *season.starts_at(),
+        *season.ends_at(),
+        Some(archived_at),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use domain::{
+        entity::season::{SeasonBoard, SeasonPeriod},
+        testing::datetime::timestamp,
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult, Value, prelude::Uuid};
+
+    use super::*;
+
+    const SEASON_ID: &str = "55555555-5555-5555-5555-555555555555";
+    const USER_ID: &str = "11111111-1111-1111-1111-111111111111";
+
+    fn exec_result(rows_affected: u64) -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        }
+    }
+
+    fn season_model() -> entities::seasons::Model {
+        entities::seasons::Model {
+            id: Uuid::parse_str(SEASON_ID).expect("valid uuid"),
+            period: "weekly".to_owned(),
+            starts_at: timestamp(2025, 11, 3, 0, 0, 0).into(),
+            ends_at: timestamp(2025, 11, 10, 0, 0, 0).into(),
+            archived_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_season_rejects_overlapping_seasons() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([exec_result(0)])
+            .append_query_results([vec![BTreeMap::from([(
+                "num_items".to_owned(),
+                Value::BigInt(Some(1)),
+            )])]])
+            .into_connection();
+        let season = Season::new_temporary(
+            SeasonPeriod::Weekly,
+            timestamp(2025, 11, 5, 0, 0, 0),
+            timestamp(2025, 11, 12, 0, 0, 0),
+        )
+        .unwrap();
+
+        let err = create_season(&db, season).await.expect_err("should fail");
+
+        assert!(matches!(
+            err,
+            SeasonRepositoryError::Overlapping(SeasonPeriod::Weekly)
+        ));
+        let log = db.into_transaction_log();
+        assert!(
+            log[0]
+                .statements()
+                .iter()
+                .all(|statement| !statement.sql.starts_with(r#"INSERT INTO "seasons""#))
+        );
+    }
+
+    #[tokio::test]
+    async fn archive_season_inserts_standings_in_chunks_within_one_transaction() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![season_model()]])
+            .append_exec_results([exec_result(1000), exec_result(1), exec_result(1)])
+            .into_connection();
+        let standings = (0..=STANDINGS_CHUNK_SIZE as u32)
+            .map(|rank| {
+                SeasonStanding::new(
+                    SeasonBoard::Plays,
+                    rank + 1,
+                    USER_ID.to_owned(),
+                    "Alice".to_owned(),
+                    10,
+                )
+            })
+            .collect();
+
+        let season = archive_season(&db, SEASON_ID, standings).await.unwrap();
+
+        assert!(season.archived_at().is_some());
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        let statements = log[0].statements();
+        assert!(statements[1].sql.ends_with("FOR UPDATE"));
+        let standings_inserts = statements
+            .iter()
+            .filter(|statement| {
+                statement
+                    .sql
+                    .starts_with(r#"INSERT INTO "season_standings""#)
+            })
+            .count();
+        assert_eq!(standings_inserts, 2);
+        assert!(
+            statements
+                .last()
+                .is_some_and(|statement| statement.sql == "COMMIT")
+        );
+    }
+
+    #[tokio::test]
+    async fn archive_season_leaves_archived_seasons_untouched() {
+        let archived_at = timestamp(2025, 11, 10, 0, 5, 0);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![entities::seasons::Model {
+                archived_at: Some(archived_at.into()),
+                ..season_model()
+            }]])
+            .into_connection();
+
+        let season = archive_season(&db, SEASON_ID, Vec::new()).await.unwrap();
+
+        assert_eq!(*season.archived_at(), Some(archived_at));
+        let log = db.into_transaction_log();
+        assert!(
+            log[0]
+                .statements()
+                .last()
+                .is_some_and(|statement| statement.sql == "ROLLBACK")
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use domain::repository::UnitOfWork;
+use sea_orm::DatabaseTransaction;
+use tracing::{debug, error};
+
+use crate::{
+    music::MusicRepositoryImpl, play::PlayRepositoryImpl, record::RecordRepositoryImpl,
+    user::UserRepositoryImpl,
+};
+
+/// Repositories sharing one SeaORM transaction. SeaORM rolls the transaction back when it is
+/// dropped, so an early return from the caller never leaves partial writes behind.
+pub struct UnitOfWorkImpl {
+    txn: Arc<DatabaseTransaction>,
+    user: UserRepositoryImpl<DatabaseTransaction>,
+    record: RecordRepositoryImpl<DatabaseTransaction>,
+    music: MusicRepositoryImpl<DatabaseTransaction>,
+    play: PlayRepositoryImpl<DatabaseTransaction>,
+}
+
+impl UnitOfWorkImpl {
+    pub fn new(txn: DatabaseTransaction) -> Self {
+        let txn = Arc::new(txn);
+        Self {
+            user: UserRepositoryImpl::new(txn.clone()),
+            record: RecordRepositoryImpl::new(txn.clone()),
+            music: MusicRepositoryImpl::new(txn.clone()),
+            play: PlayRepositoryImpl::new(txn.clone()),
+            txn,
+        }
+    }
+}
+
+impl UnitOfWork for UnitOfWorkImpl {
+    type UserRepositoryImpl = UserRepositoryImpl<DatabaseTransaction>;
+    type RecordRepositoryImpl = RecordRepositoryImpl<DatabaseTransaction>;
+    type MusicRepositoryImpl = MusicRepositoryImpl<DatabaseTransaction>;
+    type PlayRepositoryImpl = PlayRepositoryImpl<DatabaseTransaction>;
+
+    fn user(&self) -> &Self::UserRepositoryImpl {
+        &self.user
+    }
+
+    fn record(&self) -> &Self::RecordRepositoryImpl {
+        &self.record
+    }
+
+    fn music(&self) -> &Self::MusicRepositoryImpl {
+        &self.music
+    }
+
+    fn play(&self) -> &Self::PlayRepositoryImpl {
+        &self.play
+    }
+
+    async fn commit(self) -> anyhow::Result<()> {
+        let Self {
+            txn,
+            user,
+            record,
+            music,
+            play,
+        } = self;
+        // The repositories hold the remaining references to the transaction.
+        drop((user, record, music, play));
+
+        let txn = Arc::try_unwrap(txn)
+            .map_err(|_| anyhow!("Transaction is still referenced and cannot be committed"))?;
+        txn.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit unit of work");
+            anyhow::Error::from(err)
+        })?;
+        debug!("Unit of work committed");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use domain::repository::user::UserRepository;
+    use sea_orm::{DatabaseBackend, MockDatabase, TransactionTrait, prelude::Uuid};
+
+    use super::*;
+    use crate::entities;
+
+    const USER_ID: &str = "bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb";
+
+    fn user_model() -> entities::users::Model {
+        let now = Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap();
+        entities::users::Model {
+            id: Uuid::parse_str(USER_ID).expect("valid uuid"),
+            card: "CARD-0001".to_owned(),
+            display_name: "Alice".to_owned(),
+            rating: 0,
+            xp: 0,
+            credits: 0,
+            is_public: true,
+            is_admin: false,
+            created_at: now.into(),
+            updated_at: now.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn commit_wraps_writes_in_one_transaction() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![user_model()]])
+            .into_connection();
+
+        let uow = UnitOfWorkImpl::new(db.begin().await.unwrap());
+        uow.user()
+            .find_by_id(USER_ID)
+            .await
+            .unwrap()
+            .expect("user should be found");
+        uow.commit().await.unwrap();
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        assert!(
+            log[0]
+                .statements()
+                .last()
+                .is_some_and(|statement| statement.sql == "COMMIT")
+        );
+    }
+
+    #[tokio::test]
+    async fn drop_without_commit_rolls_back() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![user_model()]])
+            .into_connection();
+
+        {
+            let uow = UnitOfWorkImpl::new(db.begin().await.unwrap());
+            uow.user().find_by_id(USER_ID).await.unwrap();
+        }
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        assert!(
+            log[0]
+                .statements()
+                .last()
+                .is_some_and(|statement| statement.sql == "ROLLBACK")
+        );
+    }
+}
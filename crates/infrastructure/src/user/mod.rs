@@ -4,33 +4,42 @@ mod write;
 
 use std::sync::Arc;
 
+use chrono::NaiveDate;
 use domain::{
-    entity::{user::User, user_play_option::UserPlayOption},
-    repository::user::{UserRepository, UserRepositoryError},
+    entity::{user::User, user_play_option::UserPlayOption, user_progress::UserProgress},
+    repository::{
+        ranking::RankingWindow,
+        user::{RankedUser, UserRepository, UserRepositoryError},
+    },
 };
 use read::{
-    count_all as query_count_users, find_by_card as query_by_card, find_by_id as query_by_id,
-    find_play_option as query_play_option, public_users_by_rating as query_public_by_rating,
-    public_users_by_xp as query_public_by_xp, sum_credits as query_sum_credits,
+    all_ids as query_all_ids, count_all as query_count_users, find_by_card as query_by_card,
+    find_by_id as query_by_id, find_by_id_for_update as query_by_id_for_update,
+    find_play_option as query_play_option, progress_between as query_progress,
+    public_users_by_rating as query_public_by_rating, public_users_by_xp as query_public_by_xp,
+    sum_credits as query_sum_credits,
 };
-use sea_orm::DbConn;
+use sea_orm::{ConnectionTrait, DbConn};
 use tracing::{debug, info, instrument};
 use write::{
     create_user, increment_credits as mutate_increment_credits,
-    save_play_option as mutate_play_option, save_user,
+    save_play_option as mutate_play_option, save_progress as mutate_save_progress, save_user,
+    thin_progress as mutate_thin_progress,
 };
 
-pub struct UserRepositoryImpl {
-    db: Arc<DbConn>,
+/// Generic over the connection so the same repository can run on the pool or inside a
+/// transaction opened by [`UnitOfWorkImpl`](crate::UnitOfWorkImpl).
+pub struct UserRepositoryImpl<C = DbConn> {
+    db: Arc<C>,
 }
 
-impl UserRepositoryImpl {
-    pub fn new(db: Arc<DbConn>) -> Self {
+impl<C> UserRepositoryImpl<C> {
+    pub fn new(db: Arc<C>) -> Self {
         Self { db }
     }
 }
 
-impl UserRepository for UserRepositoryImpl {
+impl<C: ConnectionTrait + Send + Sync> UserRepository for UserRepositoryImpl<C> {
     #[instrument(skip(self, user), fields(card = %user.card()))]
     async fn create(&self, user: User) -> Result<User, UserRepositoryError> {
         debug!("Persisting user via SeaORM");
@@ -44,9 +53,13 @@ impl UserRepository for UserRepositoryImpl {
         query_by_card(self.db.as_ref(), card).await
     }
 
-    #[instrument(skip(self), fields(user_id = %user_id))]
-    async fn increment_credits(&self, user_id: &str) -> Result<u32, UserRepositoryError> {
-        mutate_increment_credits(self.db.as_ref(), user_id).await?;
+    #[instrument(skip(self), fields(user_id = %user_id, client_id = %client_id))]
+    async fn increment_credits(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<u32, UserRepositoryError> {
+        mutate_increment_credits(self.db.as_ref(), user_id, client_id).await?;
         let user = query_by_id(self.db.as_ref(), user_id)
             .await?
             .ok_or_else(|| UserRepositoryError::NotFound(user_id.to_owned()))?;
@@ -61,11 +74,24 @@ impl UserRepository for UserRepositoryImpl {
         query_by_id(self.db.as_ref(), user_id).await
     }
 
+    #[instrument(skip(self), fields(user_id = %user_id))]
+    async fn find_by_id_for_update(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<User>, UserRepositoryError> {
+        query_by_id_for_update(self.db.as_ref(), user_id).await
+    }
+
     #[instrument(skip(self, user), fields(user_id = %user.id()))]
     async fn save(&self, user: User) -> Result<User, UserRepositoryError> {
         save_user(self.db.as_ref(), user).await
     }
 
+    #[instrument(skip(self))]
+    async fn find_all_ids(&self) -> Result<Vec<String>, UserRepositoryError> {
+        query_all_ids(self.db.as_ref()).await
+    }
+
     #[instrument(skip(self))]
     async fn count_all(&self) -> Result<u64, UserRepositoryError> {
         query_count_users(self.db.as_ref()).await
@@ -92,13 +118,40 @@ impl UserRepository for UserRepositoryImpl {
         mutate_play_option(self.db.as_ref(), option).await
     }
 
-    #[instrument(skip(self), fields(limit))]
-    async fn find_public_top_by_rating(
+    #[instrument(skip(self, progress), fields(user_id = %progress.user_id(), day = %progress.day()))]
+    async fn save_progress(&self, progress: UserProgress) -> Result<(), UserRepositoryError> {
+        mutate_save_progress(self.db.as_ref(), progress).await
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id, before = %before))]
+    async fn thin_progress(
+        &self,
+        user_id: &str,
+        before: NaiveDate,
+    ) -> Result<(), UserRepositoryError> {
+        mutate_thin_progress(self.db.as_ref(), user_id, before).await
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id, from = %from, to = %to))]
+    async fn find_progress(
+        &self,
+        user_id: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<UserProgress>, UserRepositoryError> {
+        debug!("Fetching user progress via SeaORM");
+        let progress = query_progress(self.db.as_ref(), user_id, from, to).await?;
+        info!(count = progress.len(), "User progress fetched successfully");
+        Ok(progress)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_public_rating_ranking(
         &self,
-        limit: u64,
-    ) -> Result<Vec<User>, UserRepositoryError> {
+        window: &RankingWindow,
+    ) -> Result<Vec<RankedUser>, UserRepositoryError> {
         debug!("Fetching public users by rating via SeaORM");
-        let users = query_public_by_rating(self.db.as_ref(), limit).await?;
+        let users = query_public_by_rating(self.db.as_ref(), window).await?;
         info!(
             count = users.len(),
             "Public users by rating fetched successfully"
@@ -106,10 +159,13 @@ impl UserRepository for UserRepositoryImpl {
         Ok(users)
     }
 
-    #[instrument(skip(self), fields(limit))]
-    async fn find_public_top_by_xp(&self, limit: u64) -> Result<Vec<User>, UserRepositoryError> {
+    #[instrument(skip(self))]
+    async fn find_public_xp_ranking(
+        &self,
+        window: &RankingWindow,
+    ) -> Result<Vec<RankedUser>, UserRepositoryError> {
         debug!("Fetching public users by XP via SeaORM");
-        let users = query_public_by_xp(self.db.as_ref(), limit).await?;
+        let users = query_public_by_xp(self.db.as_ref(), window).await?;
         info!(
             count = users.len(),
             "Public users by XP fetched successfully"
@@ -2,20 +2,29 @@ use std::convert::TryFrom;
 
 use anyhow::Error as AnyError;
 use bigdecimal::{Signed, ToPrimitive};
+use chrono::NaiveDate;
 use domain::{
-    entity::{user::User, user_play_option::UserPlayOption},
-    repository::user::UserRepositoryError,
+    entity::{user::User, user_play_option::UserPlayOption, user_progress::UserProgress},
+    repository::{
+        ranking::RankingWindow,
+        user::{RankedUser, UserRepositoryError},
+    },
 };
 use sea_orm::{
-    ColumnTrait, DbConn, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect,
+    prelude::Uuid,
     sea_query::{Alias, Expr},
     sqlx::types::BigDecimal,
 };
 use tracing::{debug, error, info};
 
-use crate::{entities, user::adapter::parse_user_uuid};
+use crate::{entities, ranking::window_statement, user::adapter::parse_user_uuid};
 
-pub async fn find_by_card(db: &DbConn, card: &str) -> Result<Option<User>, UserRepositoryError> {
+pub async fn find_by_card<C: ConnectionTrait>(
+    db: &C,
+    card: &str,
+) -> Result<Option<User>, UserRepositoryError> {
     debug!("Querying user via SeaORM");
     let model = entities::users::Entity::find()
         .filter(entities::users::Column::Card.eq(card))
@@ -35,7 +44,10 @@ pub async fn find_by_card(db: &DbConn, card: &str) -> Result<Option<User>, UserR
     }
 }
 
-pub async fn find_by_id(db: &DbConn, user_id: &str) -> Result<Option<User>, UserRepositoryError> {
+pub async fn find_by_id<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+) -> Result<Option<User>, UserRepositoryError> {
     let uuid = parse_user_uuid(user_id)?;
 
     let model = entities::users::Entity::find_by_id(uuid)
@@ -49,8 +61,27 @@ pub async fn find_by_id(db: &DbConn, user_id: &str) -> Result<Option<User>, User
     model.map(User::try_from).transpose()
 }
 
-pub async fn find_play_option(
-    db: &DbConn,
+/// Locks the row with `FOR UPDATE`, so it only protects anything inside a transaction.
+pub async fn find_by_id_for_update<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+) -> Result<Option<User>, UserRepositoryError> {
+    let uuid = parse_user_uuid(user_id)?;
+
+    let model = entities::users::Entity::find_by_id(uuid)
+        .lock_exclusive()
+        .one(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to lock user by id");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    model.map(User::try_from).transpose()
+}
+
+pub async fn find_play_option<C: ConnectionTrait>(
+    db: &C,
     user_id: &str,
 ) -> Result<Option<UserPlayOption>, UserRepositoryError> {
     let uuid = parse_user_uuid(user_id)?;
@@ -76,7 +107,46 @@ pub async fn find_play_option(
     }
 }
 
-pub async fn count_all(db: &DbConn) -> Result<u64, UserRepositoryError> {
+pub async fn all_ids<C: ConnectionTrait>(db: &C) -> Result<Vec<String>, UserRepositoryError> {
+    debug!("Listing user ids via SeaORM");
+    let ids: Vec<Uuid> = entities::users::Entity::find()
+        .select_only()
+        .column(entities::users::Column::Id)
+        .order_by_asc(entities::users::Column::CreatedAt)
+        .order_by_asc(entities::users::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to list user ids");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+    info!(count = ids.len(), "User ids listed successfully");
+    Ok(ids.into_iter().map(|id| id.to_string()).collect())
+}
+
+pub async fn progress_between<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<UserProgress>, UserRepositoryError> {
+    let uuid = parse_user_uuid(user_id)?;
+    let models = entities::user_progress::Entity::find()
+        .filter(entities::user_progress::Column::UserId.eq(uuid))
+        .filter(entities::user_progress::Column::Day.between(from, to))
+        .order_by_asc(entities::user_progress::Column::Day)
+        .all(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to query user progress");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    models.into_iter().map(UserProgress::try_from).collect()
+}
+
+pub async fn count_all<C: ConnectionTrait>(db: &C) -> Result<u64, UserRepositoryError> {
     debug!("Counting users via SeaORM");
     let count = entities::users::Entity::find()
         .count(db)
@@ -91,7 +161,7 @@ pub async fn count_all(db: &DbConn) -> Result<u64, UserRepositoryError> {
 
 /// Aggregates user credits across all accounts. Casts SUM(...) to NUMERIC so
 /// Postgres always returns a consistent type for decoding.
-pub async fn sum_credits(db: &DbConn) -> Result<u64, UserRepositoryError> {
+pub async fn sum_credits<C: ConnectionTrait>(db: &C) -> Result<u64, UserRepositoryError> {
     debug!("Summing user credits via SeaORM");
     let sum = entities::users::Entity::find()
         .select_only()
@@ -127,29 +197,18 @@ pub async fn sum_credits(db: &DbConn) -> Result<u64, UserRepositoryError> {
     Ok(sum)
 }
 
-pub async fn public_users_by_rating(
-    db: &DbConn,
-    limit: u64,
-) -> Result<Vec<User>, UserRepositoryError> {
-    debug!(limit, "Querying public users by rating via SeaORM");
-    let models = entities::users::Entity::find()
-        .filter(entities::users::Column::IsPublic.eq(true))
-        .order_by_desc(entities::users::Column::Rating)
-        .order_by_asc(entities::users::Column::DisplayName)
-        .limit(limit)
-        .all(db)
-        .await
-        .map_err(|err| {
-            error!(error = %err, "Failed to query public users by rating");
-            UserRepositoryError::InternalError(AnyError::from(err))
-        })?;
+/// Ranks public users by rating; ties are listed by display name.
+const RATING_RANKING_SQL: &str = r#"SELECT "users".*, "users"."id" AS "user_id", RANK() OVER (ORDER BY "rating" DESC) AS "rank", ROW_NUMBER() OVER (ORDER BY "rating" DESC, "display_name" ASC, "id" ASC) AS "position" FROM "users" WHERE "is_public" = TRUE"#;
 
-    let mut result = Vec::with_capacity(models.len());
-    for model in models {
-        let user = User::try_from(model)?;
-        result.push(user);
-    }
+/// Ranks public users by XP; ties are listed by rating, then display name.
+const XP_RANKING_SQL: &str = r#"SELECT "users".*, "users"."id" AS "user_id", RANK() OVER (ORDER BY "xp" DESC) AS "rank", ROW_NUMBER() OVER (ORDER BY "xp" DESC, "rating" DESC, "display_name" ASC, "id" ASC) AS "position" FROM "users" WHERE "is_public" = TRUE"#;
 
+pub async fn public_users_by_rating<C: ConnectionTrait>(
+    db: &C,
+    window: &RankingWindow,
+) -> Result<Vec<RankedUser>, UserRepositoryError> {
+    debug!(?window, "Querying public users by rating via SeaORM");
+    let result = ranked_users(db, RATING_RANKING_SQL, window).await?;
     info!(
         count = result.len(),
         "Public users by rating fetched successfully"
@@ -157,27 +216,12 @@ pub async fn public_users_by_rating(
     Ok(result)
 }
 
-pub async fn public_users_by_xp(db: &DbConn, limit: u64) -> Result<Vec<User>, UserRepositoryError> {
-    debug!(limit, "Querying public users by XP via SeaORM");
-    let models = entities::users::Entity::find()
-        .filter(entities::users::Column::IsPublic.eq(true))
-        .order_by_desc(entities::users::Column::Xp)
-        .order_by_desc(entities::users::Column::Rating)
-        .order_by_asc(entities::users::Column::DisplayName)
-        .limit(limit)
-        .all(db)
-        .await
-        .map_err(|err| {
-            error!(error = %err, "Failed to query public users by XP");
-            UserRepositoryError::InternalError(AnyError::from(err))
-        })?;
-
-    let mut result = Vec::with_capacity(models.len());
-    for model in models {
-        let user = User::try_from(model)?;
-        result.push(user);
-    }
-
+pub async fn public_users_by_xp<C: ConnectionTrait>(
+    db: &C,
+    window: &RankingWindow,
+) -> Result<Vec<RankedUser>, UserRepositoryError> {
+    debug!(?window, "Querying public users by XP via SeaORM");
+    let result = ranked_users(db, XP_RANKING_SQL, window).await?;
     info!(
         count = result.len(),
         "Public users by XP fetched successfully"
@@ -185,6 +229,42 @@ pub async fn public_users_by_xp(db: &DbConn, limit: u64) -> Result<Vec<User>, Us
     Ok(result)
 }
 
+async fn ranked_users<C: ConnectionTrait>(
+    db: &C,
+    ranked_sql: &str,
+    window: &RankingWindow,
+) -> Result<Vec<RankedUser>, UserRepositoryError> {
+    let statement = window_statement(
+        db.get_database_backend(),
+        ranked_sql,
+        Vec::new(),
+        window,
+        parse_user_uuid,
+    )?;
+    let rows = db.query_all(statement).await.map_err(|err| {
+        error!(error = %err, "Failed to query user ranking");
+        UserRepositoryError::InternalError(AnyError::from(err))
+    })?;
+
+    let mut result = Vec::with_capacity(rows.len());
+    for row in rows {
+        let model = entities::users::Model::from_query_result(&row, "").map_err(|err| {
+            error!(error = %err, "Failed to read ranked user");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+        let rank: i64 = row.try_get("", "rank").map_err(|err| {
+            error!(error = %err, "Failed to read user rank");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+        let rank = u32::try_from(rank).map_err(|err| {
+            error!(error = %err, "Failed to convert rank to u32");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+        result.push(RankedUser::new(rank, User::try_from(model)?));
+    }
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -227,6 +307,42 @@ mod tests {
         }
     }
 
+    /// A row of the ranking queries: every user column plus the window function results.
+    fn ranked_row(model: entities::users::Model, rank: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_owned(), model.id.into()),
+            ("card".to_owned(), model.card.into()),
+            ("display_name".to_owned(), model.display_name.into()),
+            ("rating".to_owned(), model.rating.into()),
+            ("xp".to_owned(), model.xp.into()),
+            ("credits".to_owned(), model.credits.into()),
+            ("is_public".to_owned(), model.is_public.into()),
+            ("is_admin".to_owned(), model.is_admin.into()),
+            ("created_at".to_owned(), model.created_at.into()),
+            ("updated_at".to_owned(), model.updated_at.into()),
+            ("rank".to_owned(), rank.into()),
+        ])
+    }
+
+    #[tokio::test]
+    async fn find_by_id_for_update_locks_the_row() {
+        let user_id = Uuid::from_u128(1);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![user_model(
+                user_id, "CARD-1", "Alice", 1200, 300, 0, true,
+            )]])
+            .into_connection();
+
+        let user = find_by_id_for_update(&db, &user_id.to_string())
+            .await
+            .unwrap()
+            .expect("user should be found");
+
+        assert_eq!(*user.xp(), 300);
+        let log = db.into_transaction_log();
+        assert!(log[0].statements()[0].sql.ends_with("FOR UPDATE"));
+    }
+
     #[tokio::test]
     async fn sum_credits_handles_numeric_rows() {
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -251,15 +367,29 @@ mod tests {
     async fn public_users_by_rating_returns_users() {
         let user_id = Uuid::parse_str("dddddddd-dddd-dddd-dddd-dddddddddddd").expect("valid uuid");
         let db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([vec![user_model(
-                user_id, "card-1", "Alice", 1800, 42, 10, true,
-            )]])
+            .append_query_results([vec![
+                ranked_row(
+                    user_model(user_id, "card-1", "Alice", 1800, 42, 10, true),
+                    3,
+                ),
+                ranked_row(
+                    user_model(Uuid::from_u128(2), "card-3", "Carol", 1800, 7, 0, true),
+                    3,
+                ),
+            ]])
             .into_connection();
 
-        let result = public_users_by_rating(&db, 1).await.unwrap();
-
-        assert_eq!(result.len(), 1);
-        let user = &result[0];
+        let window = RankingWindow::Page {
+            offset: 2,
+            limit: 2,
+        };
+        let result = public_users_by_rating(&db, &window).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        // Equal ratings share a rank.
+        assert_eq!(result[0].rank, 3);
+        assert_eq!(result[1].rank, 3);
+        let user = &result[0].user;
         assert_eq!(user.id(), &user_id.to_string());
         assert_eq!(user.display_name(), "Alice");
         assert_eq!(user.rating().value(), 1800);
@@ -269,17 +399,55 @@ mod tests {
     async fn public_users_by_xp_returns_users() {
         let user_id = Uuid::parse_str("eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee").expect("valid uuid");
         let db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([vec![user_model(
-                user_id, "card-2", "Bob", 1700, 123, 20, true,
+            .append_query_results([vec![ranked_row(
+                user_model(user_id, "card-2", "Bob", 1700, 123, 20, true),
+                1,
             )]])
             .into_connection();
 
-        let result = public_users_by_xp(&db, 1).await.unwrap();
+        let window = RankingWindow::Around {
+            user_id: user_id.to_string(),
+            neighbors: 2,
+        };
+        let result = public_users_by_xp(&db, &window).await.unwrap();
 
         assert_eq!(result.len(), 1);
-        let user = &result[0];
+        assert_eq!(result[0].rank, 1);
+        let user = &result[0].user;
         assert_eq!(user.id(), &user_id.to_string());
         assert_eq!(user.display_name(), "Bob");
         assert_eq!(user.xp(), &123);
     }
+
+    #[tokio::test]
+    async fn progress_between_converts_snapshots() {
+        let user_id = Uuid::parse_str("dddddddd-dddd-dddd-dddd-dddddddddddd").expect("valid uuid");
+        let day = |d| NaiveDate::from_ymd_opt(2025, 11, d).unwrap();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![
+                entities::user_progress::Model {
+                    user_id,
+                    day: day(4),
+                    rating: 1200,
+                    xp: 300,
+                },
+                entities::user_progress::Model {
+                    user_id,
+                    day: day(6),
+                    rating: 1250,
+                    xp: 400,
+                },
+            ]])
+            .into_connection();
+
+        let result = progress_between(&db, &user_id.to_string(), day(1), day(30))
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].user_id(), &user_id.to_string());
+        assert_eq!(result[1].day(), &day(6));
+        assert_eq!(*result[1].rating(), 1250);
+        assert_eq!(*result[1].xp(), 400);
+    }
 }
@@ -1,13 +1,15 @@
 use std::convert::TryFrom;
 
 use anyhow::Error as AnyError;
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use domain::{
-    entity::{user::User, user_play_option::UserPlayOption},
+    entity::{user::User, user_play_option::UserPlayOption, user_progress::UserProgress},
     repository::user::UserRepositoryError,
 };
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, QueryFilter,
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    Statement,
+    prelude::Uuid,
     sea_query::{Expr, OnConflict},
 };
 use tracing::{debug, error, info};
@@ -15,7 +17,10 @@ use tracing::{debug, error, info};
 use super::adapter::{convert_user_insert_error, parse_user_uuid};
 use crate::entities;
 
-pub async fn create_user(db: &DbConn, user: User) -> Result<User, UserRepositoryError> {
+pub async fn create_user<C: ConnectionTrait>(
+    db: &C,
+    user: User,
+) -> Result<User, UserRepositoryError> {
     let card_id = user.card().to_owned();
     let db_user: entities::users::ActiveModel = user.into();
 
@@ -28,8 +33,18 @@ pub async fn create_user(db: &DbConn, user: User) -> Result<User, UserRepository
     User::try_from(db_user_model)
 }
 
-pub async fn increment_credits(db: &DbConn, user_id: &str) -> Result<(), UserRepositoryError> {
+/// Increments the user's credits and logs the consuming cabinet. Both statements should run in
+/// one transaction so that every credit has exactly one log row.
+pub async fn increment_credits<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    client_id: &str,
+) -> Result<(), UserRepositoryError> {
     let uuid = parse_user_uuid(user_id)?;
+    let client_uuid = Uuid::parse_str(client_id).map_err(|err| {
+        debug!(error = %err, "Failed to parse client id");
+        UserRepositoryError::InternalError(AnyError::from(err))
+    })?;
 
     let update = entities::users::Entity::update_many()
         .col_expr(
@@ -49,12 +64,29 @@ pub async fn increment_credits(db: &DbConn, user_id: &str) -> Result<(), UserRep
         return Err(UserRepositoryError::NotFound(user_id.to_owned()));
     }
 
+    let log = entities::credit_logs::ActiveModel {
+        id: ActiveValue::NotSet,
+        user_id: ActiveValue::Set(uuid),
+        client_id: ActiveValue::Set(client_uuid),
+        consumed_at: ActiveValue::NotSet,
+    };
+    entities::credit_logs::Entity::insert(log)
+        .exec_without_returning(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, user_id = %uuid, client_id = %client_uuid, "Failed to log credit");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
     debug!(user_id = %uuid, "User credits incremented successfully");
 
     Ok(())
 }
 
-pub async fn save_user(db: &DbConn, user: User) -> Result<User, UserRepositoryError> {
+pub async fn save_user<C: ConnectionTrait>(
+    db: &C,
+    user: User,
+) -> Result<User, UserRepositoryError> {
     let uuid = parse_user_uuid(user.id())?;
 
     let mut active: entities::users::ActiveModel = user.into();
@@ -69,8 +101,8 @@ pub async fn save_user(db: &DbConn, user: User) -> Result<User, UserRepositoryEr
     User::try_from(model)
 }
 
-pub async fn save_play_option(
-    db: &DbConn,
+pub async fn save_play_option<C: ConnectionTrait>(
+    db: &C,
     mut option: UserPlayOption,
 ) -> Result<UserPlayOption, UserRepositoryError> {
     let uuid = parse_user_uuid(option.user_id())?;
@@ -103,3 +135,120 @@ pub async fn save_play_option(
     info!(user_id = %uuid, "User play option persisted successfully");
     UserPlayOption::try_from(model)
 }
+
+pub async fn save_progress<C: ConnectionTrait>(
+    db: &C,
+    progress: UserProgress,
+) -> Result<(), UserRepositoryError> {
+    let uuid = parse_user_uuid(progress.user_id())?;
+    let rating = i32::try_from(*progress.rating()).map_err(|err| {
+        error!(error = %err, value = progress.rating(), "Rating exceeds database range");
+        UserRepositoryError::InternalError(AnyError::from(err))
+    })?;
+    let active = entities::user_progress::ActiveModel {
+        user_id: ActiveValue::Set(uuid),
+        day: ActiveValue::Set(*progress.day()),
+        rating: ActiveValue::Set(rating),
+        xp: ActiveValue::Set(i64::from(*progress.xp())),
+    };
+
+    entities::user_progress::Entity::insert(active)
+        .on_conflict(
+            OnConflict::columns([
+                entities::user_progress::Column::UserId,
+                entities::user_progress::Column::Day,
+            ])
+            .update_columns([
+                entities::user_progress::Column::Rating,
+                entities::user_progress::Column::Xp,
+            ])
+            .to_owned(),
+        )
+        .exec_without_returning(db)
+        .await
+        .map_err(|err| {
+            error!(error = %err, user_id = %uuid, "Failed to persist user progress");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    debug!(user_id = %uuid, day = %progress.day(), "User progress persisted");
+    Ok(())
+}
+
+/// Keeps the last snapshot of each ISO week before `before` and deletes the rest.
+pub async fn thin_progress<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    before: NaiveDate,
+) -> Result<(), UserRepositoryError> {
+    let uuid = parse_user_uuid(user_id)?;
+    let sql = r#"DELETE FROM "user_progress" AS "p" WHERE "p"."user_id" = $1 AND "p"."day" < $2 AND EXISTS (SELECT 1 FROM "user_progress" AS "q" WHERE "q"."user_id" = "p"."user_id" AND DATE_TRUNC('week', "q"."day") = DATE_TRUNC('week', "p"."day") AND "q"."day" > "p"."day")"#;
+
+    let result = db
+        .execute(Statement::from_sql_and_values(
+            db.get_database_backend(),
+            sql,
+            [uuid.into(), before.into()],
+        ))
+        .await
+        .map_err(|err| {
+            error!(error = %err, user_id = %uuid, "Failed to thin user progress");
+            UserRepositoryError::InternalError(AnyError::from(err))
+        })?;
+
+    debug!(user_id = %uuid, removed = result.rows_affected(), "User progress thinned");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::testing::api_key::CABINET_KEY;
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+    use super::*;
+
+    const USER_ID: &str = "bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb";
+
+    #[tokio::test]
+    async fn increment_credits_logs_the_consuming_client() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        increment_credits(&db, USER_ID, CABINET_KEY.client_id)
+            .await
+            .unwrap();
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 2);
+        let insert = format!("{:?}", log[1]);
+        assert!(insert.contains("credit_logs"));
+        assert!(insert.contains(CABINET_KEY.client_id));
+    }
+
+    #[tokio::test]
+    async fn increment_credits_skips_the_log_for_unknown_users() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let err = increment_credits(&db, USER_ID, CABINET_KEY.client_id)
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, UserRepositoryError::NotFound(id) if id == USER_ID));
+        assert_eq!(db.into_transaction_log().len(), 1);
+    }
+}
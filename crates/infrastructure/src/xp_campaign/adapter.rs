@@ -0,0 +1,16 @@
+use anyhow::Error as AnyError;
+use domain::repository::xp_campaign::XpCampaignRepositoryError;
+use sea_orm::{DbErr, prelude::Uuid};
+use tracing::{debug, error};
+
+pub fn parse_campaign_uuid(campaign_id: &str) -> Result<Uuid, XpCampaignRepositoryError> {
+    Uuid::parse_str(campaign_id).map_err(|err| {
+        debug!(error = %err, "Failed to parse XP campaign id");
+        XpCampaignRepositoryError::NotFound(campaign_id.to_owned())
+    })
+}
+
+pub fn convert_db_error(err: DbErr, context: &'static str) -> XpCampaignRepositoryError {
+    error!(error = %err, "{context}");
+    XpCampaignRepositoryError::InternalError(AnyError::from(err))
+}
@@ -0,0 +1,55 @@
+mod adapter;
+mod read;
+mod write;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use domain::{
+    entity::xp_campaign::XpCampaign,
+    repository::xp_campaign::{XpCampaignRepository, XpCampaignRepositoryError},
+};
+use read::{all_campaigns, campaigns_active_at};
+use sea_orm::{ConnectionTrait, DbConn};
+use tracing::{debug, info, instrument};
+use write::{create_campaign, delete_campaign};
+
+pub struct XpCampaignRepositoryImpl<C = DbConn> {
+    db: Arc<C>,
+}
+
+impl<C> XpCampaignRepositoryImpl<C> {
+    pub fn new(db: Arc<C>) -> Self {
+        Self { db }
+    }
+}
+
+impl<C: ConnectionTrait + Send + Sync> XpCampaignRepository for XpCampaignRepositoryImpl<C> {
+    #[instrument(skip(self, campaign), fields(name = %campaign.name()))]
+    async fn create(&self, campaign: XpCampaign) -> Result<XpCampaign, XpCampaignRepositoryError> {
+        debug!("Persisting XP campaign via SeaORM");
+        let created = create_campaign(self.db.as_ref(), campaign).await?;
+        info!(campaign_id = %created.id(), "XP campaign persisted by repository");
+        Ok(created)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_all(&self) -> Result<Vec<XpCampaign>, XpCampaignRepositoryError> {
+        all_campaigns(self.db.as_ref()).await
+    }
+
+    #[instrument(skip(self), fields(%at))]
+    async fn find_active_at(
+        &self,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<XpCampaign>, XpCampaignRepositoryError> {
+        campaigns_active_at(self.db.as_ref(), at).await
+    }
+
+    #[instrument(skip(self), fields(campaign_id = %campaign_id))]
+    async fn delete(&self, campaign_id: &str) -> Result<(), XpCampaignRepositoryError> {
+        delete_campaign(self.db.as_ref(), campaign_id).await?;
+        info!("XP campaign deleted by repository");
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use domain::{entity::xp_campaign::XpCampaign, repository::xp_campaign::XpCampaignRepositoryError};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+
+use super::adapter::convert_db_error;
+use crate::entities;
+
+pub async fn all_campaigns<C: ConnectionTrait>(
+    db: &C,
+) -> Result<Vec<XpCampaign>, XpCampaignRepositoryError> {
+    let models = entities::xp_campaigns::Entity::find()
+        .order_by_desc(entities::xp_campaigns::Column::StartsAt)
+        .order_by_desc(entities::xp_campaigns::Column::Id)
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query XP campaigns"))?;
+
+    models.into_iter().map(XpCampaign::try_from).collect()
+}
+
+pub async fn campaigns_active_at<C: ConnectionTrait>(
+    db: &C,
+    at: DateTime<Utc>,
+) -> Result<Vec<XpCampaign>, XpCampaignRepositoryError> {
+    let models = entities::xp_campaigns::Entity::find()
+        .filter(entities::xp_campaigns::Column::StartsAt.lte(at))
+        .filter(entities::xp_campaigns::Column::EndsAt.gt(at))
+        .all(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to query active XP campaigns"))?;
+
+    models.into_iter().map(XpCampaign::try_from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::testing::datetime::timestamp;
+    use sea_orm::{DatabaseBackend, MockDatabase, prelude::Uuid};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn campaigns_active_at_converts_models() {
+        let id = Uuid::parse_str("eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee").expect("valid uuid");
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![entities::xp_campaigns::Model {
+                id,
+                name: "Double XP weekend".to_owned(),
+                multiplier_percent: 200,
+                starts_at: timestamp(2025, 11, 22, 0, 0, 0).into(),
+                ends_at: timestamp(2025, 11, 24, 0, 0, 0).into(),
+                created_at: timestamp(2025, 11, 20, 0, 0, 0).into(),
+            }]])
+            .into_connection();
+
+        let campaigns = campaigns_active_at(&db, timestamp(2025, 11, 23, 12, 0, 0))
+            .await
+            .unwrap();
+
+        assert_eq!(campaigns.len(), 1);
+        assert_eq!(campaigns[0].id(), &id.to_string());
+        assert_eq!(*campaigns[0].multiplier_percent(), 200);
+    }
+}
@@ -0,0 +1,60 @@
+use domain::{entity::xp_campaign::XpCampaign, repository::xp_campaign::XpCampaignRepositoryError};
+use sea_orm::{ActiveModelTrait, ConnectionTrait, EntityTrait};
+use tracing::debug;
+
+use super::adapter::{convert_db_error, parse_campaign_uuid};
+use crate::entities;
+
+pub async fn create_campaign<C: ConnectionTrait>(
+    db: &C,
+    campaign: XpCampaign,
+) -> Result<XpCampaign, XpCampaignRepositoryError> {
+    let active: entities::xp_campaigns::ActiveModel = campaign.into();
+    let model = active
+        .insert(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to insert XP campaign"))?;
+
+    debug!(campaign_id = %model.id, "XP campaign persisted");
+    XpCampaign::try_from(model)
+}
+
+pub async fn delete_campaign<C: ConnectionTrait>(
+    db: &C,
+    campaign_id: &str,
+) -> Result<(), XpCampaignRepositoryError> {
+    let uuid = parse_campaign_uuid(campaign_id)?;
+    let result = entities::xp_campaigns::Entity::delete_by_id(uuid)
+        .exec(db)
+        .await
+        .map_err(|err| convert_db_error(err, "Failed to delete XP campaign"))?;
+
+    if result.rows_affected == 0 {
+        debug!("XP campaign not found for supplied id");
+        return Err(XpCampaignRepositoryError::NotFound(campaign_id.to_owned()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn delete_campaign_reports_missing_rows() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let err = delete_campaign(&db, "eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee")
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, XpCampaignRepositoryError::NotFound(_)));
+    }
+}
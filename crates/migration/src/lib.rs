@@ -5,6 +5,23 @@ mod m20251007_000002_create_musics_table;
 mod m20251007_000003_create_sheets_table;
 mod m20251007_000004_create_records_table;
 mod m20251007_000005_create_user_play_options_table;
+mod m20251110_000006_create_api_keys_table;
+mod m20251111_000007_create_clients_table;
+mod m20251112_000008_add_deleted_at_to_catalog;
+mod m20251113_000009_add_updated_at_to_catalog;
+mod m20251114_000010_create_plays_table;
+mod m20251115_000011_add_judgement_to_records;
+mod m20251116_000012_add_note_count_to_sheets;
+mod m20251117_000013_add_submission_id_to_plays;
+mod m20251118_000014_add_rating_to_records;
+mod m20251119_000015_create_user_progress_table;
+mod m20251120_000016_create_xp_campaigns_table;
+mod m20251121_000017_create_seasons_tables;
+mod m20251122_000018_create_rivals_table;
+mod m20251123_000019_create_credit_logs_table;
+mod m20251124_000020_create_submission_results_table;
+mod m20251125_000021_stamp_catalog_with_db_clock;
+mod m20251126_000022_scope_submission_ids_to_users;
 
 pub struct Migrator;
 
@@ -17,6 +34,23 @@ impl MigratorTrait for Migrator {
             Box::new(m20251007_000003_create_sheets_table::Migration),
             Box::new(m20251007_000004_create_records_table::Migration),
             Box::new(m20251007_000005_create_user_play_options_table::Migration),
+            Box::new(m20251110_000006_create_api_keys_table::Migration),
+            Box::new(m20251111_000007_create_clients_table::Migration),
+            Box::new(m20251112_000008_add_deleted_at_to_catalog::Migration),
+            Box::new(m20251113_000009_add_updated_at_to_catalog::Migration),
+            Box::new(m20251114_000010_create_plays_table::Migration),
+            Box::new(m20251115_000011_add_judgement_to_records::Migration),
+            Box::new(m20251116_000012_add_note_count_to_sheets::Migration),
+            Box::new(m20251117_000013_add_submission_id_to_plays::Migration),
+            Box::new(m20251118_000014_add_rating_to_records::Migration),
+            Box::new(m20251119_000015_create_user_progress_table::Migration),
+            Box::new(m20251120_000016_create_xp_campaigns_table::Migration),
+            Box::new(m20251121_000017_create_seasons_tables::Migration),
+            Box::new(m20251122_000018_create_rivals_table::Migration),
+            Box::new(m20251123_000019_create_credit_logs_table::Migration),
+            Box::new(m20251124_000020_create_submission_results_table::Migration),
+            Box::new(m20251125_000021_stamp_catalog_with_db_clock::Migration),
+            Box::new(m20251126_000022_scope_submission_ids_to_users::Migration),
         ]
     }
 }
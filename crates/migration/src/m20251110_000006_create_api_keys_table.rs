@@ -0,0 +1,94 @@
+use sea_orm_migration::prelude::*;
+use sea_query::{Expr, extension::postgres::Type};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(ClientRole::Table)
+                    .values([
+                        ClientRole::Cabinet,
+                        ClientRole::Station,
+                        ClientRole::Web,
+                        ClientRole::Admin,
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ApiKeys::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ApiKeys::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(ApiKeys::KeyHash)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ApiKeys::Role)
+                            .custom(ClientRole::Table)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ApiKeys::Label).string().not_null())
+                    .col(ColumnDef::new(ApiKeys::RevokedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(ApiKeys::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ApiKeys::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(ClientRole::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum ApiKeys {
+    Table,
+    Id,
+    KeyHash,
+    Role,
+    Label,
+    RevokedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+#[sea_orm(iden = "client_role")]
+enum ClientRole {
+    Table,
+    Cabinet,
+    Station,
+    Web,
+    Admin,
+}
@@ -0,0 +1,173 @@
+use sea_orm_migration::prelude::*;
+use sea_query::{Expr, Index};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Moves the role and label of each key onto a `clients` row so that one machine can hold a
+/// sequence of keys over time. Existing keys each become their own client, reusing the key id.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Clients::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Clients::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(Clients::Role)
+                            .custom(ClientRole::Table)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Clients::Label).string().not_null())
+                    .col(ColumnDef::new(Clients::RevokedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(Clients::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ApiKeys::Table)
+                    .add_column(ColumnDef::new(ApiKeys::ClientId).uuid())
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+            INSERT INTO "clients" ("id", "role", "label", "revoked_at", "created_at")
+            SELECT "id", "role", "label", "revoked_at", "created_at" FROM "api_keys";
+            UPDATE "api_keys" SET "client_id" = "id";
+            "#,
+        )
+        .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ApiKeys::Table)
+                    .modify_column(ColumnDef::new(ApiKeys::ClientId).uuid().not_null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_api_keys_client")
+                            .from_tbl(ApiKeys::Table)
+                            .from_col(ApiKeys::ClientId)
+                            .to_tbl(Clients::Table)
+                            .to_col(Clients::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .drop_column(ApiKeys::Role)
+                    .drop_column(ApiKeys::Label)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_api_keys_client")
+                    .table(ApiKeys::Table)
+                    .col(ApiKeys::ClientId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_api_keys_client")
+                    .table(ApiKeys::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ApiKeys::Table)
+                    .add_column(ColumnDef::new(ApiKeys::Role).custom(ClientRole::Table))
+                    .add_column(ColumnDef::new(ApiKeys::Label).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+            UPDATE "api_keys" AS k
+            SET "role" = c."role", "label" = c."label"
+            FROM "clients" AS c
+            WHERE k."client_id" = c."id";
+            "#,
+        )
+        .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ApiKeys::Table)
+                    .modify_column(
+                        ColumnDef::new(ApiKeys::Role)
+                            .custom(ClientRole::Table)
+                            .not_null(),
+                    )
+                    .modify_column(ColumnDef::new(ApiKeys::Label).string().not_null())
+                    .drop_foreign_key(Alias::new("fk_api_keys_client"))
+                    .drop_column(ApiKeys::ClientId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Clients::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Clients {
+    Table,
+    Id,
+    Role,
+    Label,
+    RevokedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum ApiKeys {
+    Table,
+    ClientId,
+    Role,
+    Label,
+}
+
+#[derive(DeriveIden)]
+#[sea_orm(iden = "client_role")]
+enum ClientRole {
+    Table,
+}
@@ -0,0 +1,137 @@
+use sea_orm_migration::prelude::*;
+use sea_query::{Expr, Index};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Lets musics and sheets be archived instead of deleted. Hard-deleting a sheet would cascade
+/// onto `records`, so removals only stamp `deleted_at`. The uniqueness of a difficulty within a
+/// music is narrowed to live sheets so an archived chart can be replaced. Ids gain a database
+/// default so that admin tooling does not need to mint UUIDs itself.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Musics::Table)
+                    .modify_column(
+                        ColumnDef::new(Musics::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .add_column(ColumnDef::new(Musics::DeletedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Sheets::Table)
+                    .modify_column(
+                        ColumnDef::new(Sheets::Id)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .add_column(ColumnDef::new(Sheets::DeletedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uk_sheets_music_difficulty")
+                    .table(Sheets::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+            CREATE UNIQUE INDEX "uk_sheets_music_difficulty"
+            ON "sheets" ("music_id", "difficulty")
+            WHERE "deleted_at" IS NULL;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+            DELETE FROM "sheets" WHERE "deleted_at" IS NOT NULL;
+            DELETE FROM "musics" WHERE "deleted_at" IS NOT NULL;
+            ALTER TABLE "sheets" ALTER COLUMN "id" DROP DEFAULT;
+            ALTER TABLE "musics" ALTER COLUMN "id" DROP DEFAULT;
+            "#,
+        )
+        .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uk_sheets_music_difficulty")
+                    .table(Sheets::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_sheets_music_difficulty")
+                    .table(Sheets::Table)
+                    .col(Sheets::MusicId)
+                    .col(Sheets::Difficulty)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Sheets::Table)
+                    .drop_column(Sheets::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Musics::Table)
+                    .drop_column(Musics::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Musics {
+    Table,
+    Id,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+enum Sheets {
+    Table,
+    Id,
+    MusicId,
+    Difficulty,
+    DeletedAt,
+}
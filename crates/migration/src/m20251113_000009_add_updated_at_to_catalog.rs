@@ -0,0 +1,115 @@
+use sea_orm_migration::prelude::*;
+use sea_query::{Expr, Index};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tracks when each music and sheet last changed so that cabinets can download only the delta
+/// since their previous `/sync`. Archiving stamps `updated_at` together with `deleted_at`, which
+/// lets removals be reported as tombstones.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Musics::Table)
+                    .add_column(
+                        ColumnDef::new(Musics::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Sheets::Table)
+                    .add_column(
+                        ColumnDef::new(Sheets::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_musics_updated_at")
+                    .table(Musics::Table)
+                    .col(Musics::UpdatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sheets_updated_at")
+                    .table(Sheets::Table)
+                    .col(Sheets::UpdatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_sheets_updated_at")
+                    .table(Sheets::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_musics_updated_at")
+                    .table(Musics::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Sheets::Table)
+                    .drop_column(Sheets::UpdatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Musics::Table)
+                    .drop_column(Musics::UpdatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Musics {
+    Table,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Sheets {
+    Table,
+    UpdatedAt,
+}
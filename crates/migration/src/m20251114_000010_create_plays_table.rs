@@ -0,0 +1,141 @@
+use sea_orm_migration::prelude::*;
+use sea_query::{Expr, Index};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Keeps every submitted play alongside the best-score aggregate in `records`. Rows are only ever
+/// inserted, so the table doubles as an audit log of what each cabinet reported.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Plays::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Plays::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(Plays::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Plays::SheetId).uuid().not_null())
+                    .col(ColumnDef::new(Plays::ClientId).uuid().not_null())
+                    .col(ColumnDef::new(Plays::Score).integer().not_null())
+                    .col(
+                        ColumnDef::new(Plays::ClearType)
+                            .custom(ClearType::Table)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Plays::JudgePerfect).integer().not_null())
+                    .col(ColumnDef::new(Plays::JudgeGreat).integer().not_null())
+                    .col(ColumnDef::new(Plays::JudgeGood).integer().not_null())
+                    .col(ColumnDef::new(Plays::JudgeMiss).integer().not_null())
+                    .col(
+                        ColumnDef::new(Plays::PlayedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_plays_user")
+                            .from(Plays::Table, Plays::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_plays_sheet")
+                            .from(Plays::Table, Plays::SheetId)
+                            .to(Sheets::Table, Sheets::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_plays_client")
+                            .from(Plays::Table, Plays::ClientId)
+                            .to(Clients::Table, Clients::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_plays_user_played_at")
+                    .table(Plays::Table)
+                    .col(Plays::UserId)
+                    .col(Plays::PlayedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_plays_user_played_at")
+                    .table(Plays::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Plays::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Plays {
+    Table,
+    Id,
+    UserId,
+    SheetId,
+    ClientId,
+    Score,
+    ClearType,
+    JudgePerfect,
+    JudgeGreat,
+    JudgeGood,
+    JudgeMiss,
+    PlayedAt,
+}
+
+#[derive(DeriveIden)]
+#[sea_orm(iden = "clear_type")]
+enum ClearType {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Sheets {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Clients {
+    Table,
+    Id,
+}
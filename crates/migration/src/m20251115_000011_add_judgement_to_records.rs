@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Stores the judgement breakdown and max combo of the best-scoring run on each record. Records
+/// written before this migration have no breakdown and report zeroes.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Records::Table)
+                    .add_column(
+                        ColumnDef::new(Records::JudgePerfect)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Records::JudgeGreat)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Records::JudgeGood)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Records::JudgeMiss)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Records::MaxCombo)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Records::Table)
+                    .drop_column(Records::JudgePerfect)
+                    .drop_column(Records::JudgeGreat)
+                    .drop_column(Records::JudgeGood)
+                    .drop_column(Records::JudgeMiss)
+                    .drop_column(Records::MaxCombo)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Records {
+    Table,
+    JudgePerfect,
+    JudgeGreat,
+    JudgeGood,
+    JudgeMiss,
+    MaxCombo,
+}
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+use sea_query::Index;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Stores the client-generated id of each submission so that a retried POST can be recognised
+/// and skipped. Plays logged before this migration keep a null id, which the unique index
+/// ignores.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Plays::Table)
+                    .add_column(ColumnDef::new(Plays::SubmissionId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_plays_submission_id")
+                    .table(Plays::Table)
+                    .col(Plays::SubmissionId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_plays_submission_id")
+                    .table(Plays::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Plays::Table)
+                    .drop_column(Plays::SubmissionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Plays {
+    Table,
+    SubmissionId,
+}
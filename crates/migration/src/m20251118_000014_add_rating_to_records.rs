@@ -0,0 +1,91 @@
+use sea_orm_migration::prelude::*;
+use sea_query::Index;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Caches the sheet rating of each best score so that a user's rating can be refreshed from their
+/// top records alone instead of reloading every record. The backfill mirrors the default
+/// `domain::service::rating::RatingPolicy`; deployments with a custom policy recompute afterwards.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Records::Table)
+                    .add_column(
+                        ColumnDef::new(Records::Rating)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"
+            UPDATE records AS r
+            SET rating = GREATEST(0, s.level * 10 + CASE
+                WHEN r.score <= 700000 THEN -200
+                WHEN r.score >= 1090000 THEN 200
+                WHEN r.score <= 750000 THEN -200 + 50 * (r.score - 700000) / 50000
+                WHEN r.score <= 800000 THEN -150 + 50 * (r.score - 750000) / 50000
+                WHEN r.score <= 850000 THEN -100 + 50 * (r.score - 800000) / 50000
+                WHEN r.score <= 900000 THEN -50 + 50 * (r.score - 850000) / 50000
+                WHEN r.score <= 950000 THEN 0 + 50 * (r.score - 900000) / 50000
+                WHEN r.score <= 1000000 THEN 50 + 50 * (r.score - 950000) / 50000
+                WHEN r.score <= 1050000 THEN 100 + 50 * (r.score - 1000000) / 50000
+                ELSE 150 + 50 * (r.score - 1050000) / 40000
+            END)
+            FROM sheets AS s
+            WHERE s.id = r.sheet_id;
+            "#,
+        )
+        .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_records_user_rating")
+                    .table(Records::Table)
+                    .col(Records::UserId)
+                    .col(Records::Rating)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_records_user_rating")
+                    .table(Records::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Records::Table)
+                    .drop_column(Records::Rating)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Records {
+    Table,
+    UserId,
+    Rating,
+}
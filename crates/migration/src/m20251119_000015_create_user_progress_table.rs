@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Daily snapshots of each user's rating and XP for progress charts. A submission overwrites the
+/// snapshot of its day, and old days are thinned out to one per week, so a user costs at most one
+/// row per active day for the recent past and one per week beyond it.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserProgress::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(UserProgress::UserId).uuid().not_null())
+                    .col(ColumnDef::new(UserProgress::Day).date().not_null())
+                    .col(ColumnDef::new(UserProgress::Rating).integer().not_null())
+                    .col(ColumnDef::new(UserProgress::Xp).big_integer().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(UserProgress::UserId)
+                            .col(UserProgress::Day),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_progress_user")
+                            .from(UserProgress::Table, UserProgress::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserProgress::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserProgress {
+    Table,
+    UserId,
+    Day,
+    Rating,
+    Xp,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,82 @@
+use sea_orm_migration::prelude::*;
+use sea_query::{Expr, Index};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Time-boxed XP multipliers such as a double XP weekend. The multiplier is stored in percent so
+/// that `200` doubles the XP of every submission made between `starts_at` and `ends_at`.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(XpCampaigns::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(XpCampaigns::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(XpCampaigns::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(XpCampaigns::MultiplierPercent)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(XpCampaigns::StartsAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(XpCampaigns::EndsAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(XpCampaigns::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_xp_campaigns_period")
+                    .table(XpCampaigns::Table)
+                    .col(XpCampaigns::StartsAt)
+                    .col(XpCampaigns::EndsAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(XpCampaigns::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum XpCampaigns {
+    Table,
+    Id,
+    Name,
+    MultiplierPercent,
+    StartsAt,
+    EndsAt,
+    CreatedAt,
+}
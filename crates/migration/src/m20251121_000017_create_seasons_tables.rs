@@ -0,0 +1,138 @@
+use sea_orm_migration::prelude::*;
+use sea_query::{Expr, Index};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Weekly and monthly ranking seasons. Live standings are computed from the play log; once a
+/// season is over its final standings are copied into `season_standings` so they stay fixed even
+/// as display names change. `archived_at` is set after the copy completes.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Seasons::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Seasons::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(Seasons::Period).string().not_null())
+                    .col(
+                        ColumnDef::new(Seasons::StartsAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Seasons::EndsAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Seasons::ArchivedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_seasons_period_starts_at")
+                    .table(Seasons::Table)
+                    .col(Seasons::Period)
+                    .col(Seasons::StartsAt)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SeasonStandings::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(SeasonStandings::SeasonId).uuid().not_null())
+                    .col(ColumnDef::new(SeasonStandings::Board).string().not_null())
+                    .col(ColumnDef::new(SeasonStandings::UserId).uuid().not_null())
+                    .col(ColumnDef::new(SeasonStandings::Rank).integer().not_null())
+                    .col(
+                        ColumnDef::new(SeasonStandings::DisplayName)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SeasonStandings::Value)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(SeasonStandings::SeasonId)
+                            .col(SeasonStandings::Board)
+                            .col(SeasonStandings::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_season_standings_season")
+                            .from(SeasonStandings::Table, SeasonStandings::SeasonId)
+                            .to(Seasons::Table, Seasons::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_season_standings_user")
+                            .from(SeasonStandings::Table, SeasonStandings::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SeasonStandings::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Seasons::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Seasons {
+    Table,
+    Id,
+    Period,
+    StartsAt,
+    EndsAt,
+    ArchivedAt,
+}
+
+#[derive(DeriveIden)]
+enum SeasonStandings {
+    Table,
+    SeasonId,
+    Board,
+    UserId,
+    Rank,
+    DisplayName,
+    Value,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
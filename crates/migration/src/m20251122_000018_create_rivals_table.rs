@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+use sea_query::{Expr, Index};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Rivals a user follows to compare scores against. A row is owned by `user_id`; deleting either
+/// user removes it.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Rivals::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Rivals::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Rivals::RivalId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(Rivals::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(Index::create().col(Rivals::UserId).col(Rivals::RivalId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_rivals_user")
+                            .from(Rivals::Table, Rivals::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_rivals_rival")
+                            .from(Rivals::Table, Rivals::RivalId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Rivals::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Rivals {
+    Table,
+    UserId,
+    RivalId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+use sea_query::{Expr, Index};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Records which cabinet consumed each credit, the same way `plays.client_id` attributes plays.
+/// Rows are only ever inserted.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CreditLogs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CreditLogs::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(ColumnDef::new(CreditLogs::UserId).uuid().not_null())
+                    .col(ColumnDef::new(CreditLogs::ClientId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(CreditLogs::ConsumedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_credit_logs_user")
+                            .from(CreditLogs::Table, CreditLogs::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_credit_logs_client")
+                            .from(CreditLogs::Table, CreditLogs::ClientId)
+                            .to(Clients::Table, Clients::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_credit_logs_user_consumed_at")
+                    .table(CreditLogs::Table)
+                    .col(CreditLogs::UserId)
+                    .col(CreditLogs::ConsumedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_credit_logs_user_consumed_at")
+                    .table(CreditLogs::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(CreditLogs::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum CreditLogs {
+    Table,
+    Id,
+    UserId,
+    ClientId,
+    ConsumedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Clients {
+    Table,
+    Id,
+}
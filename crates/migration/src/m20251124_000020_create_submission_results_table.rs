@@ -0,0 +1,149 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Keeps the answer each submission got, i.e. the record its sheet was left at and the level-up
+/// of its batch, so that a retried POST is answered exactly like the original one. Rows go away
+/// with their play.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SubmissionResults::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SubmissionResults::SubmissionId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SubmissionResults::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(SubmissionResults::RecordId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SubmissionResults::SheetId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(SubmissionResults::Score)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionResults::ClearType)
+                            .custom(ClearType::Table)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionResults::JudgePerfect)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionResults::JudgeGreat)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionResults::JudgeGood)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionResults::JudgeMiss)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionResults::MaxCombo)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionResults::PlayCount)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionResults::RecordUpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionResults::LevelFrom)
+                            .integer()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(SubmissionResults::LevelTo).integer().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_submission_results_play")
+                            .from(SubmissionResults::Table, SubmissionResults::SubmissionId)
+                            .to(Plays::Table, Plays::SubmissionId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_submission_results_user")
+                            .from(SubmissionResults::Table, SubmissionResults::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SubmissionResults::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SubmissionResults {
+    Table,
+    SubmissionId,
+    UserId,
+    RecordId,
+    SheetId,
+    Score,
+    ClearType,
+    JudgePerfect,
+    JudgeGreat,
+    JudgeGood,
+    JudgeMiss,
+    MaxCombo,
+    PlayCount,
+    RecordUpdatedAt,
+    LevelFrom,
+    LevelTo,
+}
+
+#[derive(DeriveIden)]
+#[sea_orm(iden = "clear_type")]
+enum ClearType {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum Plays {
+    Table,
+    SubmissionId,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
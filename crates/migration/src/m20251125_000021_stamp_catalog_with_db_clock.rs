@@ -0,0 +1,50 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Stamps `updated_at` of musics and sheets with the database clock at the start of the writing
+/// transaction, whatever path wrote the row. Catalog sync can then step its cursor back to the
+/// oldest transaction still in flight and be sure no row committed later carries an older stamp,
+/// which application clocks could not promise.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"
+            CREATE FUNCTION stamp_catalog_updated_at() RETURNS trigger AS $$
+            BEGIN
+                NEW.updated_at := now();
+                RETURN NEW;
+            END;
+            $$ LANGUAGE plpgsql;
+
+            CREATE TRIGGER trg_musics_updated_at
+                BEFORE INSERT OR UPDATE ON musics
+                FOR EACH ROW EXECUTE FUNCTION stamp_catalog_updated_at();
+
+            CREATE TRIGGER trg_sheets_updated_at
+                BEFORE INSERT OR UPDATE ON sheets
+                FOR EACH ROW EXECUTE FUNCTION stamp_catalog_updated_at();
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"
+            DROP TRIGGER IF EXISTS trg_sheets_updated_at ON sheets;
+            DROP TRIGGER IF EXISTS trg_musics_updated_at ON musics;
+            DROP FUNCTION IF EXISTS stamp_catalog_updated_at();
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Submission ids are generated by clients, so they are only unique per user. Scoping the unique
+/// index of `plays` and the key of `submission_results` to `(user_id, submission_id)` keeps one
+/// user's id from rejecting or replaying another user's submission.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"
+            ALTER TABLE submission_results DROP CONSTRAINT fk_submission_results_play;
+            ALTER TABLE submission_results DROP CONSTRAINT submission_results_pkey;
+            DROP INDEX idx_plays_submission_id;
+
+            CREATE UNIQUE INDEX idx_plays_user_submission_id ON plays (user_id, submission_id);
+            ALTER TABLE submission_results ADD PRIMARY KEY (user_id, submission_id);
+            ALTER TABLE submission_results
+                ADD CONSTRAINT fk_submission_results_play
+                FOREIGN KEY (user_id, submission_id) REFERENCES plays (user_id, submission_id)
+                ON DELETE CASCADE ON UPDATE CASCADE;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            r#"
+            ALTER TABLE submission_results DROP CONSTRAINT fk_submission_results_play;
+            ALTER TABLE submission_results DROP CONSTRAINT submission_results_pkey;
+            DROP INDEX idx_plays_user_submission_id;
+
+            CREATE UNIQUE INDEX idx_plays_submission_id ON plays (submission_id);
+            ALTER TABLE submission_results ADD PRIMARY KEY (submission_id);
+            ALTER TABLE submission_results
+                ADD CONSTRAINT fk_submission_results_play
+                FOREIGN KEY (submission_id) REFERENCES plays (submission_id)
+                ON DELETE CASCADE ON UPDATE CASCADE;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,244 @@
+//! Imports and exports the music catalog as a JSON manifest.
+//!
+//! The manifest is an array in the same layout the `/sync` endpoint returns, so an export can be
+//! edited and imported again. Rows are upserted by id; rows without an id are inserted. Manifests
+//! ending in `.csv` are read as one row per sheet instead, see [`CatalogCsvRow`].
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+};
+
+use presentation::{
+    env,
+    model::{
+        catalog::{CatalogCsvRow, CatalogEntryRequest, entries_from_csv_rows},
+        sync::SyncItemResponse,
+    },
+};
+use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+use usecase::music::MusicUsecase;
+
+const USAGE: &str = "\
+Usage:
+  catalog import <manifest.json|manifest.csv> [--dry-run]
+  catalog export [<output.json>]";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Import { manifest: PathBuf, dry_run: bool },
+    Export { output: Option<PathBuf> },
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    env::load_dotenv();
+    init_tracing();
+
+    let repositories = infrastructure::RepositoriesImpl::new_default(&env::postgres_url()).await;
+    let usecase = MusicUsecase::new(Arc::new(repositories));
+
+    let result = match command {
+        Command::Import { manifest, dry_run } => import(&usecase, manifest, dry_run).await,
+        Command::Export { output } => export(&usecase, output).await,
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let subcommand = args.next().ok_or("Missing subcommand")?;
+    let rest: Vec<String> = args.collect();
+
+    match subcommand.as_str() {
+        "import" => {
+            let dry_run = rest.iter().any(|arg| arg == "--dry-run");
+            let mut paths = rest.into_iter().filter(|arg| arg != "--dry-run");
+            let manifest = paths.next().ok_or("Missing manifest path")?;
+            if let Some(extra) = paths.next() {
+                return Err(format!("Unexpected argument: {extra}"));
+            }
+            Ok(Command::Import {
+                manifest: manifest.into(),
+                dry_run,
+            })
+        }
+        "export" => match rest.as_slice() {
+            [] => Ok(Command::Export { output: None }),
+            [output] => Ok(Command::Export {
+                output: Some(output.into()),
+            }),
+            [_, extra, ..] => Err(format!("Unexpected argument: {extra}")),
+        },
+        other => Err(format!("Unknown subcommand: {other}")),
+    }
+}
+
+async fn import(
+    usecase: &MusicUsecase<infrastructure::RepositoriesImpl>,
+    manifest: PathBuf,
+    dry_run: bool,
+) -> Result<ExitCode, String> {
+    let content = fs::read_to_string(&manifest)
+        .map_err(|err| format!("Failed to read {}: {err}", manifest.display()))?;
+    let entries = parse_manifest(&manifest, &content)
+        .map_err(|err| format!("Failed to parse {}: {err}", manifest.display()))?;
+
+    let report = usecase
+        .import_catalog(entries.into_iter().map(Into::into).collect(), dry_run)
+        .await
+        .map_err(|err| format!("Catalog import failed: {err}"))?;
+
+    for failure in &report.failures {
+        eprintln!("{}: {}", failure.row, failure.message);
+    }
+    if !report.failures.is_empty() {
+        eprintln!(
+            "{} row(s) failed; nothing was written",
+            report.failures.len()
+        );
+        return Ok(ExitCode::FAILURE);
+    }
+
+    if report.committed {
+        println!(
+            "Imported {} music(s) and {} sheet(s)",
+            report.musics, report.sheets
+        );
+    } else {
+        println!(
+            "Dry run: {} music(s) and {} sheet(s) would be imported",
+            report.musics, report.sheets
+        );
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn parse_manifest(path: &Path, content: &str) -> Result<Vec<CatalogEntryRequest>, String> {
+    let is_csv = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+    if !is_csv {
+        return serde_json::from_str(content).map_err(|err| err.to_string());
+    }
+
+    let rows = csv::Reader::from_reader(content.as_bytes())
+        .deserialize::<CatalogCsvRow>()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| err.to_string())?;
+    entries_from_csv_rows(rows)
+}
+
+async fn export(
+    usecase: &MusicUsecase<infrastructure::RepositoriesImpl>,
+    output: Option<PathBuf>,
+) -> Result<ExitCode, String> {
+    let musics = usecase
+        .list_all()
+        .await
+        .map_err(|err| format!("Catalog export failed: {err}"))?;
+    let response: Vec<SyncItemResponse> = musics.into_iter().map(Into::into).collect();
+    let json = serde_json::to_string_pretty(&response)
+        .map_err(|err| format!("Failed to serialize catalog: {err}"))?;
+
+    match output {
+        Some(path) => {
+            fs::write(&path, json + "\n")
+                .map_err(|err| format!("Failed to write {}: {err}", path.display()))?;
+            eprintln!("Exported {} music(s) to {}", response.len(), path.display());
+        }
+        None => println!("{json}"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Logs go to stderr so that an export written to stdout stays valid JSON. Implicitly depends on
+/// the `RUST_LOG` environment variable to override the filter configuration when present.
+fn init_tracing() {
+    let env_filter = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new("warn"))
+        .unwrap();
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_target(false)
+                .with_writer(std::io::stderr),
+        )
+        .try_init()
+        .ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_args_accepts_dry_run_in_any_position() {
+        let expected = Command::Import {
+            manifest: "catalog.json".into(),
+            dry_run: true,
+        };
+
+        assert_eq!(
+            parse_args(args(&["import", "--dry-run", "catalog.json"])),
+            Ok(expected)
+        );
+        assert_eq!(
+            parse_args(args(&["import", "catalog.json"])),
+            Ok(Command::Import {
+                manifest: "catalog.json".into(),
+                dry_run: false,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_args_rejects_unknown_input() {
+        assert!(parse_args(args(&[])).is_err());
+        assert!(parse_args(args(&["import"])).is_err());
+        assert!(parse_args(args(&["export", "a.json", "b.json"])).is_err());
+        assert!(parse_args(args(&["drop"])).is_err());
+    }
+
+    #[test]
+    fn parse_manifest_reads_csv_by_extension() {
+        let csv = "musicId,title,artist,bpm,genre,jacket,registrationDate,isTest,sheetId,difficulty,level,notesDesigner,noteCount,maxScore\n\
+                   music-1,Song,Artist,150,ORIGINAL,jacket.png,2025-01-01T00:00:00Z,false,sheet-1,hard,12.5,Designer,812,1010000\n";
+
+        let entries = parse_manifest(Path::new("catalog.CSV"), csv).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].sheets.len(), 1);
+        assert!(parse_manifest(Path::new("catalog.json"), csv).is_err());
+    }
+
+    #[test]
+    fn parse_manifest_reports_malformed_csv() {
+        let csv = "musicId,title,artist,bpm\nmusic-1,Song,Artist,fast\n";
+
+        assert!(parse_manifest(Path::new("catalog.csv"), csv).is_err());
+    }
+}
@@ -1,2 +1,237 @@
+use std::{str::FromStr, time::Duration};
+
+use domain::service::{
+    acting_user::ActingUserSigner, experience::XpPolicy, level::LevelCurve, rating::RatingPolicy,
+};
+
+use crate::env;
+
+/// Rankings are cached this long unless `RANKING_CACHE_TTL_SECONDS` says otherwise.
+const DEFAULT_RANKING_CACHE_TTL: Duration = Duration::from_secs(30);
+
 #[derive(Clone, Default)]
-pub struct Config {}
+pub struct Config {
+    pub rating_policy: RatingPolicy,
+    pub level_curve: LevelCurve,
+    pub xp_policy: XpPolicy,
+    /// Zero, the `Default`, disables the ranking cache.
+    pub ranking_cache_ttl: Duration,
+    /// Verifies acting user tokens. The `Default` has no secret and rejects them all.
+    pub acting_user_signer: ActingUserSigner,
+}
+
+impl Config {
+    /// Builds the configuration from the environment, falling back to the defaults for anything
+    /// unset. Panics on malformed values so that a bad deploy fails at startup.
+    pub fn from_env() -> Self {
+        let rating_policy = rating_policy(
+            env::rating_best_count().as_deref(),
+            env::rating_recent_count().as_deref(),
+            env::rating_bonus_curve().as_deref(),
+        )
+        .unwrap_or_else(|err| panic!("Invalid rating configuration: {err}"));
+        let level_curve = level_curve(
+            env::level_base_xp().as_deref(),
+            env::level_xp_step().as_deref(),
+            env::level_max().as_deref(),
+        )
+        .unwrap_or_else(|err| panic!("Invalid level configuration: {err}"));
+        let xp_policy = xp_policy(
+            env::xp_full_combo_bonus().as_deref(),
+            env::xp_all_perfect_bonus().as_deref(),
+            env::xp_level_bonus().as_deref(),
+            env::xp_first_clear_bonus().as_deref(),
+        )
+        .unwrap_or_else(|err| panic!("Invalid XP configuration: {err}"));
+        let ranking_cache_ttl = ranking_cache_ttl(env::ranking_cache_ttl_seconds().as_deref())
+            .unwrap_or_else(|err| panic!("Invalid ranking cache configuration: {err}"));
+        let acting_user_signer = acting_user_signer(env::acting_user_token_secret().as_deref())
+            .unwrap_or_else(|err| panic!("Invalid acting user configuration: {err}"));
+        Self {
+            rating_policy,
+            level_curve,
+            xp_policy,
+            ranking_cache_ttl,
+            acting_user_signer,
+        }
+    }
+}
+
+fn rating_policy(
+    best_count: Option<&str>,
+    recent_count: Option<&str>,
+    bonus_curve: Option<&str>,
+) -> Result<RatingPolicy, String> {
+    let defaults = RatingPolicy::default();
+    let best_count = match best_count {
+        Some(value) => parse_count("RATING_BEST_COUNT", value)?,
+        None => defaults.best_count(),
+    };
+    let recent_count = match recent_count {
+        Some(value) => parse_count("RATING_RECENT_COUNT", value)?,
+        None => defaults.recent_count(),
+    };
+    let bonus_curve = match bonus_curve {
+        Some(value) => parse_bonus_curve(value)?,
+        None => defaults.bonus_curve().to_vec(),
+    };
+
+    RatingPolicy::new(best_count, recent_count, bonus_curve).map_err(|err| err.to_string())
+}
+
+fn level_curve(
+    base_xp: Option<&str>,
+    xp_step: Option<&str>,
+    max_level: Option<&str>,
+) -> Result<LevelCurve, String> {
+    let defaults = LevelCurve::default();
+    let base_xp = match base_xp {
+        Some(value) => parse_count("LEVEL_BASE_XP", value)?,
+        None => defaults.base_xp(),
+    };
+    let xp_step = match xp_step {
+        Some(value) => parse_count("LEVEL_XP_STEP", value)?,
+        None => defaults.xp_step(),
+    };
+    let max_level = match max_level {
+        Some(value) => parse_count("LEVEL_MAX", value)?,
+        None => defaults.max_level(),
+    };
+
+    LevelCurve::new(base_xp, xp_step, max_level).map_err(|err| err.to_string())
+}
+
+fn xp_policy(
+    full_combo_bonus: Option<&str>,
+    all_perfect_bonus: Option<&str>,
+    level_bonus: Option<&str>,
+    first_clear_bonus: Option<&str>,
+) -> Result<XpPolicy, String> {
+    let bonus = |name: &str, value: Option<&str>| match value {
+        Some(value) => parse_count(name, value),
+        None => Ok(0),
+    };
+
+    Ok(XpPolicy::new(
+        bonus("XP_FULL_COMBO_BONUS", full_combo_bonus)?,
+        bonus("XP_ALL_PERFECT_BONUS", all_perfect_bonus)?,
+        bonus("XP_LEVEL_BONUS", level_bonus)?,
+        bonus("XP_FIRST_CLEAR_BONUS", first_clear_bonus)?,
+    ))
+}
+
+fn ranking_cache_ttl(seconds: Option<&str>) -> Result<Duration, String> {
+    match seconds {
+        Some(value) => parse_count("RANKING_CACHE_TTL_SECONDS", value).map(Duration::from_secs),
+        None => Ok(DEFAULT_RANKING_CACHE_TTL),
+    }
+}
+
+fn acting_user_signer(secret: Option<&str>) -> Result<ActingUserSigner, String> {
+    match secret {
+        Some(secret) => ActingUserSigner::new(secret).map_err(|err| err.to_string()),
+        None => Ok(ActingUserSigner::default()),
+    }
+}
+
+fn parse_count<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("{name} must be a non-negative integer, got {value:?}"))
+}
+
+fn parse_bonus_curve(value: &str) -> Result<Vec<(u32, i32)>, String> {
+    value
+        .split(',')
+        .map(|anchor| {
+            let parsed = anchor.split_once(':').and_then(|(score, bonus)| {
+                Some((score.trim().parse().ok()?, bonus.trim().parse().ok()?))
+            });
+            parsed.ok_or_else(|| {
+                format!("RATING_BONUS_CURVE anchors must be score:bonus pairs, got {anchor:?}")
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::service::rating::DEFAULT_BONUS_CURVE;
+
+    use super::*;
+
+    #[test]
+    fn rating_policy_defaults_when_unset() {
+        let policy = rating_policy(None, None, None).unwrap();
+
+        assert_eq!(policy, RatingPolicy::default());
+    }
+
+    #[test]
+    fn rating_policy_parses_overrides() {
+        let policy = rating_policy(Some("30"), Some("10"), Some("900000:0, 1000000:100")).unwrap();
+
+        assert_eq!(policy.best_count(), 30);
+        assert_eq!(policy.recent_count(), 10);
+        assert_eq!(policy.bonus_curve(), &[(900_000, 0), (1_000_000, 100)]);
+        assert_ne!(policy.bonus_curve(), &DEFAULT_BONUS_CURVE);
+    }
+
+    #[test]
+    fn rating_policy_rejects_malformed_values() {
+        assert!(rating_policy(Some("three"), None, None).is_err());
+        assert!(rating_policy(None, None, Some("900000=0")).is_err());
+        assert!(rating_policy(None, None, Some("1000000:100,900000:0")).is_err());
+    }
+
+    #[test]
+    fn level_curve_parses_overrides() {
+        assert_eq!(
+            level_curve(None, None, None).unwrap(),
+            LevelCurve::default()
+        );
+
+        let curve = level_curve(Some("50"), Some("0"), Some("99")).unwrap();
+
+        assert_eq!(curve, LevelCurve::new(50, 0, 99).unwrap());
+    }
+
+    #[test]
+    fn level_curve_rejects_malformed_values() {
+        assert!(level_curve(Some("-1"), None, None).is_err());
+        assert!(level_curve(Some("0"), None, None).is_err());
+        assert!(level_curve(None, None, Some("0")).is_err());
+    }
+
+    #[test]
+    fn xp_policy_parses_bonuses() {
+        assert_eq!(
+            xp_policy(None, None, None, None).unwrap(),
+            XpPolicy::default()
+        );
+
+        let policy = xp_policy(Some("20"), Some("50"), None, Some("30")).unwrap();
+
+        assert_eq!(policy, XpPolicy::new(20, 50, 0, 30));
+        assert!(xp_policy(None, None, Some("lots"), None).is_err());
+    }
+
+    #[test]
+    fn ranking_cache_ttl_defaults_and_parses_seconds() {
+        assert_eq!(ranking_cache_ttl(None).unwrap(), DEFAULT_RANKING_CACHE_TTL);
+        assert_eq!(ranking_cache_ttl(Some("0")).unwrap(), Duration::ZERO);
+        assert_eq!(
+            ranking_cache_ttl(Some("120")).unwrap(),
+            Duration::from_secs(120)
+        );
+        assert!(ranking_cache_ttl(Some("1m")).is_err());
+    }
+
+    #[test]
+    fn acting_user_signer_requires_a_long_secret() {
+        assert!(acting_user_signer(None).is_ok());
+        assert!(acting_user_signer(Some("short")).is_err());
+        assert!(acting_user_signer(Some("0123456789abcdef0123456789abcdef")).is_ok());
+    }
+}
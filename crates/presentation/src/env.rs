@@ -1,5 +1,15 @@
 use std::env;
 
+/// Loads environment variables from the `.env` file if present. Implicitly depends on environment
+/// variables provided by the surrounding process environment when `.env` is absent.
+pub fn load_dotenv() {
+    match dotenvy::dotenv_override() {
+        Ok(_) => {}
+        Err(dotenvy::Error::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => {}
+        Err(error) => panic!("Failed to load .env file: {error}"),
+    }
+}
+
 pub fn host() -> String {
     env::var("HOST").expect("HOST must be set")
 }
@@ -31,3 +41,64 @@ pub fn postgres_url() -> String {
     let port = postgres_port();
     format!("postgres://{}:{}@{}:{}/{}", user, password, host, port, db)
 }
+
+/// Number of best sheets averaged into a user's rating. Unset keeps the default policy.
+pub fn rating_best_count() -> Option<String> {
+    env::var("RATING_BEST_COUNT").ok()
+}
+
+/// Number of most recent plays averaged into a user's rating. Unset or `0` disables the frame.
+pub fn rating_recent_count() -> Option<String> {
+    env::var("RATING_RECENT_COUNT").ok()
+}
+
+/// Score-bonus anchors as comma-separated `score:bonus` pairs, e.g. `900000:0,1000000:100`.
+pub fn rating_bonus_curve() -> Option<String> {
+    env::var("RATING_BONUS_CURVE").ok()
+}
+
+/// XP needed to clear level 1. Unset keeps the default level curve.
+pub fn level_base_xp() -> Option<String> {
+    env::var("LEVEL_BASE_XP").ok()
+}
+
+/// Extra XP each further level costs on top of the one before it.
+pub fn level_xp_step() -> Option<String> {
+    env::var("LEVEL_XP_STEP").ok()
+}
+
+/// Highest level a player can reach.
+pub fn level_max() -> Option<String> {
+    env::var("LEVEL_MAX").ok()
+}
+
+/// Bonus XP for a full combo. Unset keeps the bonus off.
+pub fn xp_full_combo_bonus() -> Option<String> {
+    env::var("XP_FULL_COMBO_BONUS").ok()
+}
+
+/// Bonus XP for an all perfect, granted instead of the full combo bonus.
+pub fn xp_all_perfect_bonus() -> Option<String> {
+    env::var("XP_ALL_PERFECT_BONUS").ok()
+}
+
+/// Bonus XP per integer level of a cleared sheet.
+pub fn xp_level_bonus() -> Option<String> {
+    env::var("XP_LEVEL_BONUS").ok()
+}
+
+/// Bonus XP for clearing a sheet for the first time.
+pub fn xp_first_clear_bonus() -> Option<String> {
+    env::var("XP_FIRST_CLEAR_BONUS").ok()
+}
+
+/// Seconds a computed ranking is served from memory. `0` disables the cache.
+pub fn ranking_cache_ttl_seconds() -> Option<String> {
+    env::var("RANKING_CACHE_TTL_SECONDS").ok()
+}
+
+/// Secret shared with the web backend for signing acting user tokens. Web clients cannot act for
+/// users while it is unset.
+pub fn acting_user_token_secret() -> Option<String> {
+    env::var("ACTING_USER_TOKEN_SECRET").ok()
+}
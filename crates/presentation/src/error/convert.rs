@@ -1,9 +1,14 @@
 use domain::repository::{
-    music::MusicRepositoryError, record::RecordRepositoryError, user::UserRepositoryError,
+    api_key::ApiKeyRepositoryError, client::ClientRepositoryError, music::MusicRepositoryError,
+    play::PlayRepositoryError, record::RecordRepositoryError, rival::RivalRepositoryError,
+    season::SeasonRepositoryError, user::UserRepositoryError,
+    xp_campaign::XpCampaignRepositoryError,
 };
 use usecase::{
-    music::MusicUsecaseError, ranking::RankingUsecaseError, statistics::StatisticsUsecaseError,
-    user::UserUsecaseError,
+    auth::AuthUsecaseError, client::ClientUsecaseError, music::MusicUsecaseError,
+    ranking::RankingUsecaseError, rating::RatingUsecaseError, rival::RivalUsecaseError,
+    season::SeasonUsecaseError, statistics::StatisticsUsecaseError, user::UserUsecaseError,
+    xp_campaign::XpCampaignUsecaseError,
 };
 
 use crate::error::AppError;
@@ -46,6 +51,29 @@ impl From<RecordRepositoryError> for AppError {
     }
 }
 
+impl From<PlayRepositoryError> for AppError {
+    fn from(error: PlayRepositoryError) -> Self {
+        match error {
+            PlayRepositoryError::UserNotFound(id) => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: format!("User not found: {id}"),
+            },
+            PlayRepositoryError::SheetNotFound(id) => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: format!("Sheet not found: {id}"),
+            },
+            PlayRepositoryError::DuplicateSubmission(_) => AppError {
+                status_code: axum::http::StatusCode::CONFLICT,
+                message: error.to_string(),
+            },
+            PlayRepositoryError::InternalError(err) => AppError {
+                status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
 impl From<UserUsecaseError> for AppError {
     fn from(error: UserUsecaseError) -> Self {
         match error {
@@ -58,7 +86,22 @@ impl From<UserUsecaseError> for AppError {
                 status_code: axum::http::StatusCode::NOT_FOUND,
                 message: format!("User not found for id: {user_id}"),
             },
+            UserUsecaseError::InvalidSubmission { .. } => AppError {
+                status_code: axum::http::StatusCode::BAD_REQUEST,
+                message: error.to_string(),
+            },
+            UserUsecaseError::ImplausibleSubmission { .. } => AppError {
+                status_code: axum::http::StatusCode::UNPROCESSABLE_ENTITY,
+                message: error.to_string(),
+            },
+            UserUsecaseError::SheetNotFound { sheet_id } => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: format!("Sheet not found: {sheet_id}"),
+            },
             UserUsecaseError::RecordRepositoryError(repo_error) => repo_error.into(),
+            UserUsecaseError::MusicRepositoryError(repo_error) => repo_error.into(),
+            UserUsecaseError::PlayRepositoryError(repo_error) => repo_error.into(),
+            UserUsecaseError::XpCampaignRepositoryError(repo_error) => repo_error.into(),
             UserUsecaseError::InternalError(err) => AppError {
                 status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
                 message: err.to_string(),
@@ -70,6 +113,16 @@ impl From<UserUsecaseError> for AppError {
 impl From<MusicRepositoryError> for AppError {
     fn from(error: MusicRepositoryError) -> Self {
         match error {
+            MusicRepositoryError::MusicNotFound(_) | MusicRepositoryError::SheetNotFound(_) => {
+                AppError {
+                    status_code: axum::http::StatusCode::NOT_FOUND,
+                    message: error.to_string(),
+                }
+            }
+            MusicRepositoryError::SheetAlreadyExists { .. } => AppError {
+                status_code: axum::http::StatusCode::CONFLICT,
+                message: error.to_string(),
+            },
             MusicRepositoryError::InternalError(err) => AppError {
                 status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
                 message: err.to_string(),
@@ -81,7 +134,26 @@ impl From<MusicRepositoryError> for AppError {
 impl From<MusicUsecaseError> for AppError {
     fn from(error: MusicUsecaseError) -> Self {
         match error {
+            MusicUsecaseError::InvalidInput(_) => AppError {
+                status_code: axum::http::StatusCode::BAD_REQUEST,
+                message: error.to_string(),
+            },
+            MusicUsecaseError::MusicNotFound { .. } | MusicUsecaseError::SheetNotFound { .. } => {
+                AppError {
+                    status_code: axum::http::StatusCode::NOT_FOUND,
+                    message: error.to_string(),
+                }
+            }
+            MusicUsecaseError::SheetAlreadyExists { .. } => AppError {
+                status_code: axum::http::StatusCode::CONFLICT,
+                message: error.to_string(),
+            },
             MusicUsecaseError::MusicRepository(err) => err.into(),
+            MusicUsecaseError::Rating(err) => err.into(),
+            MusicUsecaseError::InternalError(err) => AppError {
+                status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            },
         }
     }
 }
@@ -100,6 +172,209 @@ impl From<RankingUsecaseError> for AppError {
         match error {
             RankingUsecaseError::RecordRepository(err) => err.into(),
             RankingUsecaseError::UserRepository(err) => err.into(),
+            RankingUsecaseError::PlayRepository(err) => err.into(),
+            RankingUsecaseError::SeasonRepository(err) => err.into(),
+            RankingUsecaseError::NoCurrentSeason(_) | RankingUsecaseError::NoArchivedSeason(_) => {
+                AppError {
+                    status_code: axum::http::StatusCode::NOT_FOUND,
+                    message: error.to_string(),
+                }
+            }
+        }
+    }
+}
+
+impl From<RatingUsecaseError> for AppError {
+    fn from(error: RatingUsecaseError) -> Self {
+        match error {
+            RatingUsecaseError::UserRepository(err) => err.into(),
+            RatingUsecaseError::RecordRepository(err) => err.into(),
+            RatingUsecaseError::PlayRepository(err) => err.into(),
+            RatingUsecaseError::InternalError(err) => AppError {
+                status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+impl From<SeasonRepositoryError> for AppError {
+    fn from(error: SeasonRepositoryError) -> Self {
+        match error {
+            SeasonRepositoryError::NotFound(_) => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: error.to_string(),
+            },
+            SeasonRepositoryError::Overlapping(_) => AppError {
+                status_code: axum::http::StatusCode::CONFLICT,
+                message: error.to_string(),
+            },
+            SeasonRepositoryError::InternalError(err) => AppError {
+                status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+impl From<SeasonUsecaseError> for AppError {
+    fn from(error: SeasonUsecaseError) -> Self {
+        match error {
+            SeasonUsecaseError::InvalidSeason(_) => AppError {
+                status_code: axum::http::StatusCode::BAD_REQUEST,
+                message: error.to_string(),
+            },
+            SeasonUsecaseError::NotFoundById { .. } => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: error.to_string(),
+            },
+            SeasonUsecaseError::NotEnded { .. } => AppError {
+                status_code: axum::http::StatusCode::CONFLICT,
+                message: error.to_string(),
+            },
+            SeasonUsecaseError::SeasonRepository(err) => err.into(),
+            SeasonUsecaseError::PlayRepository(err) => err.into(),
+        }
+    }
+}
+
+impl From<XpCampaignRepositoryError> for AppError {
+    fn from(error: XpCampaignRepositoryError) -> Self {
+        match error {
+            XpCampaignRepositoryError::NotFound(_) => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: error.to_string(),
+            },
+            XpCampaignRepositoryError::InternalError(err) => AppError {
+                status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+impl From<XpCampaignUsecaseError> for AppError {
+    fn from(error: XpCampaignUsecaseError) -> Self {
+        match error {
+            XpCampaignUsecaseError::InvalidCampaign(_) => AppError {
+                status_code: axum::http::StatusCode::BAD_REQUEST,
+                message: error.to_string(),
+            },
+            XpCampaignUsecaseError::NotFoundById { .. } => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: error.to_string(),
+            },
+            XpCampaignUsecaseError::XpCampaignRepository(err) => err.into(),
+        }
+    }
+}
+
+impl From<RivalRepositoryError> for AppError {
+    fn from(error: RivalRepositoryError) -> Self {
+        match error {
+            RivalRepositoryError::UserNotFound(id) => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: format!("User not found: {id}"),
+            },
+            RivalRepositoryError::AlreadyRegistered { .. } => AppError {
+                status_code: axum::http::StatusCode::CONFLICT,
+                message: error.to_string(),
+            },
+            RivalRepositoryError::NotFound { .. } => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: error.to_string(),
+            },
+            RivalRepositoryError::InternalError(err) => AppError {
+                status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+impl From<RivalUsecaseError> for AppError {
+    fn from(error: RivalUsecaseError) -> Self {
+        match error {
+            RivalUsecaseError::InvalidRival(_) => AppError {
+                status_code: axum::http::StatusCode::BAD_REQUEST,
+                message: error.to_string(),
+            },
+            RivalUsecaseError::NotFoundById { .. } | RivalUsecaseError::NotRegistered { .. } => {
+                AppError {
+                    status_code: axum::http::StatusCode::NOT_FOUND,
+                    message: error.to_string(),
+                }
+            }
+            RivalUsecaseError::AlreadyRegistered { .. }
+            | RivalUsecaseError::LimitReached { .. } => AppError {
+                status_code: axum::http::StatusCode::CONFLICT,
+                message: error.to_string(),
+            },
+            RivalUsecaseError::RivalRepository(err) => err.into(),
+            RivalUsecaseError::UserRepository(err) => err.into(),
+            RivalUsecaseError::RecordRepository(err) => err.into(),
+        }
+    }
+}
+
+impl From<ApiKeyRepositoryError> for AppError {
+    fn from(error: ApiKeyRepositoryError) -> Self {
+        match error {
+            ApiKeyRepositoryError::InternalError(err) => AppError {
+                status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+impl From<AuthUsecaseError> for AppError {
+    fn from(error: AuthUsecaseError) -> Self {
+        match error {
+            AuthUsecaseError::InvalidKey
+            | AuthUsecaseError::RevokedKey
+            | AuthUsecaseError::InvalidActingUserToken(_) => AppError {
+                status_code: axum::http::StatusCode::UNAUTHORIZED,
+                message: error.to_string(),
+            },
+            AuthUsecaseError::UnknownActingUser { .. } => AppError {
+                status_code: axum::http::StatusCode::FORBIDDEN,
+                message: error.to_string(),
+            },
+            AuthUsecaseError::ApiKeyRepository(err) => err.into(),
+            AuthUsecaseError::ClientRepository(err) => err.into(),
+            AuthUsecaseError::UserRepository(err) => err.into(),
+        }
+    }
+}
+
+impl From<ClientRepositoryError> for AppError {
+    fn from(error: ClientRepositoryError) -> Self {
+        match error {
+            ClientRepositoryError::NotFound(id) => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: format!("Client not found: {id}"),
+            },
+            ClientRepositoryError::InternalError(err) => AppError {
+                status_code: axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+impl From<ClientUsecaseError> for AppError {
+    fn from(error: ClientUsecaseError) -> Self {
+        match error {
+            ClientUsecaseError::NotFoundById { .. } => AppError {
+                status_code: axum::http::StatusCode::NOT_FOUND,
+                message: error.to_string(),
+            },
+            ClientUsecaseError::Revoked { .. } => AppError {
+                status_code: axum::http::StatusCode::CONFLICT,
+                message: error.to_string(),
+            },
+            ClientUsecaseError::ClientRepositoryError(err) => err.into(),
         }
     }
 }
@@ -1,6 +1,7 @@
 pub mod config;
 pub mod env;
 pub mod error;
+pub mod middleware;
 pub mod model;
 pub mod route;
 pub mod state;
@@ -5,13 +5,13 @@ use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitEx
 
 #[tokio::main]
 async fn main() {
-    load_env();
+    env::load_dotenv();
     init_tracing();
 
     let postgres_url = env::postgres_url();
     let repositories = infrastructure::RepositoriesImpl::new_default(&postgres_url).await;
 
-    let config = Config::default();
+    let config = Config::from_env();
     let state = State::new(config, repositories);
 
     let app = create_app(state);
@@ -22,16 +22,6 @@ async fn main() {
     axum::serve(listener, app).await.unwrap();
 }
 
-/// Loads environment variables from the `.env` file if present. Implicitly depends on environment
-/// variables provided by the surrounding process environment when `.env` is absent.
-fn load_env() {
-    match dotenvy::dotenv_override() {
-        Ok(_) => {}
-        Err(dotenvy::Error::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => {}
-        Err(error) => panic!("Failed to load .env file: {error}"),
-    }
-}
-
 /// Initializes tracing. Implicitly depends on the `RUST_LOG` environment variable to override the filter configuration when present.
 fn init_tracing() {
     if tracing::dispatcher::has_been_set() {
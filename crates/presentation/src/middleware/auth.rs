@@ -0,0 +1,233 @@
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use domain::entity::client_role::ClientRole;
+use tracing::{debug, instrument, warn};
+use usecase::model::auth::{ActingUserDto, CallerDto};
+
+use crate::error::AppError;
+
+/// Header carrying the raw API key, as declared by the `appApiKey` security scheme.
+pub const API_KEY_HEADER: &str = "XLAIR-API-Key";
+
+/// Header a web client sets to act on behalf of a signed-in user. It carries a token the web
+/// backend signed for that user, never a bare user id. Ignored for other clients.
+pub const ACTING_USER_HEADER: &str = "XLAIR-User-Token";
+
+/// Authenticated caller identity. Inserted into request extensions by [`authenticate`] so
+/// handlers can extract it with `Extension<Caller>`.
+#[derive(Debug, Clone)]
+pub struct Caller {
+    pub client_id: String,
+    pub api_key_id: String,
+    pub role: ClientRole,
+    pub acting_user: Option<ActingUser>,
+}
+
+impl From<CallerDto> for Caller {
+    fn from(dto: CallerDto) -> Self {
+        Self {
+            client_id: dto.client_id,
+            api_key_id: dto.api_key_id,
+            role: dto.role,
+            acting_user: None,
+        }
+    }
+}
+
+/// User a web client acts for, resolved from [`ACTING_USER_HEADER`].
+#[derive(Debug, Clone)]
+pub struct ActingUser {
+    pub user_id: String,
+    pub is_admin: bool,
+}
+
+impl From<ActingUserDto> for ActingUser {
+    fn from(dto: ActingUserDto) -> Self {
+        Self {
+            user_id: dto.user_id,
+            is_admin: dto.is_admin,
+        }
+    }
+}
+
+/// Rejects requests without a valid, unrevoked API key with 401 and attaches the resolved
+/// [`Caller`] to the request otherwise. Web clients may name the user they act for through
+/// [`ACTING_USER_HEADER`].
+#[instrument(skip_all, fields(method = %request.method(), path = %request.uri().path()))]
+pub async fn authenticate(
+    State(state): State<crate::state::State>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let raw_key = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| {
+            warn!("Request without API key rejected");
+            AppError::new(StatusCode::UNAUTHORIZED, "Missing API key".to_owned())
+        })?;
+
+    let caller = resolve_caller(&state, raw_key, request.headers()).await?;
+    request.extensions_mut().insert(caller);
+
+    Ok(next.run(request).await)
+}
+
+/// Like [`authenticate`], but lets requests without an API key through anonymously. Handlers
+/// behind it extract the caller with `Option<Extension<Caller>>`. A key that is sent must still
+/// be valid.
+#[instrument(skip_all, fields(method = %request.method(), path = %request.uri().path()))]
+pub async fn authenticate_optional(
+    State(state): State<crate::state::State>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let raw_key = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    match raw_key {
+        Some(raw_key) => {
+            let caller = resolve_caller(&state, raw_key, request.headers()).await?;
+            request.extensions_mut().insert(caller);
+        }
+        None => debug!("Anonymous request"),
+    }
+
+    Ok(next.run(request).await)
+}
+
+async fn resolve_caller(
+    state: &crate::state::State,
+    raw_key: &str,
+    headers: &HeaderMap,
+) -> Result<Caller, AppError> {
+    let mut caller = Caller::from(state.usecases.auth.authenticate(raw_key).await?);
+    let acting_user_token = headers
+        .get(ACTING_USER_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    if let Some(acting_user_token) = acting_user_token {
+        if caller.role == ClientRole::Web {
+            let acting_user = state
+                .usecases
+                .auth
+                .resolve_acting_user(acting_user_token)
+                .await?;
+            caller.acting_user = Some(acting_user.into());
+        } else {
+            debug!(role = %caller.role, "Ignoring acting user header from non-web client");
+        }
+    }
+
+    debug!(client_id = %caller.client_id, role = %caller.role, "Caller authenticated");
+    Ok(caller)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::Request,
+    };
+    use domain::{
+        repository::{MockRepositories, api_key::MockApiKeyRepository},
+        testing::{
+            api_key::{STATION_KEY, api_key_repository, client_repository},
+            datetime::sample_timestamp,
+        },
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn build_router(api_key_repo: MockApiKeyRepository) -> Router {
+        let config = crate::config::Config::default();
+        let repositories = MockRepositories {
+            api_key: api_key_repo,
+            client: client_repository(),
+            ..Default::default()
+        };
+        let state = crate::state::State::new(config, repositories);
+        crate::route::create_app(state)
+    }
+
+    async fn error_message(response: Response) -> String {
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        json["error"].as_str().unwrap().to_owned()
+    }
+
+    #[tokio::test]
+    async fn private_routes_reject_missing_key() {
+        let router = build_router(MockApiKeyRepository::new());
+
+        let response = router
+            .oneshot(Request::get("/sync").body(Body::empty()).unwrap())
+            .await
+            .expect("middleware should respond");
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(error_message(response).await.contains("Missing API key"));
+    }
+
+    #[tokio::test]
+    async fn private_routes_reject_unknown_key() {
+        let router = build_router(api_key_repository());
+
+        let response = router
+            .oneshot(
+                Request::get("/statistics/summary")
+                    .header(API_KEY_HEADER, "unknown-key")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("middleware should respond");
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(error_message(response).await.contains("Invalid API key"));
+    }
+
+    #[tokio::test]
+    async fn private_routes_reject_revoked_key() {
+        let mut api_key_repo = MockApiKeyRepository::new();
+        api_key_repo.expect_find_by_key_hash().returning(|_| {
+            let key = STATION_KEY.build(Some(sample_timestamp()));
+            Box::pin(async move { Ok(Some(key)) })
+        });
+        let router = build_router(api_key_repo);
+
+        let response = router
+            .oneshot(
+                Request::get("/users?card=CARD-001")
+                    .header(API_KEY_HEADER, STATION_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("middleware should respond");
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(error_message(response).await.contains("revoked"));
+    }
+
+    #[tokio::test]
+    async fn public_routes_do_not_require_key() {
+        let router = build_router(MockApiKeyRepository::new());
+
+        let response = router
+            .oneshot(Request::get("/health").body(Body::empty()).unwrap())
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
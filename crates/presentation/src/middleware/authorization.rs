@@ -0,0 +1,348 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use axum::{
+    Extension,
+    extract::{RawPathParams, Request, State, rejection::RawPathParamsRejection},
+    http::StatusCode,
+    middleware::Next,
+    response::Response,
+};
+use domain::entity::client_role::ClientRole;
+use tracing::{debug, instrument, warn};
+
+use crate::{error::AppError, middleware::auth::Caller};
+
+/// Path parameter naming the user a route operates on.
+const USER_ID_PARAM: &str = "userId";
+
+/// Role a route can require from its caller. Client roles come straight from the API key, while
+/// [`Role::WebUser`] additionally needs a web client acting for a resolved user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Cabinet,
+    Station,
+    WebUser,
+    Admin,
+}
+
+impl Role {
+    /// Returns `true` when the caller holds this role. Admin is granted to admin clients and to
+    /// web clients acting for a user whose `is_admin` flag is set; a web client only has an acting
+    /// user once its signed token has been verified.
+    pub fn is_granted_to(&self, caller: &Caller) -> bool {
+        match self {
+            Role::Cabinet => caller.role == ClientRole::Cabinet,
+            Role::Station => caller.role == ClientRole::Station,
+            Role::WebUser => caller.role == ClientRole::Web && caller.acting_user.is_some(),
+            Role::Admin => {
+                caller.role == ClientRole::Admin
+                    || (caller.role == ClientRole::Web
+                        && caller
+                            .acting_user
+                            .as_ref()
+                            .is_some_and(|user| user.is_admin))
+            }
+        }
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Role::Cabinet => write!(f, "CABINET"),
+            Role::Station => write!(f, "STATION"),
+            Role::WebUser => write!(f, "WEB_USER"),
+            Role::Admin => write!(f, "ADMIN"),
+        }
+    }
+}
+
+/// Enforces the roles a route declares. Must run after
+/// [`authenticate`](crate::middleware::auth::authenticate).
+///
+/// A caller admitted only as [`Role::WebUser`] may touch nothing but their own `userId`.
+#[instrument(
+    skip_all,
+    fields(client_id = %caller.client_id, path = %request.uri().path())
+)]
+pub async fn authorize(
+    State(roles): State<&'static [Role]>,
+    Extension(caller): Extension<Caller>,
+    params: Result<RawPathParams, RawPathParamsRejection>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let granted: Vec<Role> = roles
+        .iter()
+        .copied()
+        .filter(|role| role.is_granted_to(&caller))
+        .collect();
+
+    if granted.is_empty() {
+        warn!(
+            role = %caller.role,
+            acting_user_id = caller.acting_user.as_ref().map(|user| user.user_id.as_str()),
+            required = ?roles,
+            "Caller lacks required role"
+        );
+        return Err(forbidden());
+    }
+
+    if granted == [Role::WebUser] {
+        let acting_user_id = caller
+            .acting_user
+            .as_ref()
+            .map(|user| user.user_id.as_str())
+            .unwrap_or_default();
+        let target_user_id = params.ok().and_then(|params| {
+            params
+                .iter()
+                .find(|(key, _)| *key == USER_ID_PARAM)
+                .map(|(_, value)| value.to_owned())
+        });
+
+        if let Some(target_user_id) = target_user_id
+            && target_user_id != acting_user_id
+        {
+            warn!(
+                acting_user_id,
+                target_user_id = %target_user_id,
+                "Web user attempted to access another user"
+            );
+            return Err(forbidden());
+        }
+    }
+
+    debug!(granted = ?granted, "Caller authorized");
+    Ok(next.run(request).await)
+}
+
+fn forbidden() -> AppError {
+    AppError::new(
+        StatusCode::FORBIDDEN,
+        "Caller is not allowed to perform this operation".to_owned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::Request,
+    };
+    use domain::{
+        repository::{MockRepositories, user::MockUserRepository},
+        testing::{
+            acting_user::{acting_user_signer, acting_user_token},
+            api_key::{
+                ADMIN_KEY, CABINET_KEY, STATION_KEY, WEB_KEY, api_key_repository, client_repository,
+            },
+            datetime::sample_timestamp,
+            user::{USER1, USER2},
+        },
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::middleware::auth::{ACTING_USER_HEADER, API_KEY_HEADER};
+
+    /// Resolves USER1 as a regular user and USER2 as an admin.
+    fn user_repository() -> MockUserRepository {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id().returning(|user_id| {
+            let user = if user_id == USER1.id {
+                Some(USER1.build(true, false, sample_timestamp()))
+            } else if user_id == USER2.id {
+                Some(USER2.build(true, true, sample_timestamp()))
+            } else {
+                None
+            };
+            Box::pin(async move { Ok(user) })
+        });
+        user_repo
+            .expect_find_play_option()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        user_repo
+    }
+
+    fn build_router() -> Router {
+        let config = crate::config::Config {
+            acting_user_signer: acting_user_signer(),
+            ..Default::default()
+        };
+        let repositories = MockRepositories {
+            user: user_repository(),
+            api_key: api_key_repository(),
+            client: client_repository(),
+            ..Default::default()
+        };
+        let state = crate::state::State::new(config, repositories);
+        crate::route::create_app(state)
+    }
+
+    async fn send(request: Request<Body>) -> Response {
+        build_router()
+            .oneshot(request)
+            .await
+            .expect("router should respond")
+    }
+
+    async fn error_message(response: Response) -> String {
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        json["error"].as_str().unwrap().to_owned()
+    }
+
+    #[tokio::test]
+    async fn cabinet_cannot_register_users() {
+        let response = send(
+            Request::post("/users")
+                .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(
+                    r#"{"card":"CARD-9","displayName":"Eve","isPublic":true}"#,
+                ))
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(error_message(response).await.contains("not allowed"));
+    }
+
+    #[tokio::test]
+    async fn station_cannot_submit_records() {
+        let response = send(
+            Request::post(format!("/users/{}/records", USER1.id))
+                .header(API_KEY_HEADER, STATION_KEY.raw_key)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from("[]"))
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn web_client_without_acting_user_is_rejected() {
+        let response = send(
+            Request::get(format!("/users/{}/options", USER1.id))
+                .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn web_user_can_access_own_resources() {
+        let response = send(
+            Request::get(format!("/users/{}/options", USER1.id))
+                .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                .header(ACTING_USER_HEADER, acting_user_token(USER1.id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn web_user_cannot_access_other_users() {
+        let response = send(
+            Request::get(format!("/users/{}/options", USER2.id))
+                .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                .header(ACTING_USER_HEADER, acting_user_token(USER1.id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn unknown_acting_user_is_rejected() {
+        let response = send(
+            Request::get(format!("/users/{}/options", USER1.id))
+                .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                .header(
+                    ACTING_USER_HEADER,
+                    acting_user_token("00000000-0000-0000-0000-000000000000"),
+                )
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(
+            error_message(response)
+                .await
+                .contains("Acting user not found")
+        );
+    }
+
+    #[tokio::test]
+    async fn admin_routes_reject_non_admin_web_users() {
+        let response = send(
+            Request::get("/statistics/summary")
+                .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                .header(ACTING_USER_HEADER, acting_user_token(USER1.id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn forged_acting_user_header_is_rejected() {
+        let forged = acting_user_token(USER1.id).replacen(USER1.id, USER2.id, 1);
+        for token in [USER2.id.to_owned(), forged] {
+            let response = send(
+                Request::get("/statistics/summary")
+                    .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                    .header(ACTING_USER_HEADER, token)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await;
+
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert!(
+                error_message(response)
+                    .await
+                    .contains("Invalid acting user token")
+            );
+        }
+    }
+
+    #[test]
+    fn admin_role_is_granted_to_admin_clients_and_admin_web_users() {
+        let mut caller = Caller {
+            client_id: ADMIN_KEY.client_id.to_owned(),
+            api_key_id: ADMIN_KEY.id.to_owned(),
+            role: ClientRole::Admin,
+            acting_user: None,
+        };
+        assert!(Role::Admin.is_granted_to(&caller));
+
+        caller.role = ClientRole::Web;
+        assert!(!Role::Admin.is_granted_to(&caller));
+
+        caller.acting_user = Some(crate::middleware::auth::ActingUser {
+            user_id: USER2.id.to_owned(),
+            is_admin: true,
+        });
+        assert!(Role::Admin.is_granted_to(&caller));
+        assert!(Role::WebUser.is_granted_to(&caller));
+        assert!(!Role::Cabinet.is_granted_to(&caller));
+    }
+}
@@ -0,0 +1,270 @@
+use serde::Deserialize;
+use usecase::model::music::{CatalogEntryDto, CatalogMusicDto, CatalogSheetDto};
+
+/// A catalog manifest entry. The layout matches [`SyncItemResponse`](super::sync::SyncItemResponse)
+/// so that an export can be edited and imported again; ids may be omitted for new rows.
+#[derive(Deserialize)]
+pub struct CatalogEntryRequest {
+    pub music: CatalogMusicRequest,
+    #[serde(default)]
+    pub sheets: Vec<CatalogSheetRequest>,
+}
+
+#[derive(Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogMusicRequest {
+    #[serde(default)]
+    pub id: String,
+    pub title: String,
+    pub artist: String,
+    pub bpm: f32,
+    pub genre: String,
+    pub jacket: String,
+    pub registration_date: String,
+    #[serde(default)]
+    pub is_test: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogSheetRequest {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub music_id: String,
+    pub difficulty: String,
+    pub level: f64,
+    pub notes_designer: String,
+    pub note_count: u32,
+    pub max_score: u32,
+}
+
+/// A row of a CSV manifest: one sheet together with the columns of its music. A music without
+/// sheets is a row whose sheet columns are all empty.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogCsvRow {
+    #[serde(default)]
+    pub music_id: String,
+    pub title: String,
+    pub artist: String,
+    pub bpm: f32,
+    pub genre: String,
+    pub jacket: String,
+    pub registration_date: String,
+    pub is_test: Option<bool>,
+    #[serde(default)]
+    pub sheet_id: String,
+    pub difficulty: Option<String>,
+    pub level: Option<f64>,
+    pub notes_designer: Option<String>,
+    pub note_count: Option<u32>,
+    pub max_score: Option<u32>,
+}
+
+impl CatalogCsvRow {
+    fn music(&self) -> CatalogMusicRequest {
+        CatalogMusicRequest {
+            id: self.music_id.clone(),
+            title: self.title.clone(),
+            artist: self.artist.clone(),
+            bpm: self.bpm,
+            genre: self.genre.clone(),
+            jacket: self.jacket.clone(),
+            registration_date: self.registration_date.clone(),
+            is_test: self.is_test.unwrap_or_default(),
+        }
+    }
+
+    fn sheet(self) -> Result<Option<CatalogSheetRequest>, String> {
+        match (
+            self.difficulty,
+            self.level,
+            self.notes_designer,
+            self.note_count,
+            self.max_score,
+        ) {
+            (
+                Some(difficulty),
+                Some(level),
+                Some(notes_designer),
+                Some(note_count),
+                Some(max_score),
+            ) => Ok(Some(CatalogSheetRequest {
+                id: self.sheet_id,
+                music_id: self.music_id,
+                difficulty,
+                level,
+                notes_designer,
+                note_count,
+                max_score,
+            })),
+            (None, None, None, None, None) if self.sheet_id.is_empty() => Ok(None),
+            _ => Err("sheet columns must be either all set or all empty".to_owned()),
+        }
+    }
+}
+
+/// Folds CSV rows into manifest entries. Consecutive rows with the same music columns belong to
+/// one music, so a music is listed once per sheet. Errors name the 1-based data row.
+pub fn entries_from_csv_rows(
+    rows: impl IntoIterator<Item = CatalogCsvRow>,
+) -> Result<Vec<CatalogEntryRequest>, String> {
+    let mut entries: Vec<CatalogEntryRequest> = Vec::new();
+    for (index, row) in rows.into_iter().enumerate() {
+        let music = row.music();
+        let sheet = row
+            .sheet()
+            .map_err(|err| format!("row {}: {err}", index + 1))?;
+        match entries.last_mut() {
+            Some(entry) if entry.music == music => entry.sheets.extend(sheet),
+            _ => entries.push(CatalogEntryRequest {
+                music,
+                sheets: sheet.into_iter().collect(),
+            }),
+        }
+    }
+    Ok(entries)
+}
+
+impl From<CatalogEntryRequest> for CatalogEntryDto {
+    fn from(request: CatalogEntryRequest) -> Self {
+        let music = request.music;
+        CatalogEntryDto::new(
+            CatalogMusicDto::new(
+                music.id,
+                music.title,
+                music.artist,
+                music.bpm,
+                music.genre,
+                music.jacket,
+                music.registration_date,
+                music.is_test,
+            ),
+            request.sheets.into_iter().map(Into::into).collect(),
+        )
+    }
+}
+
+impl From<CatalogSheetRequest> for CatalogSheetDto {
+    fn from(request: CatalogSheetRequest) -> Self {
+        CatalogSheetDto::new(
+            request.id,
+            request.music_id,
+            request.difficulty,
+            request.level,
+            request.notes_designer,
+            request.note_count,
+            request.max_score,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use domain::entity::{difficulty::Difficulty, genre::Genre};
+    use usecase::model::music::{MusicDto, MusicWithSheetsDto, SheetDto};
+
+    use super::*;
+    use crate::model::sync::SyncItemResponse;
+
+    #[test]
+    fn sync_export_can_be_read_back_as_manifest() {
+        let exported = SyncItemResponse::from(MusicWithSheetsDto::new(
+            MusicDto::new(
+                "music-1".to_owned(),
+                "Song".to_owned(),
+                "Artist".to_owned(),
+                150.0,
+                Genre::ORIGINAL,
+                "jacket.png".to_owned(),
+                Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+                true,
+            ),
+            vec![SheetDto::new(
+                "sheet-1".to_owned(),
+                "music-1".to_owned(),
+                Difficulty::Hard,
+                12.5,
+                "Designer".to_owned(),
+                812,
+                1_010_000,
+            )],
+        ));
+        let json = serde_json::to_string(&[exported]).unwrap();
+
+        let manifest: Vec<CatalogEntryRequest> = serde_json::from_str(&json).unwrap();
+        let entry = CatalogEntryDto::from(manifest.into_iter().next().unwrap());
+
+        assert_eq!(entry.music.id, "music-1");
+        assert_eq!(entry.music.genre, "ORIGINAL");
+        assert_eq!(entry.music.registration_date, "2025-01-01T00:00:00+00:00");
+        assert!(entry.music.is_test);
+        assert_eq!(entry.sheets.len(), 1);
+        assert_eq!(entry.sheets[0].id, "sheet-1");
+        assert_eq!(entry.sheets[0].difficulty, "hard");
+        assert_eq!(entry.sheets[0].level, 12.5);
+        assert_eq!(entry.sheets[0].note_count, 812);
+        assert_eq!(entry.sheets[0].max_score, 1_010_000);
+    }
+
+    fn csv_rows(content: &str) -> Vec<CatalogCsvRow> {
+        csv::Reader::from_reader(content.as_bytes())
+            .deserialize()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    const CSV_HEADER: &str = "musicId,title,artist,bpm,genre,jacket,registrationDate,isTest,sheetId,difficulty,level,notesDesigner,noteCount,maxScore";
+
+    #[test]
+    fn csv_rows_of_one_music_fold_into_one_entry() {
+        let content = format!(
+            "{CSV_HEADER}\n\
+             music-1,Song,Artist,150,ORIGINAL,jacket.png,2025-01-01T00:00:00Z,,sheet-1,easy,3,Designer,300,1010000\n\
+             music-1,Song,Artist,150,ORIGINAL,jacket.png,2025-01-01T00:00:00Z,,,hard,12.5,Designer,812,1010000\n\
+             ,New,Artist,180,ORIGINAL,new.png,2025-02-01T00:00:00Z,true,,,,,,\n"
+        );
+
+        let entries = entries_from_csv_rows(csv_rows(&content)).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        let first = CatalogEntryDto::from(entries.into_iter().next().unwrap());
+        assert_eq!(first.music.id, "music-1");
+        assert!(!first.music.is_test);
+        assert_eq!(first.sheets.len(), 2);
+        assert_eq!(first.sheets[0].id, "sheet-1");
+        assert_eq!(first.sheets[1].id, "");
+        assert_eq!(first.sheets[1].music_id, "music-1");
+        assert_eq!(first.sheets[1].level, 12.5);
+    }
+
+    #[test]
+    fn csv_music_without_sheets_becomes_an_empty_entry() {
+        let content = format!(
+            "{CSV_HEADER}\n,New,Artist,180,ORIGINAL,new.png,2025-02-01T00:00:00Z,true,,,,,,\n"
+        );
+
+        let entries = entries_from_csv_rows(csv_rows(&content)).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].music.is_test);
+        assert!(entries[0].sheets.is_empty());
+    }
+
+    #[test]
+    fn csv_rows_with_partial_sheet_columns_are_rejected() {
+        let content = format!(
+            "{CSV_HEADER}\n\
+             music-1,Song,Artist,150,ORIGINAL,jacket.png,2025-01-01T00:00:00Z,,,,,,,\n\
+             music-1,Song,Artist,150,ORIGINAL,jacket.png,2025-01-01T00:00:00Z,,,hard,12.5,,812,1010000\n"
+        );
+
+        let err = entries_from_csv_rows(csv_rows(&content))
+            .err()
+            .expect("should reject");
+
+        assert!(err.starts_with("row 2:"));
+    }
+}
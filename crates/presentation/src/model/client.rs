@@ -0,0 +1,73 @@
+use domain::entity::client_role::ClientRole;
+use serde::{Deserialize, Serialize};
+use usecase::model::client::{ClientDto, ClientKeyDto, ClientRegisterDto};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterClientRequest {
+    pub role: String,
+    pub label: String,
+}
+
+impl TryFrom<RegisterClientRequest> for ClientRegisterDto {
+    type Error = String;
+
+    fn try_from(request: RegisterClientRequest) -> Result<Self, Self::Error> {
+        let role = match request.role.as_str() {
+            "cabinet" => ClientRole::Cabinet,
+            "station" => ClientRole::Station,
+            "web" => ClientRole::Web,
+            "admin" => ClientRole::Admin,
+            other => return Err(format!("Unsupported client role: {other}")),
+        };
+
+        Ok(ClientRegisterDto::new(role, request.label))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientResponse {
+    pub id: String,
+    pub role: String,
+    pub label: String,
+    pub revoked_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<ClientDto> for ClientResponse {
+    fn from(dto: ClientDto) -> Self {
+        let role = match dto.role {
+            ClientRole::Cabinet => "cabinet",
+            ClientRole::Station => "station",
+            ClientRole::Web => "web",
+            ClientRole::Admin => "admin",
+        }
+        .to_string();
+
+        Self {
+            id: dto.id,
+            role,
+            label: dto.label,
+            revoked_at: dto.revoked_at.map(|value| value.to_rfc3339()),
+            created_at: dto.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Carries the raw API key. It is returned exactly once, when the key is issued.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientKeyResponse {
+    pub client: ClientResponse,
+    pub api_key: String,
+}
+
+impl From<ClientKeyDto> for ClientKeyResponse {
+    fn from(dto: ClientKeyDto) -> Self {
+        Self {
+            client: dto.client.into(),
+            api_key: dto.api_key,
+        }
+    }
+}
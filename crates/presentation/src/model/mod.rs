@@ -1,4 +1,11 @@
+pub mod catalog;
+pub mod client;
+pub mod music;
 pub mod ranking;
+pub mod rating;
+pub mod rival;
+pub mod season;
 pub mod statistics;
 pub mod sync;
 pub mod user;
+pub mod xp_campaign;
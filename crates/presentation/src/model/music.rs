@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use usecase::model::music::{MusicInputDto, SheetInputDto};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MusicRequest {
+    pub title: String,
+    pub artist: String,
+    pub bpm: f32,
+    pub genre: String,
+    pub jacket: String,
+    pub registration_date: String,
+    #[serde(default)]
+    pub is_test: bool,
+}
+
+impl TryFrom<MusicRequest> for MusicInputDto {
+    type Error = String;
+
+    fn try_from(request: MusicRequest) -> Result<Self, Self::Error> {
+        let registration_date = DateTime::parse_from_rfc3339(&request.registration_date)
+            .map_err(|_| format!("Invalid registrationDate: {}", request.registration_date))?
+            .with_timezone(&Utc);
+
+        Ok(MusicInputDto::new(
+            request.title,
+            request.artist,
+            request.bpm,
+            request.genre,
+            request.jacket,
+            registration_date,
+            request.is_test,
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetRequest {
+    pub music_id: String,
+    pub difficulty: String,
+    pub level: f64,
+    pub notes_designer: String,
+    pub note_count: u32,
+    pub max_score: u32,
+}
+
+impl From<SheetRequest> for SheetInputDto {
+    fn from(request: SheetRequest) -> Self {
+        SheetInputDto::new(
+            request.music_id,
+            request.difficulty,
+            request.level,
+            request.notes_designer,
+            request.note_count,
+            request.max_score,
+        )
+    }
+}
@@ -1,9 +1,57 @@
-use serde::Serialize;
+use domain::entity::season::SeasonPeriod;
+use serde::{Deserialize, Serialize};
 use usecase::model::ranking::{
-    RatingRankingDto, RatingRankingEntryDto, SheetScoreRankingDto, SheetScoreRankingEntryDto,
-    TotalScoreRankingDto, TotalScoreRankingEntryDto, XpRankingDto, XpRankingEntryDto,
+    ClearCountRankingDto, ClearCountRankingEntryDto, PlayCountRankingDto, PlayCountRankingEntryDto,
+    RatingRankingDto, RatingRankingEntryDto, SeasonDto, SheetScoreRankingDto,
+    SheetScoreRankingEntryDto, TotalScoreRankingDto, TotalScoreRankingEntryDto, XpRankingDto,
+    XpRankingEntryDto,
 };
 
+/// Either `offset`/`limit` for a page of the ranking, or `around` (a user id) with `neighbors`
+/// for the entries surrounding that user. Seasonal boards also take `period` (`weekly` or
+/// `monthly`) and `season` (`current` or `previous`); record boards take the sheet filters
+/// `difficulty`, `genre`, `minLevel` and `maxLevel`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RankingQuery {
+    pub offset: Option<u64>,
+    pub limit: Option<u64>,
+    pub around: Option<String>,
+    pub neighbors: Option<u64>,
+    pub period: Option<String>,
+    pub season: Option<String>,
+    pub difficulty: Option<String>,
+    pub genre: Option<String>,
+    pub min_level: Option<f64>,
+    pub max_level: Option<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonResponse {
+    pub id: String,
+    pub period: String,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub archived_at: Option<String>,
+}
+
+impl From<SeasonDto> for SeasonResponse {
+    fn from(dto: SeasonDto) -> Self {
+        let period = match dto.period {
+            SeasonPeriod::Weekly => "weekly",
+            SeasonPeriod::Monthly => "monthly",
+        };
+        Self {
+            id: dto.id,
+            period: period.to_owned(),
+            starts_at: dto.starts_at.to_rfc3339(),
+            ends_at: dto.ends_at.to_rfc3339(),
+            archived_at: dto.archived_at.map(|value| value.to_rfc3339()),
+        }
+    }
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SheetScoreRankingEntryResponse {
@@ -11,6 +59,7 @@ pub struct SheetScoreRankingEntryResponse {
     pub user_id: String,
     pub display_name: String,
     pub score: u32,
+    pub grade: String,
 }
 
 impl From<SheetScoreRankingEntryDto> for SheetScoreRankingEntryResponse {
@@ -20,6 +69,7 @@ impl From<SheetScoreRankingEntryDto> for SheetScoreRankingEntryResponse {
             user_id: dto.user_id,
             display_name: dto.display_name,
             score: dto.score,
+            grade: dto.grade.to_string(),
         }
     }
 }
@@ -29,6 +79,7 @@ impl From<SheetScoreRankingEntryDto> for SheetScoreRankingEntryResponse {
 pub struct SheetScoreRankingResponse {
     pub sheet_id: String,
     pub entries: Vec<SheetScoreRankingEntryResponse>,
+    pub generated_at: String,
 }
 
 impl From<SheetScoreRankingDto> for SheetScoreRankingResponse {
@@ -36,6 +87,7 @@ impl From<SheetScoreRankingDto> for SheetScoreRankingResponse {
         Self {
             sheet_id: dto.sheet_id,
             entries: dto.entries.into_iter().map(Into::into).collect(),
+            generated_at: dto.generated_at.to_rfc3339(),
         }
     }
 }
@@ -64,12 +116,94 @@ impl From<TotalScoreRankingEntryDto> for TotalScoreRankingEntryResponse {
 #[serde(rename_all = "camelCase")]
 pub struct TotalScoreRankingResponse {
     pub entries: Vec<TotalScoreRankingEntryResponse>,
+    /// Only present for seasonal rankings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<SeasonResponse>,
+    pub generated_at: String,
 }
 
 impl From<TotalScoreRankingDto> for TotalScoreRankingResponse {
     fn from(dto: TotalScoreRankingDto) -> Self {
         Self {
             entries: dto.entries.into_iter().map(Into::into).collect(),
+            season: dto.season.map(Into::into),
+            generated_at: dto.generated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayCountRankingEntryResponse {
+    pub rank: u32,
+    pub user_id: String,
+    pub display_name: String,
+    pub plays: u64,
+}
+
+impl From<PlayCountRankingEntryDto> for PlayCountRankingEntryResponse {
+    fn from(dto: PlayCountRankingEntryDto) -> Self {
+        Self {
+            rank: dto.rank,
+            user_id: dto.user_id,
+            display_name: dto.display_name,
+            plays: dto.plays,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayCountRankingResponse {
+    pub entries: Vec<PlayCountRankingEntryResponse>,
+    /// Only present for seasonal rankings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<SeasonResponse>,
+    pub generated_at: String,
+}
+
+impl From<PlayCountRankingDto> for PlayCountRankingResponse {
+    fn from(dto: PlayCountRankingDto) -> Self {
+        Self {
+            entries: dto.entries.into_iter().map(Into::into).collect(),
+            season: dto.season.map(Into::into),
+            generated_at: dto.generated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClearCountRankingEntryResponse {
+    pub rank: u32,
+    pub user_id: String,
+    pub display_name: String,
+    pub clears: u64,
+}
+
+impl From<ClearCountRankingEntryDto> for ClearCountRankingEntryResponse {
+    fn from(dto: ClearCountRankingEntryDto) -> Self {
+        Self {
+            rank: dto.rank,
+            user_id: dto.user_id,
+            display_name: dto.display_name,
+            clears: dto.clears,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClearCountRankingResponse {
+    pub entries: Vec<ClearCountRankingEntryResponse>,
+    pub generated_at: String,
+}
+
+impl From<ClearCountRankingDto> for ClearCountRankingResponse {
+    fn from(dto: ClearCountRankingDto) -> Self {
+        Self {
+            entries: dto.entries.into_iter().map(Into::into).collect(),
+            generated_at: dto.generated_at.to_rfc3339(),
         }
     }
 }
@@ -98,12 +232,14 @@ impl From<RatingRankingEntryDto> for RatingRankingEntryResponse {
 #[serde(rename_all = "camelCase")]
 pub struct RatingRankingResponse {
     pub entries: Vec<RatingRankingEntryResponse>,
+    pub generated_at: String,
 }
 
 impl From<RatingRankingDto> for RatingRankingResponse {
     fn from(dto: RatingRankingDto) -> Self {
         Self {
             entries: dto.entries.into_iter().map(Into::into).collect(),
+            generated_at: dto.generated_at.to_rfc3339(),
         }
     }
 }
@@ -115,6 +251,7 @@ pub struct XpRankingEntryResponse {
     pub user_id: String,
     pub display_name: String,
     pub xp: u32,
+    pub level: u32,
 }
 
 impl From<XpRankingEntryDto> for XpRankingEntryResponse {
@@ -124,6 +261,7 @@ impl From<XpRankingEntryDto> for XpRankingEntryResponse {
             user_id: dto.user_id,
             display_name: dto.display_name,
             xp: dto.xp,
+            level: dto.level,
         }
     }
 }
@@ -132,12 +270,14 @@ impl From<XpRankingEntryDto> for XpRankingEntryResponse {
 #[serde(rename_all = "camelCase")]
 pub struct XpRankingResponse {
     pub entries: Vec<XpRankingEntryResponse>,
+    pub generated_at: String,
 }
 
 impl From<XpRankingDto> for XpRankingResponse {
     fn from(dto: XpRankingDto) -> Self {
         Self {
             entries: dto.entries.into_iter().map(Into::into).collect(),
+            generated_at: dto.generated_at.to_rfc3339(),
         }
     }
 }
@@ -0,0 +1,18 @@
+use serde::Serialize;
+use usecase::model::rating::RatingRecomputeDto;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingRecomputeResponse {
+    pub users: u64,
+    pub updated: u64,
+}
+
+impl From<RatingRecomputeDto> for RatingRecomputeResponse {
+    fn from(dto: RatingRecomputeDto) -> Self {
+        Self {
+            users: dto.users,
+            updated: dto.updated,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use usecase::model::rival::{
+    ComparisonResult, RivalComparisonDto, RivalDto, RivalRankingEntryDto, SheetComparisonDto,
+};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RivalRequest {
+    pub rival_id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RivalResponse {
+    pub user_id: String,
+    pub display_name: String,
+    /// Withheld while the rival is private.
+    pub rating: Option<u32>,
+    pub is_public: bool,
+    pub registered_at: String,
+}
+
+impl From<RivalDto> for RivalResponse {
+    fn from(dto: RivalDto) -> Self {
+        Self {
+            user_id: dto.user_id,
+            display_name: dto.display_name,
+            rating: dto.rating,
+            is_public: dto.is_public,
+            registered_at: dto.registered_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RivalRankingEntryResponse {
+    pub rank: u32,
+    pub user_id: String,
+    pub display_name: String,
+    pub rating: u32,
+    pub is_self: bool,
+}
+
+impl From<RivalRankingEntryDto> for RivalRankingEntryResponse {
+    fn from(dto: RivalRankingEntryDto) -> Self {
+        Self {
+            rank: dto.rank,
+            user_id: dto.user_id,
+            display_name: dto.display_name,
+            rating: dto.rating,
+            is_self: dto.is_self,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetComparisonResponse {
+    pub sheet_id: String,
+    pub score: u32,
+    pub rival_score: u32,
+    /// `win`, `lose` or `draw`, from the user's side.
+    pub result: &'static str,
+}
+
+impl From<SheetComparisonDto> for SheetComparisonResponse {
+    fn from(dto: SheetComparisonDto) -> Self {
+        Self {
+            sheet_id: dto.sheet_id,
+            score: dto.score,
+            rival_score: dto.rival_score,
+            result: match dto.result {
+                ComparisonResult::Win => "win",
+                ComparisonResult::Lose => "lose",
+                ComparisonResult::Draw => "draw",
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RivalComparisonResponse {
+    pub rival_id: String,
+    pub display_name: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub sheets: Vec<SheetComparisonResponse>,
+}
+
+impl From<RivalComparisonDto> for RivalComparisonResponse {
+    fn from(dto: RivalComparisonDto) -> Self {
+        Self {
+            rival_id: dto.rival_id,
+            display_name: dto.display_name,
+            wins: dto.wins,
+            losses: dto.losses,
+            draws: dto.draws,
+            sheets: dto.sheets.into_iter().map(Into::into).collect(),
+        }
+    }
+}
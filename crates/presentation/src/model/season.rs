@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use domain::entity::season::SeasonPeriod;
+use serde::Deserialize;
+use usecase::model::season::SeasonCreateDto;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonRequest {
+    pub period: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl TryFrom<SeasonRequest> for SeasonCreateDto {
+    type Error = String;
+
+    fn try_from(request: SeasonRequest) -> Result<Self, Self::Error> {
+        let period = match request.period.as_str() {
+            "weekly" => SeasonPeriod::Weekly,
+            "monthly" => SeasonPeriod::Monthly,
+            other => return Err(format!("period must be weekly or monthly, got {other}")),
+        };
+
+        Ok(SeasonCreateDto::new(
+            period,
+            request.starts_at,
+            request.ends_at,
+        ))
+    }
+}
@@ -1,6 +1,14 @@
 use domain::entity::difficulty::Difficulty;
-use serde::Serialize;
-use usecase::model::music::{MusicDto, MusicWithSheetsDto, SheetDto};
+use serde::{Deserialize, Serialize};
+use usecase::model::music::{
+    CatalogDeltaDto, MusicDto, MusicTombstoneDto, MusicWithSheetsDto, SheetDto, SheetTombstoneDto,
+};
+
+#[derive(Deserialize)]
+pub struct SyncQuery {
+    /// RFC 3339 timestamp, normally the `syncedAt` of the previous delta.
+    pub since: Option<String>,
+}
 
 #[derive(Serialize)]
 pub struct SyncItemResponse {
@@ -52,6 +60,8 @@ pub struct SheetResponse {
     pub difficulty: String,
     pub level: f64,
     pub notes_designer: String,
+    pub note_count: u32,
+    pub max_score: u32,
 }
 
 impl From<SheetDto> for SheetResponse {
@@ -62,11 +72,68 @@ impl From<SheetDto> for SheetResponse {
             difficulty: difficulty_to_string(value.difficulty).to_owned(),
             level: value.level_value,
             notes_designer: value.notes_designer,
+            note_count: value.note_count,
+            max_score: value.max_score,
+        }
+    }
+}
+
+/// Changes after `since`. Listed musics replace the cabinet's copy together with their sheets.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncDeltaResponse {
+    pub musics: Vec<SyncItemResponse>,
+    pub removed_musics: Vec<MusicTombstoneResponse>,
+    pub removed_sheets: Vec<SheetTombstoneResponse>,
+    pub synced_at: String,
+}
+
+impl From<CatalogDeltaDto> for SyncDeltaResponse {
+    fn from(value: CatalogDeltaDto) -> Self {
+        Self {
+            musics: value.musics.into_iter().map(Into::into).collect(),
+            removed_musics: value.removed_musics.into_iter().map(Into::into).collect(),
+            removed_sheets: value.removed_sheets.into_iter().map(Into::into).collect(),
+            synced_at: value.synced_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MusicTombstoneResponse {
+    pub id: String,
+    pub deleted_at: String,
+}
+
+impl From<MusicTombstoneDto> for MusicTombstoneResponse {
+    fn from(value: MusicTombstoneDto) -> Self {
+        Self {
+            id: value.id,
+            deleted_at: value.deleted_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetTombstoneResponse {
+    pub id: String,
+    pub music_id: String,
+    pub deleted_at: String,
+}
+
+impl From<SheetTombstoneDto> for SheetTombstoneResponse {
+    fn from(value: SheetTombstoneDto) -> Self {
+        Self {
+            id: value.id,
+            music_id: value.music_id,
+            deleted_at: value.deleted_at.to_rfc3339(),
         }
     }
 }
 
-fn difficulty_to_string(difficulty: Difficulty) -> &'static str {
+pub(crate) fn difficulty_to_string(difficulty: Difficulty) -> &'static str {
     match difficulty {
         Difficulty::Easy => "easy",
         Difficulty::Normal => "normal",
@@ -1,9 +1,19 @@
-use domain::entity::clear_type::ClearType;
+use chrono::NaiveDate;
+use domain::{
+    entity::{clear_type::ClearType, judgement::Judgement},
+    service::level::PlayerLevel,
+};
 use serde::{Deserialize, Serialize};
 use usecase::model::user::{
-    UserCreditsDto, UserDataDto, UserPlayOptionDto, UserPlayOptionUpdateDto, UserRecordDto,
+    ClearTypeCountsDto, DifficultyClearSummaryDto, GradeDistributionDto, HistoryBucket,
+    LevelClearSummaryDto, RatedSheetDto, UserCreditsDto, UserDataDto, UserHistoryDto,
+    UserHistoryPointDto, UserPlayDto, UserPlayOptionDto, UserPlayOptionUpdateDto, UserPlayPageDto,
+    UserProfileDto, UserRatingBreakdownDto, UserRecordDetailDto, UserRecordDto,
     UserRecordSubmissionDto, UserRegisterDto, UserUpdateDto,
 };
+use uuid::Uuid;
+
+use crate::model::sync::difficulty_to_string;
 
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -42,6 +52,11 @@ pub struct UserDataResponse {
     pub display_name: String,
     pub rating: u32,
     pub xp: u32,
+    pub level: u32,
+    /// XP earned since reaching `level`.
+    pub level_xp: u32,
+    /// XP `level` takes to clear; `None` at the maximum level.
+    pub next_level_xp: Option<u32>,
     pub credits: u32,
     pub is_public: bool,
     pub is_admin: bool,
@@ -56,6 +71,7 @@ impl UserDataResponse {
         display_name: String,
         rating: u32,
         xp: u32,
+        level: PlayerLevel,
         credits: u32,
         is_public: bool,
         is_admin: bool,
@@ -67,6 +83,9 @@ impl UserDataResponse {
             display_name,
             rating,
             xp,
+            level: level.level,
+            level_xp: level.xp_into_level,
+            next_level_xp: level.xp_for_next,
             credits,
             is_public,
             is_admin,
@@ -83,6 +102,7 @@ impl From<UserDataDto> for UserDataResponse {
             user_data.display_name,
             user_data.rating,
             user_data.xp,
+            user_data.level,
             user_data.credits,
             user_data.is_public,
             user_data.is_admin,
@@ -110,26 +130,24 @@ pub struct UserRecordResponse {
     pub id: String,
     pub sheet_id: String,
     pub score: u32,
+    pub grade: String,
     pub clear_type: String,
+    pub judgement: JudgementResponse,
+    pub max_combo: u32,
     pub play_count: u32,
     pub updated_at: String,
 }
 
 impl From<UserRecordDto> for UserRecordResponse {
     fn from(dto: UserRecordDto) -> Self {
-        let clear_type = match dto.clear_type {
-            domain::entity::clear_type::ClearType::Fail => "failed",
-            domain::entity::clear_type::ClearType::Clear => "clear",
-            domain::entity::clear_type::ClearType::FullCombo => "fullcombo",
-            domain::entity::clear_type::ClearType::AllPerfect => "perfect",
-        }
-        .to_string();
-
         Self {
             id: dto.id,
             sheet_id: dto.sheet_id,
             score: dto.score,
-            clear_type,
+            grade: dto.grade.to_string(),
+            clear_type: clear_type_label(dto.clear_type).to_owned(),
+            judgement: dto.judgement.into(),
+            max_combo: dto.max_combo,
             play_count: dto.play_count,
             updated_at: dto.updated_at.to_rfc3339(),
         }
@@ -139,32 +157,293 @@ impl From<UserRecordDto> for UserRecordResponse {
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserRecordRequest {
+    pub submission_id: String,
     pub user_id: String,
     pub sheet_id: String,
     pub score: u32,
     pub clear_type: String,
+    pub judgement: JudgementRequest,
+    pub max_combo: u32,
+}
+
+#[derive(Deserialize)]
+pub struct JudgementRequest {
+    pub perfect: u32,
+    pub great: u32,
+    pub good: u32,
+    pub miss: u32,
+}
+
+impl From<JudgementRequest> for Judgement {
+    fn from(request: JudgementRequest) -> Self {
+        Judgement::new(request.perfect, request.great, request.good, request.miss)
+    }
 }
 
 impl TryFrom<UserRecordRequest> for UserRecordSubmissionDto {
     type Error = String;
 
     fn try_from(request: UserRecordRequest) -> Result<Self, Self::Error> {
-        let clear_type = match request.clear_type.as_str() {
-            "failed" => ClearType::Fail,
-            "clear" => ClearType::Clear,
-            "fullcombo" => ClearType::FullCombo,
-            "perfect" => ClearType::AllPerfect,
-            other => return Err(format!("Unsupported clear type: {other}")),
-        };
+        let clear_type = parse_clear_type(&request.clear_type)?;
+        let submission_id = Uuid::parse_str(&request.submission_id)
+            .map_err(|_| format!("Invalid submissionId: {}", request.submission_id))?;
 
         Ok(UserRecordSubmissionDto::new(
+            submission_id.to_string(),
             request.sheet_id,
             request.score,
             clear_type,
+            request.judgement.into(),
+            request.max_combo,
         ))
     }
 }
 
+pub fn parse_clear_type(value: &str) -> Result<ClearType, String> {
+    match value {
+        "failed" => Ok(ClearType::Fail),
+        "clear" => Ok(ClearType::Clear),
+        "fullcombo" => Ok(ClearType::FullCombo),
+        "perfect" => Ok(ClearType::AllPerfect),
+        other => Err(format!("Unsupported clear type: {other}")),
+    }
+}
+
+fn clear_type_label(clear_type: ClearType) -> &'static str {
+    match clear_type {
+        ClearType::Fail => "failed",
+        ClearType::Clear => "clear",
+        ClearType::FullCombo => "fullcombo",
+        ClearType::AllPerfect => "perfect",
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RatedSheetResponse {
+    pub sheet_id: String,
+    pub level: f64,
+    pub score: u32,
+    pub rating: u32,
+}
+
+impl From<RatedSheetDto> for RatedSheetResponse {
+    fn from(dto: RatedSheetDto) -> Self {
+        Self {
+            sheet_id: dto.sheet_id,
+            level: dto.level,
+            score: dto.score,
+            rating: dto.rating,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRatingBreakdownResponse {
+    pub rating: u32,
+    pub best_count: usize,
+    pub recent_count: usize,
+    pub best: Vec<RatedSheetResponse>,
+    pub recent: Vec<RatedSheetResponse>,
+    pub next: Vec<RatedSheetResponse>,
+}
+
+impl From<UserRatingBreakdownDto> for UserRatingBreakdownResponse {
+    fn from(dto: UserRatingBreakdownDto) -> Self {
+        let convert = |sheets: Vec<RatedSheetDto>| {
+            sheets
+                .into_iter()
+                .map(RatedSheetResponse::from)
+                .collect::<Vec<_>>()
+        };
+        Self {
+            rating: dto.rating,
+            best_count: dto.best_count,
+            recent_count: dto.recent_count,
+            best: convert(dto.best),
+            recent: convert(dto.recent),
+            next: convert(dto.next),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct HistoryQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub bucket: Option<String>,
+}
+
+pub fn parse_history_bucket(value: &str) -> Option<HistoryBucket> {
+    match value {
+        "daily" => Some(HistoryBucket::Daily),
+        "weekly" => Some(HistoryBucket::Weekly),
+        _ => None,
+    }
+}
+
+fn history_bucket_label(bucket: HistoryBucket) -> &'static str {
+    match bucket {
+        HistoryBucket::Daily => "daily",
+        HistoryBucket::Weekly => "weekly",
+    }
+}
+
+#[derive(Serialize)]
+pub struct UserHistoryPointResponse {
+    pub date: NaiveDate,
+    pub rating: u32,
+    pub xp: u32,
+}
+
+impl From<UserHistoryPointDto> for UserHistoryPointResponse {
+    fn from(dto: UserHistoryPointDto) -> Self {
+        Self {
+            date: dto.date,
+            rating: dto.rating,
+            xp: dto.xp,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct UserHistoryResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub bucket: &'static str,
+    pub points: Vec<UserHistoryPointResponse>,
+}
+
+impl UserHistoryResponse {
+    pub fn new(history: UserHistoryDto, from: NaiveDate, to: NaiveDate) -> Self {
+        Self {
+            from,
+            to,
+            bucket: history_bucket_label(history.bucket),
+            points: history
+                .points
+                .into_iter()
+                .map(UserHistoryPointResponse::from)
+                .collect(),
+        }
+    }
+}
+
+/// Every parameter is optional: without any, all of the user's records are listed oldest first.
+/// `minGrade` keeps only records graded at least that well, e.g. `S`. `sort` is one of `score`,
+/// `level`, `updatedAt` and `playCount`, and `order` is `asc` or `desc`. With `limit`, pages are
+/// continued by passing the returned next cursor as `cursor`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordsQuery {
+    pub difficulty: Option<String>,
+    pub genre: Option<String>,
+    pub min_level: Option<f64>,
+    pub max_level: Option<f64>,
+    pub clear_type: Option<String>,
+    pub music_id: Option<String>,
+    pub min_grade: Option<String>,
+    pub sort: Option<String>,
+    pub order: Option<String>,
+    pub cursor: Option<String>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize)]
+pub struct PlaysQuery {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct JudgementResponse {
+    pub perfect: u32,
+    pub great: u32,
+    pub good: u32,
+    pub miss: u32,
+}
+
+impl From<Judgement> for JudgementResponse {
+    fn from(judgement: Judgement) -> Self {
+        Self {
+            perfect: *judgement.perfect(),
+            great: *judgement.great(),
+            good: *judgement.good(),
+            miss: *judgement.miss(),
+        }
+    }
+}
+
+/// A record listed with its sheet, so that clients need not look the sheet up in the catalog.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRecordDetailResponse {
+    #[serde(flatten)]
+    pub record: UserRecordResponse,
+    pub music_id: String,
+    pub music_title: String,
+    pub difficulty: String,
+    pub level: f64,
+}
+
+impl From<UserRecordDetailDto> for UserRecordDetailResponse {
+    fn from(dto: UserRecordDetailDto) -> Self {
+        Self {
+            record: dto.record.into(),
+            music_id: dto.music_id,
+            music_title: dto.music_title,
+            difficulty: difficulty_to_string(dto.difficulty).to_owned(),
+            level: dto.level,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPlayResponse {
+    pub id: String,
+    pub sheet_id: String,
+    pub client_id: String,
+    pub score: u32,
+    pub clear_type: String,
+    pub judgement: JudgementResponse,
+    pub played_at: String,
+}
+
+impl From<UserPlayDto> for UserPlayResponse {
+    fn from(dto: UserPlayDto) -> Self {
+        Self {
+            id: dto.id,
+            sheet_id: dto.sheet_id,
+            client_id: dto.client_id,
+            score: dto.score,
+            clear_type: clear_type_label(dto.clear_type).to_owned(),
+            judgement: dto.judgement.into(),
+            played_at: dto.played_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct UserPlayPageResponse {
+    pub plays: Vec<UserPlayResponse>,
+    pub total: u64,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+impl UserPlayPageResponse {
+    pub fn new(page: UserPlayPageDto, limit: u64, offset: u64) -> Self {
+        Self {
+            plays: page.plays.into_iter().map(UserPlayResponse::from).collect(),
+            total: page.total,
+            limit,
+            offset,
+        }
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateUserRequest {
@@ -206,3 +485,121 @@ impl From<UserPlayOptionRequest> for UserPlayOptionUpdateDto {
         UserPlayOptionUpdateDto::new(request.note_speed, request.judgment_offset)
     }
 }
+
+/// Record counts per clear type, keyed by the clear type labels used in record responses.
+#[derive(Serialize)]
+pub struct ClearTypeCountsResponse {
+    pub failed: u32,
+    pub clear: u32,
+    pub fullcombo: u32,
+    pub perfect: u32,
+}
+
+impl From<ClearTypeCountsDto> for ClearTypeCountsResponse {
+    fn from(dto: ClearTypeCountsDto) -> Self {
+        Self {
+            failed: dto.fail,
+            clear: dto.clear,
+            fullcombo: dto.full_combo,
+            perfect: dto.all_perfect,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DifficultyClearSummaryResponse {
+    pub difficulty: String,
+    pub counts: ClearTypeCountsResponse,
+}
+
+impl From<DifficultyClearSummaryDto> for DifficultyClearSummaryResponse {
+    fn from(dto: DifficultyClearSummaryDto) -> Self {
+        Self {
+            difficulty: difficulty_to_string(dto.difficulty).to_owned(),
+            counts: dto.counts.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LevelClearSummaryResponse {
+    pub level: String,
+    pub counts: ClearTypeCountsResponse,
+}
+
+impl From<LevelClearSummaryDto> for LevelClearSummaryResponse {
+    fn from(dto: LevelClearSummaryDto) -> Self {
+        Self {
+            level: dto.level,
+            counts: dto.counts.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct GradeDistributionResponse {
+    pub sss: u32,
+    pub ss: u32,
+    pub s: u32,
+    pub aa: u32,
+    pub a: u32,
+}
+
+impl From<GradeDistributionDto> for GradeDistributionResponse {
+    fn from(dto: GradeDistributionDto) -> Self {
+        Self {
+            sss: dto.sss,
+            ss: dto.ss,
+            s: dto.s,
+            aa: dto.aa,
+            a: dto.a,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProfileResponse {
+    pub user_id: String,
+    pub display_name: String,
+    pub rating: u32,
+    pub level: u32,
+    pub record_count: u32,
+    pub total_play_count: u64,
+    pub last_played_at: Option<String>,
+    pub clears_by_difficulty: Vec<DifficultyClearSummaryResponse>,
+    pub clears_by_level: Vec<LevelClearSummaryResponse>,
+    pub grades: GradeDistributionResponse,
+    pub top_rated: Vec<RatedSheetResponse>,
+}
+
+impl From<UserProfileDto> for UserProfileResponse {
+    fn from(dto: UserProfileDto) -> Self {
+        Self {
+            user_id: dto.user_id,
+            display_name: dto.display_name,
+            rating: dto.rating,
+            level: dto.level,
+            record_count: dto.record_count,
+            total_play_count: dto.total_play_count,
+            last_played_at: dto.last_played_at.map(|at| at.to_rfc3339()),
+            clears_by_difficulty: dto
+                .clears_by_difficulty
+                .into_iter()
+                .map(DifficultyClearSummaryResponse::from)
+                .collect(),
+            clears_by_level: dto
+                .clears_by_level
+                .into_iter()
+                .map(LevelClearSummaryResponse::from)
+                .collect(),
+            grades: dto.grades.into(),
+            top_rated: dto
+                .top_rated
+                .into_iter()
+                .map(RatedSheetResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use usecase::model::xp_campaign::{XpCampaignCreateDto, XpCampaignDto};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XpCampaignRequest {
+    pub name: String,
+    pub multiplier_percent: u32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl From<XpCampaignRequest> for XpCampaignCreateDto {
+    fn from(request: XpCampaignRequest) -> Self {
+        XpCampaignCreateDto::new(
+            request.name,
+            request.multiplier_percent,
+            request.starts_at,
+            request.ends_at,
+        )
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XpCampaignResponse {
+    pub id: String,
+    pub name: String,
+    pub multiplier_percent: u32,
+    pub starts_at: String,
+    pub ends_at: String,
+}
+
+impl From<XpCampaignDto> for XpCampaignResponse {
+    fn from(dto: XpCampaignDto) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            multiplier_percent: dto.multiplier_percent,
+            starts_at: dto.starts_at.to_rfc3339(),
+            ends_at: dto.ends_at.to_rfc3339(),
+        }
+    }
+}
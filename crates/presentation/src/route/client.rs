@@ -0,0 +1,255 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::{info, instrument};
+use usecase::model::client::ClientRegisterDto;
+
+use crate::{
+    error::AppError,
+    middleware::authorization::Role,
+    model::client::{ClientKeyResponse, ClientResponse, RegisterClientRequest},
+};
+
+type AppResult<T> = Result<T, AppError>;
+
+/// Client administration is reserved for operators.
+pub const ROLES: &[Role] = &[Role::Admin];
+
+#[instrument(skip(state, request), fields(role = %request.role))]
+pub async fn handle_post(
+    State(state): State<crate::state::State>,
+    Json(request): Json<RegisterClientRequest>,
+) -> AppResult<(StatusCode, Json<ClientKeyResponse>)> {
+    info!(label = %request.label, "Register client request received");
+    let dto = ClientRegisterDto::try_from(request)
+        .map_err(|err| AppError::new(StatusCode::BAD_REQUEST, err))?;
+    let issued = state.usecases.client.register(dto).await?;
+    info!(client_id = %issued.client.id, "Client registered successfully");
+    Ok((StatusCode::CREATED, Json(issued.into())))
+}
+
+#[instrument(skip(state), fields(client_id = %client_id))]
+pub async fn handle_rotate_key(
+    State(state): State<crate::state::State>,
+    Path(client_id): Path<String>,
+) -> AppResult<Json<ClientKeyResponse>> {
+    info!("Rotate client key request received");
+    let issued = state.usecases.client.rotate_key(client_id).await?;
+    info!("Client key rotated successfully");
+    Ok(Json(issued.into()))
+}
+
+#[instrument(skip(state), fields(client_id = %client_id))]
+pub async fn handle_revoke(
+    State(state): State<crate::state::State>,
+    Path(client_id): Path<String>,
+) -> AppResult<Json<ClientResponse>> {
+    info!("Revoke client request received");
+    let client = state.usecases.client.revoke(client_id).await?;
+    info!("Client revoked successfully");
+    Ok(Json(client.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::Request,
+    };
+    use domain::{
+        entity::client::Client,
+        repository::{MockRepositories, client::MockClientRepository},
+        service::api_key::hash_api_key,
+        testing::{
+            api_key::{ADMIN_KEY, CABINET_KEY, STATION_KEY, api_key_repository},
+            datetime::sample_timestamp,
+        },
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::middleware::auth::API_KEY_HEADER;
+
+    /// Resolves the sample clients for authentication on top of the supplied expectations.
+    fn with_sample_clients(mut client_repo: MockClientRepository) -> MockClientRepository {
+        client_repo.expect_find_by_id().returning(|client_id| {
+            let found = [ADMIN_KEY, CABINET_KEY, STATION_KEY]
+                .into_iter()
+                .find(|sample| sample.client_id == client_id)
+                .map(|sample| sample.client(None));
+            Box::pin(async move { Ok(found) })
+        });
+        client_repo
+    }
+
+    fn build_router(client_repo: MockClientRepository) -> Router {
+        let config = crate::config::Config::default();
+        let repositories = MockRepositories {
+            api_key: api_key_repository(),
+            client: with_sample_clients(client_repo),
+            ..Default::default()
+        };
+        let state = crate::state::State::new(config, repositories);
+        super::super::create_app(state)
+    }
+
+    async fn json_body(response: axum::response::Response) -> Value {
+        let bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn post_client_returns_created_with_raw_key() {
+        let mut client_repo = MockClientRepository::new();
+        client_repo.expect_create().returning(|client, _| {
+            let created = Client::new(
+                "5c9e2d41-3b7a-4f60-8e1d-2a4b6c8d0e99".to_owned(),
+                *client.role(),
+                client.label().to_owned(),
+                None,
+                sample_timestamp(),
+            );
+            Box::pin(async move { Ok(created) })
+        });
+        let router = build_router(client_repo);
+
+        let response = router
+            .oneshot(
+                Request::post("/admin/clients")
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(
+                        json!({ "role": "cabinet", "label": "Cabinet #9" }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let json = json_body(response).await;
+        assert_eq!(json["client"]["id"], "5c9e2d41-3b7a-4f60-8e1d-2a4b6c8d0e99");
+        assert_eq!(json["client"]["role"], "cabinet");
+        assert_eq!(json["client"]["label"], "Cabinet #9");
+        assert!(json["client"]["revokedAt"].is_null());
+        assert_eq!(json["apiKey"].as_str().unwrap().len(), 64);
+    }
+
+    #[tokio::test]
+    async fn post_client_rejects_unknown_role() {
+        let router = build_router(MockClientRepository::new());
+
+        let response = router
+            .oneshot(
+                Request::post("/admin/clients")
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(
+                        json!({ "role": "kiosk", "label": "Kiosk" }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn rotate_key_returns_new_key() {
+        let mut client_repo = MockClientRepository::new();
+        client_repo
+            .expect_rotate_key()
+            .withf(|client_id, key_hash| {
+                client_id == CABINET_KEY.client_id && key_hash != &hash_api_key(CABINET_KEY.raw_key)
+            })
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        let router = build_router(client_repo);
+
+        let response = router
+            .oneshot(
+                Request::post(format!(
+                    "/admin/clients/{}/rotate-key",
+                    CABINET_KEY.client_id
+                ))
+                .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                .body(Body::empty())
+                .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = json_body(response).await;
+        assert_eq!(json["client"]["id"], CABINET_KEY.client_id);
+        assert_ne!(json["apiKey"], CABINET_KEY.raw_key);
+    }
+
+    #[tokio::test]
+    async fn revoke_returns_not_found_for_unknown_client() {
+        let router = build_router(MockClientRepository::new());
+
+        let response = router
+            .oneshot(
+                Request::post("/admin/clients/5c9e2d41-3b7a-4f60-8e1d-2a4b6c8d0eff/revoke")
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn revoke_returns_revoked_client() {
+        let mut client_repo = MockClientRepository::new();
+        client_repo
+            .expect_revoke()
+            .withf(|client_id| client_id == STATION_KEY.client_id)
+            .returning(|_| {
+                let client = STATION_KEY.client(Some(sample_timestamp()));
+                Box::pin(async move { Ok(client) })
+            });
+        let router = build_router(client_repo);
+
+        let response = router
+            .oneshot(
+                Request::post(format!("/admin/clients/{}/revoke", STATION_KEY.client_id))
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = json_body(response).await;
+        assert_eq!(json["id"], STATION_KEY.client_id);
+        assert_eq!(json["revokedAt"], sample_timestamp().to_rfc3339());
+    }
+
+    #[tokio::test]
+    async fn admin_routes_reject_non_admin_clients() {
+        let mut client_repo = MockClientRepository::new();
+        client_repo.expect_revoke().never();
+        let router = build_router(client_repo);
+
+        let response = router
+            .oneshot(
+                Request::post(format!("/admin/clients/{}/revoke", STATION_KEY.client_id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("middleware should respond");
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
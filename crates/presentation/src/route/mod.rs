@@ -1,57 +1,190 @@
 use axum::{
     Router,
-    http::{HeaderValue, Method, header},
-    routing::{get, post},
+    http::{HeaderName, HeaderValue, Method, header},
+    middleware::from_fn_with_state,
+    routing::{MethodRouter, delete, get, post},
 };
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
 
-use crate::{env::allowed_origin, state::State};
+use crate::{
+    env::allowed_origin,
+    middleware::{
+        auth::{authenticate, authenticate_optional},
+        authorization::{Role, authorize},
+    },
+    state::State,
+};
 
+pub mod client;
+pub mod music;
 pub mod ranking;
+pub mod rating;
+pub mod rival;
+pub mod season;
 pub mod statistics;
 pub mod sync;
 pub mod user;
+pub mod xp_campaign;
 
 pub fn create_app(state: State) -> Router {
     let users = Router::new()
-        .route("/", post(user::handle_post))
-        .route("/", get(user::handle_get))
-        .route("/{userId}", post(user::handle_update_user))
+        .route(
+            "/",
+            guarded(post(user::handle_post), user::POST_ROLES)
+                .merge(guarded(get(user::handle_get), user::GET_ROLES)),
+        )
+        .route(
+            "/{userId}",
+            guarded(post(user::handle_update_user), user::UPDATE_USER_ROLES),
+        )
         .route(
             "/{userId}/records",
-            get(user::handle_get_records).post(user::handle_post_records),
+            guarded(get(user::handle_get_records), user::GET_RECORDS_ROLES).merge(guarded(
+                post(user::handle_post_records),
+                user::POST_RECORDS_ROLES,
+            )),
+        )
+        .route(
+            "/{userId}/rating",
+            guarded(get(user::handle_get_rating), user::GET_RATING_ROLES),
+        )
+        .route(
+            "/{userId}/history",
+            guarded(get(user::handle_get_history), user::GET_HISTORY_ROLES),
+        )
+        .route(
+            "/{userId}/plays",
+            guarded(get(user::handle_get_plays), user::GET_PLAYS_ROLES),
         )
         .route(
             "/{userId}/options",
-            get(user::handle_get_play_option).post(user::handle_post_play_option),
+            guarded(
+                get(user::handle_get_play_option),
+                user::GET_PLAY_OPTION_ROLES,
+            )
+            .merge(guarded(
+                post(user::handle_post_play_option),
+                user::POST_PLAY_OPTION_ROLES,
+            )),
+        )
+        .route(
+            "/{userId}/rivals",
+            guarded(get(rival::handle_get), rival::GET_ROLES)
+                .merge(guarded(post(rival::handle_post), rival::WRITE_ROLES)),
+        )
+        .route(
+            "/{userId}/rivals/ranking",
+            guarded(get(rival::handle_get_ranking), rival::GET_ROLES),
+        )
+        .route(
+            "/{userId}/rivals/{rivalId}",
+            guarded(delete(rival::handle_delete), rival::WRITE_ROLES),
+        )
+        .route(
+            "/{userId}/rivals/{rivalId}/comparison",
+            guarded(get(rival::handle_get_comparison), rival::GET_ROLES),
         )
         .route(
             "/{userId}/credits/increment",
-            post(user::handle_increment_credits),
+            guarded(
+                post(user::handle_increment_credits),
+                user::INCREMENT_CREDITS_ROLES,
+            ),
         );
-    let sync_route = Router::new().route("/", get(sync::handle_get));
-    let statistics_route = Router::new().route("/summary", get(statistics::handle_get_summary));
+    let sync_route = Router::new().route("/", guarded(get(sync::handle_get), sync::GET_ROLES));
+    let statistics_route = Router::new().route(
+        "/summary",
+        guarded(
+            get(statistics::handle_get_summary),
+            statistics::GET_SUMMARY_ROLES,
+        ),
+    );
     let ranking_route = Router::new()
         .route("/sheets/{sheetId}", get(ranking::handle_get_sheet_ranking))
         .route("/total-score", get(ranking::handle_get_total_ranking))
+        .route("/plays", get(ranking::handle_get_play_count_ranking))
+        .route("/clears", get(ranking::handle_get_clear_count_ranking))
         .route("/rating", get(ranking::handle_get_rating_ranking))
         .route("/xp", get(ranking::handle_get_xp_ranking));
+    let admin_route = Router::new()
+        .route(
+            "/clients",
+            guarded(post(client::handle_post), client::ROLES),
+        )
+        .route(
+            "/clients/{clientId}/rotate-key",
+            guarded(post(client::handle_rotate_key), client::ROLES),
+        )
+        .route(
+            "/clients/{clientId}/revoke",
+            guarded(post(client::handle_revoke), client::ROLES),
+        )
+        .route(
+            "/musics",
+            guarded(post(music::handle_post_music), music::ROLES),
+        )
+        .route(
+            "/musics/{musicId}",
+            guarded(post(music::handle_update_music), music::ROLES)
+                .merge(guarded(delete(music::handle_delete_music), music::ROLES)),
+        )
+        .route(
+            "/sheets",
+            guarded(post(music::handle_post_sheet), music::ROLES),
+        )
+        .route(
+            "/ratings/recompute",
+            guarded(post(rating::handle_post_recompute), rating::RECOMPUTE_ROLES),
+        )
+        .route(
+            "/xp-campaigns",
+            guarded(get(xp_campaign::handle_get), xp_campaign::ROLES)
+                .merge(guarded(post(xp_campaign::handle_post), xp_campaign::ROLES)),
+        )
+        .route(
+            "/seasons",
+            guarded(get(season::handle_get), season::ROLES)
+                .merge(guarded(post(season::handle_post), season::ROLES)),
+        )
+        .route(
+            "/seasons/{seasonId}/archive",
+            guarded(post(season::handle_post_archive), season::ROLES),
+        )
+        .route(
+            "/xp-campaigns/{campaignId}",
+            guarded(delete(xp_campaign::handle_delete), xp_campaign::ROLES),
+        )
+        .route(
+            "/sheets/{sheetId}",
+            guarded(post(music::handle_update_sheet), music::ROLES)
+                .merge(guarded(delete(music::handle_delete_sheet), music::ROLES)),
+        );
+    let profile_route = Router::new()
+        .route("/{userId}/profile", get(user::handle_get_profile))
+        .route_layer(from_fn_with_state(state.clone(), authenticate_optional));
     let health = Router::new().route("/", get(|| async { "OK" }));
 
-    // TODO: Add auth middleware
     let private_routes = Router::new()
         .nest("/users", users)
         .nest("/sync", sync_route)
-        .nest("/statistics", statistics_route);
+        .nest("/statistics", statistics_route)
+        .nest("/admin", admin_route)
+        .route_layer(from_fn_with_state(state.clone(), authenticate));
 
     let public_routes = Router::new()
         .nest("/health", health)
+        .nest("/users", profile_route)
         .nest("/rankings", ranking_route);
 
     let cors = CorsLayer::new()
         .allow_origin(allowed_origin().parse::<HeaderValue>().unwrap())
-        .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-        .allow_headers([header::CONTENT_TYPE]);
+        .allow_methods([Method::GET, Method::POST, Method::DELETE, Method::OPTIONS])
+        .allow_headers([
+            header::CONTENT_TYPE,
+            HeaderName::from_static("xlair-api-key"),
+            HeaderName::from_static("xlair-user-token"),
+        ])
+        .expose_headers([HeaderName::from_static("xlair-next-cursor")]);
 
     Router::new()
         .merge(private_routes)
@@ -60,3 +193,9 @@ pub fn create_app(state: State) -> Router {
         .layer(cors)
         .with_state(state)
 }
+
+/// Restricts a route to the roles its handler declares. Runs behind
+/// [`authenticate`](crate::middleware::auth::authenticate), which supplies the caller.
+fn guarded(method_router: MethodRouter<State>, roles: &'static [Role]) -> MethodRouter<State> {
+    method_router.route_layer(from_fn_with_state(roles, authorize))
+}
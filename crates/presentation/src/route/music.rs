@@ -0,0 +1,359 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::{info, instrument};
+use usecase::model::music::MusicInputDto;
+
+use crate::{
+    error::AppError,
+    middleware::authorization::Role,
+    model::{
+        music::{MusicRequest, SheetRequest},
+        sync::{MusicResponse, SheetResponse},
+    },
+};
+
+type AppResult<T> = Result<T, AppError>;
+
+/// Catalog management is reserved for operators.
+pub const ROLES: &[Role] = &[Role::Admin];
+
+#[instrument(skip(state, request), fields(title = %request.title))]
+pub async fn handle_post_music(
+    State(state): State<crate::state::State>,
+    Json(request): Json<MusicRequest>,
+) -> AppResult<(StatusCode, Json<MusicResponse>)> {
+    info!("Create music request received");
+    let dto = MusicInputDto::try_from(request)
+        .map_err(|err| AppError::new(StatusCode::BAD_REQUEST, err))?;
+    let music = state.usecases.music.create_music(dto).await?;
+    info!(music_id = %music.id, "Music created successfully");
+    Ok((StatusCode::CREATED, Json(music.into())))
+}
+
+#[instrument(skip(state, request), fields(music_id = %music_id))]
+pub async fn handle_update_music(
+    State(state): State<crate::state::State>,
+    Path(music_id): Path<String>,
+    Json(request): Json<MusicRequest>,
+) -> AppResult<Json<MusicResponse>> {
+    info!("Update music request received");
+    let dto = MusicInputDto::try_from(request)
+        .map_err(|err| AppError::new(StatusCode::BAD_REQUEST, err))?;
+    let music = state.usecases.music.update_music(music_id, dto).await?;
+    info!("Music updated successfully");
+    Ok(Json(music.into()))
+}
+
+#[instrument(skip(state), fields(music_id = %music_id))]
+pub async fn handle_delete_music(
+    State(state): State<crate::state::State>,
+    Path(music_id): Path<String>,
+) -> AppResult<StatusCode> {
+    info!("Delete music request received");
+    state.usecases.music.delete_music(music_id).await?;
+    info!("Music archived successfully");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip(state, request), fields(music_id = %request.music_id, difficulty = %request.difficulty))]
+pub async fn handle_post_sheet(
+    State(state): State<crate::state::State>,
+    Json(request): Json<SheetRequest>,
+) -> AppResult<(StatusCode, Json<SheetResponse>)> {
+    info!("Create sheet request received");
+    let sheet = state.usecases.music.create_sheet(request.into()).await?;
+    info!(sheet_id = %sheet.id, "Sheet created successfully");
+    Ok((StatusCode::CREATED, Json(sheet.into())))
+}
+
+#[instrument(skip(state, request), fields(sheet_id = %sheet_id))]
+pub async fn handle_update_sheet(
+    State(state): State<crate::state::State>,
+    Path(sheet_id): Path<String>,
+    Json(request): Json<SheetRequest>,
+) -> AppResult<Json<SheetResponse>> {
+    info!("Update sheet request received");
+    let sheet = state
+        .usecases
+        .music
+        .update_sheet(sheet_id, request.into())
+        .await?;
+    info!("Sheet updated successfully");
+    Ok(Json(sheet.into()))
+}
+
+#[instrument(skip(state), fields(sheet_id = %sheet_id))]
+pub async fn handle_delete_sheet(
+    State(state): State<crate::state::State>,
+    Path(sheet_id): Path<String>,
+) -> AppResult<StatusCode> {
+    info!("Delete sheet request received");
+    state.usecases.music.delete_sheet(sheet_id).await?;
+    info!("Sheet archived successfully");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::Request,
+    };
+    use domain::{
+        entity::{level::Level, music::Music, sheet::Sheet},
+        repository::{
+            MockRepositories,
+            music::{MockMusicRepository, MusicRepositoryError},
+        },
+        testing::api_key::{ADMIN_KEY, CABINET_KEY, api_key_repository, client_repository},
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::middleware::auth::API_KEY_HEADER;
+
+    const MUSIC_ID: &str = "3d2c6a0e-8f41-4b7d-9c15-0a6e2b4f8d11";
+    const SHEET_ID: &str = "7f1e9b3a-2c54-4d86-a0b7-5e3c1d9f6a22";
+
+    fn build_router(music_repo: MockMusicRepository) -> Router {
+        let config = crate::config::Config::default();
+        let repositories = MockRepositories {
+            music: music_repo,
+            api_key: api_key_repository(),
+            client: client_repository(),
+            ..Default::default()
+        };
+        let state = crate::state::State::new(config, repositories);
+        super::super::create_app(state)
+    }
+
+    async fn json_body(response: axum::response::Response) -> Value {
+        let bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    fn json_request(method: &str, uri: &str, key: &str, payload: Value) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(API_KEY_HEADER, key)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(payload.to_string()))
+            .unwrap()
+    }
+
+    fn music_payload() -> Value {
+        json!({
+            "title": "Song",
+            "artist": "Artist",
+            "bpm": 180.0,
+            "genre": "ORIGINAL",
+            "jacket": "jacket.png",
+            "registrationDate": "2025-01-01T00:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn post_music_returns_created_music() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_create_music().returning(|music| {
+            let created = Music::new(
+                MUSIC_ID.to_owned(),
+                music.title().to_owned(),
+                music.artist().to_owned(),
+                *music.bpm(),
+                *music.genre(),
+                music.jacket_image_url().to_owned(),
+                *music.registration_date(),
+                *music.is_test(),
+            );
+            Box::pin(async move { Ok(created) })
+        });
+        let router = build_router(music_repo);
+
+        let response = router
+            .oneshot(json_request(
+                "POST",
+                "/admin/musics",
+                ADMIN_KEY.raw_key,
+                music_payload(),
+            ))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let json = json_body(response).await;
+        assert_eq!(json["id"], MUSIC_ID);
+        assert_eq!(json["genre"], "ORIGINAL");
+        assert_eq!(json["registrationDate"], "2025-01-01T00:00:00+00:00");
+        assert_eq!(json["isTest"], false);
+    }
+
+    #[tokio::test]
+    async fn post_music_rejects_unknown_genre() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_create_music().never();
+        let router = build_router(music_repo);
+        let mut payload = music_payload();
+        payload["genre"] = json!("VARIETY");
+
+        let response = router
+            .oneshot(json_request(
+                "POST",
+                "/admin/musics",
+                ADMIN_KEY.raw_key,
+                payload,
+            ))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn update_music_returns_not_found_for_archived_music() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_update_music().returning(|music| {
+            let err = MusicRepositoryError::MusicNotFound(music.id().to_owned());
+            Box::pin(async move { Err(err) })
+        });
+        let router = build_router(music_repo);
+
+        let response = router
+            .oneshot(json_request(
+                "POST",
+                &format!("/admin/musics/{MUSIC_ID}"),
+                ADMIN_KEY.raw_key,
+                music_payload(),
+            ))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn post_sheet_returns_conflict_for_duplicate_difficulty() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_create_sheet().returning(|sheet| {
+            let err = MusicRepositoryError::SheetAlreadyExists {
+                music_id: sheet.music_id().to_owned(),
+                difficulty: *sheet.difficulty(),
+            };
+            Box::pin(async move { Err(err) })
+        });
+        let router = build_router(music_repo);
+
+        let response = router
+            .oneshot(json_request(
+                "POST",
+                "/admin/sheets",
+                ADMIN_KEY.raw_key,
+                json!({
+                    "musicId": MUSIC_ID,
+                    "difficulty": "hard",
+                    "level": 12.5,
+                    "notesDesigner": "Designer",
+                    "noteCount": 1000,
+                    "maxScore": 1_010_000
+                }),
+            ))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn update_sheet_returns_updated_sheet() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo
+            .expect_find_sheets_by_ids()
+            .returning(|_| Box::pin(async { Ok(Vec::new()) }));
+        music_repo
+            .expect_update_sheet()
+            .withf(|sheet| sheet.id() == SHEET_ID)
+            .returning(|sheet| {
+                let updated = Sheet::new(
+                    sheet.id().to_owned(),
+                    sheet.music_id().to_owned(),
+                    *sheet.difficulty(),
+                    Level::try_from(sheet.level().value()).unwrap(),
+                    sheet.notes_designer().to_owned(),
+                    1000,
+                    1_010_000,
+                );
+                Box::pin(async move { Ok(updated) })
+            });
+        let router = build_router(music_repo);
+
+        let response = router
+            .oneshot(json_request(
+                "POST",
+                &format!("/admin/sheets/{SHEET_ID}"),
+                ADMIN_KEY.raw_key,
+                json!({
+                    "musicId": MUSIC_ID,
+                    "difficulty": "normal",
+                    "level": 9.7,
+                    "notesDesigner": "Designer",
+                    "noteCount": 1000,
+                    "maxScore": 1_010_000
+                }),
+            ))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = json_body(response).await;
+        assert_eq!(json["id"], SHEET_ID);
+        assert_eq!(json["difficulty"], "normal");
+        assert_eq!(json["level"], 9.7);
+    }
+
+    #[tokio::test]
+    async fn delete_sheet_returns_no_content() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo
+            .expect_delete_sheet()
+            .withf(|sheet_id| sheet_id == SHEET_ID)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let router = build_router(music_repo);
+
+        let response = router
+            .oneshot(
+                Request::delete(format!("/admin/sheets/{SHEET_ID}"))
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn delete_music_rejects_non_admin_clients() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_delete_music().never();
+        let router = build_router(music_repo);
+
+        let response = router
+            .oneshot(
+                Request::delete(format!("/admin/musics/{MUSIC_ID}"))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("middleware should respond");
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -1,26 +1,167 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use domain::{
+    entity::{difficulty::Difficulty, genre::Genre, level::Level, season::SeasonPeriod},
+    repository::ranking::{RankingFilter, RankingWindow},
 };
 use tracing::{info, instrument};
+use usecase::ranking::RankingScope;
 
 use crate::{
     error::AppError,
     model::ranking::{
-        RatingRankingResponse, SheetScoreRankingResponse, TotalScoreRankingResponse,
-        XpRankingResponse,
+        ClearCountRankingResponse, PlayCountRankingResponse, RankingQuery, RatingRankingResponse,
+        SheetScoreRankingResponse, TotalScoreRankingResponse, XpRankingResponse,
     },
 };
 
 type AppResult<T> = Result<T, AppError>;
 
+const DEFAULT_RANKING_LIMIT: u64 = 20;
+const MAX_RANKING_LIMIT: u64 = 100;
+const DEFAULT_NEIGHBORS: u64 = 5;
+const MAX_NEIGHBORS: u64 = 50;
+
+fn ranking_window(query: RankingQuery) -> AppResult<RankingWindow> {
+    let bad_request = |message: String| Err(AppError::new(StatusCode::BAD_REQUEST, message));
+
+    match query.around {
+        Some(user_id) => {
+            if query.offset.is_some() || query.limit.is_some() {
+                return bad_request("around cannot be combined with offset or limit".to_owned());
+            }
+            let neighbors = query.neighbors.unwrap_or(DEFAULT_NEIGHBORS);
+            if neighbors > MAX_NEIGHBORS {
+                return bad_request(format!("neighbors must be at most {MAX_NEIGHBORS}"));
+            }
+            Ok(RankingWindow::Around { user_id, neighbors })
+        }
+        None => {
+            if query.neighbors.is_some() {
+                return bad_request("neighbors requires around".to_owned());
+            }
+            let limit = query.limit.unwrap_or(DEFAULT_RANKING_LIMIT);
+            if !(1..=MAX_RANKING_LIMIT).contains(&limit) {
+                return bad_request(format!("limit must be between 1 and {MAX_RANKING_LIMIT}"));
+            }
+            Ok(RankingWindow::Page {
+                offset: query.offset.unwrap_or(0),
+                limit,
+            })
+        }
+    }
+}
+
+/// Without `period` the ranking is all-time; `season` then has nothing to pick from.
+fn ranking_scope(query: &RankingQuery) -> AppResult<RankingScope> {
+    let bad_request =
+        |message: &str| Err(AppError::new(StatusCode::BAD_REQUEST, message.to_owned()));
+
+    let period = match query.period.as_deref() {
+        None => {
+            if query.season.is_some() {
+                return bad_request("season requires period");
+            }
+            return Ok(RankingScope::AllTime);
+        }
+        Some("weekly") => SeasonPeriod::Weekly,
+        Some("monthly") => SeasonPeriod::Monthly,
+        Some(_) => return bad_request("period must be weekly or monthly"),
+    };
+    match query.season.as_deref() {
+        None | Some("current") => Ok(RankingScope::Current(period)),
+        Some("previous") => Ok(RankingScope::Previous(period)),
+        Some(_) => bad_request("season must be current or previous"),
+    }
+}
+
+fn sheet_filter(query: &RankingQuery) -> AppResult<RankingFilter> {
+    parse_sheet_filter(
+        query.difficulty.as_deref(),
+        query.genre.as_deref(),
+        query.min_level,
+        query.max_level,
+    )
+}
+
+/// Parses the `difficulty`, `genre`, `minLevel` and `maxLevel` parameters shared by every query
+/// that can be narrowed down to some sheets.
+pub(crate) fn parse_sheet_filter(
+    difficulty: Option<&str>,
+    genre: Option<&str>,
+    min_level: Option<f64>,
+    max_level: Option<f64>,
+) -> AppResult<RankingFilter> {
+    let bad_request = |message: String| AppError::new(StatusCode::BAD_REQUEST, message);
+    let level = |value: Option<f64>, name: &str| {
+        value
+            .map(|value| {
+                Level::try_from(value)
+                    .map_err(|_| bad_request(format!("{name} is not a valid level")))
+            })
+            .transpose()
+    };
+
+    let filter = RankingFilter {
+        difficulty: difficulty
+            .map(str::parse::<Difficulty>)
+            .transpose()
+            .map_err(|err| bad_request(err.to_string()))?,
+        genre: genre
+            .map(str::parse::<Genre>)
+            .transpose()
+            .map_err(|err| bad_request(err.to_string()))?,
+        min_level: level(min_level, "minLevel")?,
+        max_level: level(max_level, "maxLevel")?,
+    };
+    if let (Some(min), Some(max)) = (filter.min_level, filter.max_level)
+        && min > max
+    {
+        return Err(bad_request(
+            "minLevel must not be greater than maxLevel".to_owned(),
+        ));
+    }
+    Ok(filter)
+}
+
+/// Rankings that are neither kept per season nor filtered by sheet.
+fn unscoped(query: &RankingQuery) -> AppResult<()> {
+    if query.period.is_some() || query.season.is_some() {
+        return Err(AppError::new(
+            StatusCode::BAD_REQUEST,
+            "this ranking does not support period or season".to_owned(),
+        ));
+    }
+    no_sheet_filter(query)
+}
+
+fn no_sheet_filter(query: &RankingQuery) -> AppResult<()> {
+    if !sheet_filter(query)?.is_empty() {
+        return Err(AppError::new(
+            StatusCode::BAD_REQUEST,
+            "this ranking does not support sheet filters".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
 #[instrument(skip(state), fields(sheet_id = %sheet_id))]
 pub async fn handle_get_sheet_ranking(
     Path(sheet_id): Path<String>,
+    Query(query): Query<RankingQuery>,
     State(state): State<crate::state::State>,
 ) -> AppResult<Json<SheetScoreRankingResponse>> {
     info!("Sheet ranking request received");
-    let ranking = state.usecases.ranking.sheet_high_scores(&sheet_id).await?;
+    unscoped(&query)?;
+    let window = ranking_window(query)?;
+    let ranking = state
+        .usecases
+        .ranking
+        .sheet_high_scores(&sheet_id, &window)
+        .await?;
     let entry_count = ranking.entries.len();
     let response = SheetScoreRankingResponse::from(ranking);
     info!(
@@ -33,22 +174,92 @@ pub async fn handle_get_sheet_ranking(
 
 #[instrument(skip(state))]
 pub async fn handle_get_total_ranking(
+    Query(query): Query<RankingQuery>,
     State(state): State<crate::state::State>,
 ) -> AppResult<Json<TotalScoreRankingResponse>> {
     info!("Total score ranking request received");
-    let ranking = state.usecases.ranking.total_high_scores().await?;
+    let scope = ranking_scope(&query)?;
+    let filter = sheet_filter(&query)?;
+    let window = ranking_window(query)?;
+    let ranking = match scope {
+        RankingScope::AllTime => {
+            state
+                .usecases
+                .ranking
+                .filtered_total_high_scores(&filter, &window)
+                .await?
+        }
+        // Seasonal standings are ranked from plays, which are not broken down by sheet.
+        _ if !filter.is_empty() => {
+            return Err(AppError::new(
+                StatusCode::BAD_REQUEST,
+                "sheet filters cannot be combined with period".to_owned(),
+            ));
+        }
+        _ => {
+            state
+                .usecases
+                .ranking
+                .total_high_scores(scope, &window)
+                .await?
+        }
+    };
     let entry_count = ranking.entries.len();
     let response = TotalScoreRankingResponse::from(ranking);
     info!(entry_count, "Total score ranking computed successfully");
     Ok(Json(response))
 }
 
+#[instrument(skip(state))]
+pub async fn handle_get_play_count_ranking(
+    Query(query): Query<RankingQuery>,
+    State(state): State<crate::state::State>,
+) -> AppResult<Json<PlayCountRankingResponse>> {
+    info!("Play count ranking request received");
+    no_sheet_filter(&query)?;
+    let scope = ranking_scope(&query)?;
+    let window = ranking_window(query)?;
+    let ranking = state.usecases.ranking.plays(scope, &window).await?;
+    let entry_count = ranking.entries.len();
+    let response = PlayCountRankingResponse::from(ranking);
+    info!(entry_count, "Play count ranking computed successfully");
+    Ok(Json(response))
+}
+
+#[instrument(skip(state))]
+pub async fn handle_get_clear_count_ranking(
+    Query(query): Query<RankingQuery>,
+    State(state): State<crate::state::State>,
+) -> AppResult<Json<ClearCountRankingResponse>> {
+    info!("Clear count ranking request received");
+    if ranking_scope(&query)? != RankingScope::AllTime {
+        return Err(AppError::new(
+            StatusCode::BAD_REQUEST,
+            "this ranking does not support period or season".to_owned(),
+        ));
+    }
+    let filter = sheet_filter(&query)?;
+    let window = ranking_window(query)?;
+    let ranking = state
+        .usecases
+        .ranking
+        .clear_counts(&filter, &window)
+        .await?;
+    let entry_count = ranking.entries.len();
+    let response = ClearCountRankingResponse::from(ranking);
+    info!(entry_count, "Clear count ranking computed successfully");
+    Ok(Json(response))
+}
+
 #[instrument(skip(state))]
 pub async fn handle_get_rating_ranking(
+    Query(query): Query<RankingQuery>,
     State(state): State<crate::state::State>,
 ) -> AppResult<Json<RatingRankingResponse>> {
     info!("Rating ranking request received");
-    let ranking = state.usecases.ranking.rating().await?;
+    unscoped(&query)?;
+    let window = ranking_window(query)?;
+    let ranking = state.usecases.ranking.rating(&window).await?;
     let entry_count = ranking.entries.len();
     let response = RatingRankingResponse::from(ranking);
     info!(entry_count, "Rating ranking computed successfully");
@@ -57,10 +268,13 @@ pub async fn handle_get_rating_ranking(
 
 #[instrument(skip(state))]
 pub async fn handle_get_xp_ranking(
+    Query(query): Query<RankingQuery>,
     State(state): State<crate::state::State>,
 ) -> AppResult<Json<XpRankingResponse>> {
     info!("XP ranking request received");
-    let ranking = state.usecases.ranking.xp().await?;
+    unscoped(&query)?;
+    let window = ranking_window(query)?;
+    let ranking = state.usecases.ranking.xp(&window).await?;
     let entry_count = ranking.entries.len();
     let response = XpRankingResponse::from(ranking);
     info!(entry_count, "XP ranking computed successfully");
@@ -69,25 +283,63 @@ pub async fn handle_get_xp_ranking(
 
 #[cfg(test)]
 mod tests {
-    use axum::{Router, body, http::Request};
+    use axum::{
+        Router, body,
+        http::{Request, StatusCode},
+    };
     use domain::{
-        entity::{rating::Rating, user::User},
+        entity::{
+            difficulty::Difficulty,
+            level::Level,
+            rating::Rating,
+            season::{Season, SeasonPeriod},
+            user::User,
+        },
         repository::{
             MockRepositories,
-            music::MockMusicRepository,
-            record::{MockRecordRepository, SheetScoreRankingRow, TotalScoreRankingRow},
-            user::MockUserRepository,
+            play::{MockPlayRepository, PlayCountRankingRow},
+            ranking::{RankingFilter, RankingWindow},
+            record::{
+                ClearCountRankingRow, MockRecordRepository, SheetScoreRankingRow,
+                TotalScoreRankingRow,
+            },
+            season::MockSeasonRepository,
+            user::{MockUserRepository, RankedUser},
+        },
+        testing::{
+            api_key::{api_key_repository, client_repository},
+            datetime::timestamp,
         },
     };
     use serde_json::Value;
     use tower::ServiceExt;
 
+    const SEASON_ID: &str = "55555555-5555-5555-5555-555555555555";
+
     fn build_router(user_repo: MockUserRepository, record_repo: MockRecordRepository) -> Router {
+        build_router_with_seasons(
+            user_repo,
+            record_repo,
+            MockPlayRepository::new(),
+            MockSeasonRepository::new(),
+        )
+    }
+
+    fn build_router_with_seasons(
+        user_repo: MockUserRepository,
+        record_repo: MockRecordRepository,
+        play_repo: MockPlayRepository,
+        season_repo: MockSeasonRepository,
+    ) -> Router {
         let config = crate::config::Config::default();
         let repositories = MockRepositories {
             user: user_repo,
             record: record_repo,
-            music: MockMusicRepository::new(),
+            api_key: api_key_repository(),
+            client: client_repository(),
+            play: play_repo,
+            season: season_repo,
+            ..Default::default()
         };
         let state = crate::state::State::new(config, repositories);
         super::super::create_app(state)
@@ -112,11 +364,18 @@ mod tests {
         let mut record_repo = MockRecordRepository::new();
         record_repo
             .expect_find_public_high_scores_by_sheet()
-            .returning(|sheet_id, limit| {
+            .returning(|sheet_id, window| {
                 assert_eq!(sheet_id, "sheet-123");
-                assert_eq!(limit, 20);
+                assert_eq!(
+                    *window,
+                    RankingWindow::Page {
+                        offset: 0,
+                        limit: 20
+                    }
+                );
                 Box::pin(async {
                     Ok(vec![SheetScoreRankingRow::new(
+                        1,
                         "user-1".to_owned(),
                         "Alice".to_owned(),
                         987_654,
@@ -141,6 +400,7 @@ mod tests {
         assert_eq!(json["sheetId"], "sheet-123");
         assert_eq!(json["entries"][0]["userId"], "user-1");
         assert_eq!(json["entries"][0]["score"], 987_654);
+        assert_eq!(json["entries"][0]["grade"], "AA");
     }
 
     #[tokio::test]
@@ -148,10 +408,17 @@ mod tests {
         let mut record_repo = MockRecordRepository::new();
         record_repo
             .expect_find_public_total_score_ranking()
-            .returning(|limit| {
-                assert_eq!(limit, 20);
+            .returning(|window| {
+                assert_eq!(
+                    *window,
+                    RankingWindow::Page {
+                        offset: 0,
+                        limit: 20
+                    }
+                );
                 Box::pin(async {
                     Ok(vec![TotalScoreRankingRow::new(
+                        1,
                         "user-2".to_owned(),
                         "Bob".to_owned(),
                         1_234_567,
@@ -175,16 +442,28 @@ mod tests {
 
         assert_eq!(json["entries"][0]["userId"], "user-2");
         assert_eq!(json["entries"][0]["totalScore"], 1_234_567);
+        assert!(json["generatedAt"].is_string());
     }
 
     #[tokio::test]
     async fn handle_get_rating_ranking_returns_entries() {
         let mut user_repo = MockUserRepository::new();
         user_repo
-            .expect_find_public_top_by_rating()
-            .returning(|limit| {
-                assert_eq!(limit, 20);
-                Box::pin(async { Ok(vec![sample_user("user-3", "Carol", 1900, 50)]) })
+            .expect_find_public_rating_ranking()
+            .returning(|window| {
+                assert_eq!(
+                    *window,
+                    RankingWindow::Page {
+                        offset: 0,
+                        limit: 20
+                    }
+                );
+                Box::pin(async {
+                    Ok(vec![RankedUser::new(
+                        1,
+                        sample_user("user-3", "Carol", 1900, 50),
+                    )])
+                })
             });
 
         let router = build_router(user_repo, MockRecordRepository::new());
@@ -208,10 +487,23 @@ mod tests {
     #[tokio::test]
     async fn handle_get_xp_ranking_returns_entries() {
         let mut user_repo = MockUserRepository::new();
-        user_repo.expect_find_public_top_by_xp().returning(|limit| {
-            assert_eq!(limit, 20);
-            Box::pin(async { Ok(vec![sample_user("user-4", "Dave", 1800, 123)]) })
-        });
+        user_repo
+            .expect_find_public_xp_ranking()
+            .returning(|window| {
+                assert_eq!(
+                    *window,
+                    RankingWindow::Page {
+                        offset: 0,
+                        limit: 20
+                    }
+                );
+                Box::pin(async {
+                    Ok(vec![RankedUser::new(
+                        1,
+                        sample_user("user-4", "Dave", 1800, 123),
+                    )])
+                })
+            });
 
         let router = build_router(user_repo, MockRecordRepository::new());
         let response = router
@@ -229,5 +521,336 @@ mod tests {
 
         assert_eq!(json["entries"][0]["userId"], "user-4");
         assert_eq!(json["entries"][0]["xp"], 123);
+        assert_eq!(json["entries"][0]["level"], 2);
+    }
+
+    #[tokio::test]
+    async fn handle_get_rating_ranking_pages_with_shared_ranks() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_public_rating_ranking()
+            .withf(|window| {
+                *window
+                    == RankingWindow::Page {
+                        offset: 20,
+                        limit: 2,
+                    }
+            })
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        RankedUser::new(20, sample_user("user-5", "Erin", 1500, 0)),
+                        RankedUser::new(20, sample_user("user-6", "Frank", 1500, 0)),
+                    ])
+                })
+            });
+
+        let router = build_router(user_repo, MockRecordRepository::new());
+        let response = router
+            .oneshot(
+                Request::get("/rankings/rating?offset=20&limit=2")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(json["entries"][0]["rank"], 20);
+        assert_eq!(json["entries"][1]["rank"], 20);
+    }
+
+    #[tokio::test]
+    async fn handle_get_xp_ranking_centres_on_the_requested_user() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_public_xp_ranking()
+            .withf(|window| {
+                *window
+                    == RankingWindow::Around {
+                        user_id: "user-4".to_owned(),
+                        neighbors: 1,
+                    }
+            })
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        RankedUser::new(41, sample_user("user-7", "Grace", 1800, 130)),
+                        RankedUser::new(42, sample_user("user-4", "Dave", 1800, 123)),
+                        RankedUser::new(43, sample_user("user-8", "Heidi", 1800, 90)),
+                    ])
+                })
+            });
+
+        let router = build_router(user_repo, MockRecordRepository::new());
+        let response = router
+            .oneshot(
+                Request::get("/rankings/xp?around=user-4&neighbors=1")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(json["entries"][1]["userId"], "user-4");
+        assert_eq!(json["entries"][1]["rank"], 42);
+    }
+
+    #[tokio::test]
+    async fn handle_get_total_ranking_rejects_around_with_offset() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo.expect_find_public_total_score_ranking().never();
+
+        let router = build_router(MockUserRepository::new(), record_repo);
+        let response = router
+            .oneshot(
+                Request::get("/rankings/total-score?around=user-1&offset=10")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn handle_get_sheet_ranking_rejects_oversized_limit() {
+        let router = build_router(MockUserRepository::new(), MockRecordRepository::new());
+        let response = router
+            .oneshot(
+                Request::get("/rankings/sheets/sheet-123?limit=101")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn handle_get_play_count_ranking_covers_the_current_week() {
+        let starts_at = timestamp(2025, 11, 17, 0, 0, 0);
+        let ends_at = timestamp(2025, 11, 24, 0, 0, 0);
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo
+            .expect_find_active_at()
+            .withf(|period, _| *period == SeasonPeriod::Weekly)
+            .returning(move |period, _| {
+                let season = Season::new(SEASON_ID.to_owned(), period, starts_at, ends_at, None);
+                Box::pin(async move { Ok(Some(season)) })
+            });
+        let mut play_repo = MockPlayRepository::new();
+        play_repo
+            .expect_find_public_play_count_ranking()
+            .withf(move |between, _| *between == Some((starts_at, ends_at)))
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![PlayCountRankingRow::new(
+                        1,
+                        "user-1".to_owned(),
+                        "Alice".to_owned(),
+                        58,
+                    )])
+                })
+            });
+
+        let router = build_router_with_seasons(
+            MockUserRepository::new(),
+            MockRecordRepository::new(),
+            play_repo,
+            season_repo,
+        );
+        let response = router
+            .oneshot(
+                Request::get("/rankings/plays?period=weekly")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(json["entries"][0]["plays"], 58);
+        assert_eq!(json["season"]["id"], SEASON_ID);
+        assert_eq!(json["season"]["period"], "weekly");
+        assert!(json["season"]["archivedAt"].is_null());
+    }
+
+    #[tokio::test]
+    async fn handle_get_total_ranking_reports_missing_archive_as_not_found() {
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo
+            .expect_find_latest_archived()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        season_repo.expect_archive().never();
+
+        let router = build_router_with_seasons(
+            MockUserRepository::new(),
+            MockRecordRepository::new(),
+            MockPlayRepository::new(),
+            season_repo,
+        );
+        let response = router
+            .oneshot(
+                Request::get("/rankings/total-score?period=monthly&season=previous")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn handle_get_total_ranking_rejects_unknown_period() {
+        let router = build_router(MockUserRepository::new(), MockRecordRepository::new());
+        let response = router
+            .oneshot(
+                Request::get("/rankings/total-score?period=daily")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn handle_get_rating_ranking_rejects_period() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_public_rating_ranking().never();
+
+        let router = build_router(user_repo, MockRecordRepository::new());
+        let response = router
+            .oneshot(
+                Request::get("/rankings/rating?period=weekly")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn handle_get_total_ranking_filters_by_difficulty_and_level() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo.expect_find_public_total_score_ranking().never();
+        record_repo
+            .expect_find_public_filtered_total_score_ranking()
+            .withf(|filter, _| {
+                *filter
+                    == RankingFilter {
+                        difficulty: Some(Difficulty::Hard),
+                        genre: None,
+                        min_level: Some(Level::new(13, 5).unwrap()),
+                        max_level: None,
+                    }
+            })
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![TotalScoreRankingRow::new(
+                        1,
+                        "user-2".to_owned(),
+                        "Bob".to_owned(),
+                        4_000_000,
+                    )])
+                })
+            });
+
+        let router = build_router(MockUserRepository::new(), record_repo);
+        let response = router
+            .oneshot(
+                Request::get("/rankings/total-score?difficulty=hard&minLevel=13.5")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(json["entries"][0]["totalScore"], 4_000_000);
+    }
+
+    #[tokio::test]
+    async fn handle_get_clear_count_ranking_returns_entries() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_public_clear_count_ranking()
+            .withf(|filter, _| filter.min_level == Some(Level::new(13, 0).unwrap()))
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![ClearCountRankingRow::new(
+                        1,
+                        "user-1".to_owned(),
+                        "Alice".to_owned(),
+                        31,
+                    )])
+                })
+            });
+
+        let router = build_router(MockUserRepository::new(), record_repo);
+        let response = router
+            .oneshot(
+                Request::get("/rankings/clears?minLevel=13")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(json["entries"][0]["clears"], 31);
+    }
+
+    #[tokio::test]
+    async fn handle_get_total_ranking_rejects_filters_with_period() {
+        let router = build_router(MockUserRepository::new(), MockRecordRepository::new());
+        let response = router
+            .oneshot(
+                Request::get("/rankings/total-score?difficulty=hard&period=weekly")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn handle_get_total_ranking_rejects_inverted_level_range() {
+        let router = build_router(MockUserRepository::new(), MockRecordRepository::new());
+        let response = router
+            .oneshot(
+                Request::get("/rankings/total-score?minLevel=14&maxLevel=13.5")
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 }
@@ -0,0 +1,120 @@
+use axum::{Json, extract::State};
+use tracing::{info, instrument};
+
+use crate::{
+    error::AppError, middleware::authorization::Role, model::rating::RatingRecomputeResponse,
+};
+
+type AppResult<T> = Result<T, AppError>;
+
+/// Recomputing touches every user, so only operators may trigger it.
+pub const RECOMPUTE_ROLES: &[Role] = &[Role::Admin];
+
+/// Re-rates every record and user with the configured rating policy, e.g. after a formula change.
+#[instrument(skip(state))]
+pub async fn handle_post_recompute(
+    State(state): State<crate::state::State>,
+) -> AppResult<Json<RatingRecomputeResponse>> {
+    info!("Rating recomputation request received");
+    let result = state.usecases.rating.recompute_all().await?;
+    info!(
+        users = result.users,
+        updated = result.updated,
+        "Rating recomputation completed successfully"
+    );
+
+    Ok(Json(result.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router, body,
+        http::{Request, StatusCode},
+    };
+    use domain::{
+        repository::{MockRepositories, record::MockRecordRepository, user::MockUserRepository},
+        testing::{
+            api_key::{ADMIN_KEY, CABINET_KEY, api_key_repository, client_repository},
+            datetime::sample_timestamp,
+            user::USER1,
+        },
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::middleware::auth::API_KEY_HEADER;
+
+    fn build_router(user_repo: MockUserRepository, record_repo: MockRecordRepository) -> Router {
+        let config = crate::config::Config::default();
+        let repositories = MockRepositories {
+            user: user_repo,
+            record: record_repo,
+            api_key: api_key_repository(),
+            client: client_repository(),
+            ..Default::default()
+        };
+        let state = crate::state::State::new(config, repositories);
+        super::super::create_app(state)
+    }
+
+    #[tokio::test]
+    async fn handle_post_recompute_reports_counts() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_all_ids()
+            .returning(|| Box::pin(async { Ok(vec![USER1.id.to_owned()]) }));
+        user_repo.expect_find_by_id_for_update().returning(|_| {
+            let user = USER1.build(true, false, sample_timestamp());
+            Box::pin(async move { Ok(Some(user)) })
+        });
+        user_repo
+            .expect_save()
+            .withf(|user| user.rating().value() == 0)
+            .returning(|user| Box::pin(async move { Ok(user) }));
+
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_with_metadata_by_user_id()
+            .returning(|_| Box::pin(async { Ok(Vec::new()) }));
+        record_repo
+            .expect_update_ratings()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let router = build_router(user_repo, record_repo);
+        let response = router
+            .oneshot(
+                Request::post("/admin/ratings/recompute")
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["users"], 1);
+        assert_eq!(json["updated"], 1);
+    }
+
+    #[tokio::test]
+    async fn handle_post_recompute_rejects_non_admin_callers() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_all_ids().never();
+
+        let router = build_router(user_repo, MockRecordRepository::new());
+        let response = router
+            .oneshot(
+                Request::post("/admin/ratings/recompute")
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
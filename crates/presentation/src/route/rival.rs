@@ -0,0 +1,323 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::{info, instrument};
+
+use crate::{
+    error::AppError,
+    middleware::authorization::Role,
+    model::rival::{
+        RivalComparisonResponse, RivalRankingEntryResponse, RivalRequest, RivalResponse,
+    },
+};
+
+type AppResult<T> = Result<T, AppError>;
+
+/// Rivals are shown in game and on the web.
+pub const GET_ROLES: &[Role] = &[Role::Cabinet, Role::WebUser, Role::Admin];
+
+/// Players manage their rivals on the web.
+pub const WRITE_ROLES: &[Role] = &[Role::WebUser, Role::Admin];
+
+#[instrument(skip(state), fields(user_id = %user_id))]
+pub async fn handle_get(
+    State(state): State<crate::state::State>,
+    Path(user_id): Path<String>,
+) -> AppResult<Json<Vec<RivalResponse>>> {
+    info!("List rivals request received");
+    let rivals = state.usecases.rival.list(user_id).await?;
+    info!(count = rivals.len(), "Rivals listed successfully");
+    Ok(Json(rivals.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(skip(state, request), fields(user_id = %user_id, rival_id = %request.rival_id))]
+pub async fn handle_post(
+    State(state): State<crate::state::State>,
+    Path(user_id): Path<String>,
+    Json(request): Json<RivalRequest>,
+) -> AppResult<(StatusCode, Json<RivalResponse>)> {
+    info!("Register rival request received");
+    let rival = state.usecases.rival.add(user_id, request.rival_id).await?;
+    info!("Rival registered successfully");
+    Ok((StatusCode::CREATED, Json(rival.into())))
+}
+
+#[instrument(skip(state), fields(user_id = %user_id, rival_id = %rival_id))]
+pub async fn handle_delete(
+    State(state): State<crate::state::State>,
+    Path((user_id, rival_id)): Path<(String, String)>,
+) -> AppResult<StatusCode> {
+    info!("Remove rival request received");
+    state.usecases.rival.remove(user_id, rival_id).await?;
+    info!("Rival removed successfully");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip(state), fields(user_id = %user_id))]
+pub async fn handle_get_ranking(
+    State(state): State<crate::state::State>,
+    Path(user_id): Path<String>,
+) -> AppResult<Json<Vec<RivalRankingEntryResponse>>> {
+    info!("Rival ranking request received");
+    let ranking = state.usecases.rival.ranking(user_id).await?;
+    info!(count = ranking.len(), "Rival ranking computed successfully");
+    Ok(Json(ranking.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(skip(state), fields(user_id = %user_id, rival_id = %rival_id))]
+pub async fn handle_get_comparison(
+    State(state): State<crate::state::State>,
+    Path((user_id, rival_id)): Path<(String, String)>,
+) -> AppResult<Json<RivalComparisonResponse>> {
+    info!("Rival comparison request received");
+    let comparison = state.usecases.rival.compare(user_id, rival_id).await?;
+    info!(
+        wins = comparison.wins,
+        losses = comparison.losses,
+        draws = comparison.draws,
+        "Rival comparison computed successfully"
+    );
+    Ok(Json(comparison.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router, body,
+        http::{Request, header},
+    };
+    use domain::{
+        entity::{clear_type::ClearType, judgement::Judgement, record::Record, rival::Rival},
+        repository::{
+            MockRepositories, record::MockRecordRepository, rival::MockRivalRepository,
+            user::MockUserRepository,
+        },
+        testing::{
+            acting_user::{acting_user_signer, acting_user_token},
+            api_key::{CABINET_KEY, WEB_KEY, api_key_repository, client_repository},
+            datetime::sample_timestamp,
+            user::{USER1, USER2, USER3},
+        },
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::middleware::auth::{ACTING_USER_HEADER, API_KEY_HEADER};
+
+    /// USER1 and USER2 are public, USER3 is private.
+    fn build_router(rival_repo: MockRivalRepository, record_repo: MockRecordRepository) -> Router {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id().returning(|user_id| {
+            let user = [(&USER1, true), (&USER2, true), (&USER3, false)]
+                .into_iter()
+                .find(|(sample, _)| sample.id == user_id)
+                .map(|(sample, is_public)| sample.build(is_public, false, sample_timestamp()));
+            Box::pin(async move { Ok(user) })
+        });
+        let config = crate::config::Config {
+            acting_user_signer: acting_user_signer(),
+            ..Default::default()
+        };
+        let repositories = MockRepositories {
+            user: user_repo,
+            record: record_repo,
+            api_key: api_key_repository(),
+            client: client_repository(),
+            rival: rival_repo,
+            ..Default::default()
+        };
+        let state = crate::state::State::new(config, repositories);
+        super::super::create_app(state)
+    }
+
+    fn registered(rival_ids: &'static [&'static str]) -> MockRivalRepository {
+        let mut rival_repo = MockRivalRepository::new();
+        rival_repo
+            .expect_find_by_user_id()
+            .returning(move |user_id| {
+                let rivals = rival_ids
+                    .iter()
+                    .map(|rival_id| {
+                        Rival::new(
+                            user_id.to_owned(),
+                            (*rival_id).to_owned(),
+                            sample_timestamp(),
+                        )
+                    })
+                    .collect();
+                Box::pin(async move { Ok(rivals) })
+            });
+        rival_repo
+    }
+
+    #[tokio::test]
+    async fn handle_post_registers_rival_for_the_acting_user() {
+        let mut rival_repo = registered(&[]);
+        rival_repo
+            .expect_create()
+            .withf(|rival| rival.user_id() == USER1.id && rival.rival_id() == USER2.id)
+            .returning(|rival| Box::pin(async move { Ok(rival) }));
+
+        let response = build_router(rival_repo, MockRecordRepository::new())
+            .oneshot(
+                Request::post(format!("/users/{}/rivals", USER1.id))
+                    .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                    .header(ACTING_USER_HEADER, acting_user_token(USER1.id))
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(body::Body::from(json!({ "rivalId": USER2.id }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["userId"], USER2.id);
+        assert_eq!(json["displayName"], USER2.display_name);
+        assert_eq!(json["rating"], USER2.rating);
+    }
+
+    #[tokio::test]
+    async fn handle_post_rejects_registering_oneself() {
+        let mut rival_repo = MockRivalRepository::new();
+        rival_repo.expect_create().never();
+
+        let response = build_router(rival_repo, MockRecordRepository::new())
+            .oneshot(
+                Request::post(format!("/users/{}/rivals", USER1.id))
+                    .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                    .header(ACTING_USER_HEADER, acting_user_token(USER1.id))
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(body::Body::from(json!({ "rivalId": USER1.id }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn handle_get_lists_private_rivals_without_rating() {
+        let response = build_router(
+            registered(&[USER2.id, USER3.id]),
+            MockRecordRepository::new(),
+        )
+        .oneshot(
+            Request::get(format!("/users/{}/rivals", USER1.id))
+                .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                .body(body::Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json[0]["userId"], USER2.id);
+        assert_eq!(json[1]["userId"], USER3.id);
+        assert_eq!(json[1]["isPublic"], false);
+        assert_eq!(json[1]["rating"], Value::Null);
+    }
+
+    #[tokio::test]
+    async fn handle_get_ranking_ranks_user_with_public_rivals() {
+        let response = build_router(
+            registered(&[USER2.id, USER3.id]),
+            MockRecordRepository::new(),
+        )
+        .oneshot(
+            Request::get(format!("/users/{}/rivals/ranking", USER1.id))
+                .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                .body(body::Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 2);
+        assert_eq!(json[0]["userId"], USER2.id);
+        assert_eq!(json[1]["rank"], 2);
+        assert_eq!(json[1]["isSelf"], true);
+    }
+
+    #[tokio::test]
+    async fn handle_get_comparison_returns_results() {
+        let record = |user_id: &str, score: u32| {
+            Record::new_from_submission(
+                user_id.to_owned(),
+                "sheet-1".to_owned(),
+                score,
+                ClearType::Clear,
+                Judgement::default(),
+                0,
+                sample_timestamp(),
+            )
+        };
+        let mut record_repo = MockRecordRepository::new();
+        record_repo.expect_find_by_user_id().returning(move |_| {
+            let records = vec![record(USER1.id, 1_000_000)];
+            Box::pin(async move { Ok(records) })
+        });
+        record_repo
+            .expect_find_by_user_id_and_sheet_ids()
+            .returning(move |_, _| {
+                let records = vec![record(USER2.id, 980_000)];
+                Box::pin(async move { Ok(records) })
+            });
+
+        let response = build_router(registered(&[USER2.id]), record_repo)
+            .oneshot(
+                Request::get(format!(
+                    "/users/{}/rivals/{}/comparison",
+                    USER1.id, USER2.id
+                ))
+                .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                .body(body::Body::empty())
+                .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["wins"], 1);
+        assert_eq!(json["losses"], 0);
+        assert_eq!(json["sheets"][0]["rivalScore"], 980_000);
+        assert_eq!(json["sheets"][0]["result"], "win");
+    }
+
+    #[tokio::test]
+    async fn handle_delete_reports_unregistered_rivals() {
+        let mut rival_repo = MockRivalRepository::new();
+        rival_repo.expect_delete().returning(|user_id, rival_id| {
+            let err = domain::repository::rival::RivalRepositoryError::NotFound {
+                user_id: user_id.to_owned(),
+                rival_id: rival_id.to_owned(),
+            };
+            Box::pin(async move { Err(err) })
+        });
+
+        let response = build_router(rival_repo, MockRecordRepository::new())
+            .oneshot(
+                Request::delete(format!("/users/{}/rivals/{}", USER1.id, USER2.id))
+                    .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                    .header(ACTING_USER_HEADER, acting_user_token(USER1.id))
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,203 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::{info, instrument};
+use usecase::model::season::SeasonCreateDto;
+
+use crate::{
+    error::AppError,
+    middleware::authorization::Role,
+    model::{ranking::SeasonResponse, season::SeasonRequest},
+};
+
+type AppResult<T> = Result<T, AppError>;
+
+/// Seasons decide what every seasonal ranking covers, so only operators schedule and archive them.
+pub const ROLES: &[Role] = &[Role::Admin];
+
+#[instrument(skip(state))]
+pub async fn handle_get(
+    State(state): State<crate::state::State>,
+) -> AppResult<Json<Vec<SeasonResponse>>> {
+    info!("List seasons request received");
+    let seasons = state.usecases.season.list().await?;
+    info!(count = seasons.len(), "Seasons listed successfully");
+    Ok(Json(seasons.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(skip(state, request), fields(period = %request.period))]
+pub async fn handle_post(
+    State(state): State<crate::state::State>,
+    Json(request): Json<SeasonRequest>,
+) -> AppResult<(StatusCode, Json<SeasonResponse>)> {
+    info!("Create season request received");
+    let dto = SeasonCreateDto::try_from(request)
+        .map_err(|err| AppError::new(StatusCode::BAD_REQUEST, err))?;
+    let season = state.usecases.season.create(dto).await?;
+    info!(season_id = %season.id, "Season created successfully");
+    Ok((StatusCode::CREATED, Json(season.into())))
+}
+
+#[instrument(skip(state), fields(season_id = %season_id))]
+pub async fn handle_post_archive(
+    State(state): State<crate::state::State>,
+    Path(season_id): Path<String>,
+) -> AppResult<Json<SeasonResponse>> {
+    info!("Archive season request received");
+    let season = state.usecases.season.archive(season_id).await?;
+    info!("Season archived successfully");
+    Ok(Json(season.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router, body,
+        http::{Request, StatusCode, header},
+    };
+    use chrono::{Duration, Utc};
+    use domain::{
+        entity::season::{Season, SeasonPeriod},
+        repository::{
+            MockRepositories,
+            season::{MockSeasonRepository, SeasonRepositoryError},
+        },
+        testing::{
+            api_key::{ADMIN_KEY, WEB_KEY, api_key_repository, client_repository},
+            datetime::timestamp,
+        },
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::middleware::auth::API_KEY_HEADER;
+
+    const SEASON_ID: &str = "55555555-5555-5555-5555-555555555555";
+
+    fn build_router(season_repo: MockSeasonRepository) -> Router {
+        let config = crate::config::Config::default();
+        let repositories = MockRepositories {
+            api_key: api_key_repository(),
+            client: client_repository(),
+            season: season_repo,
+            ..Default::default()
+        };
+        let state = crate::state::State::new(config, repositories);
+        super::super::create_app(state)
+    }
+
+    fn post_season(key: &str, payload: Value) -> Request<body::Body> {
+        Request::post("/admin/seasons")
+            .header(API_KEY_HEADER, key)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(payload.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn handle_post_creates_season() {
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo
+            .expect_create()
+            .withf(|season| {
+                *season.period() == SeasonPeriod::Weekly
+                    && *season.starts_at() == timestamp(2025, 11, 16, 15, 0, 0)
+            })
+            .returning(|season| {
+                let created = Season::new(
+                    SEASON_ID.to_owned(),
+                    *season.period(),
+                    *season.starts_at(),
+                    *season.ends_at(),
+                    None,
+                );
+                Box::pin(async move { Ok(created) })
+            });
+
+        let payload = json!({
+            "period": "weekly",
+            "startsAt": "2025-11-17T00:00:00+09:00",
+            "endsAt": "2025-11-24T00:00:00+09:00"
+        });
+        let response = build_router(season_repo)
+            .oneshot(post_season(ADMIN_KEY.raw_key, payload))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["id"], SEASON_ID);
+        assert_eq!(json["period"], "weekly");
+        assert!(json["archivedAt"].is_null());
+    }
+
+    #[tokio::test]
+    async fn handle_post_reports_overlapping_seasons_as_conflict() {
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo.expect_create().returning(|season| {
+            let period = *season.period();
+            Box::pin(async move { Err(SeasonRepositoryError::Overlapping(period)) })
+        });
+
+        let payload = json!({
+            "period": "monthly",
+            "startsAt": "2025-11-01T00:00:00Z",
+            "endsAt": "2025-12-01T00:00:00Z"
+        });
+        let response = build_router(season_repo)
+            .oneshot(post_season(ADMIN_KEY.raw_key, payload))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn handle_post_rejects_non_admins() {
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo.expect_create().never();
+
+        let payload = json!({
+            "period": "weekly",
+            "startsAt": "2025-11-17T00:00:00Z",
+            "endsAt": "2025-11-24T00:00:00Z"
+        });
+        let response = build_router(season_repo)
+            .oneshot(post_season(WEB_KEY.raw_key, payload))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn handle_post_archive_rejects_running_seasons() {
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo.expect_find_by_id().returning(|_| {
+            let season = Season::new(
+                SEASON_ID.to_owned(),
+                SeasonPeriod::Monthly,
+                Utc::now() - Duration::days(1),
+                Utc::now() + Duration::days(1),
+                None,
+            );
+            Box::pin(async move { Ok(Some(season)) })
+        });
+        season_repo.expect_archive().never();
+
+        let response = build_router(season_repo)
+            .oneshot(
+                Request::post(format!("/admin/seasons/{SEASON_ID}/archive"))
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+}
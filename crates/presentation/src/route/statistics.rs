@@ -1,10 +1,15 @@
 use axum::{Json, extract::State};
 use tracing::{info, instrument};
 
-use crate::{error::AppError, model::statistics::GlobalStatisticsResponse};
+use crate::{
+    error::AppError, middleware::authorization::Role, model::statistics::GlobalStatisticsResponse,
+};
 
 type AppResult<T> = Result<T, AppError>;
 
+/// Global statistics are reserved for operators.
+pub const GET_SUMMARY_ROLES: &[Role] = &[Role::Admin];
+
 #[instrument(skip(state))]
 pub async fn handle_get_summary(
     State(state): State<crate::state::State>,
@@ -24,19 +29,23 @@ pub async fn handle_get_summary(
 #[cfg(test)]
 mod tests {
     use axum::{Router, body, http::Request};
-    use domain::repository::{
-        MockRepositories, music::MockMusicRepository, record::MockRecordRepository,
-        user::MockUserRepository,
+    use domain::{
+        repository::{MockRepositories, record::MockRecordRepository, user::MockUserRepository},
+        testing::api_key::{ADMIN_KEY, api_key_repository, client_repository},
     };
     use serde_json::Value;
     use tower::ServiceExt;
 
+    use crate::middleware::auth::API_KEY_HEADER;
+
     fn build_router(user_repo: MockUserRepository, record_repo: MockRecordRepository) -> Router {
         let config = crate::config::Config::default();
         let repositories = MockRepositories {
             user: user_repo,
             record: record_repo,
-            music: MockMusicRepository::new(),
+            api_key: api_key_repository(),
+            client: client_repository(),
+            ..Default::default()
         };
         let state = crate::state::State::new(config, repositories);
         super::super::create_app(state)
@@ -61,6 +70,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::get("/statistics/summary")
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
                     .body(body::Body::empty())
                     .unwrap(),
             )
@@ -1,19 +1,89 @@
-use axum::{Json, extract::State};
-use tracing::{info, instrument};
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use tracing::{debug, info, instrument};
 
-use crate::{error::AppError, model::sync::SyncItemResponse};
+use crate::{
+    error::AppError,
+    middleware::authorization::Role,
+    model::sync::{SyncDeltaResponse, SyncItemResponse, SyncQuery},
+};
 
 type AppResult<T> = Result<T, AppError>;
 
-#[instrument(skip(state))]
+/// Cabinets download the catalog on boot.
+pub const GET_ROLES: &[Role] = &[Role::Cabinet, Role::Admin];
+
+/// Returns the whole catalog, or only the changes after `since` when it is given. Both forms
+/// carry an ETag so that a cabinet whose copy is current gets an empty `304` instead.
+#[instrument(skip(state, query, headers), fields(since = ?query.since))]
 pub async fn handle_get(
     State(state): State<crate::state::State>,
-) -> AppResult<Json<Vec<SyncItemResponse>>> {
+    Query(query): Query<SyncQuery>,
+    headers: HeaderMap,
+) -> AppResult<Response> {
     info!("Sync metadata request received");
-    let musics = state.usecases.music.list_all().await?;
-    let response: Vec<SyncItemResponse> = musics.into_iter().map(SyncItemResponse::from).collect();
-    info!(count = response.len(), "Sync metadata response prepared");
-    Ok(Json(response))
+    let body = match query.since {
+        Some(since) => {
+            let since = DateTime::parse_from_rfc3339(&since)
+                .map_err(|_| {
+                    AppError::new(StatusCode::BAD_REQUEST, format!("Invalid since: {since}"))
+                })?
+                .with_timezone(&Utc);
+            let delta = state.usecases.music.list_changed_since(since).await?;
+            let response = SyncDeltaResponse::from(delta);
+            info!(
+                count = response.musics.len(),
+                removed_musics = response.removed_musics.len(),
+                removed_sheets = response.removed_sheets.len(),
+                "Sync delta response prepared"
+            );
+            serde_json::to_vec(&response)
+        }
+        None => {
+            let musics = state.usecases.music.list_all().await?;
+            let response: Vec<SyncItemResponse> =
+                musics.into_iter().map(SyncItemResponse::from).collect();
+            info!(count = response.len(), "Sync metadata response prepared");
+            serde_json::to_vec(&response)
+        }
+    }
+    .map_err(|err| AppError::new(StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?;
+
+    Ok(with_etag(&headers, body))
+}
+
+/// The ETag is a digest of the serialized body, so it changes exactly when the payload would.
+fn with_etag(headers: &HeaderMap, body: Vec<u8>) -> Response {
+    let etag = format!("\"{}\"", hex::encode(Sha256::digest(&body)));
+    if if_none_match(headers, &etag) {
+        debug!(%etag, "Catalog unchanged for caller");
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+    }
+
+    (
+        [
+            (header::CONTENT_TYPE, "application/json".to_owned()),
+            (header::ETAG, etag),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// Weak comparison as required for `If-None-Match`: `W/` prefixes are ignored and `*` matches.
+fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
 }
 
 #[cfg(test)]
@@ -24,20 +94,25 @@ mod tests {
         entity::{difficulty::Difficulty, genre::Genre, level::Level, music::Music, sheet::Sheet},
         repository::{
             MockRepositories,
-            music::{MockMusicRepository, MusicWithSheets},
-            record::MockRecordRepository,
-            user::MockUserRepository,
+            music::{
+                CatalogDelta, MockMusicRepository, MusicTombstone, MusicWithSheets, SheetTombstone,
+            },
         },
+        testing::api_key::{CABINET_KEY, api_key_repository, client_repository},
     };
     use serde_json::Value;
     use tower::ServiceExt;
 
+    use super::*;
+    use crate::middleware::auth::API_KEY_HEADER;
+
     fn build_router(music_repo: MockMusicRepository) -> Router {
         let config = crate::config::Config::default();
         let repositories = MockRepositories {
-            user: MockUserRepository::new(),
-            record: MockRecordRepository::new(),
             music: music_repo,
+            api_key: api_key_repository(),
+            client: client_repository(),
+            ..Default::default()
         };
         let state = crate::state::State::new(config, repositories);
         super::super::create_app(state)
@@ -63,13 +138,20 @@ mod tests {
                 Difficulty::Hard,
                 Level::new(13, 7).expect("level"),
                 "Designer".to_owned(),
+                1000,
+                1_010_000,
             );
             Box::pin(async move { Ok(vec![MusicWithSheets::new(music, vec![sheet])]) })
         });
 
         let router = build_router(music_repo);
         let response = router
-            .oneshot(Request::get("/sync").body(body::Body::empty()).unwrap())
+            .oneshot(
+                Request::get("/sync")
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .body(body::Body::empty())
+                    .unwrap(),
+            )
             .await
             .expect("handler should respond");
 
@@ -85,4 +167,107 @@ mod tests {
         assert_eq!(first["sheets"][0]["difficulty"], "hard");
         assert_eq!(first["sheets"][0]["level"], 13.7);
     }
+
+    fn sample_entry() -> MusicWithSheets {
+        let music = Music::new(
+            "music-1".to_owned(),
+            "Song".to_owned(),
+            "Artist".to_owned(),
+            140.0,
+            Genre::ORIGINAL,
+            "jackets/song.png".to_owned(),
+            Utc.with_ymd_and_hms(2025, 10, 1, 12, 0, 0).unwrap(),
+            false,
+        );
+        MusicWithSheets::new(music, vec![])
+    }
+
+    fn sync_request(uri: &str, if_none_match: Option<&str>) -> Request<body::Body> {
+        let mut request = Request::get(uri).header(API_KEY_HEADER, CABINET_KEY.raw_key);
+        if let Some(etag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        request.body(body::Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn handle_get_returns_not_modified_for_matching_etag() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo
+            .expect_list_with_sheets()
+            .times(2)
+            .returning(|| Box::pin(async { Ok(vec![sample_entry()]) }));
+        let router = build_router(music_repo);
+
+        let first = router
+            .clone()
+            .oneshot(sync_request("/sync", None))
+            .await
+            .expect("handler should respond");
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers()[header::ETAG].to_str().unwrap().to_owned();
+
+        let second = router
+            .oneshot(sync_request("/sync", Some(&format!("W/{etag}"))))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(second.headers()[header::ETAG], etag.as_str());
+        let bytes = body::to_bytes(second.into_body(), 1024).await.unwrap();
+        assert!(bytes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn handle_get_with_since_returns_delta_with_tombstones() {
+        let since = Utc.with_ymd_and_hms(2025, 10, 1, 0, 0, 0).unwrap();
+        let archived_at = Utc.with_ymd_and_hms(2025, 10, 2, 8, 0, 0).unwrap();
+        let mut music_repo = MockMusicRepository::new();
+        music_repo
+            .expect_list_changed_since()
+            .withf(move |requested| *requested == since)
+            .returning(move |_| {
+                let delta = CatalogDelta {
+                    musics: vec![sample_entry()],
+                    removed_musics: vec![MusicTombstone::new("music-2".to_owned(), archived_at)],
+                    removed_sheets: vec![SheetTombstone::new(
+                        "sheet-3".to_owned(),
+                        "music-2".to_owned(),
+                        archived_at,
+                    )],
+                    synced_at: archived_at,
+                };
+                Box::pin(async move { Ok(delta) })
+            });
+        let router = build_router(music_repo);
+
+        let response = router
+            .oneshot(sync_request("/sync?since=2025-10-01T00:00:00Z", None))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key(header::ETAG));
+        let bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["musics"][0]["music"]["id"], "music-1");
+        assert_eq!(json["removedMusics"][0]["id"], "music-2");
+        assert_eq!(json["removedSheets"][0]["id"], "sheet-3");
+        assert_eq!(json["removedSheets"][0]["musicId"], "music-2");
+        assert_eq!(json["syncedAt"], archived_at.to_rfc3339());
+    }
+
+    #[tokio::test]
+    async fn handle_get_rejects_malformed_since() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_list_changed_since().never();
+        let router = build_router(music_repo);
+
+        let response = router
+            .oneshot(sync_request("/sync?since=yesterday", None))
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 }
@@ -1,22 +1,35 @@
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, HeaderValue, StatusCode},
+};
+use chrono::{Duration, Utc};
+use domain::{
+    entity::grade::Grade,
+    repository::record::{RecordCursor, RecordFilter, RecordQuery, RecordSort, SortOrder},
 };
 use tracing::{info, instrument};
-use usecase::model::user::UserRecordSubmissionDto;
+use usecase::model::user::{HistoryBucket, UserRecordSubmissionDto};
+use uuid::Uuid;
 
 use crate::{
     error::AppError,
+    middleware::{auth::Caller, authorization::Role},
     model::user::{
-        CreditsIncrementResponse, FindUserQuery, RegisterUserRequest, UpdateUserRequest,
-        UserDataResponse, UserPlayOptionRequest, UserPlayOptionResponse, UserRecordRequest,
-        UserRecordResponse,
+        CreditsIncrementResponse, FindUserQuery, HistoryQuery, PlaysQuery, RecordsQuery,
+        RegisterUserRequest, UpdateUserRequest, UserDataResponse, UserHistoryResponse,
+        UserPlayOptionRequest, UserPlayOptionResponse, UserPlayPageResponse, UserProfileResponse,
+        UserRatingBreakdownResponse, UserRecordDetailResponse, UserRecordRequest,
+        UserRecordResponse, parse_clear_type, parse_history_bucket,
     },
+    route::ranking::parse_sheet_filter,
 };
 
 type AppResult<T> = Result<T, AppError>;
 
+/// Card stations register new cards.
+pub const POST_ROLES: &[Role] = &[Role::Station, Role::Admin];
+
 #[instrument(skip(state, request), fields(card = %request.card))]
 pub async fn handle_post(
     State(state): State<crate::state::State>,
@@ -28,6 +41,9 @@ pub async fn handle_post(
     Ok((StatusCode::CREATED, Json(user_data.into())))
 }
 
+/// Cabinets and stations look users up by the card they read.
+pub const GET_ROLES: &[Role] = &[Role::Cabinet, Role::Station, Role::Admin];
+
 #[instrument(skip(state, query), fields(card = %query.card))]
 pub async fn handle_get(
     State(state): State<crate::state::State>,
@@ -39,30 +55,266 @@ pub async fn handle_get(
     Ok(Json(user_data.into()))
 }
 
-#[instrument(skip(state), fields(user_id = %user_id))]
+/// Credits are consumed by the cabinet being played.
+pub const INCREMENT_CREDITS_ROLES: &[Role] = &[Role::Cabinet];
+
+#[instrument(skip(state, caller), fields(user_id = %user_id, client_id = %caller.client_id))]
 pub async fn handle_increment_credits(
     State(state): State<crate::state::State>,
+    Extension(caller): Extension<Caller>,
     Path(user_id): Path<String>,
 ) -> AppResult<Json<CreditsIncrementResponse>> {
     info!("Increment credits request received");
-    let result = state.usecases.user.increment_credits(user_id).await?;
+    let result = state
+        .usecases
+        .user
+        .increment_credits(user_id, caller.client_id)
+        .await?;
     info!(credits = result.credits, "Credits incremented successfully");
     Ok(Json(result.into()))
 }
 
-#[instrument(skip(state), fields(user_id = %user_id))]
+/// Records are read by cabinets and by the user themselves on the web.
+pub const GET_RECORDS_ROLES: &[Role] = &[Role::Cabinet, Role::WebUser, Role::Admin];
+
+/// Upper bound for the `limit` of a records page.
+const MAX_RECORDS_LIMIT: u64 = 100;
+
+/// Set on a records response, to the cursor of the next page, when more records follow.
+pub const NEXT_CURSOR_HEADER: &str = "XLAIR-Next-Cursor";
+
+#[instrument(skip(state, query), fields(user_id = %user_id))]
 pub async fn handle_get_records(
     State(state): State<crate::state::State>,
     Path(user_id): Path<String>,
-) -> AppResult<Json<Vec<UserRecordResponse>>> {
+    Query(query): Query<RecordsQuery>,
+) -> AppResult<(HeaderMap, Json<Vec<UserRecordDetailResponse>>)> {
     info!("Get user records request received");
-    let records = state.usecases.user.list_records(user_id.clone()).await?;
-    info!(count = records.len(), "User records retrieved successfully");
-    let response: Vec<UserRecordResponse> =
-        records.into_iter().map(UserRecordResponse::from).collect();
-    Ok(Json(response))
+    let query = record_query(query)?;
+    let page = state
+        .usecases
+        .user
+        .list_records(user_id.clone(), query)
+        .await?;
+    info!(
+        count = page.records.len(),
+        has_next = page.next_cursor.is_some(),
+        "User records retrieved successfully"
+    );
+    let mut headers = HeaderMap::new();
+    if let Some(cursor) = page.next_cursor {
+        let value = HeaderValue::from_str(&cursor)
+            .map_err(|err| AppError::new(StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?;
+        headers.insert(NEXT_CURSOR_HEADER, value);
+    }
+    let response: Vec<UserRecordDetailResponse> = page
+        .records
+        .into_iter()
+        .map(UserRecordDetailResponse::from)
+        .collect();
+    Ok((headers, Json(response)))
 }
 
+fn record_query(query: RecordsQuery) -> AppResult<RecordQuery> {
+    let bad_request = |message: String| AppError::new(StatusCode::BAD_REQUEST, message);
+
+    let sheet = parse_sheet_filter(
+        query.difficulty.as_deref(),
+        query.genre.as_deref(),
+        query.min_level,
+        query.max_level,
+    )?;
+    let clear_type = query
+        .clear_type
+        .as_deref()
+        .map(parse_clear_type)
+        .transpose()
+        .map_err(bad_request)?;
+    if let Some(music_id) = &query.music_id
+        && Uuid::parse_str(music_id).is_err()
+    {
+        return Err(bad_request(format!("Invalid musicId: {music_id}")));
+    }
+    let min_grade = query
+        .min_grade
+        .as_deref()
+        .map(str::parse::<Grade>)
+        .transpose()
+        .map_err(|err| bad_request(err.to_string()))?;
+    let sort = match query.sort.as_deref() {
+        None | Some("updatedAt") => RecordSort::UpdatedAt,
+        Some("score") => RecordSort::Score,
+        Some("level") => RecordSort::Level,
+        Some("playCount") => RecordSort::PlayCount,
+        Some(other) => return Err(bad_request(format!("Unsupported sort: {other}"))),
+    };
+    let order = match query.order.as_deref() {
+        None | Some("asc") => SortOrder::Ascending,
+        Some("desc") => SortOrder::Descending,
+        Some(other) => return Err(bad_request(format!("Unsupported order: {other}"))),
+    };
+    let after = query
+        .cursor
+        .as_deref()
+        .map(|cursor| {
+            cursor
+                .parse::<RecordCursor>()
+                .ok()
+                .filter(|parsed| Uuid::parse_str(&parsed.record_id).is_ok())
+                .ok_or_else(|| bad_request(format!("Invalid cursor: {cursor}")))
+        })
+        .transpose()?;
+    if let Some(limit) = query.limit
+        && !(1..=MAX_RECORDS_LIMIT).contains(&limit)
+    {
+        return Err(bad_request(format!(
+            "limit must be between 1 and {MAX_RECORDS_LIMIT}"
+        )));
+    }
+
+    Ok(RecordQuery {
+        filter: RecordFilter {
+            sheet,
+            clear_type,
+            music_id: query.music_id,
+            min_grade,
+        },
+        sort,
+        order,
+        after,
+        limit: query.limit,
+    })
+}
+
+/// The rating breakdown is derived from records, so it shares their audience.
+pub const GET_RATING_ROLES: &[Role] = &[Role::Cabinet, Role::WebUser, Role::Admin];
+
+#[instrument(skip(state), fields(user_id = %user_id))]
+pub async fn handle_get_rating(
+    State(state): State<crate::state::State>,
+    Path(user_id): Path<String>,
+) -> AppResult<Json<UserRatingBreakdownResponse>> {
+    info!("Get user rating breakdown request received");
+    let breakdown = state
+        .usecases
+        .user
+        .rating_breakdown(user_id.clone())
+        .await?;
+    info!(
+        rating = breakdown.rating,
+        counted = breakdown.best.len(),
+        "User rating breakdown retrieved successfully"
+    );
+    Ok(Json(breakdown.into()))
+}
+
+/// Profile pages are public, so this route is served without a required API key. Private
+/// profiles are shown only to the user themselves and to admins.
+#[instrument(skip(state, caller), fields(user_id = %user_id))]
+pub async fn handle_get_profile(
+    State(state): State<crate::state::State>,
+    caller: Option<Extension<Caller>>,
+    Path(user_id): Path<String>,
+) -> AppResult<Json<UserProfileResponse>> {
+    info!("Get user profile request received");
+    let include_private = caller.is_some_and(|Extension(caller)| {
+        Role::Admin.is_granted_to(&caller)
+            || caller
+                .acting_user
+                .as_ref()
+                .is_some_and(|user| user.user_id == user_id)
+    });
+    let profile = state
+        .usecases
+        .user
+        .profile(user_id.clone(), include_private)
+        .await?;
+    info!(
+        record_count = profile.record_count,
+        "User profile retrieved successfully"
+    );
+    Ok(Json(profile.into()))
+}
+
+/// Progress history is visible wherever records are.
+pub const GET_HISTORY_ROLES: &[Role] = &[Role::Cabinet, Role::WebUser, Role::Admin];
+
+const DEFAULT_HISTORY_DAYS: i64 = 90;
+
+#[instrument(skip(state, query), fields(user_id = %user_id))]
+pub async fn handle_get_history(
+    State(state): State<crate::state::State>,
+    Path(user_id): Path<String>,
+    Query(query): Query<HistoryQuery>,
+) -> AppResult<Json<UserHistoryResponse>> {
+    info!("Get user history request received");
+    let bucket = match query.bucket.as_deref() {
+        None => HistoryBucket::Daily,
+        Some(value) => parse_history_bucket(value).ok_or_else(|| {
+            AppError::new(StatusCode::BAD_REQUEST, format!("Invalid bucket: {value}"))
+        })?,
+    };
+    let to = query.to.unwrap_or_else(|| Utc::now().date_naive());
+    let from = query
+        .from
+        .unwrap_or_else(|| to - Duration::days(DEFAULT_HISTORY_DAYS - 1));
+    if from > to {
+        return Err(AppError::new(
+            StatusCode::BAD_REQUEST,
+            "from must not be after to".to_owned(),
+        ));
+    }
+
+    let history = state
+        .usecases
+        .user
+        .progress_history(user_id.clone(), from, to, bucket)
+        .await?;
+    info!(
+        count = history.points.len(),
+        "User history retrieved successfully"
+    );
+    Ok(Json(UserHistoryResponse::new(history, from, to)))
+}
+
+/// Play history is visible wherever records are.
+pub const GET_PLAYS_ROLES: &[Role] = &[Role::Cabinet, Role::WebUser, Role::Admin];
+
+const DEFAULT_PLAYS_LIMIT: u64 = 20;
+const MAX_PLAYS_LIMIT: u64 = 100;
+
+#[instrument(skip(state, query), fields(user_id = %user_id))]
+pub async fn handle_get_plays(
+    State(state): State<crate::state::State>,
+    Path(user_id): Path<String>,
+    Query(query): Query<PlaysQuery>,
+) -> AppResult<Json<UserPlayPageResponse>> {
+    info!("Get user plays request received");
+    let limit = query.limit.unwrap_or(DEFAULT_PLAYS_LIMIT);
+    if !(1..=MAX_PLAYS_LIMIT).contains(&limit) {
+        return Err(AppError::new(
+            StatusCode::BAD_REQUEST,
+            format!("limit must be between 1 and {MAX_PLAYS_LIMIT}"),
+        ));
+    }
+    let offset = query.offset.unwrap_or(0);
+
+    let page = state
+        .usecases
+        .user
+        .list_plays(user_id.clone(), limit, offset)
+        .await?;
+    info!(
+        count = page.plays.len(),
+        total = page.total,
+        "User plays retrieved successfully"
+    );
+    Ok(Json(UserPlayPageResponse::new(page, limit, offset)))
+}
+
+/// Profiles are edited at stations or by the user themselves on the web.
+pub const UPDATE_USER_ROLES: &[Role] = &[Role::Station, Role::WebUser, Role::Admin];
+
 #[instrument(skip(state, request), fields(user_id = %user_id))]
 pub async fn handle_update_user(
     State(state): State<crate::state::State>,
@@ -79,6 +331,9 @@ pub async fn handle_update_user(
     Ok(Json(user_data.into()))
 }
 
+/// Play options are loaded by cabinets and by the user themselves on the web.
+pub const GET_PLAY_OPTION_ROLES: &[Role] = &[Role::Cabinet, Role::WebUser];
+
 #[instrument(skip(state), fields(user_id = %user_id))]
 pub async fn handle_get_play_option(
     State(state): State<crate::state::State>,
@@ -90,6 +345,9 @@ pub async fn handle_get_play_option(
     Ok(Json(option.into()))
 }
 
+/// Play options are saved by cabinets and by the user themselves on the web.
+pub const POST_PLAY_OPTION_ROLES: &[Role] = &[Role::Cabinet, Role::WebUser];
+
 #[instrument(skip(state, request), fields(user_id = %user_id))]
 pub async fn handle_post_play_option(
     State(state): State<crate::state::State>,
@@ -106,12 +364,19 @@ pub async fn handle_post_play_option(
     Ok(Json(dto.into()))
 }
 
-#[instrument(skip(state, payload), fields(user_id = %user_id))]
+/// Only the cabinet the chart was played on submits results.
+pub const POST_RECORDS_ROLES: &[Role] = &[Role::Cabinet];
+
+/// Set on a record submission response, to the level reached, when the batch levelled the user up.
+pub const LEVEL_UP_HEADER: &str = "XLAIR-Level-Up";
+
+#[instrument(skip(state, caller, payload), fields(user_id = %user_id, client_id = %caller.client_id))]
 pub async fn handle_post_records(
     State(state): State<crate::state::State>,
+    Extension(caller): Extension<Caller>,
     Path(user_id): Path<String>,
     Json(payload): Json<Vec<UserRecordRequest>>,
-) -> AppResult<(StatusCode, Json<Vec<UserRecordResponse>>)> {
+) -> AppResult<(StatusCode, HeaderMap, Json<Vec<UserRecordResponse>>)> {
     info!(
         count = payload.len(),
         "Submit user records request received"
@@ -133,18 +398,25 @@ pub async fn handle_post_records(
         submissions.push(dto);
     }
 
-    let records = state
+    let result = state
         .usecases
         .user
-        .submit_records(user_id.clone(), submissions)
+        .submit_records(user_id.clone(), caller.client_id, submissions)
         .await?;
-    let response: Vec<UserRecordResponse> =
-        records.into_iter().map(UserRecordResponse::from).collect();
+    let mut headers = HeaderMap::new();
+    if let Some(level_up) = result.level_up {
+        headers.insert(LEVEL_UP_HEADER, HeaderValue::from(level_up.to));
+    }
+    let response: Vec<UserRecordResponse> = result
+        .records
+        .into_iter()
+        .map(UserRecordResponse::from)
+        .collect();
     info!(
         count = response.len(),
         "User records persisted successfully"
     );
-    Ok((StatusCode::CREATED, Json(response)))
+    Ok((StatusCode::CREATED, headers, Json(response)))
 }
 
 #[cfg(test)]
@@ -154,18 +426,25 @@ mod tests {
         body::{self, Body},
         http::Request,
     };
+    use chrono::NaiveDate;
     use domain::{
         entity::{
-            clear_type::ClearType, level::Level, rating::Rating, record::Record, user::User,
-            user_play_option::UserPlayOption,
+            clear_type::ClearType, difficulty::Difficulty, judgement::Judgement, level::Level,
+            play::Play, rating::Rating, record::Record, sheet::Sheet, user::User,
+            user_play_option::UserPlayOption, user_progress::UserProgress,
         },
         repository::{
             MockRepositories,
             music::MockMusicRepository,
-            record::{MockRecordRepository, RecordWithMetadata},
+            play::{MockPlayRepository, PlayPage},
+            ranking::RankingFilter,
+            record::{MockRecordRepository, RecordDetail, RecordDetailPage, RecordWithMetadata},
             user::UserRepositoryError,
+            xp_campaign::MockXpCampaignRepository,
         },
         testing::{
+            acting_user::{acting_user_signer, acting_user_token},
+            api_key::{CABINET_KEY, STATION_KEY, WEB_KEY, api_key_repository, client_repository},
             datetime::timestamp,
             user::{USER1, USER2},
         },
@@ -174,21 +453,79 @@ mod tests {
     use tower::ServiceExt;
 
     use super::*;
+    use crate::middleware::auth::{ACTING_USER_HEADER, API_KEY_HEADER};
+
+    const SUBMISSION_ID: &str = "0193a4c2-5e1f-7c3a-9d42-8b6f1e2a7c10";
 
     fn test_router(
         user_repo: domain::repository::user::MockUserRepository,
         record_repo: MockRecordRepository,
     ) -> Router {
-        let config = crate::config::Config::default();
+        test_router_with(
+            user_repo,
+            record_repo,
+            MockMusicRepository::new(),
+            MockPlayRepository::new(),
+        )
+    }
+
+    fn test_router_with(
+        user_repo: domain::repository::user::MockUserRepository,
+        record_repo: MockRecordRepository,
+        music_repo: MockMusicRepository,
+        play_repo: MockPlayRepository,
+    ) -> Router {
+        let config = crate::config::Config {
+            acting_user_signer: acting_user_signer(),
+            ..Default::default()
+        };
+        // No campaign is running unless a test says otherwise.
+        let mut campaign_repo = MockXpCampaignRepository::new();
+        campaign_repo
+            .expect_find_active_at()
+            .returning(|_| Box::pin(async { Ok(Vec::new()) }));
         let repositories = MockRepositories {
             user: user_repo,
             record: record_repo,
-            music: MockMusicRepository::new(),
+            music: music_repo,
+            api_key: api_key_repository(),
+            client: client_repository(),
+            play: play_repo,
+            xp_campaign: campaign_repo,
+            ..Default::default()
         };
         let state = crate::state::State::new(config, repositories);
         super::super::create_app(state)
     }
 
+    fn fresh_play_repo() -> MockPlayRepository {
+        let mut play_repo = MockPlayRepository::new();
+        play_repo
+            .expect_find_submitted_ids()
+            .returning(|_, _| Box::pin(async { Ok(Vec::new()) }));
+        play_repo
+            .expect_save_results()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        play_repo
+    }
+
+    fn sheet_music_repo() -> MockMusicRepository {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_find_sheets_by_ids().returning(|_| {
+            let sheet = Sheet::new(
+                "sheet-1".to_owned(),
+                "music-1".to_owned(),
+                Difficulty::Hard,
+                Level::new(13, 7).expect("valid level"),
+                "Designer".to_owned(),
+                1000,
+                1_010_000,
+            );
+            Box::pin(async move { Ok(vec![sheet]) })
+        });
+        music_repo
+    }
+
     fn sample_timestamp() -> chrono::DateTime<chrono::Utc> {
         chrono::NaiveDate::from_ymd_opt(2025, 10, 26)
             .unwrap()
@@ -234,6 +571,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::post("/users")
+                    .header(API_KEY_HEADER, STATION_KEY.raw_key)
                     .header(axum::http::header::CONTENT_TYPE, "application/json")
                     .body(Body::from(payload.to_string()))
                     .unwrap(),
@@ -250,6 +588,9 @@ mod tests {
         assert_eq!(json["displayName"], USER2.display_name);
         assert_eq!(json["rating"], USER2.rating);
         assert_eq!(json["xp"], USER2.xp);
+        assert_eq!(json["level"], 7);
+        assert_eq!(json["levelXp"], 99);
+        assert_eq!(json["nextLevelXp"], 220);
         assert_eq!(json["credits"], USER2.credits);
         assert_eq!(json["isPublic"], false);
         assert_eq!(json["isAdmin"], false);
@@ -278,6 +619,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::post("/users")
+                    .header(API_KEY_HEADER, STATION_KEY.raw_key)
                     .header(axum::http::header::CONTENT_TYPE, "application/json")
                     .body(Body::from(payload.to_string()))
                     .unwrap(),
@@ -313,6 +655,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::get("/users?card=CARD-001")
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -341,6 +684,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::get("/users?card=CARD-002")
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -359,14 +703,15 @@ mod tests {
         let mut user_repo = domain::repository::user::MockUserRepository::new();
         user_repo
             .expect_increment_credits()
-            .withf(|user_id| user_id == USER1.id)
-            .returning(|_| Box::pin(async { Ok(USER1.credits + 1) }));
+            .withf(|user_id, client_id| user_id == USER1.id && client_id == CABINET_KEY.client_id)
+            .returning(|_, _| Box::pin(async { Ok(USER1.credits + 1) }));
 
         let router = test_router(user_repo, MockRecordRepository::new());
 
         let response = router
             .oneshot(
                 Request::post(format!("/users/{}/credits/increment", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -383,7 +728,7 @@ mod tests {
     #[tokio::test]
     async fn handle_increment_credits_returns_not_found() {
         let mut user_repo = domain::repository::user::MockUserRepository::new();
-        user_repo.expect_increment_credits().returning(|_| {
+        user_repo.expect_increment_credits().returning(|_, _| {
             Box::pin(async { Err(UserRepositoryError::NotFound("missing".to_owned())) })
         });
 
@@ -392,6 +737,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::post("/users/missing/credits/increment")
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -409,23 +755,35 @@ mod tests {
     async fn handle_get_records_returns_data() {
         let mut record_repo = MockRecordRepository::new();
         record_repo
-            .expect_find_by_user_id()
-            .withf(|user_id| user_id == USER1.id)
-            .returning(|_| {
+            .expect_find_details_by_user_id()
+            .withf(|user_id, query| user_id == USER1.id && *query == RecordQuery::default())
+            .returning(|_, _| {
+                let record = Record::new(
+                    "record-1".to_owned(),
+                    USER1.id.to_owned(),
+                    "sheet-1".to_owned(),
+                    1_000_000,
+                    ClearType::Clear,
+                    Judgement::default(),
+                    0,
+                    5,
+                    chrono::NaiveDate::from_ymd_opt(2025, 10, 26)
+                        .unwrap()
+                        .and_hms_opt(12, 0, 0)
+                        .unwrap()
+                        .and_utc(),
+                );
                 Box::pin(async move {
-                    Ok(vec![Record::new(
-                        "record-1".to_owned(),
-                        USER1.id.to_owned(),
-                        "sheet-1".to_owned(),
-                        1_000_000,
-                        ClearType::Clear,
-                        5,
-                        chrono::NaiveDate::from_ymd_opt(2025, 10, 26)
-                            .unwrap()
-                            .and_hms_opt(12, 0, 0)
-                            .unwrap()
-                            .and_utc(),
-                    )])
+                    Ok(RecordDetailPage {
+                        records: vec![RecordDetail {
+                            record,
+                            music_id: "music-1".to_owned(),
+                            music_title: "Music 1".to_owned(),
+                            difficulty: Difficulty::Hard,
+                            level: Level::new(13, 7).unwrap(),
+                        }],
+                        next_cursor: None,
+                    })
                 })
             });
 
@@ -437,6 +795,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::get(format!("/users/{}/records", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -444,26 +803,292 @@ mod tests {
             .expect("handler should respond");
 
         assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(NEXT_CURSOR_HEADER).is_none());
 
         let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
         let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
         assert!(json.is_array());
         assert_eq!(json[0]["id"], "record-1");
+        assert_eq!(json[0]["grade"], "S");
         assert_eq!(json[0]["clearType"], "clear");
+        assert_eq!(json[0]["musicId"], "music-1");
+        assert_eq!(json[0]["musicTitle"], "Music 1");
+        assert_eq!(json[0]["difficulty"], "hard");
+        assert_eq!(json[0]["level"], 13.7);
     }
 
     #[tokio::test]
-    async fn handle_get_records_returns_not_found() {
+    async fn handle_get_records_applies_filters_and_returns_next_cursor() {
+        let cursor = RecordCursor {
+            key: 1_000_000,
+            record_id: SUBMISSION_ID.to_owned(),
+        };
+        let expected = RecordQuery {
+            filter: RecordFilter {
+                sheet: RankingFilter {
+                    difficulty: Some(Difficulty::Hard),
+                    min_level: Some(Level::new(13, 0).unwrap()),
+                    ..Default::default()
+                },
+                clear_type: Some(ClearType::FullCombo),
+                music_id: None,
+                min_grade: Some(Grade::S),
+            },
+            sort: RecordSort::Score,
+            order: SortOrder::Descending,
+            after: Some(cursor.clone()),
+            limit: Some(1),
+        };
         let mut record_repo = MockRecordRepository::new();
-        record_repo.expect_find_by_user_id().returning(|_| {
-            Box::pin(async {
-                Err(
-                    domain::repository::record::RecordRepositoryError::UserNotFound(
-                        "missing".to_owned(),
-                    ),
+        record_repo
+            .expect_find_details_by_user_id()
+            .withf(move |_, query| *query == expected)
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(RecordDetailPage {
+                        records: Vec::new(),
+                        next_cursor: Some(RecordCursor {
+                            key: 990_000,
+                            record_id: SUBMISSION_ID.to_owned(),
+                        }),
+                    })
+                })
+            });
+
+        let router = test_router(
+            domain::repository::user::MockUserRepository::new(),
+            record_repo,
+        );
+
+        let response = router
+            .oneshot(
+                Request::get(format!(
+                    "/users/{}/records?difficulty=hard&minLevel=13&clearType=fullcombo&minGrade=s\
+                     &sort=score&order=desc&limit=1&cursor={cursor}",
+                    USER1.id
+                ))
+                .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                .body(Body::empty())
+                .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[NEXT_CURSOR_HEADER],
+            format!("990000.{SUBMISSION_ID}")
+        );
+    }
+
+    #[tokio::test]
+    async fn handle_get_records_rejects_invalid_cursor_and_limit() {
+        for query in [
+            "cursor=12.not-a-uuid",
+            "cursor=abc",
+            "limit=0",
+            "sort=title",
+        ] {
+            let router = test_router(
+                domain::repository::user::MockUserRepository::new(),
+                MockRecordRepository::new(),
+            );
+
+            let response = router
+                .oneshot(
+                    Request::get(format!("/users/{}/records?{query}", USER1.id))
+                        .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                        .body(Body::empty())
+                        .unwrap(),
                 )
-            })
+                .await
+                .expect("handler should respond");
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{query}");
+        }
+    }
+
+    #[tokio::test]
+    async fn handle_get_records_rejects_unknown_min_grade() {
+        let router = test_router(
+            domain::repository::user::MockUserRepository::new(),
+            MockRecordRepository::new(),
+        );
+
+        let response = router
+            .oneshot(
+                Request::get(format!("/users/{}/records?minGrade=AAA", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn handle_get_rating_lists_counted_and_near_miss_records() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_with_metadata_by_user_id()
+            .withf(|user_id| user_id == USER1.id)
+            .returning(|_| {
+                let entry = |sheet_id: &str, level: Level, score: u32, is_test: bool| {
+                    let record = Record::new_from_submission(
+                        USER1.id.to_owned(),
+                        sheet_id.to_owned(),
+                        score,
+                        ClearType::Clear,
+                        Judgement::default(),
+                        0,
+                        sample_timestamp(),
+                    );
+                    RecordWithMetadata::new(record, Difficulty::Hard, level, is_test)
+                };
+                let records = vec![
+                    entry("sheet-1", Level::new(13, 7).unwrap(), 1_000_000, false),
+                    entry("sheet-2", Level::new(12, 0).unwrap(), 980_000, false),
+                    entry("sheet-3", Level::new(14, 0).unwrap(), 900_000, false),
+                    entry("sheet-4", Level::new(10, 0).unwrap(), 800_000, false),
+                    entry("sheet-5", Level::new(15, 0).unwrap(), 1_090_000, true),
+                ];
+                Box::pin(async move { Ok(records) })
+            });
+
+        let router = test_router(
+            domain::repository::user::MockUserRepository::new(),
+            record_repo,
+        );
+
+        let response = router
+            .oneshot(
+                Request::get(format!("/users/{}/rating", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["rating"], 1383);
+        assert_eq!(json["bestCount"], 3);
+        assert_eq!(json["best"].as_array().unwrap().len(), 3);
+        assert_eq!(json["best"][0]["sheetId"], "sheet-1");
+        assert_eq!(json["best"][0]["level"], 13.7);
+        assert_eq!(json["best"][0]["rating"], 1470);
+        assert_eq!(json["next"][0]["sheetId"], "sheet-4");
+        assert_eq!(json["recent"], json!([]));
+    }
+
+    fn profile_router(is_public: bool) -> Router {
+        let mut user_repo = domain::repository::user::MockUserRepository::new();
+        user_repo.expect_find_by_id().returning(move |user_id| {
+            let user =
+                (user_id == USER1.id).then(|| USER1.build(is_public, false, sample_timestamp()));
+            Box::pin(async move { Ok(user) })
         });
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_with_metadata_by_user_id()
+            .returning(|_| {
+                let record = Record::new_from_submission(
+                    USER1.id.to_owned(),
+                    "sheet-1".to_owned(),
+                    960_000,
+                    ClearType::FullCombo,
+                    Judgement::default(),
+                    0,
+                    sample_timestamp(),
+                );
+                let records = vec![RecordWithMetadata::new(
+                    record,
+                    Difficulty::Hard,
+                    Level::new(13, 7).unwrap(),
+                    false,
+                )];
+                Box::pin(async move { Ok(records) })
+            });
+        test_router(user_repo, record_repo)
+    }
+
+    #[tokio::test]
+    async fn handle_get_profile_serves_public_profiles_anonymously() {
+        let response = profile_router(true)
+            .oneshot(
+                Request::get(format!("/users/{}/profile", USER1.id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = body::to_bytes(response.into_body(), 4096).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["userId"], USER1.id);
+        assert_eq!(json["recordCount"], 1);
+        assert_eq!(json["lastPlayedAt"], sample_timestamp().to_rfc3339());
+        assert_eq!(json["clearsByDifficulty"][2]["difficulty"], "hard");
+        assert_eq!(json["clearsByDifficulty"][2]["counts"]["fullcombo"], 1);
+        assert_eq!(json["clearsByLevel"][0]["level"], "13+");
+        assert_eq!(
+            json["grades"],
+            json!({ "SSS": 0, "SS": 0, "S": 0, "AA": 1, "A": 0 })
+        );
+        assert_eq!(json["topRated"][0]["sheetId"], "sheet-1");
+    }
+
+    #[tokio::test]
+    async fn handle_get_profile_hides_private_profiles_from_anonymous_viewers() {
+        let response = profile_router(false)
+            .oneshot(
+                Request::get(format!("/users/{}/profile", USER1.id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn handle_get_profile_shows_private_profiles_to_their_owner() {
+        let response = profile_router(false)
+            .oneshot(
+                Request::get(format!("/users/{}/profile", USER1.id))
+                    .header(API_KEY_HEADER, WEB_KEY.raw_key)
+                    .header(ACTING_USER_HEADER, acting_user_token(USER1.id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn handle_get_records_returns_not_found() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_details_by_user_id()
+            .returning(|_, _| {
+                Box::pin(async {
+                    Err(
+                        domain::repository::record::RecordRepositoryError::UserNotFound(
+                            "missing".to_owned(),
+                        ),
+                    )
+                })
+            });
 
         let router = test_router(
             domain::repository::user::MockUserRepository::new(),
@@ -473,6 +1098,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::get("/users/missing/records")
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -486,6 +1112,92 @@ mod tests {
         assert!(json["error"].as_str().unwrap().contains("User not found"));
     }
 
+    #[tokio::test]
+    async fn handle_get_history_buckets_snapshots_weekly() {
+        let mut user_repo = domain::repository::user::MockUserRepository::new();
+        user_repo
+            .expect_find_by_id()
+            .withf(|user_id| user_id == USER1.id)
+            .returning(|_| {
+                let user = User::new(
+                    USER1.id.to_owned(),
+                    USER1.card.to_owned(),
+                    USER1.display_name.to_owned(),
+                    Rating::new(USER1.rating),
+                    USER1.xp,
+                    USER1.credits,
+                    true,
+                    false,
+                    timestamp(2025, 10, 21, 15, 0, 0),
+                );
+                Box::pin(async move { Ok(Some(user)) })
+            });
+        user_repo
+            .expect_find_progress()
+            .withf(|user_id, from, to| {
+                user_id == USER1.id
+                    && *from == NaiveDate::from_ymd_opt(2025, 11, 3).unwrap()
+                    && *to == NaiveDate::from_ymd_opt(2025, 11, 16).unwrap()
+            })
+            .returning(|_, _, _| {
+                let day = |d| NaiveDate::from_ymd_opt(2025, 11, d).unwrap();
+                let progress = vec![
+                    UserProgress::new(USER1.id.to_owned(), day(4), 1200, 300),
+                    UserProgress::new(USER1.id.to_owned(), day(6), 1250, 400),
+                    UserProgress::new(USER1.id.to_owned(), day(12), 1300, 500),
+                ];
+                Box::pin(async move { Ok(progress) })
+            });
+
+        let router = test_router(user_repo, MockRecordRepository::new());
+
+        let response = router
+            .oneshot(
+                Request::get(format!(
+                    "/users/{}/history?from=2025-11-03&to=2025-11-16&bucket=weekly",
+                    USER1.id
+                ))
+                .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                .body(Body::empty())
+                .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["bucket"], "weekly");
+        assert_eq!(
+            json["points"],
+            json!([
+                { "date": "2025-11-03", "rating": 1250, "xp": 400 },
+                { "date": "2025-11-10", "rating": 1300, "xp": 500 },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn handle_get_history_rejects_unknown_bucket() {
+        let router = test_router(
+            domain::repository::user::MockUserRepository::new(),
+            MockRecordRepository::new(),
+        );
+
+        let response = router
+            .oneshot(
+                Request::get(format!("/users/{}/history?bucket=monthly", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn handle_get_play_option_returns_value() {
         let mut user_repo = domain::repository::user::MockUserRepository::new();
@@ -524,6 +1236,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::get(format!("/users/{}/options", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -577,6 +1290,7 @@ mod tests {
         let response = router
             .oneshot(
                 Request::post(format!("/users/{}/options", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .header(axum::http::header::CONTENT_TYPE, "application/json")
                     .body(Body::from(payload.to_string()))
                     .unwrap(),
@@ -596,49 +1310,36 @@ mod tests {
     async fn handle_post_records_returns_created() {
         let mut record_repo = MockRecordRepository::new();
         record_repo
-            .expect_find_by_user_id_and_sheet_ids()
-            .withf(|user_id, sheet_ids| {
-                user_id == USER1.id && sheet_ids.len() == 1 && sheet_ids[0] == "sheet-1"
+            .expect_upsert_many()
+            .withf(|upserts| {
+                upserts.len() == 1
+                    && upserts[0].record.user_id() == USER1.id
+                    && upserts[0].record.sheet_id() == "sheet-1"
+                    && upserts[0].rating == 1470
             })
-            .returning(|_, _| Box::pin(async { Ok(Vec::new()) }));
-        record_repo
-            .expect_insert()
-            .withf(|record| record.user_id() == USER1.id && record.sheet_id() == "sheet-1")
-            .returning(|record| {
-                Box::pin(async move {
-                    Ok(Record::new(
-                        record.id().to_owned(),
-                        record.user_id().to_owned(),
-                        record.sheet_id().to_owned(),
-                        *record.score(),
-                        *record.clear_type(),
-                        *record.play_count(),
-                        sample_timestamp(),
-                    ))
-                })
+            .returning(|upserts| {
+                let record = &upserts[0].record;
+                let stored = Record::new(
+                    "record-1".to_owned(),
+                    record.user_id().to_owned(),
+                    record.sheet_id().to_owned(),
+                    *record.score(),
+                    *record.clear_type(),
+                    *record.judgement(),
+                    *record.max_combo(),
+                    *record.play_count(),
+                    sample_timestamp(),
+                );
+                Box::pin(async move { Ok(vec![stored]) })
             });
         record_repo
-            .expect_find_with_metadata_by_user_id()
-            .withf(|user_id| user_id == USER1.id)
-            .returning(|_| {
-                Box::pin(async move {
-                    let level = Level::new(13, 7).expect("valid level");
-                    let record = Record::new(
-                        "record-1".to_owned(),
-                        USER1.id.to_owned(),
-                        "sheet-1".to_owned(),
-                        1_000_000,
-                        ClearType::FullCombo,
-                        1,
-                        sample_timestamp(),
-                    );
-                    Ok(vec![RecordWithMetadata::new(record, level, false)])
-                })
-            });
+            .expect_find_top_sheet_ratings()
+            .withf(|user_id, _| user_id == USER1.id)
+            .returning(|_, _| Box::pin(async { Ok(vec![1470]) }));
 
         let mut user_repo = domain::repository::user::MockUserRepository::new();
         user_repo
-            .expect_find_by_id()
+            .expect_find_by_id_for_update()
             .withf(|user_id| user_id == USER1.id)
             .returning(|_| {
                 let user = User::new(
@@ -662,19 +1363,44 @@ mod tests {
                     && *user.xp() == USER1.xp + 100
             })
             .returning(|user| Box::pin(async move { Ok(user) }));
+        user_repo
+            .expect_save_progress()
+            .withf(|progress| progress.user_id() == USER1.id && *progress.rating() == 1470)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        user_repo
+            .expect_thin_progress()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let mut play_repo = fresh_play_repo();
+        play_repo
+            .expect_append()
+            .withf(|plays| {
+                plays.len() == 1
+                    && plays[0].client_id() == CABINET_KEY.client_id
+                    && plays[0].submission_id().as_deref() == Some(SUBMISSION_ID)
+                    && *plays[0].judgement().perfect() == 1000
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
 
-        let router = test_router(user_repo, record_repo);
+        let router = test_router_with(user_repo, record_repo, sheet_music_repo(), play_repo);
 
         let payload = json!([{
+            "submissionId": SUBMISSION_ID,
             "userId": USER1.id,
             "sheetId": "sheet-1",
             "score": 1_000_000,
-            "clearType": "fullcombo"
+            "clearType": "fullcombo",
+            "judgement": { "perfect": 1000, "great": 0, "good": 0, "miss": 0 },
+            "maxCombo": 1000
         }]);
 
         let response = router
             .oneshot(
                 Request::post(format!("/users/{}/records", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .header(axum::http::header::CONTENT_TYPE, "application/json")
                     .body(Body::from(payload.to_string()))
                     .unwrap(),
@@ -683,12 +1409,16 @@ mod tests {
             .expect("handler should respond");
 
         assert_eq!(response.status(), StatusCode::CREATED);
+        // USER1's 200 XP plus the 100 earned here clears level 2 on the default curve.
+        assert_eq!(response.headers()[LEVEL_UP_HEADER], "3");
 
         let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
         let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
         assert!(json.is_array());
         assert_eq!(json[0]["sheetId"], "sheet-1");
         assert_eq!(json[0]["score"], 1_000_000);
+        assert_eq!(json[0]["judgement"]["perfect"], 1000);
+        assert_eq!(json[0]["maxCombo"], 1000);
     }
 
     #[tokio::test]
@@ -699,15 +1429,19 @@ mod tests {
         );
 
         let payload = json!([{
+            "submissionId": SUBMISSION_ID,
             "userId": "someone-else",
             "sheetId": "sheet-1",
             "score": 1_000_000,
-            "clearType": "fullcombo"
+            "clearType": "fullcombo",
+            "judgement": { "perfect": 1000, "great": 0, "good": 0, "miss": 0 },
+            "maxCombo": 1000
         }]);
 
         let response = router
             .oneshot(
                 Request::post(format!("/users/{}/records", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .header(axum::http::header::CONTENT_TYPE, "application/json")
                     .body(Body::from(payload.to_string()))
                     .unwrap(),
@@ -730,15 +1464,19 @@ mod tests {
         );
 
         let payload = json!([{
+            "submissionId": SUBMISSION_ID,
             "userId": USER1.id,
             "sheetId": "sheet-1",
             "score": 900_000,
-            "clearType": "unknown"
+            "clearType": "unknown",
+            "judgement": { "perfect": 900, "great": 0, "good": 0, "miss": 0 },
+            "maxCombo": 900
         }]);
 
         let response = router
             .oneshot(
                 Request::post(format!("/users/{}/records", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
                     .header(axum::http::header::CONTENT_TYPE, "application/json")
                     .body(Body::from(payload.to_string()))
                     .unwrap(),
@@ -757,4 +1495,226 @@ mod tests {
                 .contains("Unsupported clear type")
         );
     }
+
+    #[tokio::test]
+    async fn handle_post_records_requires_judgement() {
+        let router = test_router(
+            domain::repository::user::MockUserRepository::new(),
+            MockRecordRepository::new(),
+        );
+
+        let payload = json!([{
+            "submissionId": SUBMISSION_ID,
+            "userId": USER1.id,
+            "sheetId": "sheet-1",
+            "score": 900_000,
+            "clearType": "clear"
+        }]);
+
+        let response = router
+            .oneshot(
+                Request::post(format!("/users/{}/records", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn handle_get_plays_returns_page() {
+        let mut play_repo = MockPlayRepository::new();
+        play_repo
+            .expect_find_by_user_id()
+            .withf(|user_id, limit, offset| user_id == USER1.id && *limit == 20 && *offset == 0)
+            .returning(|_, _, _| {
+                let play = Play::new(
+                    "play-1".to_owned(),
+                    USER1.id.to_owned(),
+                    "sheet-1".to_owned(),
+                    CABINET_KEY.client_id.to_owned(),
+                    987_000,
+                    ClearType::Clear,
+                    Judgement::new(900, 80, 15, 5),
+                    sample_timestamp(),
+                    None,
+                );
+                Box::pin(async move { Ok(PlayPage::new(vec![play], 1)) })
+            });
+
+        let router = test_router_with(
+            domain::repository::user::MockUserRepository::new(),
+            MockRecordRepository::new(),
+            MockMusicRepository::new(),
+            play_repo,
+        );
+
+        let response = router
+            .oneshot(
+                Request::get(format!("/users/{}/plays", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["total"], 1);
+        assert_eq!(json["limit"], 20);
+        assert_eq!(json["offset"], 0);
+        assert_eq!(json["plays"][0]["id"], "play-1");
+        assert_eq!(json["plays"][0]["clientId"], CABINET_KEY.client_id);
+        assert_eq!(json["plays"][0]["clearType"], "clear");
+        assert_eq!(json["plays"][0]["judgement"]["miss"], 5);
+    }
+
+    #[tokio::test]
+    async fn handle_get_plays_rejects_oversized_limit() {
+        let router = test_router(
+            domain::repository::user::MockUserRepository::new(),
+            MockRecordRepository::new(),
+        );
+
+        let response = router
+            .oneshot(
+                Request::get(format!("/users/{}/plays?limit=101", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn handle_post_records_rejects_inconsistent_clear_type() {
+        let router = test_router(
+            domain::repository::user::MockUserRepository::new(),
+            MockRecordRepository::new(),
+        );
+
+        let payload = json!([{
+            "submissionId": SUBMISSION_ID,
+            "userId": USER1.id,
+            "sheetId": "sheet-1",
+            "score": 990_000,
+            "clearType": "fullcombo",
+            "judgement": { "perfect": 990, "great": 0, "good": 0, "miss": 10 },
+            "maxCombo": 500
+        }]);
+
+        let response = router
+            .oneshot(
+                Request::post(format!("/users/{}/records", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(
+            json["error"]
+                .as_str()
+                .unwrap()
+                .contains("does not match the judgement counts")
+        );
+    }
+
+    #[tokio::test]
+    async fn handle_post_records_rejects_implausible_score() {
+        let router = test_router_with(
+            domain::repository::user::MockUserRepository::new(),
+            MockRecordRepository::new(),
+            sheet_music_repo(),
+            fresh_play_repo(),
+        );
+
+        let payload = json!([{
+            "submissionId": SUBMISSION_ID,
+            "userId": USER1.id,
+            "sheetId": "sheet-1",
+            "score": 2_000_000,
+            "clearType": "perfect",
+            "judgement": { "perfect": 1000, "great": 0, "good": 0, "miss": 0 },
+            "maxCombo": 1000
+        }]);
+
+        let response = router
+            .oneshot(
+                Request::post(format!("/users/{}/records", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(
+            json["error"]
+                .as_str()
+                .unwrap()
+                .contains("exceeds the maximum")
+        );
+    }
+
+    #[tokio::test]
+    async fn handle_post_records_rejects_malformed_submission_id() {
+        let router = test_router(
+            domain::repository::user::MockUserRepository::new(),
+            MockRecordRepository::new(),
+        );
+
+        let payload = json!([{
+            "submissionId": "retry-1",
+            "userId": USER1.id,
+            "sheetId": "sheet-1",
+            "score": 900_000,
+            "clearType": "clear",
+            "judgement": { "perfect": 900, "great": 80, "good": 20, "miss": 0 },
+            "maxCombo": 1000
+        }]);
+
+        let response = router
+            .oneshot(
+                Request::post(format!("/users/{}/records", USER1.id))
+                    .header(API_KEY_HEADER, CABINET_KEY.raw_key)
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(
+            json["error"]
+                .as_str()
+                .unwrap()
+                .contains("Invalid submissionId")
+        );
+    }
 }
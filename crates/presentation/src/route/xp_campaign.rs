@@ -0,0 +1,149 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::{info, instrument};
+
+use crate::{
+    error::AppError,
+    middleware::authorization::Role,
+    model::xp_campaign::{XpCampaignRequest, XpCampaignResponse},
+};
+
+type AppResult<T> = Result<T, AppError>;
+
+/// Campaigns change what every player earns, so only operators manage them.
+pub const ROLES: &[Role] = &[Role::Admin];
+
+#[instrument(skip(state))]
+pub async fn handle_get(
+    State(state): State<crate::state::State>,
+) -> AppResult<Json<Vec<XpCampaignResponse>>> {
+    info!("List XP campaigns request received");
+    let campaigns = state.usecases.xp_campaign.list().await?;
+    info!(count = campaigns.len(), "XP campaigns listed successfully");
+    Ok(Json(campaigns.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(skip(state, request), fields(name = %request.name))]
+pub async fn handle_post(
+    State(state): State<crate::state::State>,
+    Json(request): Json<XpCampaignRequest>,
+) -> AppResult<(StatusCode, Json<XpCampaignResponse>)> {
+    info!(
+        multiplier = request.multiplier_percent,
+        "Create XP campaign request received"
+    );
+    let campaign = state.usecases.xp_campaign.create(request.into()).await?;
+    info!(campaign_id = %campaign.id, "XP campaign created successfully");
+    Ok((StatusCode::CREATED, Json(campaign.into())))
+}
+
+#[instrument(skip(state), fields(campaign_id = %campaign_id))]
+pub async fn handle_delete(
+    State(state): State<crate::state::State>,
+    Path(campaign_id): Path<String>,
+) -> AppResult<StatusCode> {
+    info!("Delete XP campaign request received");
+    state.usecases.xp_campaign.delete(campaign_id).await?;
+    info!("XP campaign deleted successfully");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router, body,
+        http::{Request, StatusCode, header},
+    };
+    use domain::{
+        entity::xp_campaign::XpCampaign,
+        repository::{MockRepositories, xp_campaign::MockXpCampaignRepository},
+        testing::api_key::{ADMIN_KEY, api_key_repository, client_repository},
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use crate::middleware::auth::API_KEY_HEADER;
+
+    fn build_router(campaign_repo: MockXpCampaignRepository) -> Router {
+        let config = crate::config::Config::default();
+        let repositories = MockRepositories {
+            api_key: api_key_repository(),
+            client: client_repository(),
+            xp_campaign: campaign_repo,
+            ..Default::default()
+        };
+        let state = crate::state::State::new(config, repositories);
+        super::super::create_app(state)
+    }
+
+    #[tokio::test]
+    async fn handle_post_creates_campaign() {
+        let mut campaign_repo = MockXpCampaignRepository::new();
+        campaign_repo
+            .expect_create()
+            .withf(|campaign| {
+                campaign.name() == "Double XP weekend" && *campaign.multiplier_percent() == 200
+            })
+            .returning(|campaign| {
+                let created = XpCampaign::new(
+                    "eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee".to_owned(),
+                    campaign.name().to_owned(),
+                    *campaign.multiplier_percent(),
+                    *campaign.starts_at(),
+                    *campaign.ends_at(),
+                );
+                Box::pin(async move { Ok(created) })
+            });
+
+        let payload = json!({
+            "name": "Double XP weekend",
+            "multiplierPercent": 200,
+            "startsAt": "2025-11-22T00:00:00+09:00",
+            "endsAt": "2025-11-24T00:00:00+09:00"
+        });
+        let response = build_router(campaign_repo)
+            .oneshot(
+                Request::post("/admin/xp-campaigns")
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(body::Body::from(payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["id"], "eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee");
+        assert_eq!(json["startsAt"], "2025-11-21T15:00:00+00:00");
+    }
+
+    #[tokio::test]
+    async fn handle_post_rejects_multipliers_below_base() {
+        let mut campaign_repo = MockXpCampaignRepository::new();
+        campaign_repo.expect_create().never();
+
+        let payload = json!({
+            "name": "Half XP",
+            "multiplierPercent": 50,
+            "startsAt": "2025-11-22T00:00:00Z",
+            "endsAt": "2025-11-24T00:00:00Z"
+        });
+        let response = build_router(campaign_repo)
+            .oneshot(
+                Request::post("/admin/xp-campaigns")
+                    .header(API_KEY_HEADER, ADMIN_KEY.raw_key)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(body::Body::from(payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .expect("handler should respond");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
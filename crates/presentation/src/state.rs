@@ -18,7 +18,14 @@ pub struct State {
 impl State {
     pub fn new(config: Config, repositories: RepositoriesImpl) -> Self {
         let repositories = Arc::new(repositories);
-        let usecases = Arc::new(usecase::Usecases::new(repositories));
+        let usecases = Arc::new(usecase::Usecases::new(
+            repositories,
+            config.rating_policy.clone(),
+            config.level_curve.clone(),
+            config.xp_policy.clone(),
+            config.ranking_cache_ttl,
+            config.acting_user_signer.clone(),
+        ));
         Self { usecases, config }
     }
 }
@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use domain::{
+    repository::{
+        Repositories,
+        api_key::{ApiKeyRepository, ApiKeyRepositoryError},
+        client::{ClientRepository, ClientRepositoryError},
+        user::{UserRepository, UserRepositoryError},
+    },
+    service::{
+        acting_user::{ActingUserSigner, ActingUserTokenError},
+        api_key::hash_api_key,
+    },
+};
+use thiserror::Error;
+use tracing::{debug, instrument, warn};
+
+use crate::model::auth::{ActingUserDto, CallerDto};
+
+#[derive(Debug, Error)]
+pub enum AuthUsecaseError {
+    #[error("Invalid API key")]
+    InvalidKey,
+    #[error("API key has been revoked")]
+    RevokedKey,
+    #[error("Invalid acting user token: {0}")]
+    InvalidActingUserToken(#[from] ActingUserTokenError),
+    #[error("Acting user not found: {user_id}")]
+    UnknownActingUser { user_id: String },
+    #[error(transparent)]
+    ApiKeyRepository(#[from] ApiKeyRepositoryError),
+    #[error(transparent)]
+    ClientRepository(#[from] ClientRepositoryError),
+    #[error(transparent)]
+    UserRepository(UserRepositoryError),
+}
+
+pub struct AuthUsecase<R: Repositories> {
+    repositories: Arc<R>,
+    acting_user_signer: Arc<ActingUserSigner>,
+}
+
+impl<R: Repositories> AuthUsecase<R> {
+    /// Acting user tokens are rejected until a signer is supplied.
+    pub fn new(repositories: Arc<R>) -> Self {
+        Self {
+            repositories,
+            acting_user_signer: Arc::default(),
+        }
+    }
+
+    pub fn with_acting_user_signer(mut self, acting_user_signer: Arc<ActingUserSigner>) -> Self {
+        self.acting_user_signer = acting_user_signer;
+        self
+    }
+
+    /// Resolves the client behind a raw API key. The key itself is never logged; lookups happen
+    /// by its hash only. Both the key and its owning client must be active.
+    #[instrument(skip(self, raw_key))]
+    pub async fn authenticate(&self, raw_key: &str) -> Result<CallerDto, AuthUsecaseError> {
+        let key_hash = hash_api_key(raw_key);
+        let key = self
+            .repositories
+            .api_key()
+            .find_by_key_hash(&key_hash)
+            .await?
+            .ok_or_else(|| {
+                warn!("Unknown API key presented");
+                AuthUsecaseError::InvalidKey
+            })?;
+
+        if !key.is_active() {
+            warn!(api_key_id = %key.id(), "Revoked API key presented");
+            return Err(AuthUsecaseError::RevokedKey);
+        }
+
+        let client = self
+            .repositories
+            .client()
+            .find_by_id(key.client_id())
+            .await?
+            .ok_or_else(|| {
+                warn!(api_key_id = %key.id(), "API key references unknown client");
+                AuthUsecaseError::InvalidKey
+            })?;
+
+        if !client.is_active() {
+            warn!(client_id = %client.id(), "API key of revoked client presented");
+            return Err(AuthUsecaseError::RevokedKey);
+        }
+
+        debug!(client_id = %client.id(), role = %client.role(), "API key authenticated");
+        Ok((client, key).into())
+    }
+
+    /// Resolves the user a web client acts for from a token its backend signed, so their
+    /// `is_admin` flag can be honoured. The user id is only trusted once the signature checks out.
+    #[instrument(skip(self, token))]
+    pub async fn resolve_acting_user(
+        &self,
+        token: &str,
+    ) -> Result<ActingUserDto, AuthUsecaseError> {
+        let user_id = self
+            .acting_user_signer
+            .verify(token, Utc::now())
+            .inspect_err(|err| warn!(%err, "Acting user token rejected"))?;
+        let user_id = user_id.as_str();
+        let user = match self.repositories.user().find_by_id(user_id).await {
+            Ok(user) => user,
+            Err(UserRepositoryError::NotFound(_)) => None,
+            Err(err) => return Err(AuthUsecaseError::UserRepository(err)),
+        };
+
+        user.map(ActingUserDto::from).ok_or_else(|| {
+            warn!(user_id = %user_id, "Unknown acting user presented");
+            AuthUsecaseError::UnknownActingUser {
+                user_id: user_id.to_owned(),
+            }
+        })
+    }
+}
+
+impl<R: Repositories> Clone for AuthUsecase<R> {
+    fn clone(&self) -> Self {
+        Self {
+            repositories: Arc::clone(&self.repositories),
+            acting_user_signer: Arc::clone(&self.acting_user_signer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use domain::{
+        entity::client_role::ClientRole,
+        repository::{
+            MockRepositories, api_key::MockApiKeyRepository, client::MockClientRepository,
+            user::MockUserRepository,
+        },
+        testing::{
+            acting_user::{acting_user_signer, acting_user_token},
+            api_key::{CABINET_KEY, STATION_KEY, api_key_repository, client_repository},
+            datetime::sample_timestamp,
+            user::USER1,
+        },
+    };
+
+    use super::*;
+
+    fn build_usecase(
+        api_key_repo: MockApiKeyRepository,
+        client_repo: MockClientRepository,
+    ) -> AuthUsecase<MockRepositories> {
+        build_usecase_with_users(api_key_repo, client_repo, MockUserRepository::new())
+    }
+
+    fn build_usecase_with_users(
+        api_key_repo: MockApiKeyRepository,
+        client_repo: MockClientRepository,
+        user_repo: MockUserRepository,
+    ) -> AuthUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            user: user_repo,
+            api_key: api_key_repo,
+            client: client_repo,
+            ..Default::default()
+        };
+        AuthUsecase::new(Arc::new(repositories))
+            .with_acting_user_signer(Arc::new(acting_user_signer()))
+    }
+
+    #[tokio::test]
+    async fn authenticate_returns_caller_for_active_key() {
+        let usecase = build_usecase(api_key_repository(), client_repository());
+
+        let caller = usecase
+            .authenticate(CABINET_KEY.raw_key)
+            .await
+            .expect("should authenticate");
+
+        assert_eq!(caller.client_id, CABINET_KEY.client_id);
+        assert_eq!(caller.api_key_id, CABINET_KEY.id);
+        assert_eq!(caller.role, ClientRole::Cabinet);
+    }
+
+    #[tokio::test]
+    async fn authenticate_rejects_unknown_key() {
+        let usecase = build_usecase(api_key_repository(), client_repository());
+
+        let err = usecase
+            .authenticate("not-a-key")
+            .await
+            .expect_err("should reject");
+
+        assert!(matches!(err, AuthUsecaseError::InvalidKey));
+    }
+
+    #[tokio::test]
+    async fn authenticate_rejects_revoked_key() {
+        let mut api_key_repo = MockApiKeyRepository::new();
+        api_key_repo.expect_find_by_key_hash().returning(|_| {
+            let key = STATION_KEY.build(Some(sample_timestamp()));
+            Box::pin(async move { Ok(Some(key)) })
+        });
+        let usecase = build_usecase(api_key_repo, MockClientRepository::new());
+
+        let err = usecase
+            .authenticate(STATION_KEY.raw_key)
+            .await
+            .expect_err("should reject");
+
+        assert!(matches!(err, AuthUsecaseError::RevokedKey));
+    }
+
+    #[tokio::test]
+    async fn authenticate_rejects_key_of_revoked_client() {
+        let mut client_repo = MockClientRepository::new();
+        client_repo.expect_find_by_id().returning(|_| {
+            let client = CABINET_KEY.client(Some(sample_timestamp()));
+            Box::pin(async move { Ok(Some(client)) })
+        });
+        let usecase = build_usecase(api_key_repository(), client_repo);
+
+        let err = usecase
+            .authenticate(CABINET_KEY.raw_key)
+            .await
+            .expect_err("should reject");
+
+        assert!(matches!(err, AuthUsecaseError::RevokedKey));
+    }
+
+    #[tokio::test]
+    async fn resolve_acting_user_reports_admin_flag() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_by_id()
+            .withf(|user_id| user_id == USER1.id)
+            .returning(|_| {
+                let user = USER1.build(true, true, sample_timestamp());
+                Box::pin(async move { Ok(Some(user)) })
+            });
+        let usecase = build_usecase_with_users(
+            MockApiKeyRepository::new(),
+            MockClientRepository::new(),
+            user_repo,
+        );
+
+        let acting = usecase
+            .resolve_acting_user(&acting_user_token(USER1.id))
+            .await
+            .expect("should resolve");
+
+        assert_eq!(acting.user_id, USER1.id);
+        assert!(acting.is_admin);
+    }
+
+    #[tokio::test]
+    async fn resolve_acting_user_rejects_malformed_id() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id().returning(|user_id| {
+            let user_id = user_id.to_owned();
+            Box::pin(async move { Err(UserRepositoryError::NotFound(user_id)) })
+        });
+        let usecase = build_usecase_with_users(
+            MockApiKeyRepository::new(),
+            MockClientRepository::new(),
+            user_repo,
+        );
+
+        let err = usecase
+            .resolve_acting_user(&acting_user_token("not-a-uuid"))
+            .await
+            .expect_err("should reject");
+
+        assert!(
+            matches!(err, AuthUsecaseError::UnknownActingUser { user_id } if user_id == "not-a-uuid")
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_acting_user_rejects_unsigned_user_ids() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id().never();
+        let usecase = build_usecase_with_users(
+            MockApiKeyRepository::new(),
+            MockClientRepository::new(),
+            user_repo,
+        );
+
+        let err = usecase
+            .resolve_acting_user(USER1.id)
+            .await
+            .expect_err("should reject");
+
+        assert!(matches!(
+            err,
+            AuthUsecaseError::InvalidActingUserToken(ActingUserTokenError::Malformed)
+        ));
+    }
+}
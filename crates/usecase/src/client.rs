@@ -0,0 +1,237 @@
+use std::sync::Arc;
+
+use domain::{
+    entity::client::Client,
+    repository::{
+        Repositories,
+        client::{ClientRepository, ClientRepositoryError},
+    },
+    service::api_key::{generate_api_key, hash_api_key},
+};
+use thiserror::Error;
+use tracing::{debug, info, instrument};
+
+use crate::model::client::{ClientDto, ClientKeyDto, ClientRegisterDto};
+
+#[derive(Debug, Error)]
+pub enum ClientUsecaseError {
+    #[error("Client not found for id: {client_id}")]
+    NotFoundById { client_id: String },
+    #[error("Client has been revoked: {client_id}")]
+    Revoked { client_id: String },
+    #[error(transparent)]
+    ClientRepositoryError(ClientRepositoryError),
+}
+
+impl From<ClientRepositoryError> for ClientUsecaseError {
+    fn from(err: ClientRepositoryError) -> Self {
+        match err {
+            ClientRepositoryError::NotFound(client_id) => Self::NotFoundById { client_id },
+            err => Self::ClientRepositoryError(err),
+        }
+    }
+}
+
+pub struct ClientUsecase<R: Repositories> {
+    repositories: Arc<R>,
+}
+
+impl<R: Repositories> ClientUsecase<R> {
+    pub fn new(repositories: Arc<R>) -> Self {
+        Self { repositories }
+    }
+
+    /// Registers a machine and issues its first API key.
+    #[instrument(skip(self, dto), fields(role = %dto.role, label = %dto.label))]
+    pub async fn register(
+        &self,
+        dto: ClientRegisterDto,
+    ) -> Result<ClientKeyDto, ClientUsecaseError> {
+        debug!("Registering client via usecase");
+        let client = Client::new_temporary(dto.role, dto.label);
+        let raw_key = generate_api_key();
+
+        let created = self
+            .repositories
+            .client()
+            .create(client, hash_api_key(&raw_key))
+            .await?;
+        info!(client_id = %created.id(), "Client registered");
+
+        Ok(ClientKeyDto::new(created.into(), raw_key))
+    }
+
+    /// Replaces every active key of the client with a newly generated one.
+    #[instrument(skip(self), fields(client_id = %client_id))]
+    pub async fn rotate_key(&self, client_id: String) -> Result<ClientKeyDto, ClientUsecaseError> {
+        let client = self.find_active(&client_id).await?;
+        let raw_key = generate_api_key();
+
+        self.repositories
+            .client()
+            .rotate_key(&client_id, hash_api_key(&raw_key))
+            .await?;
+        info!("Client API key rotated");
+
+        Ok(ClientKeyDto::new(client.into(), raw_key))
+    }
+
+    /// Revokes the client along with all of its keys. Revoking twice is rejected so the original
+    /// revocation timestamp is preserved.
+    #[instrument(skip(self), fields(client_id = %client_id))]
+    pub async fn revoke(&self, client_id: String) -> Result<ClientDto, ClientUsecaseError> {
+        self.find_active(&client_id).await?;
+
+        let client = self.repositories.client().revoke(&client_id).await?;
+        info!("Client revoked");
+
+        Ok(client.into())
+    }
+
+    async fn find_active(&self, client_id: &str) -> Result<Client, ClientUsecaseError> {
+        let client = self
+            .repositories
+            .client()
+            .find_by_id(client_id)
+            .await?
+            .ok_or_else(|| ClientUsecaseError::NotFoundById {
+                client_id: client_id.to_owned(),
+            })?;
+
+        if !client.is_active() {
+            return Err(ClientUsecaseError::Revoked {
+                client_id: client_id.to_owned(),
+            });
+        }
+
+        Ok(client)
+    }
+}
+
+impl<R: Repositories> Clone for ClientUsecase<R> {
+    fn clone(&self) -> Self {
+        Self {
+            repositories: Arc::clone(&self.repositories),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use domain::{
+        entity::client_role::ClientRole,
+        repository::{MockRepositories, client::MockClientRepository},
+        testing::{
+            api_key::{CABINET_KEY, STATION_KEY},
+            datetime::sample_timestamp,
+        },
+    };
+
+    use super::*;
+
+    fn build_usecase(client_repo: MockClientRepository) -> ClientUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            client: client_repo,
+            ..Default::default()
+        };
+        ClientUsecase::new(Arc::new(repositories))
+    }
+
+    #[tokio::test]
+    async fn register_issues_key_and_stores_only_its_hash() {
+        let mut client_repo = MockClientRepository::new();
+        client_repo
+            .expect_create()
+            .withf(|client, key_hash| {
+                *client.role() == ClientRole::Cabinet
+                    && client.label() == "Cabinet #9"
+                    && key_hash.len() == 64
+            })
+            .returning(|client, _| {
+                let created = Client::new(
+                    CABINET_KEY.client_id.to_owned(),
+                    *client.role(),
+                    client.label().to_owned(),
+                    None,
+                    sample_timestamp(),
+                );
+                Box::pin(async move { Ok(created) })
+            });
+        let usecase = build_usecase(client_repo);
+
+        let issued = usecase
+            .register(ClientRegisterDto::new(
+                ClientRole::Cabinet,
+                "Cabinet #9".to_owned(),
+            ))
+            .await
+            .expect("should register");
+
+        assert_eq!(issued.client.id, CABINET_KEY.client_id);
+        assert_eq!(issued.api_key.len(), 64);
+    }
+
+    #[tokio::test]
+    async fn rotate_key_persists_hash_of_returned_key() {
+        let mut client_repo = MockClientRepository::new();
+        client_repo.expect_find_by_id().returning(|_| {
+            let client = STATION_KEY.client(None);
+            Box::pin(async move { Ok(Some(client)) })
+        });
+        let (sender, receiver) = std::sync::mpsc::channel();
+        client_repo
+            .expect_rotate_key()
+            .withf(|client_id, _| client_id == STATION_KEY.client_id)
+            .returning(move |_, key_hash| {
+                sender.send(key_hash).unwrap();
+                Box::pin(async { Ok(()) })
+            });
+        let usecase = build_usecase(client_repo);
+
+        let issued = usecase
+            .rotate_key(STATION_KEY.client_id.to_owned())
+            .await
+            .expect("should rotate");
+
+        assert_eq!(receiver.recv().unwrap(), hash_api_key(&issued.api_key));
+    }
+
+    #[tokio::test]
+    async fn rotate_key_rejects_revoked_client() {
+        let mut client_repo = MockClientRepository::new();
+        client_repo.expect_find_by_id().returning(|_| {
+            let client = STATION_KEY.client(Some(sample_timestamp()));
+            Box::pin(async move { Ok(Some(client)) })
+        });
+        client_repo.expect_rotate_key().never();
+        let usecase = build_usecase(client_repo);
+
+        let err = usecase
+            .rotate_key(STATION_KEY.client_id.to_owned())
+            .await
+            .expect_err("should reject");
+
+        assert!(matches!(err, ClientUsecaseError::Revoked { .. }));
+    }
+
+    #[tokio::test]
+    async fn revoke_returns_not_found_for_unknown_client() {
+        let mut client_repo = MockClientRepository::new();
+        client_repo
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        client_repo.expect_revoke().never();
+        let usecase = build_usecase(client_repo);
+
+        let err = usecase
+            .revoke("missing".to_owned())
+            .await
+            .expect_err("should fail");
+
+        assert!(
+            matches!(err, ClientUsecaseError::NotFoundById { client_id } if client_id == "missing")
+        );
+    }
+}
@@ -1,31 +1,82 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use domain::repository::Repositories;
+use domain::{
+    repository::Repositories,
+    service::{
+        acting_user::ActingUserSigner, experience::XpPolicy, level::LevelCurve,
+        rating::RatingPolicy,
+    },
+};
 
+pub mod auth;
+pub mod client;
 pub mod model;
 pub mod music;
 pub mod ranking;
+pub mod rating;
+pub mod rival;
+pub mod season;
 pub mod statistics;
 pub mod user;
+pub mod xp_campaign;
 
 pub struct Usecases<R: Repositories> {
+    pub auth: auth::AuthUsecase<R>,
+    pub client: client::ClientUsecase<R>,
     pub user: user::UserUsecase<R>,
     pub music: music::MusicUsecase<R>,
     pub statistics: statistics::StatisticsUsecase<R>,
     pub ranking: ranking::RankingUsecase<R>,
+    pub rating: rating::RatingUsecase<R>,
+    pub xp_campaign: xp_campaign::XpCampaignUsecase<R>,
+    pub season: season::SeasonUsecase<R>,
+    pub rival: rival::RivalUsecase<R>,
 }
 
 impl<R: Repositories> Usecases<R> {
-    pub fn new(repositories: Arc<R>) -> Self {
-        let music = music::MusicUsecase::new(Arc::clone(&repositories));
-        let user = user::UserUsecase::new(Arc::clone(&repositories));
+    pub fn new(
+        repositories: Arc<R>,
+        rating_policy: RatingPolicy,
+        level_curve: LevelCurve,
+        xp_policy: XpPolicy,
+        ranking_cache_ttl: Duration,
+        acting_user_signer: ActingUserSigner,
+    ) -> Self {
+        let rating_policy = Arc::new(rating_policy);
+        let level_curve = Arc::new(level_curve);
+        let ranking_cache = Arc::new(ranking::RankingCache::new(ranking_cache_ttl));
+        let auth = auth::AuthUsecase::new(Arc::clone(&repositories))
+            .with_acting_user_signer(Arc::new(acting_user_signer));
+        let client = client::ClientUsecase::new(Arc::clone(&repositories));
+        let music = music::MusicUsecase::new(Arc::clone(&repositories))
+            .with_rating_policy(Arc::clone(&rating_policy))
+            .with_ranking_cache(Arc::clone(&ranking_cache));
+        let user = user::UserUsecase::new(Arc::clone(&repositories))
+            .with_rating_policy(Arc::clone(&rating_policy))
+            .with_level_curve(Arc::clone(&level_curve))
+            .with_xp_policy(Arc::new(xp_policy))
+            .with_ranking_cache(Arc::clone(&ranking_cache));
         let statistics = statistics::StatisticsUsecase::new(Arc::clone(&repositories));
-        let ranking = ranking::RankingUsecase::new(repositories);
+        let rating = rating::RatingUsecase::new(Arc::clone(&repositories), rating_policy)
+            .with_ranking_cache(Arc::clone(&ranking_cache));
+        let season = season::SeasonUsecase::new(Arc::clone(&repositories))
+            .with_ranking_cache(Arc::clone(&ranking_cache));
+        let ranking = ranking::RankingUsecase::new(Arc::clone(&repositories))
+            .with_level_curve(level_curve)
+            .with_cache(ranking_cache);
+        let xp_campaign = xp_campaign::XpCampaignUsecase::new(Arc::clone(&repositories));
+        let rival = rival::RivalUsecase::new(repositories);
         Self {
+            auth,
+            client,
             user,
             music,
             statistics,
             ranking,
+            rating,
+            xp_campaign,
+            season,
+            rival,
         }
     }
 }
@@ -33,10 +84,16 @@ impl<R: Repositories> Usecases<R> {
 impl<R: Repositories> Clone for Usecases<R> {
     fn clone(&self) -> Self {
         Self {
+            auth: self.auth.clone(),
+            client: self.client.clone(),
             user: self.user.clone(),
             music: self.music.clone(),
             statistics: self.statistics.clone(),
             ranking: self.ranking.clone(),
+            rating: self.rating.clone(),
+            xp_campaign: self.xp_campaign.clone(),
+            season: self.season.clone(),
+            rival: self.rival.clone(),
         }
     }
 }
@@ -0,0 +1,44 @@
+use domain::entity::{api_key::ApiKey, client::Client, client_role::ClientRole, user::User};
+
+/// Identity of an authenticated API caller.
+#[derive(Debug, Clone)]
+pub struct CallerDto {
+    pub client_id: String,
+    pub api_key_id: String,
+    pub role: ClientRole,
+}
+
+impl CallerDto {
+    pub fn new(client_id: String, api_key_id: String, role: ClientRole) -> Self {
+        Self {
+            client_id,
+            api_key_id,
+            role,
+        }
+    }
+}
+
+impl From<(Client, ApiKey)> for CallerDto {
+    fn from((client, key): (Client, ApiKey)) -> Self {
+        Self::new(client.id().to_owned(), key.id().to_owned(), *client.role())
+    }
+}
+
+/// A user on whose behalf a web client is acting.
+#[derive(Debug, Clone)]
+pub struct ActingUserDto {
+    pub user_id: String,
+    pub is_admin: bool,
+}
+
+impl ActingUserDto {
+    pub fn new(user_id: String, is_admin: bool) -> Self {
+        Self { user_id, is_admin }
+    }
+}
+
+impl From<User> for ActingUserDto {
+    fn from(user: User) -> Self {
+        Self::new(user.id().to_owned(), *user.is_admin())
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use domain::entity::{client::Client, client_role::ClientRole};
+
+#[derive(Debug)]
+pub struct ClientRegisterDto {
+    pub role: ClientRole,
+    pub label: String,
+}
+
+impl ClientRegisterDto {
+    pub fn new(role: ClientRole, label: String) -> Self {
+        Self { role, label }
+    }
+}
+
+#[derive(Debug)]
+pub struct ClientDto {
+    pub id: String,
+    pub role: ClientRole,
+    pub label: String,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ClientDto {
+    pub fn new(
+        id: String,
+        role: ClientRole,
+        label: String,
+        revoked_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            role,
+            label,
+            revoked_at,
+            created_at,
+        }
+    }
+}
+
+impl From<Client> for ClientDto {
+    fn from(client: Client) -> Self {
+        Self::new(
+            client.id().to_owned(),
+            *client.role(),
+            client.label().to_owned(),
+            *client.revoked_at(),
+            *client.created_at(),
+        )
+    }
+}
+
+/// A client together with a freshly issued raw API key. This is the only place the raw key ever
+/// leaves the server.
+#[derive(Debug)]
+pub struct ClientKeyDto {
+    pub client: ClientDto,
+    pub api_key: String,
+}
+
+impl ClientKeyDto {
+    pub fn new(client: ClientDto, api_key: String) -> Self {
+        Self { client, api_key }
+    }
+}
@@ -1,4 +1,10 @@
+pub mod auth;
+pub mod client;
 pub mod music;
 pub mod ranking;
+pub mod rating;
+pub mod rival;
+pub mod season;
 pub mod statistics;
 pub mod user;
+pub mod xp_campaign;
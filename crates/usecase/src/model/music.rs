@@ -1,5 +1,10 @@
 use chrono::{DateTime, Utc};
-use domain::entity::{difficulty::Difficulty, genre::Genre, music::Music, sheet::Sheet};
+use domain::{
+    entity::{difficulty::Difficulty, genre::Genre, music::Music, sheet::Sheet},
+    repository::music::{
+        CatalogDelta, CatalogImportReport, CatalogRowError, MusicTombstone, SheetTombstone,
+    },
+};
 
 #[derive(Debug)]
 pub struct MusicDto {
@@ -60,15 +65,20 @@ pub struct SheetDto {
     pub difficulty: Difficulty,
     pub level_value: f64,
     pub notes_designer: String,
+    pub note_count: u32,
+    pub max_score: u32,
 }
 
 impl SheetDto {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: String,
         music_id: String,
         difficulty: Difficulty,
         level_value: f64,
         notes_designer: String,
+        note_count: u32,
+        max_score: u32,
     ) -> Self {
         Self {
             id,
@@ -76,6 +86,8 @@ impl SheetDto {
             difficulty,
             level_value,
             notes_designer,
+            note_count,
+            max_score,
         }
     }
 }
@@ -88,6 +100,8 @@ impl From<Sheet> for SheetDto {
             *value.difficulty(),
             value.level().value(),
             value.notes_designer().to_owned(),
+            *value.note_count(),
+            *value.max_score(),
         )
     }
 }
@@ -103,3 +117,243 @@ impl MusicWithSheetsDto {
         Self { music, sheets }
     }
 }
+
+#[derive(Debug)]
+pub struct MusicTombstoneDto {
+    pub id: String,
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl From<MusicTombstone> for MusicTombstoneDto {
+    fn from(value: MusicTombstone) -> Self {
+        Self {
+            id: value.id,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SheetTombstoneDto {
+    pub id: String,
+    pub music_id: String,
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl From<SheetTombstone> for SheetTombstoneDto {
+    fn from(value: SheetTombstone) -> Self {
+        Self {
+            id: value.id,
+            music_id: value.music_id,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CatalogDeltaDto {
+    pub musics: Vec<MusicWithSheetsDto>,
+    pub removed_musics: Vec<MusicTombstoneDto>,
+    pub removed_sheets: Vec<SheetTombstoneDto>,
+    pub synced_at: DateTime<Utc>,
+}
+
+impl From<CatalogDelta> for CatalogDeltaDto {
+    fn from(value: CatalogDelta) -> Self {
+        Self {
+            musics: value.musics.into_iter().map(Into::into).collect(),
+            removed_musics: value.removed_musics.into_iter().map(Into::into).collect(),
+            removed_sheets: value.removed_sheets.into_iter().map(Into::into).collect(),
+            synced_at: value.synced_at,
+        }
+    }
+}
+
+/// Catalog fields supplied by an administrator. Enumerations and the level arrive in their wire
+/// form and are validated by [`MusicUsecase`](crate::music::MusicUsecase).
+#[derive(Debug)]
+pub struct MusicInputDto {
+    pub title: String,
+    pub artist: String,
+    pub bpm: f32,
+    pub genre: String,
+    pub jacket: String,
+    pub registration_date: DateTime<Utc>,
+    pub is_test: bool,
+}
+
+impl MusicInputDto {
+    pub fn new(
+        title: String,
+        artist: String,
+        bpm: f32,
+        genre: String,
+        jacket: String,
+        registration_date: DateTime<Utc>,
+        is_test: bool,
+    ) -> Self {
+        Self {
+            title,
+            artist,
+            bpm,
+            genre,
+            jacket,
+            registration_date,
+            is_test,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SheetInputDto {
+    pub music_id: String,
+    pub difficulty: String,
+    pub level: f64,
+    pub notes_designer: String,
+    pub note_count: u32,
+    pub max_score: u32,
+}
+
+impl SheetInputDto {
+    pub fn new(
+        music_id: String,
+        difficulty: String,
+        level: f64,
+        notes_designer: String,
+        note_count: u32,
+        max_score: u32,
+    ) -> Self {
+        Self {
+            music_id,
+            difficulty,
+            level,
+            notes_designer,
+            note_count,
+            max_score,
+        }
+    }
+}
+
+/// One entry of a catalog manifest, as read from the file. Every field is validated by
+/// [`MusicUsecase::import_catalog`](crate::music::MusicUsecase::import_catalog).
+#[derive(Debug)]
+pub struct CatalogEntryDto {
+    pub music: CatalogMusicDto,
+    pub sheets: Vec<CatalogSheetDto>,
+}
+
+impl CatalogEntryDto {
+    pub fn new(music: CatalogMusicDto, sheets: Vec<CatalogSheetDto>) -> Self {
+        Self { music, sheets }
+    }
+}
+
+/// An empty `id` inserts a new music.
+#[derive(Debug)]
+pub struct CatalogMusicDto {
+    pub id: String,
+    pub title: String,
+    pub artist: String,
+    pub bpm: f32,
+    pub genre: String,
+    pub jacket: String,
+    pub registration_date: String,
+    pub is_test: bool,
+}
+
+impl CatalogMusicDto {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        title: String,
+        artist: String,
+        bpm: f32,
+        genre: String,
+        jacket: String,
+        registration_date: String,
+        is_test: bool,
+    ) -> Self {
+        Self {
+            id,
+            title,
+            artist,
+            bpm,
+            genre,
+            jacket,
+            registration_date,
+            is_test,
+        }
+    }
+}
+
+/// An empty `id` inserts a new sheet and an empty `music_id` refers to the enclosing entry.
+#[derive(Debug)]
+pub struct CatalogSheetDto {
+    pub id: String,
+    pub music_id: String,
+    pub difficulty: String,
+    pub level: f64,
+    pub notes_designer: String,
+    pub note_count: u32,
+    pub max_score: u32,
+}
+
+impl CatalogSheetDto {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        music_id: String,
+        difficulty: String,
+        level: f64,
+        notes_designer: String,
+        note_count: u32,
+        max_score: u32,
+    ) -> Self {
+        Self {
+            id,
+            music_id,
+            difficulty,
+            level,
+            notes_designer,
+            note_count,
+            max_score,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CatalogRowErrorDto {
+    pub row: String,
+    pub message: String,
+}
+
+impl CatalogRowErrorDto {
+    pub fn new(row: String, message: String) -> Self {
+        Self { row, message }
+    }
+}
+
+impl From<CatalogRowError> for CatalogRowErrorDto {
+    fn from(value: CatalogRowError) -> Self {
+        Self::new(value.row.to_string(), value.message)
+    }
+}
+
+#[derive(Debug)]
+pub struct CatalogImportReportDto {
+    pub musics: usize,
+    pub sheets: usize,
+    pub failures: Vec<CatalogRowErrorDto>,
+    pub committed: bool,
+}
+
+impl From<CatalogImportReport> for CatalogImportReportDto {
+    fn from(value: CatalogImportReport) -> Self {
+        Self {
+            musics: value.musics,
+            sheets: value.sheets,
+            failures: value.failures.into_iter().map(Into::into).collect(),
+            committed: value.committed,
+        }
+    }
+}
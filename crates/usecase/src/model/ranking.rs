@@ -1,9 +1,16 @@
-#[derive(Debug)]
+use chrono::{DateTime, Utc};
+use domain::entity::{
+    grade::Grade,
+    season::{Season, SeasonPeriod},
+};
+
+#[derive(Debug, Clone)]
 pub struct SheetScoreRankingEntryDto {
     pub rank: u32,
     pub user_id: String,
     pub display_name: String,
     pub score: u32,
+    pub grade: Grade,
 }
 
 impl SheetScoreRankingEntryDto {
@@ -13,23 +20,30 @@ impl SheetScoreRankingEntryDto {
             user_id,
             display_name,
             score,
+            grade: Grade::from_score(score),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SheetScoreRankingDto {
     pub sheet_id: String,
     pub entries: Vec<SheetScoreRankingEntryDto>,
+    /// When the ranking was computed. Cached copies keep the original time.
+    pub generated_at: DateTime<Utc>,
 }
 
 impl SheetScoreRankingDto {
     pub fn new(sheet_id: String, entries: Vec<SheetScoreRankingEntryDto>) -> Self {
-        Self { sheet_id, entries }
+        Self {
+            sheet_id,
+            entries,
+            generated_at: Utc::now(),
+        }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TotalScoreRankingEntryDto {
     pub rank: u32,
     pub user_id: String,
@@ -48,18 +62,149 @@ impl TotalScoreRankingEntryDto {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TotalScoreRankingDto {
     pub entries: Vec<TotalScoreRankingEntryDto>,
+    /// Set when the ranking only covers one season.
+    pub season: Option<SeasonDto>,
+    /// When the ranking was computed. Cached copies keep the original time.
+    pub generated_at: DateTime<Utc>,
 }
 
 impl TotalScoreRankingDto {
     pub fn new(entries: Vec<TotalScoreRankingEntryDto>) -> Self {
-        Self { entries }
+        Self {
+            entries,
+            season: None,
+            generated_at: Utc::now(),
+        }
+    }
+
+    pub fn with_season(mut self, season: SeasonDto) -> Self {
+        self.season = Some(season);
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlayCountRankingEntryDto {
+    pub rank: u32,
+    pub user_id: String,
+    pub display_name: String,
+    pub plays: u64,
+}
+
+impl PlayCountRankingEntryDto {
+    pub fn new(rank: u32, user_id: String, display_name: String, plays: u64) -> Self {
+        Self {
+            rank,
+            user_id,
+            display_name,
+            plays,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlayCountRankingDto {
+    pub entries: Vec<PlayCountRankingEntryDto>,
+    /// Set when the ranking only covers one season.
+    pub season: Option<SeasonDto>,
+    /// When the ranking was computed. Cached copies keep the original time.
+    pub generated_at: DateTime<Utc>,
+}
+
+impl PlayCountRankingDto {
+    pub fn new(entries: Vec<PlayCountRankingEntryDto>) -> Self {
+        Self {
+            entries,
+            season: None,
+            generated_at: Utc::now(),
+        }
+    }
+
+    pub fn with_season(mut self, season: SeasonDto) -> Self {
+        self.season = Some(season);
+        self
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeasonDto {
+    pub id: String,
+    pub period: SeasonPeriod,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// When the final standings were stored; `None` until an operator archives the season.
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+impl SeasonDto {
+    pub fn new(
+        id: String,
+        period: SeasonPeriod,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            period,
+            starts_at,
+            ends_at,
+            archived_at,
+        }
+    }
+}
+
+impl From<&Season> for SeasonDto {
+    fn from(season: &Season) -> Self {
+        Self::new(
+            season.id().to_owned(),
+            *season.period(),
+            *season.starts_at(),
+            *season.ends_at(),
+            *season.archived_at(),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClearCountRankingEntryDto {
+    pub rank: u32,
+    pub user_id: String,
+    pub display_name: String,
+    pub clears: u64,
+}
+
+impl ClearCountRankingEntryDto {
+    pub fn new(rank: u32, user_id: String, display_name: String, clears: u64) -> Self {
+        Self {
+            rank,
+            user_id,
+            display_name,
+            clears,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClearCountRankingDto {
+    pub entries: Vec<ClearCountRankingEntryDto>,
+    /// When the ranking was computed. Cached copies keep the original time.
+    pub generated_at: DateTime<Utc>,
+}
+
+impl ClearCountRankingDto {
+    pub fn new(entries: Vec<ClearCountRankingEntryDto>) -> Self {
+        Self {
+            entries,
+            generated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct RatingRankingEntryDto {
     pub rank: u32,
     pub user_id: String,
@@ -78,43 +223,55 @@ impl RatingRankingEntryDto {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RatingRankingDto {
     pub entries: Vec<RatingRankingEntryDto>,
+    /// When the ranking was computed. Cached copies keep the original time.
+    pub generated_at: DateTime<Utc>,
 }
 
 impl RatingRankingDto {
     pub fn new(entries: Vec<RatingRankingEntryDto>) -> Self {
-        Self { entries }
+        Self {
+            entries,
+            generated_at: Utc::now(),
+        }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct XpRankingEntryDto {
     pub rank: u32,
     pub user_id: String,
     pub display_name: String,
     pub xp: u32,
+    pub level: u32,
 }
 
 impl XpRankingEntryDto {
-    pub fn new(rank: u32, user_id: String, display_name: String, xp: u32) -> Self {
+    pub fn new(rank: u32, user_id: String, display_name: String, xp: u32, level: u32) -> Self {
         Self {
             rank,
             user_id,
             display_name,
             xp,
+            level,
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct XpRankingDto {
     pub entries: Vec<XpRankingEntryDto>,
+    /// When the ranking was computed. Cached copies keep the original time.
+    pub generated_at: DateTime<Utc>,
 }
 
 impl XpRankingDto {
     pub fn new(entries: Vec<XpRankingEntryDto>) -> Self {
-        Self { entries }
+        Self {
+            entries,
+            generated_at: Utc::now(),
+        }
     }
 }
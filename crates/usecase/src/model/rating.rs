@@ -0,0 +1,11 @@
+#[derive(Debug)]
+pub struct RatingRecomputeDto {
+    pub users: u64,
+    pub updated: u64,
+}
+
+impl RatingRecomputeDto {
+    pub fn new(users: u64, updated: u64) -> Self {
+        Self { users, updated }
+    }
+}
@@ -0,0 +1,81 @@
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+use domain::entity::user::User;
+
+/// A registered rival. Private rivals stay listed so that they can be removed, but their rating is
+/// withheld.
+#[derive(Debug, Clone)]
+pub struct RivalDto {
+    pub user_id: String,
+    pub display_name: String,
+    pub rating: Option<u32>,
+    pub is_public: bool,
+    pub registered_at: DateTime<Utc>,
+}
+
+impl RivalDto {
+    pub fn new(user: &User, registered_at: DateTime<Utc>) -> Self {
+        let is_public = *user.is_public();
+        Self {
+            user_id: user.id().to_owned(),
+            display_name: user.display_name().to_owned(),
+            rating: is_public.then(|| user.rating().value()),
+            is_public,
+            registered_at,
+        }
+    }
+}
+
+/// The user or one of their public rivals, ranked by rating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RivalRankingEntryDto {
+    pub rank: u32,
+    pub user_id: String,
+    pub display_name: String,
+    pub rating: u32,
+    pub is_self: bool,
+}
+
+/// Outcome of a sheet from the user's side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonResult {
+    Win,
+    Lose,
+    Draw,
+}
+
+#[derive(Debug, Clone)]
+pub struct SheetComparisonDto {
+    pub sheet_id: String,
+    pub score: u32,
+    pub rival_score: u32,
+    pub result: ComparisonResult,
+}
+
+impl SheetComparisonDto {
+    pub fn new(sheet_id: String, score: u32, rival_score: u32) -> Self {
+        let result = match score.cmp(&rival_score) {
+            Ordering::Greater => ComparisonResult::Win,
+            Ordering::Less => ComparisonResult::Lose,
+            Ordering::Equal => ComparisonResult::Draw,
+        };
+        Self {
+            sheet_id,
+            score,
+            rival_score,
+            result,
+        }
+    }
+}
+
+/// Scores of the user against one rival on every sheet both of them have played.
+#[derive(Debug, Clone)]
+pub struct RivalComparisonDto {
+    pub rival_id: String,
+    pub display_name: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub sheets: Vec<SheetComparisonDto>,
+}
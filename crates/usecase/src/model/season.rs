@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use domain::entity::season::SeasonPeriod;
+
+#[derive(Debug)]
+pub struct SeasonCreateDto {
+    pub period: SeasonPeriod,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl SeasonCreateDto {
+    pub fn new(period: SeasonPeriod, starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> Self {
+        Self {
+            period,
+            starts_at,
+            ends_at,
+        }
+    }
+}
@@ -1,6 +1,15 @@
-use chrono::{DateTime, Utc};
-use domain::entity::{
-    clear_type::ClearType, record::Record, user::User, user_play_option::UserPlayOption,
+use chrono::{DateTime, NaiveDate, Utc};
+use domain::{
+    entity::{
+        clear_type::ClearType, difficulty::Difficulty, grade::Grade, judgement::Judgement,
+        play::Play, record::Record, submission_result::LevelUp, user::User,
+        user_play_option::UserPlayOption,
+    },
+    repository::{
+        play::PlayPage,
+        record::{RecordDetail, RecordDetailPage},
+    },
+    service::level::{LevelCurve, PlayerLevel},
 };
 
 #[derive(Debug)]
@@ -27,6 +36,7 @@ pub struct UserDataDto {
     pub display_name: String,
     pub rating: u32,
     pub xp: u32,
+    pub level: PlayerLevel,
     pub credits: u32,
     pub is_public: bool,
     pub is_admin: bool,
@@ -41,6 +51,7 @@ impl UserDataDto {
         display_name: String,
         rating: u32,
         xp: u32,
+        level: PlayerLevel,
         credits: u32,
         is_public: bool,
         is_admin: bool,
@@ -52,6 +63,7 @@ impl UserDataDto {
             display_name,
             rating,
             xp,
+            level,
             credits,
             is_public,
             is_admin,
@@ -60,14 +72,15 @@ impl UserDataDto {
     }
 }
 
-impl From<User> for UserDataDto {
-    fn from(user: User) -> Self {
+impl UserDataDto {
+    pub fn from_user(user: User, level_curve: &LevelCurve) -> Self {
         Self::new(
             user.id().to_owned(),
             user.card().to_owned(),
             user.display_name().clone(),
             user.rating().value(),
             user.xp().to_owned(),
+            level_curve.level_for(*user.xp()),
             user.credits().to_owned(),
             user.is_public().to_owned(),
             user.is_admin().to_owned(),
@@ -132,7 +145,10 @@ pub struct UserRecordDto {
     pub id: String,
     pub sheet_id: String,
     pub score: u32,
+    pub grade: Grade,
     pub clear_type: ClearType,
+    pub judgement: Judgement,
+    pub max_combo: u32,
     pub play_count: u32,
     pub updated_at: DateTime<Utc>,
 }
@@ -169,27 +185,67 @@ impl UserPlayOptionUpdateDto {
 
 #[derive(Debug, Clone)]
 pub struct UserRecordSubmissionDto {
+    /// Client-generated id that makes retrying the same submission a no-op.
+    pub submission_id: String,
     pub sheet_id: String,
     pub score: u32,
     pub clear_type: ClearType,
+    pub judgement: Judgement,
+    pub max_combo: u32,
 }
 
 impl UserRecordSubmissionDto {
-    pub fn new(sheet_id: String, score: u32, clear_type: ClearType) -> Self {
+    pub fn new(
+        submission_id: String,
+        sheet_id: String,
+        score: u32,
+        clear_type: ClearType,
+        judgement: Judgement,
+        max_combo: u32,
+    ) -> Self {
         Self {
+            submission_id,
             sheet_id,
             score,
             clear_type,
+            judgement,
+            max_combo,
+        }
+    }
+}
+
+/// Levels crossed by the XP a submission batch earned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelUpDto {
+    pub from: u32,
+    pub to: u32,
+}
+
+impl From<LevelUp> for LevelUpDto {
+    fn from(level_up: LevelUp) -> Self {
+        Self {
+            from: *level_up.from(),
+            to: *level_up.to(),
         }
     }
 }
 
+#[derive(Debug)]
+pub struct RecordSubmissionResultDto {
+    pub records: Vec<UserRecordDto>,
+    /// Set only when the batch took the user to a new level.
+    pub level_up: Option<LevelUpDto>,
+}
+
+#[allow(clippy::too_many_arguments)]
 impl UserRecordDto {
     pub fn new(
         id: String,
         sheet_id: String,
         score: u32,
         clear_type: ClearType,
+        judgement: Judgement,
+        max_combo: u32,
         play_count: u32,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -197,7 +253,10 @@ impl UserRecordDto {
             id,
             sheet_id,
             score,
+            grade: Grade::from_score(score),
             clear_type,
+            judgement,
+            max_combo,
             play_count,
             updated_at,
         }
@@ -211,8 +270,217 @@ impl From<Record> for UserRecordDto {
             record.sheet_id().to_owned(),
             record.score().to_owned(),
             *record.clear_type(),
+            *record.judgement(),
+            *record.max_combo(),
             record.play_count().to_owned(),
             record.updated_at().to_owned(),
         )
     }
 }
+
+/// A record with the sheet it was set on and the title of that sheet's music.
+#[derive(Debug)]
+pub struct UserRecordDetailDto {
+    pub record: UserRecordDto,
+    pub music_id: String,
+    pub music_title: String,
+    pub difficulty: Difficulty,
+    pub level: f64,
+}
+
+impl From<RecordDetail> for UserRecordDetailDto {
+    fn from(detail: RecordDetail) -> Self {
+        Self {
+            record: detail.record.into(),
+            music_id: detail.music_id,
+            music_title: detail.music_title,
+            difficulty: detail.difficulty,
+            level: detail.level.value(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UserRecordPageDto {
+    pub records: Vec<UserRecordDetailDto>,
+    /// Pass back as the cursor to fetch the next page; `None` on the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl From<RecordDetailPage> for UserRecordPageDto {
+    fn from(page: RecordDetailPage) -> Self {
+        Self {
+            records: page
+                .records
+                .into_iter()
+                .map(UserRecordDetailDto::from)
+                .collect(),
+            next_cursor: page.next_cursor.map(|cursor| cursor.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UserPlayDto {
+    pub id: String,
+    pub sheet_id: String,
+    pub client_id: String,
+    pub score: u32,
+    pub clear_type: ClearType,
+    pub judgement: Judgement,
+    pub played_at: DateTime<Utc>,
+}
+
+impl From<Play> for UserPlayDto {
+    fn from(play: Play) -> Self {
+        Self {
+            id: play.id().to_owned(),
+            sheet_id: play.sheet_id().to_owned(),
+            client_id: play.client_id().to_owned(),
+            score: *play.score(),
+            clear_type: *play.clear_type(),
+            judgement: *play.judgement(),
+            played_at: *play.played_at(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UserPlayPageDto {
+    pub plays: Vec<UserPlayDto>,
+    pub total: u64,
+}
+
+impl From<PlayPage> for UserPlayPageDto {
+    fn from(page: PlayPage) -> Self {
+        Self {
+            plays: page.plays.into_iter().map(UserPlayDto::from).collect(),
+            total: page.total,
+        }
+    }
+}
+
+/// A single sheet's contribution to a user's rating.
+#[derive(Debug)]
+pub struct RatedSheetDto {
+    pub sheet_id: String,
+    pub level: f64,
+    pub score: u32,
+    pub rating: u32,
+}
+
+impl RatedSheetDto {
+    pub fn new(sheet_id: String, level: f64, score: u32, rating: u32) -> Self {
+        Self {
+            sheet_id,
+            level,
+            score,
+            rating,
+        }
+    }
+}
+
+/// Explains a user's rating: the best records and recent plays that are averaged into it, and
+/// the best records that narrowly missed the counted set.
+#[derive(Debug)]
+pub struct UserRatingBreakdownDto {
+    pub rating: u32,
+    pub best_count: usize,
+    pub recent_count: usize,
+    pub best: Vec<RatedSheetDto>,
+    pub recent: Vec<RatedSheetDto>,
+    pub next: Vec<RatedSheetDto>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryBucket {
+    Daily,
+    Weekly,
+}
+
+#[derive(Debug)]
+pub struct UserHistoryPointDto {
+    /// The day of the snapshot, or the Monday of its week for weekly buckets.
+    pub date: NaiveDate,
+    pub rating: u32,
+    pub xp: u32,
+}
+
+#[derive(Debug)]
+pub struct UserHistoryDto {
+    pub bucket: HistoryBucket,
+    pub points: Vec<UserHistoryPointDto>,
+}
+
+/// Number of records per clear type.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ClearTypeCountsDto {
+    pub fail: u32,
+    pub clear: u32,
+    pub full_combo: u32,
+    pub all_perfect: u32,
+}
+
+impl ClearTypeCountsDto {
+    pub fn add(&mut self, clear_type: ClearType) {
+        match clear_type {
+            ClearType::Fail => self.fail += 1,
+            ClearType::Clear => self.clear += 1,
+            ClearType::FullCombo => self.full_combo += 1,
+            ClearType::AllPerfect => self.all_perfect += 1,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DifficultyClearSummaryDto {
+    pub difficulty: Difficulty,
+    pub counts: ClearTypeCountsDto,
+}
+
+#[derive(Debug)]
+pub struct LevelClearSummaryDto {
+    /// The level folder, e.g. `13` or `13+`.
+    pub level: String,
+    pub counts: ClearTypeCountsDto,
+}
+
+/// Number of records per grade. Each record counts towards its own grade only, and records below
+/// A are not counted.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GradeDistributionDto {
+    pub sss: u32,
+    pub ss: u32,
+    pub s: u32,
+    pub aa: u32,
+    pub a: u32,
+}
+
+impl GradeDistributionDto {
+    pub fn add(&mut self, grade: Grade) {
+        match grade {
+            Grade::SSS => self.sss += 1,
+            Grade::SS => self.ss += 1,
+            Grade::S => self.s += 1,
+            Grade::AA => self.aa += 1,
+            Grade::A => self.a += 1,
+            Grade::B | Grade::C | Grade::D => {}
+        }
+    }
+}
+
+/// Everything a profile page shows about a player, aggregated from their records.
+#[derive(Debug)]
+pub struct UserProfileDto {
+    pub user_id: String,
+    pub display_name: String,
+    pub rating: u32,
+    pub level: u32,
+    pub record_count: u32,
+    pub total_play_count: u64,
+    pub last_played_at: Option<DateTime<Utc>>,
+    pub clears_by_difficulty: Vec<DifficultyClearSummaryDto>,
+    pub clears_by_level: Vec<LevelClearSummaryDto>,
+    pub grades: GradeDistributionDto,
+    pub top_rated: Vec<RatedSheetDto>,
+}
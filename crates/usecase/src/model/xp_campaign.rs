@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use domain::entity::xp_campaign::XpCampaign;
+
+#[derive(Debug)]
+pub struct XpCampaignCreateDto {
+    pub name: String,
+    pub multiplier_percent: u32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl XpCampaignCreateDto {
+    pub fn new(
+        name: String,
+        multiplier_percent: u32,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            name,
+            multiplier_percent,
+            starts_at,
+            ends_at,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct XpCampaignDto {
+    pub id: String,
+    pub name: String,
+    pub multiplier_percent: u32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl From<XpCampaign> for XpCampaignDto {
+    fn from(campaign: XpCampaign) -> Self {
+        Self {
+            id: campaign.id().to_owned(),
+            name: campaign.name().to_owned(),
+            multiplier_percent: *campaign.multiplier_percent(),
+            starts_at: *campaign.starts_at(),
+            ends_at: *campaign.ends_at(),
+        }
+    }
+}
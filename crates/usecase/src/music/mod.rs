@@ -1,38 +1,438 @@
-use std::sync::Arc;
+use std::{collections::HashMap, slice, sync::Arc};
 
-use domain::repository::{
-    Repositories,
-    music::{MusicRepository, MusicRepositoryError, MusicWithSheets},
+use chrono::{DateTime, Utc};
+use domain::{
+    entity::{difficulty::Difficulty, genre::Genre, level::Level, music::Music, sheet::Sheet},
+    repository::{
+        Repositories, UnitOfWork,
+        music::{
+            CatalogImportReport, CatalogRow, CatalogRowError, MusicRepository,
+            MusicRepositoryError, MusicWithSheets,
+        },
+        record::RecordRepository,
+    },
+    service::rating::RatingPolicy,
 };
 use thiserror::Error;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    model::music::{
+        CatalogDeltaDto, CatalogEntryDto, CatalogImportReportDto, CatalogMusicDto, CatalogSheetDto,
+        MusicDto, MusicInputDto, MusicWithSheetsDto, SheetDto, SheetInputDto,
+    },
+    ranking::RankingCache,
+    rating::{RatingUsecaseError, rerate_user},
+};
 
-use crate::model::music::MusicWithSheetsDto;
+const MAX_BPM: f32 = 1000.0;
 
 #[derive(Debug, Error)]
 pub enum MusicUsecaseError {
+    #[error("Invalid catalog input: {0}")]
+    InvalidInput(String),
+    #[error("Music not found for id: {music_id}")]
+    MusicNotFound { music_id: String },
+    #[error("Sheet not found for id: {sheet_id}")]
+    SheetNotFound { sheet_id: String },
+    #[error("Sheet already exists for music {music_id} at difficulty {difficulty}")]
+    SheetAlreadyExists {
+        music_id: String,
+        difficulty: Difficulty,
+    },
     #[error(transparent)]
-    MusicRepository(#[from] MusicRepositoryError),
+    MusicRepository(MusicRepositoryError),
+    #[error(transparent)]
+    Rating(#[from] RatingUsecaseError),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+impl From<MusicRepositoryError> for MusicUsecaseError {
+    fn from(err: MusicRepositoryError) -> Self {
+        match err {
+            MusicRepositoryError::MusicNotFound(music_id) => Self::MusicNotFound { music_id },
+            MusicRepositoryError::SheetNotFound(sheet_id) => Self::SheetNotFound { sheet_id },
+            MusicRepositoryError::SheetAlreadyExists {
+                music_id,
+                difficulty,
+            } => Self::SheetAlreadyExists {
+                music_id,
+                difficulty,
+            },
+            err => Self::MusicRepository(err),
+        }
+    }
 }
 
 pub struct MusicUsecase<R: Repositories> {
     repositories: Arc<R>,
+    rating_policy: Arc<RatingPolicy>,
+    ranking_cache: Arc<RankingCache>,
 }
 
 impl<R: Repositories> MusicUsecase<R> {
     pub fn new(repositories: Arc<R>) -> Self {
-        Self { repositories }
+        Self {
+            repositories,
+            rating_policy: Arc::default(),
+            ranking_cache: Arc::default(),
+        }
+    }
+
+    /// The policy used to re-rate the players of a sheet whose level changed.
+    pub fn with_rating_policy(mut self, rating_policy: Arc<RatingPolicy>) -> Self {
+        self.rating_policy = rating_policy;
+        self
+    }
+
+    /// Shares the cache of [`crate::ranking::RankingUsecase`] so that rankings drop archived
+    /// musics and sheets and follow level changes right away.
+    pub fn with_ranking_cache(mut self, ranking_cache: Arc<RankingCache>) -> Self {
+        self.ranking_cache = ranking_cache;
+        self
     }
 
     pub async fn list_all(&self) -> Result<Vec<MusicWithSheetsDto>, MusicUsecaseError> {
         let musics = self.repositories.music().list_with_sheets().await?;
         Ok(musics.into_iter().map(MusicWithSheetsDto::from).collect())
     }
+
+    #[instrument(skip(self), fields(since = %since))]
+    pub async fn list_changed_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<CatalogDeltaDto, MusicUsecaseError> {
+        let delta = self.repositories.music().list_changed_since(since).await?;
+        Ok(delta.into())
+    }
+
+    #[instrument(skip(self, dto), fields(title = %dto.title))]
+    pub async fn create_music(&self, dto: MusicInputDto) -> Result<MusicDto, MusicUsecaseError> {
+        let music = build_music(String::new(), dto)?;
+        let created = self.repositories.music().create_music(music).await?;
+        info!(music_id = %created.id(), "Music created");
+        Ok(created.into())
+    }
+
+    #[instrument(skip(self, dto), fields(music_id = %music_id))]
+    pub async fn update_music(
+        &self,
+        music_id: String,
+        dto: MusicInputDto,
+    ) -> Result<MusicDto, MusicUsecaseError> {
+        let music = build_music(music_id, dto)?;
+        let updated = self.repositories.music().update_music(music).await?;
+        self.ranking_cache.invalidate();
+        info!("Music updated");
+        Ok(updated.into())
+    }
+
+    #[instrument(skip(self), fields(music_id = %music_id))]
+    pub async fn delete_music(&self, music_id: String) -> Result<(), MusicUsecaseError> {
+        self.repositories.music().delete_music(&music_id).await?;
+        self.ranking_cache.invalidate();
+        info!("Music archived");
+        Ok(())
+    }
+
+    #[instrument(skip(self, dto), fields(music_id = %dto.music_id, difficulty = %dto.difficulty))]
+    pub async fn create_sheet(&self, dto: SheetInputDto) -> Result<SheetDto, MusicUsecaseError> {
+        let sheet = build_sheet(String::new(), dto)?;
+        let created = self.repositories.music().create_sheet(sheet).await?;
+        info!(sheet_id = %created.id(), "Sheet created");
+        Ok(created.into())
+    }
+
+    #[instrument(skip(self, dto), fields(sheet_id = %sheet_id))]
+    pub async fn update_sheet(
+        &self,
+        sheet_id: String,
+        dto: SheetInputDto,
+    ) -> Result<SheetDto, MusicUsecaseError> {
+        let sheet = build_sheet(sheet_id, dto)?;
+        let uow = self.repositories.begin().await?;
+        let previous = uow
+            .music()
+            .find_sheets_by_ids(slice::from_ref(sheet.id()))
+            .await?;
+        let updated = uow.music().update_sheet(sheet).await?;
+        // Record ratings are cached per sheet level, so a new level re-rates its players along
+        // with the sheet.
+        if previous
+            .first()
+            .is_some_and(|previous| previous.level() != updated.level())
+        {
+            self.rerate_players(&uow, slice::from_ref(updated.id()))
+                .await?;
+        }
+        uow.commit().await?;
+        self.ranking_cache.invalidate();
+        info!("Sheet updated");
+        Ok(updated.into())
+    }
+
+    #[instrument(skip(self), fields(sheet_id = %sheet_id))]
+    pub async fn delete_sheet(&self, sheet_id: String) -> Result<(), MusicUsecaseError> {
+        self.repositories.music().delete_sheet(&sheet_id).await?;
+        self.ranking_cache.invalidate();
+        info!("Sheet archived");
+        Ok(())
+    }
+
+    /// Validates every manifest row before anything is written, so that all problems are
+    /// reported at once. Storage is only touched when the whole manifest is valid.
+    #[instrument(skip(self, entries), fields(entries = entries.len(), dry_run))]
+    pub async fn import_catalog(
+        &self,
+        entries: Vec<CatalogEntryDto>,
+        dry_run: bool,
+    ) -> Result<CatalogImportReportDto, MusicUsecaseError> {
+        let mut validated = Vec::with_capacity(entries.len());
+        let mut failures = Vec::new();
+        for (index, entry) in entries.into_iter().enumerate() {
+            match validate_catalog_entry(index, entry) {
+                Ok(entry) => validated.push(entry),
+                Err(errors) => failures.extend(errors),
+            }
+        }
+
+        if !failures.is_empty() {
+            info!(
+                failures = failures.len(),
+                "Catalog manifest rejected by validation"
+            );
+            let report = CatalogImportReport {
+                failures,
+                ..Default::default()
+            };
+            return Ok(report.into());
+        }
+
+        // Sheets that are not live yet count as relevelled, so reviving an archived sheet at a
+        // new level also re-rates its players.
+        let levels: HashMap<String, Level> = validated
+            .iter()
+            .flat_map(|entry| &entry.sheets)
+            .filter(|sheet| !sheet.id().is_empty())
+            .map(|sheet| (sheet.id().to_owned(), *sheet.level()))
+            .collect();
+        let uow = self.repositories.begin().await?;
+        let mut relevelled: Vec<String> = levels.keys().cloned().collect();
+        if !relevelled.is_empty() {
+            let live = uow.music().find_sheets_by_ids(&relevelled).await?;
+            relevelled.retain(|id| {
+                !live
+                    .iter()
+                    .any(|sheet| sheet.id() == id && Some(sheet.level()) == levels.get(id))
+            });
+        }
+
+        let report = uow.music().import_catalog(validated, dry_run).await?;
+        if report.committed {
+            self.rerate_players(&uow, &relevelled).await?;
+            uow.commit().await?;
+            self.ranking_cache.invalidate();
+        }
+        info!(
+            musics = report.musics,
+            sheets = report.sheets,
+            committed = report.committed,
+            "Catalog manifest imported"
+        );
+        Ok(report.into())
+    }
+
+    /// Re-rates everyone holding a record on the sheets inside `uow`.
+    async fn rerate_players<U: UnitOfWork>(
+        &self,
+        uow: &U,
+        sheet_ids: &[String],
+    ) -> Result<(), RatingUsecaseError> {
+        if sheet_ids.is_empty() {
+            return Ok(());
+        }
+
+        let user_ids = uow.record().find_user_ids_by_sheet_ids(sheet_ids).await?;
+        let mut updated = 0;
+        for user_id in &user_ids {
+            if rerate_user(uow, &self.rating_policy, user_id).await? {
+                updated += 1;
+            }
+        }
+        info!(
+            sheets = sheet_ids.len(),
+            users = user_ids.len(),
+            updated,
+            "Players of relevelled sheets re-rated"
+        );
+        Ok(())
+    }
+}
+
+fn build_music(id: String, dto: MusicInputDto) -> Result<Music, MusicUsecaseError> {
+    if dto.title.trim().is_empty() {
+        debug!("Rejected music without title");
+        return Err(MusicUsecaseError::InvalidInput(
+            "title must not be empty".to_owned(),
+        ));
+    }
+    if dto.artist.trim().is_empty() {
+        debug!("Rejected music without artist");
+        return Err(MusicUsecaseError::InvalidInput(
+            "artist must not be empty".to_owned(),
+        ));
+    }
+    if !(dto.bpm > 0.0 && dto.bpm < MAX_BPM) {
+        debug!(bpm = dto.bpm, "Rejected music with out-of-range bpm");
+        return Err(MusicUsecaseError::InvalidInput(format!(
+            "bpm must be between 0 and {MAX_BPM}: {}",
+            dto.bpm
+        )));
+    }
+    let genre = dto
+        .genre
+        .parse::<Genre>()
+        .map_err(|err| MusicUsecaseError::InvalidInput(err.to_string()))?;
+
+    Ok(Music::new(
+        id,
+        dto.title,
+        dto.artist,
+        dto.bpm,
+        genre,
+        dto.jacket,
+        dto.registration_date,
+        dto.is_test,
+    ))
+}
+
+/// Returns every problem of the entry, not just the first one.
+fn validate_catalog_entry(
+    index: usize,
+    entry: CatalogEntryDto,
+) -> Result<MusicWithSheets, Vec<CatalogRowError>> {
+    let mut errors = Vec::new();
+    let music_id = entry.music.id.clone();
+
+    let music = match catalog_music_input(entry.music).and_then(|(id, dto)| build_music(id, dto)) {
+        Ok(music) => Some(music),
+        Err(err) => {
+            errors.push(CatalogRowError::new(
+                CatalogRow::music(index),
+                err.to_string(),
+            ));
+            None
+        }
+    };
+
+    let mut sheets = Vec::with_capacity(entry.sheets.len());
+    let mut difficulties = Vec::with_capacity(entry.sheets.len());
+    for (sheet_index, sheet) in entry.sheets.into_iter().enumerate() {
+        let row = CatalogRow::sheet(index, sheet_index);
+        match catalog_sheet(&music_id, sheet) {
+            Ok(sheet) if difficulties.contains(sheet.difficulty()) => errors.push(
+                CatalogRowError::new(row, format!("Duplicate difficulty: {}", sheet.difficulty())),
+            ),
+            Ok(sheet) => {
+                difficulties.push(*sheet.difficulty());
+                sheets.push(sheet);
+            }
+            Err(err) => errors.push(CatalogRowError::new(row, err.to_string())),
+        }
+    }
+
+    match music {
+        Some(music) if errors.is_empty() => Ok(MusicWithSheets::new(music, sheets)),
+        _ => {
+            debug!(
+                entry = index,
+                errors = errors.len(),
+                "Rejected catalog entry"
+            );
+            Err(errors)
+        }
+    }
+}
+
+fn catalog_music_input(dto: CatalogMusicDto) -> Result<(String, MusicInputDto), MusicUsecaseError> {
+    let registration_date = DateTime::parse_from_rfc3339(&dto.registration_date)
+        .map_err(|_| {
+            MusicUsecaseError::InvalidInput(format!(
+                "Invalid registration date: {}",
+                dto.registration_date
+            ))
+        })?
+        .with_timezone(&Utc);
+
+    let input = MusicInputDto::new(
+        dto.title,
+        dto.artist,
+        dto.bpm,
+        dto.genre,
+        dto.jacket,
+        registration_date,
+        dto.is_test,
+    );
+    Ok((dto.id, input))
+}
+
+fn catalog_sheet(music_id: &str, dto: CatalogSheetDto) -> Result<Sheet, MusicUsecaseError> {
+    if !dto.music_id.is_empty() && dto.music_id != music_id {
+        return Err(MusicUsecaseError::InvalidInput(format!(
+            "Sheet refers to music {} but is listed under {music_id}",
+            dto.music_id
+        )));
+    }
+
+    let input = SheetInputDto::new(
+        music_id.to_owned(),
+        dto.difficulty,
+        dto.level,
+        dto.notes_designer,
+        dto.note_count,
+        dto.max_score,
+    );
+    build_sheet(dto.id, input)
+}
+
+fn build_sheet(id: String, dto: SheetInputDto) -> Result<Sheet, MusicUsecaseError> {
+    let difficulty = dto
+        .difficulty
+        .parse::<Difficulty>()
+        .map_err(|err| MusicUsecaseError::InvalidInput(err.to_string()))?;
+    let level = Level::try_from(dto.level).map_err(|_| {
+        debug!(level = dto.level, "Rejected sheet with invalid level");
+        MusicUsecaseError::InvalidInput(format!("Invalid level: {}", dto.level))
+    })?;
+    if dto.note_count == 0 {
+        return Err(MusicUsecaseError::InvalidInput(
+            "Note count must be positive".to_owned(),
+        ));
+    }
+    if dto.max_score == 0 {
+        return Err(MusicUsecaseError::InvalidInput(
+            "Max score must be positive".to_owned(),
+        ));
+    }
+
+    Ok(Sheet::new(
+        id,
+        dto.music_id,
+        difficulty,
+        level,
+        dto.notes_designer,
+        dto.note_count,
+        dto.max_score,
+    ))
 }
 
 impl<R: Repositories> Clone for MusicUsecase<R> {
     fn clone(&self) -> Self {
         Self {
             repositories: Arc::clone(&self.repositories),
+            rating_policy: Arc::clone(&self.rating_policy),
+            ranking_cache: Arc::clone(&self.ranking_cache),
         }
     }
 }
@@ -52,16 +452,22 @@ mod tests {
 
     use chrono::Utc;
     use domain::{
-        entity::{difficulty::Difficulty, genre::Genre, level::Level, music::Music, sheet::Sheet},
+        entity::{
+            clear_type::ClearType, difficulty::Difficulty, genre::Genre, judgement::Judgement,
+            level::Level, music::Music, record::Record, sheet::Sheet,
+        },
         repository::{
             MockRepositories,
-            music::{MockMusicRepository, MusicWithSheets},
-            record::MockRecordRepository,
+            music::{MockMusicRepository, MusicRepositoryError, MusicWithSheets},
+            ranking::RankingWindow,
+            record::{MockRecordRepository, RecordWithMetadata, SheetRating},
             user::MockUserRepository,
         },
+        testing::{datetime::sample_timestamp, user::USER1},
     };
 
     use super::*;
+    use crate::ranking::{RankingScope, RankingUsecase};
 
     #[tokio::test]
     async fn list_all_returns_entries() {
@@ -83,14 +489,15 @@ mod tests {
                 Difficulty::Easy,
                 Level::new(12, 3).expect("level"),
                 "Designer".to_owned(),
+                1000,
+                1_010_000,
             );
             Box::pin(async move { Ok(vec![MusicWithSheets::new(music, vec![sheet])]) })
         });
 
         let repositories = MockRepositories {
-            user: MockUserRepository::new(),
-            record: MockRecordRepository::new(),
             music: music_repo,
+            ..Default::default()
         };
         let usecase = MusicUsecase::new(Arc::new(repositories));
 
@@ -100,4 +507,414 @@ mod tests {
         assert_eq!(result[0].sheets.len(), 1);
         assert_eq!(result[0].sheets[0].id, "sheet-1");
     }
+
+    fn repositories_with(music: MockMusicRepository) -> MockRepositories {
+        MockRepositories {
+            music,
+            ..Default::default()
+        }
+    }
+
+    fn music_input(bpm: f32, genre: &str) -> MusicInputDto {
+        MusicInputDto::new(
+            "Song".to_owned(),
+            "Artist".to_owned(),
+            bpm,
+            genre.to_owned(),
+            "jacket.png".to_owned(),
+            Utc::now(),
+            false,
+        )
+    }
+
+    #[tokio::test]
+    async fn create_music_persists_validated_entity() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo
+            .expect_create_music()
+            .withf(|music| music.id().is_empty() && *music.genre() == Genre::ORIGINAL)
+            .returning(|music| {
+                let created = Music::new(
+                    "music-1".to_owned(),
+                    music.title().to_owned(),
+                    music.artist().to_owned(),
+                    *music.bpm(),
+                    *music.genre(),
+                    music.jacket_image_url().to_owned(),
+                    *music.registration_date(),
+                    *music.is_test(),
+                );
+                Box::pin(async move { Ok(created) })
+            });
+        let usecase = MusicUsecase::new(Arc::new(repositories_with(music_repo)));
+
+        let result = usecase
+            .create_music(music_input(150.0, "original"))
+            .await
+            .expect("should succeed");
+
+        assert_eq!(result.id, "music-1");
+        assert_eq!(result.genre, Genre::ORIGINAL);
+    }
+
+    #[tokio::test]
+    async fn create_music_rejects_invalid_input() {
+        let usecase = MusicUsecase::new(Arc::new(repositories_with(MockMusicRepository::new())));
+
+        let bad_bpm = usecase.create_music(music_input(0.0, "ORIGINAL")).await;
+        let bad_genre = usecase.create_music(music_input(120.0, "VARIETY")).await;
+
+        assert!(matches!(bad_bpm, Err(MusicUsecaseError::InvalidInput(_))));
+        assert!(matches!(bad_genre, Err(MusicUsecaseError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn create_sheet_rejects_invalid_level() {
+        let usecase = MusicUsecase::new(Arc::new(repositories_with(MockMusicRepository::new())));
+
+        let result = usecase
+            .create_sheet(SheetInputDto::new(
+                "music-1".to_owned(),
+                "hard".to_owned(),
+                13.75,
+                "Designer".to_owned(),
+                1000,
+                1_010_000,
+            ))
+            .await;
+
+        assert!(matches!(result, Err(MusicUsecaseError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn create_sheet_rejects_missing_bounds() {
+        let usecase = MusicUsecase::new(Arc::new(repositories_with(MockMusicRepository::new())));
+
+        let result = usecase
+            .create_sheet(SheetInputDto::new(
+                "music-1".to_owned(),
+                "hard".to_owned(),
+                13.7,
+                "Designer".to_owned(),
+                0,
+                1_010_000,
+            ))
+            .await;
+
+        assert!(matches!(result, Err(MusicUsecaseError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn create_sheet_maps_duplicate_difficulty() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_create_sheet().returning(|sheet| {
+            let err = MusicRepositoryError::SheetAlreadyExists {
+                music_id: sheet.music_id().to_owned(),
+                difficulty: *sheet.difficulty(),
+            };
+            Box::pin(async move { Err(err) })
+        });
+        let usecase = MusicUsecase::new(Arc::new(repositories_with(music_repo)));
+
+        let result = usecase
+            .create_sheet(SheetInputDto::new(
+                "music-1".to_owned(),
+                "hard".to_owned(),
+                13.7,
+                "Designer".to_owned(),
+                1000,
+                1_010_000,
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(MusicUsecaseError::SheetAlreadyExists {
+                difficulty: Difficulty::Hard,
+                ..
+            })
+        ));
+    }
+
+    fn music_repo_relevelling_sheet_1(previous_level: (u32, u32)) -> MockMusicRepository {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo
+            .expect_find_sheets_by_ids()
+            .withf(|ids| ids == ["sheet-1"])
+            .returning(move |_| {
+                let sheet = Sheet::new(
+                    "sheet-1".to_owned(),
+                    "music-1".to_owned(),
+                    Difficulty::Hard,
+                    Level::try_from(previous_level).unwrap(),
+                    "Designer".to_owned(),
+                    1000,
+                    1_010_000,
+                );
+                Box::pin(async move { Ok(vec![sheet]) })
+            });
+        music_repo
+            .expect_update_sheet()
+            .returning(|sheet| Box::pin(async move { Ok(sheet) }));
+        music_repo
+    }
+
+    fn hard_sheet_input(level: f64) -> SheetInputDto {
+        SheetInputDto::new(
+            "music-1".to_owned(),
+            "hard".to_owned(),
+            level,
+            "Designer".to_owned(),
+            1000,
+            1_010_000,
+        )
+    }
+
+    #[tokio::test]
+    async fn update_sheet_rerates_players_when_level_changes() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_user_ids_by_sheet_ids()
+            .withf(|ids| ids == ["sheet-1"])
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(vec![USER1.id.to_owned()]) }));
+        record_repo
+            .expect_find_with_metadata_by_user_id()
+            .returning(|_| {
+                let record = Record::new_from_submission(
+                    USER1.id.to_owned(),
+                    "sheet-1".to_owned(),
+                    1_000_000,
+                    ClearType::Clear,
+                    Judgement::default(),
+                    0,
+                    sample_timestamp(),
+                );
+                let entry = RecordWithMetadata::new(
+                    record,
+                    Difficulty::Hard,
+                    Level::new(13, 7).unwrap(),
+                    false,
+                );
+                Box::pin(async move { Ok(vec![entry]) })
+            });
+        record_repo
+            .expect_update_ratings()
+            .withf(|user_id, ratings| {
+                user_id == USER1.id && *ratings == [SheetRating::new("sheet-1".to_owned(), 1470)]
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id_for_update().returning(|_| {
+            let user = USER1.build(true, false, sample_timestamp());
+            Box::pin(async move { Ok(Some(user)) })
+        });
+        user_repo
+            .expect_save()
+            .withf(|user| user.rating().value() == 1470)
+            .times(1)
+            .returning(|user| Box::pin(async move { Ok(user) }));
+        let usecase = MusicUsecase::new(Arc::new(MockRepositories {
+            music: music_repo_relevelling_sheet_1((12, 0)),
+            record: record_repo,
+            user: user_repo,
+            ..Default::default()
+        }));
+
+        let result = usecase
+            .update_sheet("sheet-1".to_owned(), hard_sheet_input(13.7))
+            .await
+            .expect("should succeed");
+
+        assert_eq!(result.level_value, 13.7);
+    }
+
+    #[tokio::test]
+    async fn update_sheet_keeps_ratings_when_level_is_unchanged() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo.expect_find_user_ids_by_sheet_ids().never();
+        let usecase = MusicUsecase::new(Arc::new(MockRepositories {
+            music: music_repo_relevelling_sheet_1((13, 7)),
+            record: record_repo,
+            ..Default::default()
+        }));
+
+        usecase
+            .update_sheet("sheet-1".to_owned(), hard_sheet_input(13.7))
+            .await
+            .expect("should succeed");
+    }
+
+    #[tokio::test]
+    async fn delete_sheet_maps_missing_sheet() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_delete_sheet().returning(|sheet_id| {
+            let err = MusicRepositoryError::SheetNotFound(sheet_id.to_owned());
+            Box::pin(async move { Err(err) })
+        });
+        let usecase = MusicUsecase::new(Arc::new(repositories_with(music_repo)));
+
+        let result = usecase.delete_sheet("sheet-9".to_owned()).await;
+
+        assert!(matches!(
+            result,
+            Err(MusicUsecaseError::SheetNotFound { sheet_id }) if sheet_id == "sheet-9"
+        ));
+    }
+
+    #[tokio::test]
+    async fn delete_sheet_invalidates_cached_rankings() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo
+            .expect_delete_sheet()
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_public_total_score_ranking()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(Vec::new()) }));
+        let repositories = Arc::new(MockRepositories {
+            music: music_repo,
+            record: record_repo,
+            ..Default::default()
+        });
+        let cache = Arc::new(RankingCache::new(std::time::Duration::from_secs(60)));
+        let ranking = RankingUsecase::new(Arc::clone(&repositories)).with_cache(Arc::clone(&cache));
+        let usecase = MusicUsecase::new(repositories).with_ranking_cache(cache);
+        let window = RankingWindow::Page {
+            offset: 0,
+            limit: 20,
+        };
+
+        ranking
+            .total_high_scores(RankingScope::AllTime, &window)
+            .await
+            .unwrap();
+        usecase
+            .delete_sheet("sheet-1".to_owned())
+            .await
+            .expect("should succeed");
+        ranking
+            .total_high_scores(RankingScope::AllTime, &window)
+            .await
+            .unwrap();
+    }
+
+    fn manifest_entry(music_id: &str, sheets: Vec<CatalogSheetDto>) -> CatalogEntryDto {
+        CatalogEntryDto::new(
+            CatalogMusicDto::new(
+                music_id.to_owned(),
+                "Song".to_owned(),
+                "Artist".to_owned(),
+                150.0,
+                "ORIGINAL".to_owned(),
+                "jacket.png".to_owned(),
+                "2025-01-01T00:00:00Z".to_owned(),
+                false,
+            ),
+            sheets,
+        )
+    }
+
+    fn manifest_sheet(music_id: &str, difficulty: &str, level: f64) -> CatalogSheetDto {
+        CatalogSheetDto::new(
+            String::new(),
+            music_id.to_owned(),
+            difficulty.to_owned(),
+            level,
+            "Designer".to_owned(),
+            1000,
+            1_010_000,
+        )
+    }
+
+    #[tokio::test]
+    async fn import_catalog_attaches_sheets_to_their_entry() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo
+            .expect_import_catalog()
+            .withf(|entries, dry_run| {
+                *dry_run
+                    && entries.len() == 1
+                    && entries[0].music.id() == "music-1"
+                    && entries[0]
+                        .sheets
+                        .iter()
+                        .all(|sheet| sheet.music_id() == "music-1")
+            })
+            .returning(|entries, _| {
+                let report = CatalogImportReport {
+                    musics: entries.len(),
+                    sheets: entries.iter().map(|entry| entry.sheets.len()).sum(),
+                    ..Default::default()
+                };
+                Box::pin(async move { Ok(report) })
+            });
+        let usecase = MusicUsecase::new(Arc::new(repositories_with(music_repo)));
+
+        let report = usecase
+            .import_catalog(
+                vec![manifest_entry(
+                    "music-1",
+                    vec![
+                        manifest_sheet("", "easy", 3.0),
+                        manifest_sheet("music-1", "hard", 12.5),
+                    ],
+                )],
+                true,
+            )
+            .await
+            .expect("should succeed");
+
+        assert_eq!(report.musics, 1);
+        assert_eq!(report.sheets, 2);
+        assert!(report.failures.is_empty());
+        assert!(!report.committed);
+    }
+
+    #[tokio::test]
+    async fn import_catalog_reports_every_invalid_row_without_writing() {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo.expect_import_catalog().never();
+        let usecase = MusicUsecase::new(Arc::new(repositories_with(music_repo)));
+        let mut invalid_music = manifest_entry("music-2", vec![]);
+        invalid_music.music.registration_date = "yesterday".to_owned();
+
+        let report = usecase
+            .import_catalog(
+                vec![
+                    manifest_entry(
+                        "music-1",
+                        vec![
+                            manifest_sheet("", "easy", 3.0),
+                            manifest_sheet("", "expert", 14.0),
+                            manifest_sheet("", "easy", 4.0),
+                            manifest_sheet("music-9", "hard", 12.75),
+                        ],
+                    ),
+                    invalid_music,
+                ],
+                false,
+            )
+            .await
+            .expect("should succeed");
+
+        let rows: Vec<&str> = report
+            .failures
+            .iter()
+            .map(|failure| failure.row.as_str())
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                "[0].sheets[1]",
+                "[0].sheets[2]",
+                "[0].sheets[3]",
+                "[1].music"
+            ]
+        );
+        assert_eq!(report.musics, 0);
+        assert!(!report.committed);
+    }
 }
@@ -0,0 +1,255 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    future::Future,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use domain::repository::ranking::{RankingFilter, RankingWindow};
+use tracing::debug;
+
+use super::RankingScope;
+
+/// Identifies one computed ranking response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum RankingKey {
+    Sheet {
+        sheet_id: String,
+        window: RankingWindow,
+    },
+    TotalScore {
+        scope: RankingScope,
+        filter: RankingFilter,
+        window: RankingWindow,
+    },
+    ClearCount {
+        filter: RankingFilter,
+        window: RankingWindow,
+    },
+    Plays {
+        scope: RankingScope,
+        window: RankingWindow,
+    },
+    Rating {
+        window: RankingWindow,
+    },
+    Xp {
+        window: RankingWindow,
+    },
+}
+
+/// Keys carry caller-supplied offsets, `around` users and sheet ids, so the number of entries is
+/// capped rather than left to the TTL.
+const MAX_ENTRIES: usize = 1024;
+
+struct CachedRanking {
+    stored_at: Instant,
+    /// Store order, so that eviction does not depend on the clock resolution.
+    sequence: u64,
+    value: Arc<dyn Any + Send + Sync>,
+}
+
+/// Keeps computed rankings in memory for `ttl` so that public ranking traffic does not aggregate
+/// every record on each hit. A zero `ttl` turns caching off. Once full, the oldest entry makes
+/// room for the next one.
+pub struct RankingCache {
+    ttl: Duration,
+    capacity: usize,
+    stores: AtomicU64,
+    /// Bumped by every [`RankingCache::invalidate`], so that a load which started before an
+    /// invalidation does not store what it read.
+    generation: AtomicU64,
+    entries: Mutex<HashMap<RankingKey, CachedRanking>>,
+}
+
+impl RankingCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            capacity: MAX_ENTRIES,
+            stores: AtomicU64::new(0),
+            generation: AtomicU64::new(0),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Drops every cached ranking, e.g. after new records change the standings.
+    pub fn invalidate(&self) {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        entries.clear();
+    }
+
+    /// Returns the cached ranking for `key`, or runs `load` and caches its result. Concurrent
+    /// misses may load the same ranking more than once; the last one stored wins. A result is not
+    /// stored when the cache was invalidated while it loaded, as it may predate the change.
+    pub(crate) async fn get_or_load<T, E, F>(&self, key: RankingKey, load: F) -> Result<T, E>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: Future<Output = Result<T, E>>,
+    {
+        if self.ttl.is_zero() {
+            return load.await;
+        }
+        if let Some(value) = self.lookup::<T>(&key) {
+            debug!(?key, "Serving ranking from cache");
+            return Ok(value);
+        }
+
+        let generation = self.generation.load(Ordering::Acquire);
+        let value = load.await?;
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if self.generation.load(Ordering::Acquire) != generation {
+            debug!(?key, "Ranking changed while loading, not caching it");
+            return Ok(value);
+        }
+        entries.retain(|_, cached| cached.stored_at.elapsed() < self.ttl);
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.sequence)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(
+            key,
+            CachedRanking {
+                stored_at: Instant::now(),
+                sequence: self.stores.fetch_add(1, Ordering::Relaxed),
+                value: Arc::new(value.clone()),
+            },
+        );
+        Ok(value)
+    }
+
+    fn lookup<T: Clone + 'static>(&self, key: &RankingKey) -> Option<T> {
+        let entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries
+            .get(key)
+            .filter(|cached| cached.stored_at.elapsed() < self.ttl)
+            .and_then(|cached| cached.value.downcast_ref::<T>())
+            .cloned()
+    }
+}
+
+impl Default for RankingCache {
+    fn default() -> Self {
+        Self::new(Duration::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    fn key() -> RankingKey {
+        page_key(0)
+    }
+
+    fn page_key(offset: u64) -> RankingKey {
+        RankingKey::Rating {
+            window: RankingWindow::Page { offset, limit: 20 },
+        }
+    }
+
+    #[tokio::test]
+    async fn get_or_load_reuses_the_value_until_invalidated() {
+        let cache = RankingCache::new(Duration::from_secs(60));
+
+        let first = cache
+            .get_or_load(key(), async { Ok::<_, Infallible>(1) })
+            .await
+            .unwrap();
+        let cached = cache
+            .get_or_load(key(), async { Ok::<_, Infallible>(2) })
+            .await
+            .unwrap();
+        cache.invalidate();
+        let reloaded = cache
+            .get_or_load(key(), async { Ok::<_, Infallible>(3) })
+            .await
+            .unwrap();
+
+        assert_eq!((first, cached, reloaded), (1, 1, 3));
+    }
+
+    #[tokio::test]
+    async fn get_or_load_skips_the_cache_without_ttl() {
+        let cache = RankingCache::default();
+
+        cache
+            .get_or_load(key(), async { Ok::<_, Infallible>(1) })
+            .await
+            .unwrap();
+        let second = cache
+            .get_or_load(key(), async { Ok::<_, Infallible>(2) })
+            .await
+            .unwrap();
+
+        assert_eq!(second, 2);
+    }
+
+    #[tokio::test]
+    async fn get_or_load_evicts_the_oldest_entry_when_full() {
+        let cache = RankingCache {
+            capacity: 2,
+            ..RankingCache::new(Duration::from_secs(60))
+        };
+
+        for offset in 0..3 {
+            cache
+                .get_or_load(
+                    page_key(offset),
+                    async move { Ok::<u64, Infallible>(offset) },
+                )
+                .await
+                .unwrap();
+        }
+        let evicted = cache
+            .get_or_load(page_key(0), async { Ok::<u64, Infallible>(10) })
+            .await
+            .unwrap();
+        let kept = cache
+            .get_or_load(page_key(2), async { Ok::<u64, Infallible>(12) })
+            .await
+            .unwrap();
+
+        assert_eq!((evicted, kept), (10, 2));
+    }
+
+    #[tokio::test]
+    async fn get_or_load_drops_results_loaded_across_an_invalidation() {
+        let cache = RankingCache::new(Duration::from_secs(60));
+
+        let stale = cache
+            .get_or_load(key(), async {
+                cache.invalidate();
+                Ok::<_, Infallible>(1)
+            })
+            .await
+            .unwrap();
+        let fresh = cache
+            .get_or_load(key(), async { Ok::<_, Infallible>(2) })
+            .await
+            .unwrap();
+
+        assert_eq!((stale, fresh), (1, 2));
+    }
+}
@@ -0,0 +1,664 @@
+mod cache;
+
+use std::sync::Arc;
+
+pub use cache::RankingCache;
+use cache::RankingKey;
+use chrono::Utc;
+use domain::{
+    entity::season::{Season, SeasonBoard, SeasonPeriod},
+    repository::{
+        Repositories,
+        play::{PlayCountRankingRow, PlayRepository, PlayRepositoryError},
+        ranking::{RankingFilter, RankingWindow},
+        record::{
+            RecordRepository, RecordRepositoryError, SheetScoreRankingRow, TotalScoreRankingRow,
+        },
+        season::{SeasonRepository, SeasonRepositoryError},
+        user::{RankedUser, UserRepository, UserRepositoryError},
+    },
+    service::level::LevelCurve,
+};
+use thiserror::Error;
+
+use crate::model::ranking::{
+    ClearCountRankingDto, ClearCountRankingEntryDto, PlayCountRankingDto, PlayCountRankingEntryDto,
+    RatingRankingDto, RatingRankingEntryDto, SeasonDto, SheetScoreRankingDto,
+    SheetScoreRankingEntryDto, TotalScoreRankingDto, TotalScoreRankingEntryDto, XpRankingDto,
+    XpRankingEntryDto,
+};
+
+#[derive(Debug, Error)]
+pub enum RankingUsecaseError {
+    #[error(transparent)]
+    RecordRepository(#[from] RecordRepositoryError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    PlayRepository(#[from] PlayRepositoryError),
+    #[error(transparent)]
+    SeasonRepository(#[from] SeasonRepositoryError),
+    #[error("No {0:?} season is running")]
+    NoCurrentSeason(SeasonPeriod),
+    #[error("No {0:?} season has been archived yet")]
+    NoArchivedSeason(SeasonPeriod),
+}
+
+/// The stretch of play history a ranking covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RankingScope {
+    AllTime,
+    /// The running season, ranked live from plays.
+    Current(SeasonPeriod),
+    /// The most recently archived season, ranked from its final standings.
+    Previous(SeasonPeriod),
+}
+
+pub struct RankingUsecase<R: Repositories> {
+    repositories: Arc<R>,
+    level_curve: Arc<LevelCurve>,
+    cache: Arc<RankingCache>,
+}
+
+impl<R: Repositories> RankingUsecase<R> {
+    pub fn new(repositories: Arc<R>) -> Self {
+        Self {
+            repositories,
+            level_curve: Arc::default(),
+            cache: Arc::default(),
+        }
+    }
+
+    pub fn with_level_curve(mut self, level_curve: Arc<LevelCurve>) -> Self {
+        self.level_curve = level_curve;
+        self
+    }
+
+    pub fn with_cache(mut self, cache: Arc<RankingCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub async fn sheet_high_scores(
+        &self,
+        sheet_id: &str,
+        window: &RankingWindow,
+    ) -> Result<SheetScoreRankingDto, RankingUsecaseError> {
+        let key = RankingKey::Sheet {
+            sheet_id: sheet_id.to_owned(),
+            window: window.clone(),
+        };
+        self.cache
+            .get_or_load(key, self.load_sheet_high_scores(sheet_id, window))
+            .await
+    }
+
+    pub async fn total_high_scores(
+        &self,
+        scope: RankingScope,
+        window: &RankingWindow,
+    ) -> Result<TotalScoreRankingDto, RankingUsecaseError> {
+        let key = RankingKey::TotalScore {
+            scope,
+            filter: RankingFilter::default(),
+            window: window.clone(),
+        };
+        self.cache
+            .get_or_load(key, self.load_total_high_scores(scope, window))
+            .await
+    }
+
+    /// All-time total score over the sheets that match `filter`.
+    pub async fn filtered_total_high_scores(
+        &self,
+        filter: &RankingFilter,
+        window: &RankingWindow,
+    ) -> Result<TotalScoreRankingDto, RankingUsecaseError> {
+        let key = RankingKey::TotalScore {
+            scope: RankingScope::AllTime,
+            filter: filter.clone(),
+            window: window.clone(),
+        };
+        self.cache
+            .get_or_load(key, self.load_filtered_total_high_scores(filter, window))
+            .await
+    }
+
+    pub async fn clear_counts(
+        &self,
+        filter: &RankingFilter,
+        window: &RankingWindow,
+    ) -> Result<ClearCountRankingDto, RankingUsecaseError> {
+        let key = RankingKey::ClearCount {
+            filter: filter.clone(),
+            window: window.clone(),
+        };
+        self.cache
+            .get_or_load(key, self.load_clear_counts(filter, window))
+            .await
+    }
+
+    pub async fn plays(
+        &self,
+        scope: RankingScope,
+        window: &RankingWindow,
+    ) -> Result<PlayCountRankingDto, RankingUsecaseError> {
+        let key = RankingKey::Plays {
+            scope,
+            window: window.clone(),
+        };
+        self.cache
+            .get_or_load(key, self.load_plays(scope, window))
+            .await
+    }
+
+    pub async fn rating(
+        &self,
+        window: &RankingWindow,
+    ) -> Result<RatingRankingDto, RankingUsecaseError> {
+        let key = RankingKey::Rating {
+            window: window.clone(),
+        };
+        self.cache.get_or_load(key, self.load_rating(window)).await
+    }
+
+    pub async fn xp(&self, window: &RankingWindow) -> Result<XpRankingDto, RankingUsecaseError> {
+        let key = RankingKey::Xp {
+            window: window.clone(),
+        };
+        self.cache.get_or_load(key, self.load_xp(window)).await
+    }
+}
+
+impl<R: Repositories> RankingUsecase<R> {
+    async fn load_sheet_high_scores(
+        &self,
+        sheet_id: &str,
+        window: &RankingWindow,
+    ) -> Result<SheetScoreRankingDto, RankingUsecaseError> {
+        let rows = self
+            .repositories
+            .record()
+            .find_public_high_scores_by_sheet(sheet_id, window)
+            .await?;
+
+        Ok(SheetScoreRankingDto::new(
+            sheet_id.to_owned(),
+            self.decorate_sheet_rows(rows),
+        ))
+    }
+
+    async fn load_total_high_scores(
+        &self,
+        scope: RankingScope,
+        window: &RankingWindow,
+    ) -> Result<TotalScoreRankingDto, RankingUsecaseError> {
+        match scope {
+            RankingScope::AllTime => {
+                let rows = self
+                    .repositories
+                    .record()
+                    .find_public_total_score_ranking(window)
+                    .await?;
+                Ok(TotalScoreRankingDto::new(self.decorate_total_rows(rows)))
+            }
+            RankingScope::Current(period) => {
+                let season = self.current_season(period).await?;
+                let rows = self
+                    .repositories
+                    .play()
+                    .find_public_total_score_ranking_between(
+                        *season.starts_at(),
+                        *season.ends_at(),
+                        window,
+                    )
+                    .await?;
+                Ok(TotalScoreRankingDto::new(self.decorate_total_rows(rows))
+                    .with_season(SeasonDto::from(&season)))
+            }
+            RankingScope::Previous(period) => {
+                let season = self.latest_archived_season(period).await?;
+                let standings = self
+                    .repositories
+                    .season()
+                    .find_standings(season.id(), SeasonBoard::TotalScore, window)
+                    .await?;
+                let entries = standings
+                    .into_iter()
+                    .map(|standing| {
+                        TotalScoreRankingEntryDto::new(
+                            standing.rank,
+                            standing.user_id,
+                            standing.display_name,
+                            standing.value,
+                        )
+                    })
+                    .collect();
+                Ok(TotalScoreRankingDto::new(entries).with_season(SeasonDto::from(&season)))
+            }
+        }
+    }
+
+    async fn load_filtered_total_high_scores(
+        &self,
+        filter: &RankingFilter,
+        window: &RankingWindow,
+    ) -> Result<TotalScoreRankingDto, RankingUsecaseError> {
+        let record_repo = self.repositories.record();
+        let rows = if filter.is_empty() {
+            record_repo.find_public_total_score_ranking(window).await?
+        } else {
+            record_repo
+                .find_public_filtered_total_score_ranking(filter, window)
+                .await?
+        };
+        Ok(TotalScoreRankingDto::new(self.decorate_total_rows(rows)))
+    }
+
+    async fn load_clear_counts(
+        &self,
+        filter: &RankingFilter,
+        window: &RankingWindow,
+    ) -> Result<ClearCountRankingDto, RankingUsecaseError> {
+        let rows = self
+            .repositories
+            .record()
+            .find_public_clear_count_ranking(filter, window)
+            .await?;
+        Ok(ClearCountRankingDto::new(
+            rows.into_iter()
+                .map(|row| {
+                    ClearCountRankingEntryDto::new(
+                        row.rank,
+                        row.user_id,
+                        row.display_name,
+                        row.clears,
+                    )
+                })
+                .collect(),
+        ))
+    }
+
+    async fn load_plays(
+        &self,
+        scope: RankingScope,
+        window: &RankingWindow,
+    ) -> Result<PlayCountRankingDto, RankingUsecaseError> {
+        let play_repo = self.repositories.play();
+        match scope {
+            RankingScope::AllTime => {
+                let rows = play_repo
+                    .find_public_play_count_ranking(None, window)
+                    .await?;
+                Ok(PlayCountRankingDto::new(
+                    self.decorate_play_count_rows(rows),
+                ))
+            }
+            RankingScope::Current(period) => {
+                let season = self.current_season(period).await?;
+                let rows = play_repo
+                    .find_public_play_count_ranking(
+                        Some((*season.starts_at(), *season.ends_at())),
+                        window,
+                    )
+                    .await?;
+                Ok(
+                    PlayCountRankingDto::new(self.decorate_play_count_rows(rows))
+                        .with_season(SeasonDto::from(&season)),
+                )
+            }
+            RankingScope::Previous(period) => {
+                let season = self.latest_archived_season(period).await?;
+                let standings = self
+                    .repositories
+                    .season()
+                    .find_standings(season.id(), SeasonBoard::Plays, window)
+                    .await?;
+                let entries = standings
+                    .into_iter()
+                    .map(|standing| {
+                        PlayCountRankingEntryDto::new(
+                            standing.rank,
+                            standing.user_id,
+                            standing.display_name,
+                            standing.value,
+                        )
+                    })
+                    .collect();
+                Ok(PlayCountRankingDto::new(entries).with_season(SeasonDto::from(&season)))
+            }
+        }
+    }
+
+    async fn load_rating(
+        &self,
+        window: &RankingWindow,
+    ) -> Result<RatingRankingDto, RankingUsecaseError> {
+        let users = self
+            .repositories
+            .user()
+            .find_public_rating_ranking(window)
+            .await?;
+        Ok(RatingRankingDto::new(
+            users
+                .into_iter()
+                .map(|RankedUser { rank, user }| {
+                    RatingRankingEntryDto::new(
+                        rank,
+                        user.id().to_owned(),
+                        user.display_name().clone(),
+                        user.rating().value(),
+                    )
+                })
+                .collect(),
+        ))
+    }
+
+    async fn load_xp(&self, window: &RankingWindow) -> Result<XpRankingDto, RankingUsecaseError> {
+        let users = self
+            .repositories
+            .user()
+            .find_public_xp_ranking(window)
+            .await?;
+        Ok(XpRankingDto::new(
+            users
+                .into_iter()
+                .map(|RankedUser { rank, user }| {
+                    XpRankingEntryDto::new(
+                        rank,
+                        user.id().to_owned(),
+                        user.display_name().clone(),
+                        *user.xp(),
+                        self.level_curve.level_for(*user.xp()).level,
+                    )
+                })
+                .collect(),
+        ))
+    }
+
+    async fn current_season(&self, period: SeasonPeriod) -> Result<Season, RankingUsecaseError> {
+        self.repositories
+            .season()
+            .find_active_at(period, Utc::now())
+            .await?
+            .ok_or(RankingUsecaseError::NoCurrentSeason(period))
+    }
+
+    /// Finished seasons only get standings once an operator archives them, so this never ranks
+    /// plays itself.
+    async fn latest_archived_season(
+        &self,
+        period: SeasonPeriod,
+    ) -> Result<Season, RankingUsecaseError> {
+        self.repositories
+            .season()
+            .find_latest_archived(period)
+            .await?
+            .ok_or(RankingUsecaseError::NoArchivedSeason(period))
+    }
+
+    fn decorate_sheet_rows(
+        &self,
+        rows: Vec<SheetScoreRankingRow>,
+    ) -> Vec<SheetScoreRankingEntryDto> {
+        rows.into_iter()
+            .map(|row| {
+                SheetScoreRankingEntryDto::new(row.rank, row.user_id, row.display_name, row.score)
+            })
+            .collect()
+    }
+
+    fn decorate_total_rows(
+        &self,
+        rows: Vec<TotalScoreRankingRow>,
+    ) -> Vec<TotalScoreRankingEntryDto> {
+        rows.into_iter()
+            .map(|row| {
+                TotalScoreRankingEntryDto::new(
+                    row.rank,
+                    row.user_id,
+                    row.display_name,
+                    row.total_score,
+                )
+            })
+            .collect()
+    }
+
+    fn decorate_play_count_rows(
+        &self,
+        rows: Vec<PlayCountRankingRow>,
+    ) -> Vec<PlayCountRankingEntryDto> {
+        rows.into_iter()
+            .map(|row| {
+                PlayCountRankingEntryDto::new(row.rank, row.user_id, row.display_name, row.plays)
+            })
+            .collect()
+    }
+}
+
+impl<R: Repositories> Clone for RankingUsecase<R> {
+    fn clone(&self) -> Self {
+        Self {
+            repositories: Arc::clone(&self.repositories),
+            level_curve: Arc::clone(&self.level_curve),
+            cache: Arc::clone(&self.cache),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        repository::{
+            MockRepositories,
+            play::MockPlayRepository,
+            record::MockRecordRepository,
+            season::{MockSeasonRepository, SeasonStanding},
+        },
+        testing::datetime::timestamp,
+    };
+
+    use super::*;
+
+    const SEASON_ID: &str = "55555555-5555-5555-5555-555555555555";
+
+    fn build_usecase(
+        record_repo: MockRecordRepository,
+        play_repo: MockPlayRepository,
+        season_repo: MockSeasonRepository,
+    ) -> RankingUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            record: record_repo,
+            play: play_repo,
+            season: season_repo,
+            ..Default::default()
+        };
+        RankingUsecase::new(Arc::new(repositories))
+    }
+
+    fn page() -> RankingWindow {
+        RankingWindow::Page {
+            offset: 0,
+            limit: 20,
+        }
+    }
+
+    fn archived_season() -> Season {
+        Season::new(
+            SEASON_ID.to_owned(),
+            SeasonPeriod::Weekly,
+            timestamp(2025, 11, 10, 0, 0, 0),
+            timestamp(2025, 11, 17, 0, 0, 0),
+            Some(timestamp(2025, 11, 17, 0, 5, 0)),
+        )
+    }
+
+    #[tokio::test]
+    async fn plays_ranks_the_current_season_from_play_history() {
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo
+            .expect_find_active_at()
+            .withf(|period, _| *period == SeasonPeriod::Monthly)
+            .returning(|period, _| {
+                let season = Season::new(
+                    SEASON_ID.to_owned(),
+                    period,
+                    timestamp(2025, 11, 1, 0, 0, 0),
+                    timestamp(2025, 12, 1, 0, 0, 0),
+                    None,
+                );
+                Box::pin(async move { Ok(Some(season)) })
+            });
+        let mut play_repo = MockPlayRepository::new();
+        play_repo
+            .expect_find_public_play_count_ranking()
+            .withf(|between, _| {
+                *between
+                    == Some((
+                        timestamp(2025, 11, 1, 0, 0, 0),
+                        timestamp(2025, 12, 1, 0, 0, 0),
+                    ))
+            })
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![PlayCountRankingRow::new(
+                        1,
+                        "user-1".to_owned(),
+                        "Alice".to_owned(),
+                        42,
+                    )])
+                })
+            });
+        let usecase = build_usecase(MockRecordRepository::new(), play_repo, season_repo);
+
+        let ranking = usecase
+            .plays(RankingScope::Current(SeasonPeriod::Monthly), &page())
+            .await
+            .unwrap();
+
+        assert_eq!(ranking.entries.len(), 1);
+        assert_eq!(ranking.entries[0].plays, 42);
+        let season = ranking.season.expect("season should be reported");
+        assert_eq!(season.period, SeasonPeriod::Monthly);
+        assert!(season.archived_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn previous_season_reads_existing_archive() {
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo
+            .expect_find_latest_archived()
+            .returning(|_| Box::pin(async { Ok(Some(archived_season())) }));
+        season_repo.expect_archive().never();
+        season_repo
+            .expect_find_standings()
+            .withf(|season_id, board, _| {
+                season_id == SEASON_ID && *board == SeasonBoard::TotalScore
+            })
+            .returning(|_, board, _| {
+                Box::pin(async move {
+                    Ok(vec![SeasonStanding::new(
+                        board,
+                        1,
+                        "user-1".to_owned(),
+                        "Alice".to_owned(),
+                        2_000_000,
+                    )])
+                })
+            });
+        let usecase = build_usecase(
+            MockRecordRepository::new(),
+            MockPlayRepository::new(),
+            season_repo,
+        );
+
+        let ranking = usecase
+            .total_high_scores(RankingScope::Previous(SeasonPeriod::Weekly), &page())
+            .await
+            .unwrap();
+
+        assert_eq!(ranking.entries[0].total_score, 2_000_000);
+        assert_eq!(
+            ranking.season.map(|season| season.starts_at),
+            Some(timestamp(2025, 11, 10, 0, 0, 0))
+        );
+    }
+
+    #[tokio::test]
+    async fn previous_season_is_never_archived_by_a_read() {
+        let mut play_repo = MockPlayRepository::new();
+        play_repo.expect_find_public_play_count_ranking().never();
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo
+            .expect_find_latest_archived()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        season_repo.expect_archive().never();
+        let usecase = build_usecase(MockRecordRepository::new(), play_repo, season_repo);
+
+        let err = usecase
+            .plays(RankingScope::Previous(SeasonPeriod::Weekly), &page())
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(
+            err,
+            RankingUsecaseError::NoArchivedSeason(SeasonPeriod::Weekly)
+        ));
+    }
+
+    #[tokio::test]
+    async fn filtered_total_high_scores_without_criteria_uses_the_full_ranking() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_public_filtered_total_score_ranking()
+            .never();
+        record_repo
+            .expect_find_public_total_score_ranking()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(Vec::new()) }));
+        let usecase = build_usecase(
+            record_repo,
+            MockPlayRepository::new(),
+            MockSeasonRepository::new(),
+        );
+
+        let ranking = usecase
+            .filtered_total_high_scores(&RankingFilter::default(), &page())
+            .await
+            .unwrap();
+
+        assert!(ranking.entries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn total_high_scores_are_cached_until_invalidated() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_public_total_score_ranking()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(Vec::new()) }));
+        let cache = Arc::new(RankingCache::new(std::time::Duration::from_secs(60)));
+        let usecase = build_usecase(
+            record_repo,
+            MockPlayRepository::new(),
+            MockSeasonRepository::new(),
+        )
+        .with_cache(Arc::clone(&cache));
+
+        let first = usecase
+            .total_high_scores(RankingScope::AllTime, &page())
+            .await
+            .unwrap();
+        let cached = usecase
+            .total_high_scores(RankingScope::AllTime, &page())
+            .await
+            .unwrap();
+        cache.invalidate();
+        usecase
+            .total_high_scores(RankingScope::AllTime, &page())
+            .await
+            .unwrap();
+
+        assert_eq!(cached.generated_at, first.generated_at);
+    }
+}
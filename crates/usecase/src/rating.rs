@@ -0,0 +1,264 @@
+use std::sync::Arc;
+
+use domain::{
+    repository::{
+        Repositories, UnitOfWork,
+        play::{PlayRepository, PlayRepositoryError},
+        record::{RecordRepository, RecordRepositoryError, SheetRating},
+        user::{UserRepository, UserRepositoryError},
+    },
+    service::rating::RatingPolicy,
+};
+use thiserror::Error;
+use tracing::{debug, info, instrument};
+
+use crate::{model::rating::RatingRecomputeDto, ranking::RankingCache};
+
+#[derive(Debug, Error)]
+pub enum RatingUsecaseError {
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    RecordRepository(#[from] RecordRepositoryError),
+    #[error(transparent)]
+    PlayRepository(#[from] PlayRepositoryError),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+pub struct RatingUsecase<R: Repositories> {
+    repositories: Arc<R>,
+    rating_policy: Arc<RatingPolicy>,
+    ranking_cache: Arc<RankingCache>,
+}
+
+impl<R: Repositories> RatingUsecase<R> {
+    pub fn new(repositories: Arc<R>, rating_policy: Arc<RatingPolicy>) -> Self {
+        Self {
+            repositories,
+            rating_policy,
+            ranking_cache: Arc::default(),
+        }
+    }
+
+    /// Shares the cache of [`crate::ranking::RankingUsecase`] so that recomputed ratings are
+    /// served right away.
+    pub fn with_ranking_cache(mut self, ranking_cache: Arc<RankingCache>) -> Self {
+        self.ranking_cache = ranking_cache;
+        self
+    }
+
+    /// Re-rates every record and user with the current policy. Each user is rewritten in their
+    /// own transaction, so an interrupted run leaves every user either fully old or fully new and
+    /// can simply be started again.
+    #[instrument(skip(self))]
+    pub async fn recompute_all(&self) -> Result<RatingRecomputeDto, RatingUsecaseError> {
+        let user_ids = self.repositories.user().find_all_ids().await?;
+
+        let mut updated = 0;
+        for user_id in &user_ids {
+            match self.recompute_user(user_id).await {
+                Ok(true) => updated += 1,
+                Ok(false) => {}
+                Err(err) => {
+                    // Users rewritten before the failure are already committed.
+                    if updated > 0 {
+                        self.ranking_cache.invalidate();
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        if updated > 0 {
+            self.ranking_cache.invalidate();
+        }
+
+        info!(users = user_ids.len(), updated, "Ratings recomputed");
+        Ok(RatingRecomputeDto::new(user_ids.len() as u64, updated))
+    }
+
+    /// Returns whether the user's cached rating changed.
+    async fn recompute_user(&self, user_id: &str) -> Result<bool, RatingUsecaseError> {
+        let uow = self.repositories.begin().await?;
+        let changed = rerate_user(&uow, &self.rating_policy, user_id).await?;
+        uow.commit().await?;
+
+        Ok(changed)
+    }
+}
+
+/// Re-rates the user's records and the user inside `uow` and returns whether the user's cached
+/// rating changed. Users that no longer exist are skipped.
+pub(crate) async fn rerate_user<U: UnitOfWork>(
+    uow: &U,
+    policy: &RatingPolicy,
+    user_id: &str,
+) -> Result<bool, RatingUsecaseError> {
+    // Locked first, so that a concurrent submission cannot commit between reading the records and
+    // saving the rating computed from them.
+    let Some(mut user) = uow.user().find_by_id_for_update(user_id).await? else {
+        debug!(user_id, "User disappeared during recomputation");
+        return Ok(false);
+    };
+
+    let records = uow.record().find_with_metadata_by_user_id(user_id).await?;
+    let sheet_ratings = records
+        .iter()
+        .map(|entry| {
+            SheetRating::new(
+                entry.record.sheet_id().to_owned(),
+                policy.sheet_rating(&entry.level, *entry.record.score()),
+            )
+        })
+        .collect();
+    uow.record().update_ratings(user_id, sheet_ratings).await?;
+
+    let recent_ratings: Vec<u32> = if policy.recent_count() == 0 {
+        Vec::new()
+    } else {
+        uow.play()
+            .find_recent_scores(user_id, policy.recent_count() as u64)
+            .await?
+            .iter()
+            .map(|play| policy.sheet_rating(&play.level, play.score))
+            .collect()
+    };
+    let rating = policy.user_rating_from_records(&records, recent_ratings);
+
+    let changed = user.rating().value() != rating.value();
+    if changed {
+        user.update_rating(rating);
+        uow.user().save(user).await?;
+    }
+
+    Ok(changed)
+}
+
+impl<R: Repositories> Clone for RatingUsecase<R> {
+    fn clone(&self) -> Self {
+        Self {
+            repositories: Arc::clone(&self.repositories),
+            rating_policy: Arc::clone(&self.rating_policy),
+            ranking_cache: Arc::clone(&self.ranking_cache),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        entity::{
+            clear_type::ClearType, difficulty::Difficulty, judgement::Judgement, level::Level,
+            record::Record,
+        },
+        repository::{
+            MockRepositories,
+            record::{MockRecordRepository, RecordWithMetadata},
+            user::MockUserRepository,
+        },
+        testing::{datetime::sample_timestamp, user::USER1},
+    };
+
+    use super::*;
+
+    fn entry(sheet_id: &str, level: (u32, u32), score: u32, is_test: bool) -> RecordWithMetadata {
+        let record = Record::new_from_submission(
+            USER1.id.to_owned(),
+            sheet_id.to_owned(),
+            score,
+            ClearType::Clear,
+            Judgement::default(),
+            0,
+            sample_timestamp(),
+        );
+        RecordWithMetadata::new(
+            record,
+            Difficulty::Hard,
+            Level::try_from(level).unwrap(),
+            is_test,
+        )
+    }
+
+    fn repositories(
+        records: fn() -> Vec<RecordWithMetadata>,
+        user_repo: MockUserRepository,
+        expected_ratings: Vec<SheetRating>,
+    ) -> MockRepositories {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_with_metadata_by_user_id()
+            .returning(move |_| Box::pin(async move { Ok(records()) }));
+        record_repo
+            .expect_update_ratings()
+            .withf(move |user_id, ratings| user_id == USER1.id && *ratings == expected_ratings)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        MockRepositories {
+            user: user_repo,
+            record: record_repo,
+            ..Default::default()
+        }
+    }
+
+    fn user_repo_with_user1() -> MockUserRepository {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_all_ids()
+            .returning(|| Box::pin(async { Ok(vec![USER1.id.to_owned()]) }));
+        user_repo.expect_find_by_id_for_update().returning(|_| {
+            let user = USER1.build(true, false, sample_timestamp());
+            Box::pin(async move { Ok(Some(user)) })
+        });
+        user_repo
+    }
+
+    #[tokio::test]
+    async fn recompute_all_rewrites_records_and_changed_users() {
+        let mut user_repo = user_repo_with_user1();
+        user_repo
+            .expect_save()
+            .withf(|user| user.id() == USER1.id && user.rating().value() == 1470)
+            .times(1)
+            .returning(|user| Box::pin(async move { Ok(user) }));
+
+        let repositories = repositories(
+            || {
+                vec![
+                    entry("sheet-1", (13, 7), 1_000_000, false),
+                    entry("sheet-2", (15, 0), 1_090_000, true),
+                ]
+            },
+            user_repo,
+            // Test musics keep an up-to-date record rating even though they never count.
+            vec![
+                SheetRating::new("sheet-1".to_owned(), 1470),
+                SheetRating::new("sheet-2".to_owned(), 1700),
+            ],
+        );
+        let usecase = RatingUsecase::new(Arc::new(repositories), Arc::default());
+
+        let result = usecase.recompute_all().await.expect("should succeed");
+
+        assert_eq!(result.users, 1);
+        assert_eq!(result.updated, 1);
+    }
+
+    #[tokio::test]
+    async fn recompute_all_skips_saving_unchanged_users() {
+        let mut user_repo = user_repo_with_user1();
+        user_repo.expect_save().never();
+
+        let repositories = repositories(
+            || vec![entry("sheet-1", (14, 0), 1_000_000, false)],
+            user_repo,
+            vec![SheetRating::new("sheet-1".to_owned(), USER1.rating)],
+        );
+        let usecase = RatingUsecase::new(Arc::new(repositories), Arc::default());
+
+        let result = usecase.recompute_all().await.expect("should succeed");
+
+        assert_eq!(result.users, 1);
+        assert_eq!(result.updated, 0);
+    }
+}
@@ -0,0 +1,421 @@
+use std::{collections::HashMap, sync::Arc};
+
+use domain::{
+    entity::{
+        rival::{Rival, RivalError},
+        user::User,
+    },
+    repository::{
+        Repositories,
+        record::{RecordRepository, RecordRepositoryError},
+        rival::{RivalRepository, RivalRepositoryError},
+        user::{UserRepository, UserRepositoryError},
+    },
+};
+use thiserror::Error;
+use tracing::{debug, info, instrument};
+
+use crate::model::rival::{
+    ComparisonResult, RivalComparisonDto, RivalDto, RivalRankingEntryDto, SheetComparisonDto,
+};
+
+/// Most rivals a single user can register.
+pub const MAX_RIVALS: usize = 20;
+
+#[derive(Debug, Error)]
+pub enum RivalUsecaseError {
+    #[error("Invalid rival: {0}")]
+    InvalidRival(#[from] RivalError),
+    #[error("User not found for id: {user_id}")]
+    NotFoundById { user_id: String },
+    #[error("Rival {rival_id} is already registered")]
+    AlreadyRegistered { rival_id: String },
+    #[error("Rival {rival_id} is not registered")]
+    NotRegistered { rival_id: String },
+    #[error("At most {limit} rivals can be registered")]
+    LimitReached { limit: usize },
+    #[error(transparent)]
+    RivalRepository(RivalRepositoryError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    RecordRepository(#[from] RecordRepositoryError),
+}
+
+impl From<RivalRepositoryError> for RivalUsecaseError {
+    fn from(err: RivalRepositoryError) -> Self {
+        match err {
+            RivalRepositoryError::UserNotFound(user_id) => Self::NotFoundById { user_id },
+            RivalRepositoryError::AlreadyRegistered { rival_id, .. } => {
+                Self::AlreadyRegistered { rival_id }
+            }
+            RivalRepositoryError::NotFound { rival_id, .. } => Self::NotRegistered { rival_id },
+            err => Self::RivalRepository(err),
+        }
+    }
+}
+
+/// Manages the rivals users follow and compares their scores. Private users can neither be
+/// registered as rivals nor compared against, and they are left out of rival rankings.
+pub struct RivalUsecase<R: Repositories> {
+    repositories: Arc<R>,
+}
+
+impl<R: Repositories> RivalUsecase<R> {
+    pub fn new(repositories: Arc<R>) -> Self {
+        Self { repositories }
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id, rival_id = %rival_id))]
+    pub async fn add(
+        &self,
+        user_id: String,
+        rival_id: String,
+    ) -> Result<RivalDto, RivalUsecaseError> {
+        let rival = Rival::new_temporary(user_id.clone(), rival_id.clone())?;
+        self.find_user(&user_id).await?;
+        let rival_user = self.find_public_user(&rival_id).await?;
+
+        let registered = self.repositories.rival().find_by_user_id(&user_id).await?;
+        if registered.len() >= MAX_RIVALS {
+            return Err(RivalUsecaseError::LimitReached { limit: MAX_RIVALS });
+        }
+
+        let created = self.repositories.rival().create(rival).await?;
+        info!("Rival registered");
+        Ok(RivalDto::new(&rival_user, *created.created_at()))
+    }
+
+    /// The user's rivals in the order they were registered.
+    #[instrument(skip(self), fields(user_id = %user_id))]
+    pub async fn list(&self, user_id: String) -> Result<Vec<RivalDto>, RivalUsecaseError> {
+        self.find_user(&user_id).await?;
+        let mut rivals = Vec::new();
+        for rival in self.repositories.rival().find_by_user_id(&user_id).await? {
+            // rows are removed along with their user, so a miss only means a concurrent deletion
+            if let Some(user) = self
+                .repositories
+                .user()
+                .find_by_id(rival.rival_id())
+                .await?
+            {
+                rivals.push(RivalDto::new(&user, *rival.created_at()));
+            }
+        }
+        debug!(count = rivals.len(), "Rivals resolved");
+        Ok(rivals)
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id, rival_id = %rival_id))]
+    pub async fn remove(&self, user_id: String, rival_id: String) -> Result<(), RivalUsecaseError> {
+        self.repositories
+            .rival()
+            .delete(&user_id, &rival_id)
+            .await?;
+        info!("Rival removed");
+        Ok(())
+    }
+
+    /// Ranks the user and their public rivals by rating. Tied users share a rank and are ordered
+    /// by display name, like the global rating ranking.
+    #[instrument(skip(self), fields(user_id = %user_id))]
+    pub async fn ranking(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<RivalRankingEntryDto>, RivalUsecaseError> {
+        let user = self.find_user(&user_id).await?;
+        let mut users = vec![user];
+        for rival in self.repositories.rival().find_by_user_id(&user_id).await? {
+            if let Some(rival_user) = self
+                .repositories
+                .user()
+                .find_by_id(rival.rival_id())
+                .await?
+                && *rival_user.is_public()
+            {
+                users.push(rival_user);
+            }
+        }
+        users.sort_by(|a, b| {
+            b.rating()
+                .value()
+                .cmp(&a.rating().value())
+                .then_with(|| a.display_name().cmp(b.display_name()))
+        });
+
+        let mut entries: Vec<RivalRankingEntryDto> = Vec::with_capacity(users.len());
+        for (position, user) in users.iter().enumerate() {
+            let rating = user.rating().value();
+            let rank = match entries.last() {
+                Some(previous) if previous.rating == rating => previous.rank,
+                _ => position as u32 + 1,
+            };
+            entries.push(RivalRankingEntryDto {
+                rank,
+                user_id: user.id().to_owned(),
+                display_name: user.display_name().to_owned(),
+                rating,
+                is_self: user.id() == &user_id,
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Compares the user's best scores with a registered rival's on every sheet both have played,
+    /// ordered by sheet id.
+    #[instrument(skip(self), fields(user_id = %user_id, rival_id = %rival_id))]
+    pub async fn compare(
+        &self,
+        user_id: String,
+        rival_id: String,
+    ) -> Result<RivalComparisonDto, RivalUsecaseError> {
+        self.find_user(&user_id).await?;
+        let registered = self.repositories.rival().find_by_user_id(&user_id).await?;
+        if !registered.iter().any(|rival| rival.rival_id() == &rival_id) {
+            return Err(RivalUsecaseError::NotRegistered { rival_id });
+        }
+        let rival_user = self.find_public_user(&rival_id).await?;
+
+        let records = self.repositories.record().find_by_user_id(&user_id).await?;
+        let sheet_ids: Vec<String> = records
+            .iter()
+            .map(|record| record.sheet_id().to_owned())
+            .collect();
+        let rival_scores: HashMap<String, u32> = self
+            .repositories
+            .record()
+            .find_by_user_id_and_sheet_ids(&rival_id, &sheet_ids)
+            .await?
+            .into_iter()
+            .map(|record| (record.sheet_id().to_owned(), *record.score()))
+            .collect();
+
+        let mut sheets: Vec<SheetComparisonDto> = records
+            .iter()
+            .filter_map(|record| {
+                rival_scores.get(record.sheet_id()).map(|rival_score| {
+                    SheetComparisonDto::new(
+                        record.sheet_id().to_owned(),
+                        *record.score(),
+                        *rival_score,
+                    )
+                })
+            })
+            .collect();
+        sheets.sort_by(|a, b| a.sheet_id.cmp(&b.sheet_id));
+
+        let count = |result: ComparisonResult| {
+            sheets.iter().filter(|sheet| sheet.result == result).count() as u32
+        };
+        debug!(compared = sheets.len(), "Rival comparison computed");
+        Ok(RivalComparisonDto {
+            rival_id: rival_user.id().to_owned(),
+            display_name: rival_user.display_name().to_owned(),
+            wins: count(ComparisonResult::Win),
+            losses: count(ComparisonResult::Lose),
+            draws: count(ComparisonResult::Draw),
+            sheets,
+        })
+    }
+
+    async fn find_user(&self, user_id: &str) -> Result<User, RivalUsecaseError> {
+        self.repositories
+            .user()
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| RivalUsecaseError::NotFoundById {
+                user_id: user_id.to_owned(),
+            })
+    }
+
+    /// Private users are reported as missing so that callers cannot tell them apart from unknown
+    /// ids.
+    async fn find_public_user(&self, user_id: &str) -> Result<User, RivalUsecaseError> {
+        match self.find_user(user_id).await? {
+            user if *user.is_public() => Ok(user),
+            _ => Err(RivalUsecaseError::NotFoundById {
+                user_id: user_id.to_owned(),
+            }),
+        }
+    }
+}
+
+impl<R: Repositories> Clone for RivalUsecase<R> {
+    fn clone(&self) -> Self {
+        Self {
+            repositories: Arc::clone(&self.repositories),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        entity::{clear_type::ClearType, judgement::Judgement, record::Record},
+        repository::{
+            MockRepositories, record::MockRecordRepository, rival::MockRivalRepository,
+            user::MockUserRepository,
+        },
+        testing::{
+            datetime::sample_timestamp,
+            user::{USER1, USER2, USER3},
+        },
+    };
+
+    use super::*;
+
+    /// USER1 and USER2 are public, USER3 is private.
+    fn user_repo() -> MockUserRepository {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id().returning(|user_id| {
+            let user = [(&USER1, true), (&USER2, true), (&USER3, false)]
+                .into_iter()
+                .find(|(sample, _)| sample.id == user_id)
+                .map(|(sample, is_public)| sample.build(is_public, false, sample_timestamp()));
+            Box::pin(async move { Ok(user) })
+        });
+        user_repo
+    }
+
+    fn rival_repo(rival_ids: &'static [&'static str]) -> MockRivalRepository {
+        let mut rival_repo = MockRivalRepository::new();
+        rival_repo
+            .expect_find_by_user_id()
+            .returning(move |user_id| {
+                let rivals = rival_ids
+                    .iter()
+                    .map(|rival_id| {
+                        Rival::new(
+                            user_id.to_owned(),
+                            (*rival_id).to_owned(),
+                            sample_timestamp(),
+                        )
+                    })
+                    .collect();
+                Box::pin(async move { Ok(rivals) })
+            });
+        rival_repo
+    }
+
+    fn build_usecase(
+        rival_repo: MockRivalRepository,
+        record_repo: MockRecordRepository,
+    ) -> RivalUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            user: user_repo(),
+            record: record_repo,
+            rival: rival_repo,
+            ..Default::default()
+        };
+        RivalUsecase::new(Arc::new(repositories))
+    }
+
+    fn record(user_id: &str, sheet_id: &str, score: u32) -> Record {
+        Record::new_from_submission(
+            user_id.to_owned(),
+            sheet_id.to_owned(),
+            score,
+            ClearType::Clear,
+            Judgement::default(),
+            0,
+            sample_timestamp(),
+        )
+    }
+
+    #[tokio::test]
+    async fn add_rejects_private_users() {
+        let usecase = build_usecase(rival_repo(&[]), MockRecordRepository::new());
+
+        let err = usecase
+            .add(USER1.id.to_owned(), USER3.id.to_owned())
+            .await
+            .expect_err("should reject");
+
+        assert!(matches!(
+            err,
+            RivalUsecaseError::NotFoundById { user_id } if user_id == USER3.id
+        ));
+    }
+
+    #[tokio::test]
+    async fn ranking_skips_private_rivals_and_marks_the_user() {
+        let usecase = build_usecase(
+            rival_repo(&[USER2.id, USER3.id]),
+            MockRecordRepository::new(),
+        );
+
+        let ranking = usecase
+            .ranking(USER1.id.to_owned())
+            .await
+            .expect("should succeed");
+
+        let users: Vec<(u32, &str, bool)> = ranking
+            .iter()
+            .map(|entry| (entry.rank, entry.user_id.as_str(), entry.is_self))
+            .collect();
+        assert_eq!(users, vec![(1, USER2.id, false), (2, USER1.id, true)]);
+    }
+
+    #[tokio::test]
+    async fn compare_counts_sheets_both_users_played() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_by_user_id()
+            .withf(|user_id| user_id == USER1.id)
+            .returning(|_| {
+                let records = vec![
+                    record(USER1.id, "sheet-1", 1_000_000),
+                    record(USER1.id, "sheet-2", 900_000),
+                    record(USER1.id, "sheet-3", 950_000),
+                    record(USER1.id, "sheet-4", 800_000),
+                ];
+                Box::pin(async move { Ok(records) })
+            });
+        record_repo
+            .expect_find_by_user_id_and_sheet_ids()
+            .withf(|user_id, sheet_ids| user_id == USER2.id && sheet_ids.len() == 4)
+            .returning(|_, _| {
+                let records = vec![
+                    record(USER2.id, "sheet-1", 990_000),
+                    record(USER2.id, "sheet-2", 910_000),
+                    record(USER2.id, "sheet-3", 950_000),
+                ];
+                Box::pin(async move { Ok(records) })
+            });
+        let usecase = build_usecase(rival_repo(&[USER2.id]), record_repo);
+
+        let comparison = usecase
+            .compare(USER1.id.to_owned(), USER2.id.to_owned())
+            .await
+            .expect("should succeed");
+
+        assert_eq!(comparison.display_name, USER2.display_name);
+        assert_eq!(
+            (comparison.wins, comparison.losses, comparison.draws),
+            (1, 1, 1)
+        );
+        assert_eq!(comparison.sheets.len(), 3);
+        assert_eq!(comparison.sheets[1].result, ComparisonResult::Lose);
+    }
+
+    #[tokio::test]
+    async fn compare_requires_a_registered_public_rival() {
+        let usecase = build_usecase(rival_repo(&[USER3.id]), MockRecordRepository::new());
+
+        let unregistered = usecase
+            .compare(USER1.id.to_owned(), USER2.id.to_owned())
+            .await;
+        let private = usecase
+            .compare(USER1.id.to_owned(), USER3.id.to_owned())
+            .await;
+
+        assert!(matches!(
+            unregistered,
+            Err(RivalUsecaseError::NotRegistered { .. })
+        ));
+        assert!(matches!(
+            private,
+            Err(RivalUsecaseError::NotFoundById { .. })
+        ));
+    }
+}
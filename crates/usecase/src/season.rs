@@ -0,0 +1,331 @@
+use std::{future::Future, sync::Arc};
+
+use chrono::Utc;
+use domain::{
+    entity::season::{Season, SeasonBoard, SeasonError},
+    repository::{
+        Repositories,
+        play::{PlayRepository, PlayRepositoryError},
+        ranking::RankingWindow,
+        season::{SeasonRepository, SeasonRepositoryError, SeasonStanding},
+    },
+};
+use thiserror::Error;
+use tracing::{info, instrument};
+
+use crate::{
+    model::{ranking::SeasonDto, season::SeasonCreateDto},
+    ranking::RankingCache,
+};
+
+/// Entries ranked per query while archiving, so no single query ranks every player.
+const ARCHIVE_PAGE_SIZE: u64 = 1000;
+
+#[derive(Debug, Error)]
+pub enum SeasonUsecaseError {
+    #[error("Invalid season: {0}")]
+    InvalidSeason(#[from] SeasonError),
+    #[error("Season not found for id: {season_id}")]
+    NotFoundById { season_id: String },
+    #[error("Season {season_id} has not ended yet")]
+    NotEnded { season_id: String },
+    #[error(transparent)]
+    SeasonRepository(SeasonRepositoryError),
+    #[error(transparent)]
+    PlayRepository(#[from] PlayRepositoryError),
+}
+
+impl From<SeasonRepositoryError> for SeasonUsecaseError {
+    fn from(err: SeasonRepositoryError) -> Self {
+        match err {
+            SeasonRepositoryError::NotFound(season_id) => Self::NotFoundById { season_id },
+            err => Self::SeasonRepository(err),
+        }
+    }
+}
+
+/// Administers the seasons that seasonal rankings cover, and freezes their final standings once
+/// they end.
+pub struct SeasonUsecase<R: Repositories> {
+    repositories: Arc<R>,
+    ranking_cache: Arc<RankingCache>,
+}
+
+impl<R: Repositories> SeasonUsecase<R> {
+    pub fn new(repositories: Arc<R>) -> Self {
+        Self {
+            repositories,
+            ranking_cache: Arc::default(),
+        }
+    }
+
+    /// Shares the cache of [`crate::ranking::RankingUsecase`] so that seasonal rankings follow
+    /// new and archived seasons right away.
+    pub fn with_ranking_cache(mut self, ranking_cache: Arc<RankingCache>) -> Self {
+        self.ranking_cache = ranking_cache;
+        self
+    }
+
+    #[instrument(skip(self, dto), fields(period = ?dto.period))]
+    pub async fn create(&self, dto: SeasonCreateDto) -> Result<SeasonDto, SeasonUsecaseError> {
+        let season = Season::new_temporary(dto.period, dto.starts_at, dto.ends_at)?;
+        let created = self.repositories.season().create(season).await?;
+        self.ranking_cache.invalidate();
+        info!(season_id = %created.id(), "Season created");
+        Ok(SeasonDto::from(&created))
+    }
+
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<SeasonDto>, SeasonUsecaseError> {
+        let seasons = self.repositories.season().find_all().await?;
+        Ok(seasons.iter().map(SeasonDto::from).collect())
+    }
+
+    /// Ranks every board over the season's plays and stores the result as its final standings.
+    /// Archiving an archived season again returns it unchanged.
+    #[instrument(skip(self), fields(season_id = %season_id))]
+    pub async fn archive(&self, season_id: String) -> Result<SeasonDto, SeasonUsecaseError> {
+        let season_repo = self.repositories.season();
+        let season = season_repo.find_by_id(&season_id).await?.ok_or_else(|| {
+            SeasonUsecaseError::NotFoundById {
+                season_id: season_id.clone(),
+            }
+        })?;
+        if season.archived_at().is_some() {
+            return Ok(SeasonDto::from(&season));
+        }
+        if !season.has_ended_at(Utc::now()) {
+            return Err(SeasonUsecaseError::NotEnded { season_id });
+        }
+
+        let standings = self.final_standings(&season).await?;
+        let count = standings.len();
+        let archived = season_repo.archive(season.id(), standings).await?;
+        self.ranking_cache.invalidate();
+        info!(standings = count, "Season archived");
+        Ok(SeasonDto::from(&archived))
+    }
+
+    async fn final_standings(
+        &self,
+        season: &Season,
+    ) -> Result<Vec<SeasonStanding>, SeasonUsecaseError> {
+        let play_repo = self.repositories.play();
+        let (starts_at, ends_at) = (*season.starts_at(), *season.ends_at());
+
+        let totals = collect_pages(|window| async move {
+            play_repo
+                .find_public_total_score_ranking_between(starts_at, ends_at, &window)
+                .await
+        })
+        .await?;
+        let plays = collect_pages(|window| async move {
+            play_repo
+                .find_public_play_count_ranking(Some((starts_at, ends_at)), &window)
+                .await
+        })
+        .await?;
+
+        Ok(totals
+            .into_iter()
+            .map(|row| {
+                SeasonStanding::new(
+                    SeasonBoard::TotalScore,
+                    row.rank,
+                    row.user_id,
+                    row.display_name,
+                    row.total_score,
+                )
+            })
+            .chain(plays.into_iter().map(|row| {
+                SeasonStanding::new(
+                    SeasonBoard::Plays,
+                    row.rank,
+                    row.user_id,
+                    row.display_name,
+                    row.plays,
+                )
+            }))
+            .collect())
+    }
+}
+
+/// Reads a ranking page by page until a page comes back short.
+async fn collect_pages<T, F, Fut>(mut fetch_page: F) -> Result<Vec<T>, PlayRepositoryError>
+where
+    F: FnMut(RankingWindow) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, PlayRepositoryError>>,
+{
+    let mut rows = Vec::new();
+    let mut offset = 0;
+    loop {
+        let page = fetch_page(RankingWindow::Page {
+            offset,
+            limit: ARCHIVE_PAGE_SIZE,
+        })
+        .await?;
+        let fetched = page.len() as u64;
+        rows.extend(page);
+        if fetched < ARCHIVE_PAGE_SIZE {
+            return Ok(rows);
+        }
+        offset += ARCHIVE_PAGE_SIZE;
+    }
+}
+
+impl<R: Repositories> Clone for SeasonUsecase<R> {
+    fn clone(&self) -> Self {
+        Self {
+            repositories: Arc::clone(&self.repositories),
+            ranking_cache: Arc::clone(&self.ranking_cache),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration};
+    use domain::{
+        entity::season::SeasonPeriod,
+        repository::{
+            MockRepositories,
+            play::{MockPlayRepository, PlayCountRankingRow},
+            record::TotalScoreRankingRow,
+            season::MockSeasonRepository,
+        },
+        testing::datetime::timestamp,
+    };
+
+    use super::*;
+
+    const SEASON_ID: &str = "55555555-5555-5555-5555-555555555555";
+
+    fn build_usecase(
+        play_repo: MockPlayRepository,
+        season_repo: MockSeasonRepository,
+    ) -> SeasonUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            play: play_repo,
+            season: season_repo,
+            ..Default::default()
+        };
+        SeasonUsecase::new(Arc::new(repositories))
+    }
+
+    fn season_ending_at(ends_at: DateTime<Utc>) -> Season {
+        Season::new(
+            SEASON_ID.to_owned(),
+            SeasonPeriod::Weekly,
+            ends_at - Duration::days(7),
+            ends_at,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn create_rejects_seasons_that_end_before_they_start() {
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo.expect_create().never();
+        let usecase = build_usecase(MockPlayRepository::new(), season_repo);
+
+        let err = usecase
+            .create(SeasonCreateDto::new(
+                SeasonPeriod::Weekly,
+                timestamp(2025, 11, 24, 0, 0, 0),
+                timestamp(2025, 11, 17, 0, 0, 0),
+            ))
+            .await
+            .expect_err("should reject");
+
+        assert!(matches!(
+            err,
+            SeasonUsecaseError::InvalidSeason(SeasonError::EmptyPeriod)
+        ));
+    }
+
+    #[tokio::test]
+    async fn archive_rejects_seasons_that_are_still_running() {
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo.expect_find_by_id().returning(|_| {
+            let season = season_ending_at(Utc::now() + Duration::days(1));
+            Box::pin(async move { Ok(Some(season)) })
+        });
+        season_repo.expect_archive().never();
+        let usecase = build_usecase(MockPlayRepository::new(), season_repo);
+
+        let err = usecase
+            .archive(SEASON_ID.to_owned())
+            .await
+            .expect_err("should reject");
+
+        assert!(matches!(err, SeasonUsecaseError::NotEnded { .. }));
+    }
+
+    #[tokio::test]
+    async fn archive_ranks_the_season_page_by_page() {
+        let ends_at = timestamp(2025, 11, 24, 0, 0, 0);
+        let mut play_repo = MockPlayRepository::new();
+        play_repo
+            .expect_find_public_total_score_ranking_between()
+            .times(2)
+            .returning(|_, _, window| {
+                let RankingWindow::Page { offset, limit } = *window else {
+                    panic!("archiving should page through the ranking");
+                };
+                let size = if offset == 0 { limit } else { 1 };
+                let rows = (0..size)
+                    .map(|index| {
+                        TotalScoreRankingRow::new(
+                            (offset + index + 1) as u32,
+                            format!("user-{}", offset + index),
+                            "Alice".to_owned(),
+                            1_000_000,
+                        )
+                    })
+                    .collect();
+                Box::pin(async move { Ok(rows) })
+            });
+        play_repo
+            .expect_find_public_play_count_ranking()
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![PlayCountRankingRow::new(
+                        1,
+                        "user-0".to_owned(),
+                        "Alice".to_owned(),
+                        7,
+                    )])
+                })
+            });
+        let mut season_repo = MockSeasonRepository::new();
+        season_repo
+            .expect_find_by_id()
+            .returning(move |_| Box::pin(async move { Ok(Some(season_ending_at(ends_at))) }));
+        season_repo
+            .expect_archive()
+            .withf(|season_id, standings| {
+                season_id == SEASON_ID
+                    && standings.len() == ARCHIVE_PAGE_SIZE as usize + 2
+                    && standings
+                        .last()
+                        .is_some_and(|standing| standing.board == SeasonBoard::Plays)
+            })
+            .times(1)
+            .returning(move |_, _| {
+                let season = Season::new(
+                    SEASON_ID.to_owned(),
+                    SeasonPeriod::Weekly,
+                    ends_at - Duration::days(7),
+                    ends_at,
+                    Some(ends_at),
+                );
+                Box::pin(async move { Ok(season) })
+            });
+        let usecase = build_usecase(play_repo, season_repo);
+
+        let season = usecase.archive(SEASON_ID.to_owned()).await.unwrap();
+
+        assert_eq!(season.archived_at, Some(ends_at));
+    }
+}
@@ -1,5 +1,5 @@
-use domain::repository::{Repositories, user::UserRepository};
-use tracing::{debug, instrument};
+use domain::repository::{Repositories, UnitOfWork, user::UserRepository};
+use tracing::{debug, info, instrument};
 
 use crate::{
     model::user::UserCreditsDto,
@@ -7,14 +7,22 @@ use crate::{
 };
 
 impl<R: Repositories> UserUsecase<R> {
-    #[instrument(skip(self), fields(user_id = %user_id))]
+    /// Consumes one credit on behalf of the cabinet identified by `client_id`, which is logged
+    /// alongside the increment.
+    #[instrument(skip(self), fields(user_id = %user_id, client_id = %client_id))]
     pub async fn increment_credits(
         &self,
         user_id: String,
+        client_id: String,
     ) -> Result<UserCreditsDto, UserUsecaseError> {
         debug!("Incrementing credits via usecase");
-        match self.repositories.user().increment_credits(&user_id).await {
-            Ok(credits) => Ok(UserCreditsDto::new(credits)),
+        let uow = self.repositories.begin().await?;
+        match uow.user().increment_credits(&user_id, &client_id).await {
+            Ok(credits) => {
+                uow.commit().await?;
+                info!(credits, "Credit consumed by client");
+                Ok(UserCreditsDto::new(credits))
+            }
             Err(domain::repository::user::UserRepositoryError::NotFound(_)) => {
                 Err(UserUsecaseError::NotFoundById { user_id })
             }
@@ -28,11 +36,12 @@ mod tests {
     use std::sync::Arc;
 
     use anyhow::anyhow;
-    use domain::repository::{
-        MockRepositories,
-        music::MockMusicRepository,
-        record::MockRecordRepository,
-        user::{MockUserRepository, UserRepositoryError},
+    use domain::{
+        repository::{
+            MockRepositories,
+            user::{MockUserRepository, UserRepositoryError},
+        },
+        testing::api_key::CABINET_KEY,
     };
 
     use super::*;
@@ -42,18 +51,17 @@ mod tests {
         let mut user_repo = MockUserRepository::new();
         user_repo
             .expect_increment_credits()
-            .withf(|user_id| user_id == "user-123")
-            .returning(|_| Box::pin(async { Ok(12) }));
+            .withf(|user_id, client_id| user_id == "user-123" && client_id == CABINET_KEY.client_id)
+            .returning(|_, _| Box::pin(async { Ok(12) }));
 
         let repositories = MockRepositories {
             user: user_repo,
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
         let response = usecase
-            .increment_credits("user-123".to_owned())
+            .increment_credits("user-123".to_owned(), CABINET_KEY.client_id.to_owned())
             .await
             .expect("should succeed");
 
@@ -63,19 +71,18 @@ mod tests {
     #[tokio::test]
     async fn increment_credits_maps_not_found() {
         let mut user_repo = MockUserRepository::new();
-        user_repo.expect_increment_credits().returning(|_| {
+        user_repo.expect_increment_credits().returning(|_, _| {
             Box::pin(async { Err(UserRepositoryError::NotFound("user-404".to_owned())) })
         });
 
         let repositories = MockRepositories {
             user: user_repo,
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
         let err = usecase
-            .increment_credits("user-404".to_owned())
+            .increment_credits("user-404".to_owned(), CABINET_KEY.client_id.to_owned())
             .await
             .expect_err("should map to not found");
 
@@ -88,19 +95,18 @@ mod tests {
     #[tokio::test]
     async fn increment_credits_propagates_other_errors() {
         let mut user_repo = MockUserRepository::new();
-        user_repo.expect_increment_credits().returning(|_| {
+        user_repo.expect_increment_credits().returning(|_, _| {
             Box::pin(async { Err(UserRepositoryError::InternalError(anyhow!("boom"))) })
         });
 
         let repositories = MockRepositories {
             user: user_repo,
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
         let err = usecase
-            .increment_credits("user-err".to_owned())
+            .increment_credits("user-err".to_owned(), CABINET_KEY.client_id.to_owned())
             .await
             .expect_err("should propagate repository error");
 
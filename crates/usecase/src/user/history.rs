@@ -0,0 +1,173 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use domain::repository::{Repositories, user::UserRepository};
+use tracing::{debug, instrument};
+
+use crate::{
+    model::user::{HistoryBucket, UserHistoryDto, UserHistoryPointDto},
+    user::{UserUsecase, UserUsecaseError},
+};
+
+impl<R: Repositories> UserUsecase<R> {
+    /// Returns the user's rating and XP between `from` and `to` inclusive. Days without a
+    /// submission have no point. Weekly buckets carry the last snapshot of each week, which is
+    /// also all that is kept for snapshots older than the daily retention window.
+    #[instrument(skip(self), fields(user_id = %user_id, %from, %to, ?bucket))]
+    pub async fn progress_history(
+        &self,
+        user_id: String,
+        from: NaiveDate,
+        to: NaiveDate,
+        bucket: HistoryBucket,
+    ) -> Result<UserHistoryDto, UserUsecaseError> {
+        debug!("Resolving progress history for user");
+        let users = self.repositories.user();
+        if users.find_by_id(&user_id).await?.is_none() {
+            return Err(UserUsecaseError::NotFoundById { user_id });
+        }
+
+        let snapshots = users.find_progress(&user_id, from, to).await?;
+        let mut points: Vec<UserHistoryPointDto> = Vec::with_capacity(snapshots.len());
+        for snapshot in snapshots {
+            let date = match bucket {
+                HistoryBucket::Daily => *snapshot.day(),
+                HistoryBucket::Weekly => week_start(*snapshot.day()),
+            };
+            let point = UserHistoryPointDto {
+                date,
+                rating: *snapshot.rating(),
+                xp: *snapshot.xp(),
+            };
+            // Snapshots arrive oldest first, so a later one in the same bucket supersedes it.
+            match points.last_mut() {
+                Some(last) if last.date == date => *last = point,
+                _ => points.push(point),
+            }
+        }
+
+        Ok(UserHistoryDto { bucket, points })
+    }
+}
+
+fn week_start(day: NaiveDate) -> NaiveDate {
+    day - Duration::days(i64::from(day.weekday().num_days_from_monday()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use domain::{
+        entity::user_progress::UserProgress,
+        repository::{MockRepositories, user::MockUserRepository},
+        testing::{datetime::sample_timestamp, user::USER1},
+    };
+
+    use super::*;
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn build_usecase(user_repo: MockUserRepository) -> UserUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            user: user_repo,
+            ..Default::default()
+        };
+        UserUsecase::new(Arc::new(repositories))
+    }
+
+    fn user_repo_with_snapshots() -> MockUserRepository {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id().returning(|_| {
+            let user = USER1.build(true, false, sample_timestamp());
+            Box::pin(async move { Ok(Some(user)) })
+        });
+        user_repo
+            .expect_find_progress()
+            .withf(|user_id, from, to| {
+                user_id == USER1.id && *from == day(11, 1) && *to == day(11, 30)
+            })
+            .returning(|user_id, _, _| {
+                let snapshot =
+                    |date, rating, xp| UserProgress::new(user_id.to_owned(), date, rating, xp);
+                // 2025-11-10 is a Monday.
+                let snapshots = vec![
+                    snapshot(day(11, 10), 1200, 100),
+                    snapshot(day(11, 12), 1250, 180),
+                    snapshot(day(11, 16), 1300, 260),
+                    snapshot(day(11, 17), 1310, 300),
+                ];
+                Box::pin(async move { Ok(snapshots) })
+            });
+        user_repo
+    }
+
+    #[tokio::test]
+    async fn progress_history_returns_daily_points() {
+        let usecase = build_usecase(user_repo_with_snapshots());
+
+        let history = usecase
+            .progress_history(
+                USER1.id.to_owned(),
+                day(11, 1),
+                day(11, 30),
+                HistoryBucket::Daily,
+            )
+            .await
+            .expect("should succeed");
+
+        assert_eq!(history.bucket, HistoryBucket::Daily);
+        assert_eq!(history.points.len(), 4);
+        assert_eq!(history.points[1].date, day(11, 12));
+        assert_eq!(history.points[1].rating, 1250);
+    }
+
+    #[tokio::test]
+    async fn progress_history_keeps_last_snapshot_per_week() {
+        let usecase = build_usecase(user_repo_with_snapshots());
+
+        let history = usecase
+            .progress_history(
+                USER1.id.to_owned(),
+                day(11, 1),
+                day(11, 30),
+                HistoryBucket::Weekly,
+            )
+            .await
+            .expect("should succeed");
+
+        let points: Vec<(NaiveDate, u32, u32)> = history
+            .points
+            .iter()
+            .map(|point| (point.date, point.rating, point.xp))
+            .collect();
+        assert_eq!(
+            points,
+            vec![(day(11, 10), 1300, 260), (day(11, 17), 1310, 300)]
+        );
+    }
+
+    #[tokio::test]
+    async fn progress_history_maps_unknown_user() {
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        user_repo.expect_find_progress().never();
+        let usecase = build_usecase(user_repo);
+
+        let result = usecase
+            .progress_history(
+                "missing".to_owned(),
+                day(11, 1),
+                day(11, 30),
+                HistoryBucket::Daily,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(UserUsecaseError::NotFoundById { user_id }) if user_id == "missing"
+        ));
+    }
+}
@@ -1,10 +1,26 @@
 use std::sync::Arc;
 
-use domain::repository::{Repositories, record::RecordRepositoryError, user::UserRepositoryError};
+use domain::{
+    repository::{
+        Repositories, music::MusicRepositoryError, play::PlayRepositoryError,
+        record::RecordRepositoryError, user::UserRepositoryError,
+        xp_campaign::XpCampaignRepositoryError,
+    },
+    service::{
+        experience::XpPolicy, level::LevelCurve, rating::RatingPolicy,
+        score_validation::ScoreValidationError,
+    },
+};
 use thiserror::Error;
 
+use crate::ranking::RankingCache;
+
 pub mod credits;
+pub mod history;
 pub mod options;
+pub mod plays;
+pub mod profile;
+pub mod rating;
 pub mod records;
 pub mod register;
 pub mod search;
@@ -18,19 +34,67 @@ pub enum UserUsecaseError {
     NotFoundByCard { card: String },
     #[error("User not found for id: {user_id}")]
     NotFoundById { user_id: String },
+    #[error("Invalid submission for sheet {sheet_id}: {reason}")]
+    InvalidSubmission { sheet_id: String, reason: String },
+    #[error("Implausible submission for sheet {sheet_id}: {source}")]
+    ImplausibleSubmission {
+        sheet_id: String,
+        source: ScoreValidationError,
+    },
+    #[error("Sheet not found: {sheet_id}")]
+    SheetNotFound { sheet_id: String },
     #[error(transparent)]
     RecordRepositoryError(RecordRepositoryError),
     #[error(transparent)]
+    PlayRepositoryError(PlayRepositoryError),
+    #[error(transparent)]
+    MusicRepositoryError(MusicRepositoryError),
+    #[error(transparent)]
+    XpCampaignRepositoryError(XpCampaignRepositoryError),
+    #[error(transparent)]
     InternalError(#[from] anyhow::Error),
 }
 
 pub struct UserUsecase<R: Repositories> {
     repositories: Arc<R>,
+    rating_policy: Arc<RatingPolicy>,
+    level_curve: Arc<LevelCurve>,
+    xp_policy: Arc<XpPolicy>,
+    ranking_cache: Arc<RankingCache>,
 }
 
 impl<R: Repositories> UserUsecase<R> {
+    /// Instantiates the usecase with the default [`RatingPolicy`].
     pub fn new(repositories: Arc<R>) -> Self {
-        Self { repositories }
+        Self {
+            repositories,
+            rating_policy: Arc::default(),
+            level_curve: Arc::default(),
+            xp_policy: Arc::default(),
+            ranking_cache: Arc::default(),
+        }
+    }
+
+    pub fn with_rating_policy(mut self, rating_policy: Arc<RatingPolicy>) -> Self {
+        self.rating_policy = rating_policy;
+        self
+    }
+
+    pub fn with_level_curve(mut self, level_curve: Arc<LevelCurve>) -> Self {
+        self.level_curve = level_curve;
+        self
+    }
+
+    pub fn with_xp_policy(mut self, xp_policy: Arc<XpPolicy>) -> Self {
+        self.xp_policy = xp_policy;
+        self
+    }
+
+    /// Shares the cache of [`crate::ranking::RankingUsecase`] so that submissions and profile
+    /// updates can invalidate it.
+    pub fn with_ranking_cache(mut self, ranking_cache: Arc<RankingCache>) -> Self {
+        self.ranking_cache = ranking_cache;
+        self
     }
 }
 
@@ -38,6 +102,10 @@ impl<R: Repositories> Clone for UserUsecase<R> {
     fn clone(&self) -> Self {
         Self {
             repositories: Arc::clone(&self.repositories),
+            rating_policy: Arc::clone(&self.rating_policy),
+            level_curve: Arc::clone(&self.level_curve),
+            xp_policy: Arc::clone(&self.xp_policy),
+            ranking_cache: Arc::clone(&self.ranking_cache),
         }
     }
 }
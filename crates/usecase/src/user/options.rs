@@ -67,8 +67,6 @@ mod tests {
         entity::user_play_option::UserPlayOption,
         repository::{
             MockRepositories,
-            music::MockMusicRepository,
-            record::MockRecordRepository,
             user::{MockUserRepository, UserRepositoryError},
         },
         testing::{datetime::sample_timestamp, user::USER1},
@@ -94,8 +92,7 @@ mod tests {
                 });
                 user_repo
             },
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         UserUsecase::new(Arc::new(repositories))
     }
@@ -155,8 +152,7 @@ mod tests {
 
         let repositories = MockRepositories {
             user: user_repo,
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
@@ -0,0 +1,114 @@
+use domain::repository::{
+    Repositories,
+    play::{PlayRepository, PlayRepositoryError},
+};
+use tracing::{debug, instrument};
+
+use crate::{
+    model::user::UserPlayPageDto,
+    user::{UserUsecase, UserUsecaseError},
+};
+
+impl<R: Repositories> UserUsecase<R> {
+    /// Returns the user's plays newest first. `limit` and `offset` are expected to be bounded by
+    /// the caller.
+    #[instrument(skip(self), fields(user_id = %user_id, limit, offset))]
+    pub async fn list_plays(
+        &self,
+        user_id: String,
+        limit: u64,
+        offset: u64,
+    ) -> Result<UserPlayPageDto, UserUsecaseError> {
+        debug!("Resolving play history for user");
+        match self
+            .repositories
+            .play()
+            .find_by_user_id(&user_id, limit, offset)
+            .await
+        {
+            Ok(page) => Ok(UserPlayPageDto::from(page)),
+            Err(PlayRepositoryError::UserNotFound(_)) => {
+                Err(UserUsecaseError::NotFoundById { user_id })
+            }
+            Err(err) => Err(UserUsecaseError::PlayRepositoryError(err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+    use domain::{
+        entity::{clear_type::ClearType, judgement::Judgement, play::Play},
+        repository::{
+            MockRepositories,
+            play::{MockPlayRepository, PlayPage},
+        },
+        testing::api_key::CABINET_KEY,
+    };
+
+    use super::*;
+
+    fn build_usecase(play_repo: MockPlayRepository) -> UserUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            play: play_repo,
+            ..Default::default()
+        };
+        UserUsecase::new(Arc::new(repositories))
+    }
+
+    #[tokio::test]
+    async fn list_plays_returns_page() {
+        let mut play_repo = MockPlayRepository::new();
+        play_repo
+            .expect_find_by_user_id()
+            .withf(|user_id, limit, offset| user_id == "user-123" && *limit == 20 && *offset == 40)
+            .returning(|_, _, _| {
+                let play = Play::new(
+                    "play-1".to_owned(),
+                    "user-123".to_owned(),
+                    "sheet-1".to_owned(),
+                    CABINET_KEY.client_id.to_owned(),
+                    987_000,
+                    ClearType::Clear,
+                    Judgement::new(900, 80, 15, 5),
+                    Utc.with_ymd_and_hms(2025, 11, 14, 12, 0, 0).unwrap(),
+                    None,
+                );
+                Box::pin(async move { Ok(PlayPage::new(vec![play], 41)) })
+            });
+        let usecase = build_usecase(play_repo);
+
+        let page = usecase
+            .list_plays("user-123".to_owned(), 20, 40)
+            .await
+            .expect("should succeed");
+
+        assert_eq!(page.total, 41);
+        assert_eq!(page.plays.len(), 1);
+        assert_eq!(page.plays[0].id, "play-1");
+        assert_eq!(page.plays[0].client_id, CABINET_KEY.client_id);
+        assert_eq!(page.plays[0].judgement, Judgement::new(900, 80, 15, 5));
+    }
+
+    #[tokio::test]
+    async fn list_plays_maps_user_not_found() {
+        let mut play_repo = MockPlayRepository::new();
+        play_repo.expect_find_by_user_id().returning(|_, _, _| {
+            Box::pin(async { Err(PlayRepositoryError::UserNotFound("missing".to_owned())) })
+        });
+        let usecase = build_usecase(play_repo);
+
+        let err = usecase
+            .list_plays("missing".to_owned(), 20, 0)
+            .await
+            .expect_err("should return not found");
+
+        match err {
+            UserUsecaseError::NotFoundById { user_id } => assert_eq!(user_id, "missing"),
+            _ => panic!("unexpected error variant"),
+        }
+    }
+}
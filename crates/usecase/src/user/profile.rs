@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+
+use domain::{
+    entity::difficulty::Difficulty,
+    repository::{
+        Repositories,
+        record::{RecordRepository, RecordRepositoryError},
+        user::UserRepository,
+    },
+};
+use tracing::{debug, instrument};
+
+use crate::{
+    model::user::{
+        ClearTypeCountsDto, DifficultyClearSummaryDto, GradeDistributionDto, LevelClearSummaryDto,
+        RatedSheetDto, UserProfileDto,
+    },
+    user::{UserUsecase, UserUsecaseError},
+};
+
+/// Number of best-rated sheets shown on a profile.
+const TOP_RATED_COUNT: usize = 10;
+
+impl<R: Repositories> UserUsecase<R> {
+    /// Aggregates the user's records into a profile. Private users are reported as missing unless
+    /// `include_private` is set, so anonymous viewers cannot tell them apart from unknown ids.
+    /// Sheets of test musics are left out like they are from the rating.
+    #[instrument(skip(self), fields(user_id = %user_id))]
+    pub async fn profile(
+        &self,
+        user_id: String,
+        include_private: bool,
+    ) -> Result<UserProfileDto, UserUsecaseError> {
+        debug!("Resolving profile for user");
+        let user = match self.repositories.user().find_by_id(&user_id).await? {
+            Some(user) if include_private || *user.is_public() => user,
+            _ => return Err(UserUsecaseError::NotFoundById { user_id }),
+        };
+
+        let records = match self
+            .repositories
+            .record()
+            .find_with_metadata_by_user_id(&user_id)
+            .await
+        {
+            Ok(records) => records,
+            Err(RecordRepositoryError::UserNotFound(_)) => {
+                return Err(UserUsecaseError::NotFoundById { user_id });
+            }
+            Err(err) => return Err(UserUsecaseError::RecordRepositoryError(err)),
+        };
+
+        let mut clears_by_difficulty: Vec<DifficultyClearSummaryDto> =
+            [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard]
+                .into_iter()
+                .map(|difficulty| DifficultyClearSummaryDto {
+                    difficulty,
+                    counts: ClearTypeCountsDto::default(),
+                })
+                .collect();
+        // Keyed by the level's integer part and whether it is a `+` level, i.e. its folder.
+        let mut clears_by_level: BTreeMap<(u32, bool), LevelClearSummaryDto> = BTreeMap::new();
+        let mut grades = GradeDistributionDto::default();
+        let mut record_count = 0;
+        let mut total_play_count = 0;
+        let mut last_played_at = None;
+        let mut top_rated = Vec::new();
+
+        for entry in records.iter().filter(|entry| !entry.is_test) {
+            let record = &entry.record;
+            let clear_type = *record.clear_type();
+            let score = *record.score();
+            if let Some(summary) = clears_by_difficulty
+                .iter_mut()
+                .find(|summary| summary.difficulty == entry.difficulty)
+            {
+                summary.counts.add(clear_type);
+            }
+            let (integer, decimal) = entry.level.components();
+            clears_by_level
+                .entry((integer, decimal >= 5))
+                .or_insert_with(|| LevelClearSummaryDto {
+                    level: entry.level.to_string(),
+                    counts: ClearTypeCountsDto::default(),
+                })
+                .counts
+                .add(clear_type);
+
+            grades.add(record.grade());
+
+            record_count += 1;
+            total_play_count += u64::from(*record.play_count());
+            last_played_at = last_played_at.max(Some(*record.updated_at()));
+
+            top_rated.push(RatedSheetDto::new(
+                record.sheet_id().to_owned(),
+                entry.level.value(),
+                score,
+                self.rating_policy.sheet_rating(&entry.level, score),
+            ));
+        }
+        top_rated.sort_by(|a, b| b.rating.cmp(&a.rating).then_with(|| b.score.cmp(&a.score)));
+        top_rated.truncate(TOP_RATED_COUNT);
+
+        Ok(UserProfileDto {
+            user_id: user.id().to_owned(),
+            display_name: user.display_name().clone(),
+            rating: user.rating().value(),
+            level: self.level_curve.level_for(*user.xp()).level,
+            record_count,
+            total_play_count,
+            last_played_at,
+            clears_by_difficulty,
+            clears_by_level: clears_by_level.into_values().collect(),
+            grades,
+            top_rated,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, TimeZone, Utc};
+    use domain::{
+        entity::{clear_type::ClearType, judgement::Judgement, level::Level, record::Record},
+        repository::{
+            MockRepositories,
+            record::{MockRecordRepository, RecordWithMetadata},
+            user::MockUserRepository,
+        },
+        testing::{datetime::sample_timestamp, user::USER1},
+    };
+
+    use super::*;
+
+    fn entry(
+        sheet_id: &str,
+        difficulty: Difficulty,
+        level: (u32, u32),
+        score: u32,
+        clear_type: ClearType,
+        days_ago: i64,
+    ) -> RecordWithMetadata {
+        let played_at =
+            Utc.with_ymd_and_hms(2025, 11, 20, 0, 0, 0).unwrap() - Duration::days(days_ago);
+        let record = Record::new_from_submission(
+            USER1.id.to_owned(),
+            sheet_id.to_owned(),
+            score,
+            clear_type,
+            Judgement::default(),
+            0,
+            played_at,
+        );
+        RecordWithMetadata::new(record, difficulty, Level::try_from(level).unwrap(), false)
+    }
+
+    fn build_usecase(
+        is_public: bool,
+        records: Vec<RecordWithMetadata>,
+    ) -> UserUsecase<MockRepositories> {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id().returning(move |user_id| {
+            let user =
+                (user_id == USER1.id).then(|| USER1.build(is_public, false, sample_timestamp()));
+            Box::pin(async move { Ok(user) })
+        });
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_with_metadata_by_user_id()
+            .returning(move |_| {
+                let records = records.clone();
+                Box::pin(async move { Ok(records) })
+            });
+        let repositories = MockRepositories {
+            user: user_repo,
+            record: record_repo,
+            ..Default::default()
+        };
+        UserUsecase::new(Arc::new(repositories))
+    }
+
+    #[tokio::test]
+    async fn profile_aggregates_lamps_grades_and_plays() {
+        let usecase = build_usecase(
+            true,
+            vec![
+                entry(
+                    "sheet-1",
+                    Difficulty::Hard,
+                    (13, 7),
+                    1_005_000,
+                    ClearType::AllPerfect,
+                    3,
+                ),
+                entry(
+                    "sheet-2",
+                    Difficulty::Hard,
+                    (13, 2),
+                    960_000,
+                    ClearType::FullCombo,
+                    1,
+                ),
+                entry(
+                    "sheet-3",
+                    Difficulty::Normal,
+                    (10, 0),
+                    920_000,
+                    ClearType::Clear,
+                    5,
+                ),
+                entry(
+                    "sheet-4",
+                    Difficulty::Easy,
+                    (5, 0),
+                    700_000,
+                    ClearType::Fail,
+                    2,
+                ),
+            ],
+        );
+
+        let profile = usecase
+            .profile(USER1.id.to_owned(), false)
+            .await
+            .expect("should succeed");
+
+        assert_eq!(profile.user_id, USER1.id);
+        assert_eq!(profile.record_count, 4);
+        assert_eq!(
+            profile.last_played_at,
+            Some(Utc.with_ymd_and_hms(2025, 11, 19, 0, 0, 0).unwrap())
+        );
+        let hard = &profile.clears_by_difficulty[2];
+        assert_eq!(hard.difficulty, Difficulty::Hard);
+        assert_eq!((hard.counts.full_combo, hard.counts.all_perfect), (1, 1));
+        assert_eq!(profile.clears_by_difficulty[0].counts.fail, 1);
+        let levels: Vec<&str> = profile
+            .clears_by_level
+            .iter()
+            .map(|summary| summary.level.as_str())
+            .collect();
+        assert_eq!(levels, vec!["5", "10", "13", "13+"]);
+        assert_eq!(
+            profile.grades,
+            GradeDistributionDto {
+                s: 1,
+                aa: 1,
+                a: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(profile.top_rated[0].sheet_id, "sheet-1");
+        assert_eq!(profile.top_rated.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn profile_hides_private_users_unless_allowed() {
+        let usecase = build_usecase(false, Vec::new());
+
+        let hidden = usecase.profile(USER1.id.to_owned(), false).await;
+        let own = usecase.profile(USER1.id.to_owned(), true).await;
+
+        assert!(matches!(hidden, Err(UserUsecaseError::NotFoundById { .. })));
+        let own = own.expect("should succeed");
+        assert_eq!(own.record_count, 0);
+        assert_eq!(own.last_played_at, None);
+        assert_eq!(own.clears_by_difficulty.len(), 3);
+    }
+}
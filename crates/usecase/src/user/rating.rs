@@ -0,0 +1,191 @@
+use domain::repository::{
+    Repositories,
+    play::PlayRepository,
+    record::{RecordRepository, RecordRepositoryError},
+};
+use tracing::{debug, instrument};
+
+use crate::{
+    model::user::{RatedSheetDto, UserRatingBreakdownDto},
+    user::{UserUsecase, UserUsecaseError},
+};
+
+/// Number of records listed just below the counted set, so players can see what to improve next.
+const NEAR_MISS_COUNT: usize = 5;
+
+impl<R: Repositories> UserUsecase<R> {
+    /// Rates every record with the current policy. The returned rating is recomputed from the
+    /// breakdown rather than read from the user, so the two always agree.
+    #[instrument(skip(self), fields(user_id = %user_id))]
+    pub async fn rating_breakdown(
+        &self,
+        user_id: String,
+    ) -> Result<UserRatingBreakdownDto, UserUsecaseError> {
+        debug!("Resolving rating breakdown for user");
+        let records = match self
+            .repositories
+            .record()
+            .find_with_metadata_by_user_id(&user_id)
+            .await
+        {
+            Ok(records) => records,
+            Err(RecordRepositoryError::UserNotFound(_)) => {
+                return Err(UserUsecaseError::NotFoundById { user_id });
+            }
+            Err(err) => return Err(UserUsecaseError::RecordRepositoryError(err)),
+        };
+
+        let policy = &self.rating_policy;
+        let mut best: Vec<RatedSheetDto> = records
+            .iter()
+            .filter(|entry| !entry.is_test)
+            .map(|entry| {
+                let score = *entry.record.score();
+                RatedSheetDto::new(
+                    entry.record.sheet_id().to_owned(),
+                    entry.level.value(),
+                    score,
+                    policy.sheet_rating(&entry.level, score),
+                )
+            })
+            .collect();
+        best.sort_by(|a, b| b.rating.cmp(&a.rating).then_with(|| b.score.cmp(&a.score)));
+        let mut next = best.split_off(best.len().min(policy.best_count()));
+        next.truncate(NEAR_MISS_COUNT);
+
+        let recent: Vec<RatedSheetDto> = if policy.recent_count() == 0 {
+            Vec::new()
+        } else {
+            self.repositories
+                .play()
+                .find_recent_scores(&user_id, policy.recent_count() as u64)
+                .await
+                .map_err(UserUsecaseError::PlayRepositoryError)?
+                .into_iter()
+                .map(|play| {
+                    let rating = policy.sheet_rating(&play.level, play.score);
+                    RatedSheetDto::new(play.sheet_id, play.level.value(), play.score, rating)
+                })
+                .collect()
+        };
+
+        let rating = policy.user_rating(
+            best.iter().map(|sheet| sheet.rating),
+            recent.iter().map(|sheet| sheet.rating),
+        );
+
+        Ok(UserRatingBreakdownDto {
+            rating: rating.value(),
+            best_count: policy.best_count(),
+            recent_count: policy.recent_count(),
+            best,
+            recent,
+            next,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use domain::{
+        entity::{
+            clear_type::ClearType, difficulty::Difficulty, judgement::Judgement, level::Level,
+            record::Record,
+        },
+        repository::{
+            MockRepositories,
+            record::{MockRecordRepository, RecordWithMetadata},
+        },
+        testing::datetime::sample_timestamp,
+    };
+
+    use super::*;
+
+    fn entry(sheet_id: &str, level: (u32, u32), score: u32, is_test: bool) -> RecordWithMetadata {
+        let record = Record::new_from_submission(
+            "user-1".to_owned(),
+            sheet_id.to_owned(),
+            score,
+            ClearType::Clear,
+            Judgement::default(),
+            0,
+            sample_timestamp(),
+        );
+        RecordWithMetadata::new(
+            record,
+            Difficulty::Hard,
+            Level::try_from(level).unwrap(),
+            is_test,
+        )
+    }
+
+    fn build_usecase(record_repo: MockRecordRepository) -> UserUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            record: record_repo,
+            ..Default::default()
+        };
+        UserUsecase::new(Arc::new(repositories))
+    }
+
+    #[tokio::test]
+    async fn rating_breakdown_splits_counted_and_near_miss_records() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_with_metadata_by_user_id()
+            .withf(|user_id| user_id == "user-1")
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        entry("sheet-4", (10, 0), 800_000, false),
+                        entry("sheet-1", (13, 7), 1_000_000, false),
+                        entry("sheet-5", (15, 0), 1_090_000, true),
+                        entry("sheet-3", (14, 0), 900_000, false),
+                        entry("sheet-2", (12, 0), 980_000, false),
+                    ])
+                })
+            });
+        let usecase = build_usecase(record_repo);
+
+        let breakdown = usecase
+            .rating_breakdown("user-1".to_owned())
+            .await
+            .expect("should succeed");
+
+        assert_eq!(breakdown.rating, 1383);
+        assert_eq!(breakdown.best_count, 3);
+        let best: Vec<(&str, u32)> = breakdown
+            .best
+            .iter()
+            .map(|sheet| (sheet.sheet_id.as_str(), sheet.rating))
+            .collect();
+        assert_eq!(
+            best,
+            vec![("sheet-1", 1470), ("sheet-3", 1400), ("sheet-2", 1280)]
+        );
+        assert_eq!(breakdown.next.len(), 1);
+        assert_eq!(breakdown.next[0].sheet_id, "sheet-4");
+        assert!((breakdown.next[0].level - 10.0).abs() < f64::EPSILON);
+        assert!(breakdown.recent.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rating_breakdown_maps_user_not_found() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_with_metadata_by_user_id()
+            .returning(|user_id| {
+                let user_id = user_id.to_owned();
+                Box::pin(async move { Err(RecordRepositoryError::UserNotFound(user_id)) })
+            });
+        let usecase = build_usecase(record_repo);
+
+        let result = usecase.rating_breakdown("missing".to_owned()).await;
+
+        assert!(matches!(
+            result,
+            Err(UserUsecaseError::NotFoundById { user_id }) if user_id == "missing"
+        ));
+    }
+}
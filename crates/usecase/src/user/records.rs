@@ -1,34 +1,51 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use domain::{
-    entity::record::Record,
+    entity::{
+        clear_type::ClearType,
+        play::Play,
+        record::Record,
+        sheet::Sheet,
+        submission_result::{LevelUp, SubmissionResult},
+        user_progress::{DAILY_RETENTION_DAYS, UserProgress},
+    },
     repository::{
-        Repositories,
-        record::{RecordRepository, RecordRepositoryError},
+        Repositories, UnitOfWork,
+        music::MusicRepository,
+        play::{PlayRepository, PlayRepositoryError},
+        record::{RecordQuery, RecordRepository, RecordRepositoryError, RecordUpsert},
         user::UserRepository,
+        xp_campaign::XpCampaignRepository,
     },
-    service::{experience, rating},
+    service::{experience, score_validation},
 };
-use tracing::{debug, instrument};
+use tracing::{debug, info, instrument};
 
 use crate::{
-    model::user::{UserRecordDto, UserRecordSubmissionDto},
+    model::user::{
+        LevelUpDto, RecordSubmissionResultDto, UserRecordDto, UserRecordPageDto,
+        UserRecordSubmissionDto,
+    },
     user::{UserUsecase, UserUsecaseError},
 };
 
 impl<R: Repositories> UserUsecase<R> {
+    /// Lists the user's records matching `query`, with their sheet and music title.
     #[instrument(skip(self), fields(user_id = %user_id))]
     pub async fn list_records(
         &self,
         user_id: String,
-    ) -> Result<Vec<UserRecordDto>, UserUsecaseError> {
-        debug!("Resolving records for user");
-        match self.repositories.record().find_by_user_id(&user_id).await {
-            Ok(records) => {
-                let result = records.into_iter().map(UserRecordDto::from).collect();
-                Ok(result)
-            }
+        query: RecordQuery,
+    ) -> Result<UserRecordPageDto, UserUsecaseError> {
+        debug!(?query, "Resolving records for user");
+        match self
+            .repositories
+            .record()
+            .find_details_by_user_id(&user_id, &query)
+            .await
+        {
+            Ok(page) => Ok(page.into()),
             Err(RecordRepositoryError::UserNotFound(_)) => {
                 Err(UserUsecaseError::NotFoundById { user_id })
             }
@@ -36,118 +53,455 @@ impl<R: Repositories> UserUsecase<R> {
         }
     }
 
-    #[instrument(skip(self, submissions), fields(user_id = %user_id, count = submissions.len()))]
+    /// Applies submissions played on the cabinet identified by `client_id`. Every submission is
+    /// also appended to the play log, whether or not it improves the best record.
+    ///
+    /// Submissions whose id has already been logged are replays of an earlier request: they are
+    /// answered with the result stored for them and change neither records nor the user. Plays
+    /// logged before results were kept fall back to the record now stored for their sheet. A
+    /// request that loses the race against a concurrent retry of the same submissions is replayed
+    /// the same way.
+    #[instrument(
+        skip(self, submissions),
+        fields(user_id = %user_id, client_id = %client_id, count = submissions.len())
+    )]
     pub async fn submit_records(
         &self,
         user_id: String,
+        client_id: String,
         submissions: Vec<UserRecordSubmissionDto>,
-    ) -> Result<Vec<UserRecordDto>, UserUsecaseError> {
+    ) -> Result<RecordSubmissionResultDto, UserUsecaseError> {
         debug!("Processing record submissions");
 
         if submissions.is_empty() {
             debug!("No submissions provided");
-            return Ok(Vec::new());
+            return Ok(RecordSubmissionResultDto {
+                records: Vec::new(),
+                level_up: None,
+            });
         }
 
-        let sheet_ids: Vec<String> = submissions.iter().map(|s| s.sheet_id.clone()).collect();
-        let existing_records = match self
+        let mut seen_ids = HashSet::with_capacity(submissions.len());
+        for submission in &submissions {
+            if !seen_ids.insert(submission.submission_id.as_str()) {
+                return Err(UserUsecaseError::InvalidSubmission {
+                    sheet_id: submission.sheet_id.clone(),
+                    reason: format!(
+                        "submission id {} appears more than once",
+                        submission.submission_id
+                    ),
+                });
+            }
+
+            if !submission
+                .clear_type
+                .is_consistent_with(&submission.judgement)
+            {
+                return Err(UserUsecaseError::InvalidSubmission {
+                    sheet_id: submission.sheet_id.clone(),
+                    reason: format!(
+                        "clear type {} does not match the judgement counts",
+                        submission.clear_type
+                    ),
+                });
+            }
+        }
+
+        match self
+            .apply_submissions(&user_id, &client_id, &submissions)
+            .await
+        {
+            // The concurrent request has committed by the time its submission ids collide, so a
+            // second pass finds them logged and replays their results.
+            Err(UserUsecaseError::PlayRepositoryError(
+                PlayRepositoryError::DuplicateSubmission(submission_id),
+            )) => {
+                debug!(%submission_id, "Submission raced a concurrent request; replaying");
+                self.apply_submissions(&user_id, &client_id, &submissions)
+                    .await
+            }
+            result => result,
+        }
+    }
+
+    async fn apply_submissions(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        submissions: &[UserRecordSubmissionDto],
+    ) -> Result<RecordSubmissionResultDto, UserUsecaseError> {
+        let submission_ids: Vec<String> = submissions
+            .iter()
+            .map(|s| s.submission_id.clone())
+            .collect();
+        let replayed: HashSet<String> = match self
             .repositories
-            .record()
-            .find_by_user_id_and_sheet_ids(&user_id, &sheet_ids)
+            .play()
+            .find_submitted_ids(user_id, &submission_ids)
             .await
         {
-            Ok(records) => records,
-            Err(RecordRepositoryError::UserNotFound(_)) => {
-                return Err(UserUsecaseError::NotFoundById { user_id });
+            Ok(ids) => ids.into_iter().collect(),
+            Err(PlayRepositoryError::UserNotFound(_)) => {
+                return Err(UserUsecaseError::NotFoundById {
+                    user_id: user_id.to_owned(),
+                });
             }
-            Err(err) => return Err(UserUsecaseError::RecordRepositoryError(err)),
+            Err(err) => return Err(UserUsecaseError::PlayRepositoryError(err)),
+        };
+        let fresh: Vec<&UserRecordSubmissionDto> = submissions
+            .iter()
+            .filter(|s| !replayed.contains(&s.submission_id))
+            .collect();
+        let stored_results: HashMap<String, SubmissionResult> = if replayed.is_empty() {
+            HashMap::new()
+        } else {
+            debug!(count = replayed.len(), "Replaying logged submissions");
+            let replayed_ids: Vec<String> = replayed.iter().cloned().collect();
+            self.repositories
+                .play()
+                .find_results(user_id, &replayed_ids)
+                .await
+                .map_err(UserUsecaseError::PlayRepositoryError)?
+                .into_iter()
+                .map(|result| (result.submission_id().to_owned(), result))
+                .collect()
         };
 
-        let mut user = self
-            .repositories
+        let fresh_sheet_ids: Vec<String> = fresh.iter().map(|s| s.sheet_id.clone()).collect();
+        let sheets = self
+            .validate_against_sheets(&fresh_sheet_ids, fresh.iter().copied())
+            .await?;
+
+        // Everything below goes through one unit of work so that records, plays and the user's
+        // XP and rating are committed together or not at all.
+        let uow = self.repositories.begin().await?;
+        // XP and rating are rewritten from what is read here, so concurrent submissions of the
+        // same user wait on this lock rather than overwrite each other's progress.
+        let mut user = uow
             .user()
-            .find_by_id(&user_id)
+            .find_by_id_for_update(user_id)
             .await?
             .ok_or_else(|| UserUsecaseError::NotFoundById {
-                user_id: user_id.clone(),
+                user_id: user_id.to_owned(),
             })?;
 
-        let mut record_map: HashMap<String, Record> = existing_records
-            .into_iter()
-            .map(|record| (record.sheet_id().to_owned(), record))
+        // Replays without a stored result are answered with the stored record of their sheet;
+        // fresh submissions get the rows returned by the upsert instead. The stored records of
+        // fresh sheets are only needed to tell first clears apart, so they are skipped while that
+        // bonus is off.
+        let mut lookup_sheet_ids: Vec<String> = submissions
+            .iter()
+            .filter(|s| {
+                replayed.contains(&s.submission_id)
+                    && !stored_results.contains_key(&s.submission_id)
+            })
+            .map(|s| s.sheet_id.clone())
             .collect();
+        if self.xp_policy.first_clear_bonus() > 0 {
+            lookup_sheet_ids.extend(fresh_sheet_ids);
+            lookup_sheet_ids.sort_unstable();
+            lookup_sheet_ids.dedup();
+        }
+        let mut record_map: HashMap<String, Record> = if lookup_sheet_ids.is_empty() {
+            HashMap::new()
+        } else {
+            match uow
+                .record()
+                .find_by_user_id_and_sheet_ids(user_id, &lookup_sheet_ids)
+                .await
+            {
+                Ok(records) => records
+                    .into_iter()
+                    .map(|record| (record.sheet_id().to_owned(), record))
+                    .collect(),
+                Err(RecordRepositoryError::UserNotFound(_)) => {
+                    return Err(UserUsecaseError::NotFoundById {
+                        user_id: user_id.to_owned(),
+                    });
+                }
+                Err(err) => return Err(UserUsecaseError::RecordRepositoryError(err)),
+            }
+        };
 
-        let mut xp_delta: u32 = 0;
-        let mut responses = Vec::with_capacity(submissions.len());
+        if fresh.is_empty() {
+            debug!("Every submission was a replay");
+            return Ok(RecordSubmissionResultDto {
+                records: Self::responses_for(submissions, &stored_results, &record_map),
+                level_up: Self::replayed_level_up(submissions, &stored_results),
+            });
+        }
 
-        for submission in submissions {
-            let sheet_id = submission.sheet_id.clone();
-            xp_delta = xp_delta.saturating_add(experience::xp_for_score(submission.score));
-            let submitted_at = Utc::now();
-
-            match record_map.remove(&sheet_id) {
-                Some(mut record) => {
-                    record.apply_submission(submission.score, submission.clear_type, submitted_at);
-
-                    let updated = match self.repositories.record().update(record).await {
-                        Ok(value) => value,
-                        Err(RecordRepositoryError::UserNotFound(_)) => {
-                            return Err(UserUsecaseError::NotFoundById {
-                                user_id: user_id.clone(),
-                            });
-                        }
-                        Err(err) => return Err(UserUsecaseError::RecordRepositoryError(err)),
-                    };
-
-                    record_map.insert(sheet_id, updated.clone());
-                    responses.push(UserRecordDto::from(updated));
-                }
+        // Logging the plays first lets the unique submission id reject a concurrent retry before
+        // it can touch the records.
+        let submitted_at = Utc::now();
+        let plays = fresh
+            .iter()
+            .map(|submission| {
+                Play::new_from_submission(
+                    submission.submission_id.clone(),
+                    user_id.to_owned(),
+                    submission.sheet_id.clone(),
+                    client_id.to_owned(),
+                    submission.score,
+                    submission.clear_type,
+                    submission.judgement,
+                    submitted_at,
+                )
+            })
+            .collect();
+        match uow.play().append(plays).await {
+            Ok(()) => {}
+            Err(PlayRepositoryError::UserNotFound(_)) => {
+                return Err(UserUsecaseError::NotFoundById {
+                    user_id: user_id.to_owned(),
+                });
+            }
+            Err(err) => return Err(UserUsecaseError::PlayRepositoryError(err)),
+        }
+
+        // A sheet counts as cleared once any stored or earlier submitted run got past Fail.
+        let mut cleared: HashSet<String> = record_map
+            .values()
+            .filter(|record| *record.clear_type() != ClearType::Fail)
+            .map(|record| record.sheet_id().to_owned())
+            .collect();
+
+        // A single upsert cannot touch the same row twice, so repeated sheets are folded first.
+        let mut xp_delta: u32 = 0;
+        let mut folded: Vec<Record> = Vec::with_capacity(fresh.len());
+        let mut folded_index: HashMap<&str, usize> = HashMap::with_capacity(fresh.len());
+        for submission in &fresh {
+            let first_clear = submission.clear_type != ClearType::Fail
+                && cleared.insert(submission.sheet_id.clone());
+            // Unknown sheets were rejected during validation, so a miss here is a bug.
+            let sheet = sheets.get(&submission.sheet_id).ok_or_else(|| {
+                UserUsecaseError::InternalError(anyhow::anyhow!(
+                    "sheet {} missing after validation",
+                    submission.sheet_id
+                ))
+            })?;
+            let xp = self.xp_policy.xp_for(
+                submission.score,
+                submission.clear_type,
+                sheet.level(),
+                first_clear,
+            );
+            xp_delta = xp_delta.saturating_add(xp);
+            match folded_index.get(submission.sheet_id.as_str()) {
+                Some(&index) => folded[index].apply_submission(
+                    submission.score,
+                    submission.clear_type,
+                    submission.judgement,
+                    submission.max_combo,
+                    submitted_at,
+                ),
                 None => {
-                    let record = Record::new_from_submission(
-                        user_id.clone(),
-                        sheet_id.clone(),
+                    folded_index.insert(submission.sheet_id.as_str(), folded.len());
+                    folded.push(Record::new_from_submission(
+                        user_id.to_owned(),
+                        submission.sheet_id.clone(),
                         submission.score,
                         submission.clear_type,
+                        submission.judgement,
+                        submission.max_combo,
                         submitted_at,
-                    );
-
-                    let inserted = match self.repositories.record().insert(record).await {
-                        Ok(value) => value,
-                        Err(RecordRepositoryError::UserNotFound(_)) => {
-                            return Err(UserUsecaseError::NotFoundById {
-                                user_id: user_id.clone(),
-                            });
-                        }
-                        Err(err) => return Err(UserUsecaseError::RecordRepositoryError(err)),
-                    };
-
-                    record_map.insert(sheet_id, inserted.clone());
-                    responses.push(UserRecordDto::from(inserted));
+                    ));
                 }
             }
         }
 
-        let metadata = match self
-            .repositories
+        let upserts = folded
+            .into_iter()
+            .map(|record| {
+                let sheet_rating = sheets
+                    .get(record.sheet_id())
+                    .map(|sheet| {
+                        self.rating_policy
+                            .sheet_rating(sheet.level(), *record.score())
+                    })
+                    .unwrap_or_default();
+                RecordUpsert::new(record, sheet_rating)
+            })
+            .collect();
+        let stored = match uow.record().upsert_many(upserts).await {
+            Ok(records) => records,
+            Err(RecordRepositoryError::UserNotFound(_)) => {
+                return Err(UserUsecaseError::NotFoundById {
+                    user_id: user_id.to_owned(),
+                });
+            }
+            Err(err) => return Err(UserUsecaseError::RecordRepositoryError(err)),
+        };
+        record_map.extend(
+            stored
+                .into_iter()
+                .map(|record| (record.sheet_id().to_owned(), record)),
+        );
+
+        // Sheet ratings only ever go up, so the user's best sheets after the upsert are all that
+        // is needed to refresh the rating.
+        let policy = &self.rating_policy;
+        let top_ratings = match uow
             .record()
-            .find_with_metadata_by_user_id(&user_id)
+            .find_top_sheet_ratings(user_id, policy.best_count() as u64)
             .await
         {
-            Ok(data) => data,
+            Ok(ratings) => ratings,
             Err(RecordRepositoryError::UserNotFound(_)) => {
-                return Err(UserUsecaseError::NotFoundById { user_id });
+                return Err(UserUsecaseError::NotFoundById {
+                    user_id: user_id.to_owned(),
+                });
             }
             Err(err) => return Err(UserUsecaseError::RecordRepositoryError(err)),
         };
+        let recent_ratings = if policy.recent_count() == 0 {
+            Vec::new()
+        } else {
+            // Runs after the append, so this batch's plays are part of the frame.
+            uow.play()
+                .find_recent_scores(user_id, policy.recent_count() as u64)
+                .await
+                .map_err(UserUsecaseError::PlayRepositoryError)?
+                .iter()
+                .map(|play| policy.sheet_rating(&play.level, play.score))
+                .collect()
+        };
+        let new_rating = policy.user_rating(top_ratings, recent_ratings);
 
-        let new_rating = rating::calculate_user_rating(&metadata);
+        // Overlapping campaigns do not stack; the most generous one applies.
+        let multiplier = self
+            .repositories
+            .xp_campaign()
+            .find_active_at(submitted_at)
+            .await
+            .map_err(UserUsecaseError::XpCampaignRepositoryError)?
+            .iter()
+            .map(|campaign| *campaign.multiplier_percent())
+            .max()
+            .unwrap_or(100);
+        let xp_delta = experience::apply_multiplier(xp_delta, multiplier);
 
+        let level_before = self.level_curve.level_for(*user.xp()).level;
         user.add_xp(xp_delta);
         user.update_rating(new_rating);
+        let level_after = self.level_curve.level_for(*user.xp()).level;
+        let level_up =
+            (level_after > level_before).then(|| LevelUp::new(level_before, level_after));
 
-        self.repositories.user().save(user).await?;
+        let today = submitted_at.date_naive();
+        let progress =
+            UserProgress::new(user_id.to_owned(), today, user.rating().value(), *user.xp());
+        uow.user().save(user).await?;
+        uow.user().save_progress(progress).await?;
+        uow.user()
+            .thin_progress(user_id, today - Duration::days(DAILY_RETENTION_DAYS))
+            .await?;
+        let results = fresh
+            .iter()
+            .filter_map(|submission| {
+                record_map.get(&submission.sheet_id).map(|record| {
+                    SubmissionResult::new(
+                        submission.submission_id.clone(),
+                        record.clone(),
+                        level_up,
+                    )
+                })
+            })
+            .collect();
+        uow.play()
+            .save_results(user_id, results)
+            .await
+            .map_err(UserUsecaseError::PlayRepositoryError)?;
+        uow.commit().await?;
+        self.ranking_cache.invalidate();
 
-        Ok(responses)
+        if let Some(level_up) = level_up {
+            info!(
+                from = level_up.from(),
+                to = level_up.to(),
+                "User levelled up"
+            );
+        }
+        // A batch mixing replays with fresh submissions reports the fresh level-up, if any.
+        Ok(RecordSubmissionResultDto {
+            records: Self::responses_for(submissions, &stored_results, &record_map),
+            level_up: level_up
+                .map(LevelUpDto::from)
+                .or_else(|| Self::replayed_level_up(submissions, &stored_results)),
+        })
+    }
+}
+
+impl<R: Repositories> UserUsecase<R> {
+    /// Rejects the whole batch if any submission is impossible on its sheet, so that garbage
+    /// never reaches the records the rankings are built from. Returns the sheets by id.
+    async fn validate_against_sheets<'a>(
+        &self,
+        sheet_ids: &[String],
+        submissions: impl IntoIterator<Item = &'a UserRecordSubmissionDto>,
+    ) -> Result<HashMap<String, Sheet>, UserUsecaseError> {
+        let sheets: HashMap<String, Sheet> = self
+            .repositories
+            .music()
+            .find_sheets_by_ids(sheet_ids)
+            .await
+            .map_err(UserUsecaseError::MusicRepositoryError)?
+            .into_iter()
+            .map(|sheet| (sheet.id().to_owned(), sheet))
+            .collect();
+
+        for submission in submissions {
+            let sheet = sheets.get(&submission.sheet_id).ok_or_else(|| {
+                UserUsecaseError::SheetNotFound {
+                    sheet_id: submission.sheet_id.clone(),
+                }
+            })?;
+            score_validation::validate_submission(
+                sheet,
+                submission.score,
+                &submission.judgement,
+                submission.max_combo,
+            )
+            .map_err(|source| {
+                    debug!(sheet_id = %submission.sheet_id, error = %source, "Rejected implausible submission");
+                    UserUsecaseError::ImplausibleSubmission {
+                        sheet_id: submission.sheet_id.clone(),
+                        source,
+                    }
+                })?;
+        }
+
+        Ok(sheets)
+    }
+
+    /// Answers every submission, in order, with its stored result or else the record now stored
+    /// for its sheet.
+    fn responses_for(
+        submissions: &[UserRecordSubmissionDto],
+        stored_results: &HashMap<String, SubmissionResult>,
+        record_map: &HashMap<String, Record>,
+    ) -> Vec<UserRecordDto> {
+        submissions
+            .iter()
+            .filter_map(|s| match stored_results.get(&s.submission_id) {
+                Some(result) => Some(result.record().clone()),
+                None => record_map.get(&s.sheet_id).cloned(),
+            })
+            .map(UserRecordDto::from)
+            .collect()
+    }
+
+    /// The level-up the original request reported, taken from the first replayed submission that
+    /// has one.
+    fn replayed_level_up(
+        submissions: &[UserRecordSubmissionDto],
+        stored_results: &HashMap<String, SubmissionResult>,
+    ) -> Option<LevelUpDto> {
+        submissions
+            .iter()
+            .filter_map(|s| stored_results.get(&s.submission_id))
+            .find_map(|result| *result.level_up())
+            .map(LevelUpDto::from)
     }
 }
 
@@ -156,13 +510,27 @@ mod tests {
     use std::sync::Arc;
 
     use domain::{
-        entity::{clear_type::ClearType, level::Level, rating::Rating, record::Record, user::User},
+        entity::{
+            clear_type::ClearType, difficulty::Difficulty, grade::Grade, judgement::Judgement,
+            level::Level, rating::Rating, record::Record, user::User, xp_campaign::XpCampaign,
+        },
         repository::{
             MockRepositories,
             music::MockMusicRepository,
-            record::{MockRecordRepository, RecordRepositoryError, RecordWithMetadata},
+            play::{MockPlayRepository, RecentPlayScore},
+            record::{
+                MockRecordRepository, RecordCursor, RecordDetail, RecordDetailPage, RecordFilter,
+                RecordRepositoryError, RecordSort,
+            },
             user::{MockUserRepository, UserRepositoryError},
+            xp_campaign::MockXpCampaignRepository,
+        },
+        service::{
+            experience::XpPolicy,
+            rating::{DEFAULT_BONUS_CURVE, RatingPolicy},
+            score_validation::ScoreValidationError,
         },
+        testing::api_key::CABINET_KEY,
     };
 
     use super::*;
@@ -175,60 +543,194 @@ mod tests {
             .and_utc()
     }
 
+    fn sheet_music_repo() -> MockMusicRepository {
+        let mut music_repo = MockMusicRepository::new();
+        music_repo
+            .expect_find_sheets_by_ids()
+            .returning(|sheet_ids| {
+                let sheets = sheet_ids
+                    .iter()
+                    .filter(|id| *id == "sheet-1")
+                    .map(|id| {
+                        Sheet::new(
+                            id.clone(),
+                            "music-1".to_owned(),
+                            Difficulty::Hard,
+                            Level::new(13, 7).expect("level should be valid"),
+                            "Designer".to_owned(),
+                            1000,
+                            1_010_000,
+                        )
+                    })
+                    .collect();
+                Box::pin(async move { Ok(sheets) })
+            });
+        music_repo
+    }
+
+    fn expect_progress(user_repo: &mut MockUserRepository) {
+        user_repo
+            .expect_save_progress()
+            .returning(|_| Box::pin(async { Ok(()) }));
+        user_repo
+            .expect_thin_progress()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+    }
+
+    /// What the database returns for a first-time upsert.
+    fn stored_record(upsert: &RecordUpsert) -> Record {
+        let record = &upsert.record;
+        Record::new(
+            "record-1".to_owned(),
+            record.user_id().to_owned(),
+            record.sheet_id().to_owned(),
+            *record.score(),
+            *record.clear_type(),
+            *record.judgement(),
+            *record.max_combo(),
+            *record.play_count(),
+            sample_timestamp(),
+        )
+    }
+
+    /// Campaigns active at submission time, one per multiplier.
+    fn campaign_repo(multipliers: &[u32]) -> MockXpCampaignRepository {
+        let campaigns: Vec<XpCampaign> = multipliers
+            .iter()
+            .enumerate()
+            .map(|(index, multiplier)| {
+                XpCampaign::new(
+                    format!("campaign-{index}"),
+                    format!("Campaign {index}"),
+                    *multiplier,
+                    sample_timestamp(),
+                    Utc::now() + chrono::Duration::days(1),
+                )
+            })
+            .collect();
+        let mut campaign_repo = MockXpCampaignRepository::new();
+        campaign_repo.expect_find_active_at().returning(move |_| {
+            let campaigns = campaigns.clone();
+            Box::pin(async move { Ok(campaigns) })
+        });
+        campaign_repo
+    }
+
+    fn fresh_play_repo() -> MockPlayRepository {
+        let mut play_repo = MockPlayRepository::new();
+        play_repo
+            .expect_find_submitted_ids()
+            .returning(|_, _| Box::pin(async { Ok(Vec::new()) }));
+        play_repo
+            .expect_save_results()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        play_repo
+    }
+
+    fn play_repo_expecting(count: usize) -> MockPlayRepository {
+        let mut play_repo = fresh_play_repo();
+        play_repo
+            .expect_append()
+            .withf(move |plays| {
+                plays.len() == count
+                    && plays.iter().all(|play| {
+                        play.client_id() == CABINET_KEY.client_id
+                            && play.judgement().total() == 1000
+                    })
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        play_repo
+    }
+
     #[tokio::test]
-    async fn list_records_returns_records() {
+    async fn list_records_returns_records_with_sheets() {
         let mut record_repo = MockRecordRepository::new();
         record_repo
-            .expect_find_by_user_id()
-            .withf(|user_id| user_id == "user-123")
-            .returning(|_| {
-                Box::pin(async move {
-                    Ok(vec![Record::new(
-                        "record-1".to_owned(),
-                        "user-123".to_owned(),
-                        "sheet-1".to_owned(),
-                        1_050_000,
-                        ClearType::FullCombo,
-                        7,
-                        sample_timestamp(),
-                    )])
-                })
+            .expect_find_details_by_user_id()
+            .withf(|user_id, query| {
+                user_id == "user-123"
+                    && query.filter.min_grade == Some(Grade::S)
+                    && query.sort == RecordSort::Score
+                    && query.limit == Some(1)
+            })
+            .returning(|_, _| {
+                let record = Record::new(
+                    "record-1".to_owned(),
+                    "user-123".to_owned(),
+                    "sheet-1".to_owned(),
+                    1_050_000,
+                    ClearType::FullCombo,
+                    Judgement::default(),
+                    0,
+                    7,
+                    sample_timestamp(),
+                );
+                let page = RecordDetailPage {
+                    records: vec![RecordDetail {
+                        record,
+                        music_id: "music-1".to_owned(),
+                        music_title: "Song".to_owned(),
+                        difficulty: Difficulty::Hard,
+                        level: Level::new(13, 7).unwrap(),
+                    }],
+                    next_cursor: Some(RecordCursor {
+                        key: 1_050_000,
+                        record_id: "record-1".to_owned(),
+                    }),
+                };
+                Box::pin(async move { Ok(page) })
             });
 
         let repositories = MockRepositories {
-            user: MockUserRepository::new(),
             record: record_repo,
-            music: MockMusicRepository::new(),
+            music: sheet_music_repo(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
-        let result = usecase
-            .list_records("user-123".to_owned())
+        let query = RecordQuery {
+            filter: RecordFilter {
+                min_grade: Some(Grade::S),
+                ..Default::default()
+            },
+            sort: RecordSort::Score,
+            limit: Some(1),
+            ..Default::default()
+        };
+        let page = usecase
+            .list_records("user-123".to_owned(), query)
             .await
             .expect("should succeed");
 
-        assert_eq!(result.len(), 1);
-        assert_eq!(result[0].id, "record-1");
-        assert_eq!(result[0].sheet_id, "sheet-1");
-        assert_eq!(result[0].clear_type, ClearType::FullCombo);
+        assert_eq!(page.records.len(), 1);
+        let detail = &page.records[0];
+        assert_eq!(detail.record.id, "record-1");
+        assert_eq!(detail.record.grade, Grade::SS);
+        assert_eq!(detail.record.clear_type, ClearType::FullCombo);
+        assert_eq!(detail.music_title, "Song");
+        assert!((detail.level - 13.7).abs() < f64::EPSILON);
+        assert_eq!(page.next_cursor.as_deref(), Some("1050000.record-1"));
     }
 
     #[tokio::test]
     async fn list_records_maps_user_not_found() {
         let mut record_repo = MockRecordRepository::new();
-        record_repo.expect_find_by_user_id().returning(|_| {
-            Box::pin(async { Err(RecordRepositoryError::UserNotFound("missing".to_owned())) })
-        });
+        record_repo
+            .expect_find_details_by_user_id()
+            .returning(|_, _| {
+                Box::pin(async { Err(RecordRepositoryError::UserNotFound("missing".to_owned())) })
+            });
 
         let repositories = MockRepositories {
-            user: MockUserRepository::new(),
             record: record_repo,
-            music: MockMusicRepository::new(),
+            music: sheet_music_repo(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
         let err = usecase
-            .list_records("missing".to_owned())
+            .list_records("missing".to_owned(), RecordQuery::default())
             .await
             .expect_err("should return not found");
 
@@ -241,23 +743,25 @@ mod tests {
     #[tokio::test]
     async fn list_records_wraps_other_errors() {
         let mut record_repo = MockRecordRepository::new();
-        record_repo.expect_find_by_user_id().returning(|_| {
-            Box::pin(async {
-                Err(RecordRepositoryError::InternalError(anyhow::anyhow!(
-                    "db error"
-                )))
-            })
-        });
+        record_repo
+            .expect_find_details_by_user_id()
+            .returning(|_, _| {
+                Box::pin(async {
+                    Err(RecordRepositoryError::InternalError(anyhow::anyhow!(
+                        "db error"
+                    )))
+                })
+            });
 
         let repositories = MockRepositories {
-            user: MockUserRepository::new(),
             record: record_repo,
-            music: MockMusicRepository::new(),
+            music: sheet_music_repo(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
         let err = usecase
-            .list_records("user-err".to_owned())
+            .list_records("user-err".to_owned(), RecordQuery::default())
             .await
             .expect_err("should propagate repo error");
 
@@ -275,49 +779,26 @@ mod tests {
     async fn submit_records_creates_new_entries() {
         let mut record_repo = MockRecordRepository::new();
         record_repo
-            .expect_find_by_user_id_and_sheet_ids()
-            .withf(|user_id, sheet_ids| {
-                user_id == "user-123" && sheet_ids.len() == 1 && sheet_ids[0] == "sheet-1"
+            .expect_upsert_many()
+            .withf(|upserts| {
+                upserts.len() == 1
+                    && upserts[0].record.user_id() == "user-123"
+                    && upserts[0].record.sheet_id() == "sheet-1"
+                    && upserts[0].rating == 1470
             })
-            .returning(|_, _| Box::pin(async { Ok(Vec::new()) }));
-        record_repo
-            .expect_insert()
-            .withf(|record| record.user_id() == "user-123" && record.sheet_id() == "sheet-1")
-            .returning(|record| {
-                Box::pin(async move {
-                    Ok(Record::new(
-                        record.id().to_owned(),
-                        record.user_id().to_owned(),
-                        record.sheet_id().to_owned(),
-                        *record.score(),
-                        *record.clear_type(),
-                        *record.play_count(),
-                        sample_timestamp(),
-                    ))
-                })
+            .times(1)
+            .returning(|upserts| {
+                let stored = upserts.iter().map(stored_record).collect();
+                Box::pin(async move { Ok(stored) })
             });
         record_repo
-            .expect_find_with_metadata_by_user_id()
-            .withf(|user_id| user_id == "user-123")
-            .returning(|_| {
-                Box::pin(async move {
-                    let level = Level::new(13, 7).expect("level should be valid");
-                    let record = Record::new(
-                        "record-1".to_owned(),
-                        "user-123".to_owned(),
-                        "sheet-1".to_owned(),
-                        1_000_000,
-                        ClearType::FullCombo,
-                        1,
-                        sample_timestamp(),
-                    );
-                    Ok(vec![RecordWithMetadata::new(record, level, false)])
-                })
-            });
+            .expect_find_top_sheet_ratings()
+            .withf(|user_id, limit| user_id == "user-123" && *limit == 3)
+            .returning(|_, _| Box::pin(async { Ok(vec![1470]) }));
 
         let mut user_repo = MockUserRepository::new();
         user_repo
-            .expect_find_by_id()
+            .expect_find_by_id_for_update()
             .withf(|user_id| user_id == "user-123")
             .returning(|_| {
                 let user = User::new(
@@ -340,89 +821,95 @@ mod tests {
             })
             .returning(|user| Box::pin(async move { Ok(user) }));
 
+        user_repo
+            .expect_save_progress()
+            .withf(|progress| {
+                progress.user_id() == "user-123"
+                    && *progress.rating() == 1470
+                    && *progress.xp() == 100
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        user_repo
+            .expect_thin_progress()
+            .withf(|user_id, before| {
+                let age = (Utc::now().date_naive() - *before).num_days();
+                user_id == "user-123"
+                    && (DAILY_RETENTION_DAYS..=DAILY_RETENTION_DAYS + 1).contains(&age)
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
         let repositories = MockRepositories {
             user: user_repo,
             record: record_repo,
-            music: MockMusicRepository::new(),
+            music: sheet_music_repo(),
+            play: play_repo_expecting(1),
+            xp_campaign: campaign_repo(&[]),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
-        let submission =
-            UserRecordSubmissionDto::new("sheet-1".to_owned(), 1_000_000, ClearType::FullCombo);
+        let submission = UserRecordSubmissionDto::new(
+            "submission-1".to_owned(),
+            "sheet-1".to_owned(),
+            1_000_000,
+            ClearType::FullCombo,
+            Judgement::new(900, 80, 20, 0),
+            1000,
+        );
         let result = usecase
-            .submit_records("user-123".to_owned(), vec![submission])
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
             .await
             .expect("should succeed");
 
-        assert_eq!(result.len(), 1);
-        assert_eq!(result[0].sheet_id, "sheet-1");
-        assert_eq!(result[0].score, 1_000_000);
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].sheet_id, "sheet-1");
+        assert_eq!(result.records[0].score, 1_000_000);
+        // 100 XP is exactly what the default curve charges for level 1.
+        assert_eq!(result.level_up, Some(LevelUpDto { from: 1, to: 2 }));
     }
 
     #[tokio::test]
     async fn submit_records_updates_existing_entries() {
         let mut record_repo = MockRecordRepository::new();
         record_repo
-            .expect_find_by_user_id_and_sheet_ids()
-            .withf(|user_id, sheet_ids| {
-                user_id == "user-456" && sheet_ids.len() == 1 && sheet_ids[0] == "sheet-1"
+            .expect_upsert_many()
+            .withf(|upserts| {
+                upserts.len() == 1
+                    && *upserts[0].record.score() == 980_000
+                    && *upserts[0].record.play_count() == 1
+                    && upserts[0].rating == 1450
             })
-            .returning(|_, _| {
-                Box::pin(async move {
-                    Ok(vec![Record::new(
-                        "record-1".to_owned(),
-                        "user-456".to_owned(),
-                        "sheet-1".to_owned(),
-                        950_000,
-                        ClearType::Clear,
-                        3,
-                        sample_timestamp(),
-                    )])
-                })
-            });
-        record_repo
-            .expect_update()
-            .withf(|record| {
-                record.user_id() == "user-456"
-                    && record.sheet_id() == "sheet-1"
-                    && *record.score() == 980_000
-                    && record.play_count() == &4
-            })
-            .returning(|record| {
-                Box::pin(async move {
-                    Ok(Record::new(
-                        record.id().to_owned(),
-                        record.user_id().to_owned(),
-                        record.sheet_id().to_owned(),
-                        *record.score(),
-                        *record.clear_type(),
-                        *record.play_count(),
-                        sample_timestamp(),
-                    ))
-                })
+            .times(1)
+            .returning(|upserts| {
+                // The database merges the submission into an existing row played three times.
+                let record = &upserts[0].record;
+                let merged = Record::new(
+                    "record-1".to_owned(),
+                    record.user_id().to_owned(),
+                    record.sheet_id().to_owned(),
+                    *record.score(),
+                    *record.clear_type(),
+                    *record.judgement(),
+                    *record.max_combo(),
+                    4,
+                    sample_timestamp(),
+                );
+                Box::pin(async move { Ok(vec![merged]) })
             });
         record_repo
-            .expect_find_with_metadata_by_user_id()
-            .withf(|user_id| user_id == "user-456")
-            .returning(|_| {
-                Box::pin(async move {
-                    let level = Level::new(12, 5).expect("level should be valid");
-                    let record = Record::new(
-                        "record-1".to_owned(),
-                        "user-456".to_owned(),
-                        "sheet-1".to_owned(),
-                        980_000,
-                        ClearType::FullCombo,
-                        4,
-                        sample_timestamp(),
-                    );
-                    Ok(vec![RecordWithMetadata::new(record, level, false)])
-                })
-            });
+            .expect_find_top_sheet_ratings()
+            .withf(|user_id, _| user_id == "user-456")
+            .returning(|_, _| Box::pin(async { Ok(vec![1450, 1200]) }));
 
         let mut user_repo = MockUserRepository::new();
         user_repo
-            .expect_find_by_id()
+            .expect_find_by_id_for_update()
             .withf(|user_id| user_id == "user-456")
             .returning(|_| {
                 let user = User::new(
@@ -441,50 +928,75 @@ mod tests {
         user_repo
             .expect_save()
             .withf(|user| {
-                user.id() == "user-456" && *user.xp() == 180 && user.rating().value() == 1330
+                user.id() == "user-456" && *user.xp() == 180 && user.rating().value() == 1325
             })
             .returning(|user| Box::pin(async move { Ok(user) }));
 
+        expect_progress(&mut user_repo);
+
         let repositories = MockRepositories {
             user: user_repo,
             record: record_repo,
-            music: MockMusicRepository::new(),
+            music: sheet_music_repo(),
+            play: play_repo_expecting(1),
+            xp_campaign: campaign_repo(&[]),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
-        let submission =
-            UserRecordSubmissionDto::new("sheet-1".to_owned(), 980_000, ClearType::FullCombo);
+        let submission = UserRecordSubmissionDto::new(
+            "submission-2".to_owned(),
+            "sheet-1".to_owned(),
+            980_000,
+            ClearType::FullCombo,
+            Judgement::new(900, 80, 20, 0),
+            1000,
+        );
         let result = usecase
-            .submit_records("user-456".to_owned(), vec![submission])
+            .submit_records(
+                "user-456".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
             .await
             .expect("should succeed");
 
-        assert_eq!(result[0].score, 980_000);
-        assert_eq!(result[0].clear_type, ClearType::FullCombo);
-        assert_eq!(result[0].play_count, 4);
+        assert_eq!(result.records[0].score, 980_000);
+        assert_eq!(result.level_up, None);
+        assert_eq!(result.records[0].clear_type, ClearType::FullCombo);
+        assert_eq!(result.records[0].play_count, 4);
     }
 
     #[tokio::test]
     async fn submit_records_maps_user_not_found() {
-        let mut record_repo = MockRecordRepository::new();
-        record_repo
-            .expect_find_by_user_id_and_sheet_ids()
-            .returning(|_, _| {
-                Box::pin(async { Err(RecordRepositoryError::UserNotFound("missing".to_owned())) })
-            });
+        let mut user_repo = MockUserRepository::new();
+        user_repo
+            .expect_find_by_id_for_update()
+            .returning(|_| Box::pin(async { Ok(None) }));
 
         let repositories = MockRepositories {
-            user: MockUserRepository::new(),
-            record: record_repo,
-            music: MockMusicRepository::new(),
+            user: user_repo,
+            music: sheet_music_repo(),
+            play: fresh_play_repo(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
-        let submission =
-            UserRecordSubmissionDto::new("sheet-1".to_owned(), 900_000, ClearType::Clear);
+        let submission = UserRecordSubmissionDto::new(
+            "submission-3".to_owned(),
+            "sheet-1".to_owned(),
+            900_000,
+            ClearType::Clear,
+            Judgement::new(900, 80, 20, 0),
+            1000,
+        );
 
         let err = usecase
-            .submit_records("missing".to_owned(), vec![submission])
+            .submit_records(
+                "missing".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
             .await
             .expect_err("should return not found");
 
@@ -497,43 +1009,17 @@ mod tests {
     #[tokio::test]
     async fn submit_records_propagates_user_repo_errors() {
         let mut record_repo = MockRecordRepository::new();
-        record_repo
-            .expect_find_by_user_id_and_sheet_ids()
-            .returning(|_, _| Box::pin(async { Ok(Vec::new()) }));
-        record_repo.expect_insert().returning(|record| {
-            Box::pin(async move {
-                Ok(Record::new(
-                    record.id().to_owned(),
-                    record.user_id().to_owned(),
-                    record.sheet_id().to_owned(),
-                    *record.score(),
-                    *record.clear_type(),
-                    *record.play_count(),
-                    sample_timestamp(),
-                ))
-            })
+        record_repo.expect_upsert_many().returning(|upserts| {
+            let stored = upserts.iter().map(stored_record).collect();
+            Box::pin(async move { Ok(stored) })
         });
         record_repo
-            .expect_find_with_metadata_by_user_id()
-            .returning(|_| {
-                Box::pin(async move {
-                    let level = Level::new(11, 0).expect("level valid");
-                    let record = Record::new(
-                        "record-1".to_owned(),
-                        "user-789".to_owned(),
-                        "sheet-1".to_owned(),
-                        910_000,
-                        ClearType::Clear,
-                        1,
-                        sample_timestamp(),
-                    );
-                    Ok(vec![RecordWithMetadata::new(record, level, false)])
-                })
-            });
+            .expect_find_top_sheet_ratings()
+            .returning(|_, _| Box::pin(async { Ok(vec![1100]) }));
 
         let mut user_repo = MockUserRepository::new();
         user_repo
-            .expect_find_by_id()
+            .expect_find_by_id_for_update()
             .withf(|user_id| user_id == "user-789")
             .returning(|_| {
                 let user = User::new(
@@ -556,15 +1042,28 @@ mod tests {
         let repositories = MockRepositories {
             user: user_repo,
             record: record_repo,
-            music: MockMusicRepository::new(),
+            music: sheet_music_repo(),
+            play: play_repo_expecting(1),
+            xp_campaign: campaign_repo(&[]),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
-        let submission =
-            UserRecordSubmissionDto::new("sheet-1".to_owned(), 910_000, ClearType::Clear);
+        let submission = UserRecordSubmissionDto::new(
+            "submission-4".to_owned(),
+            "sheet-1".to_owned(),
+            910_000,
+            ClearType::Clear,
+            Judgement::new(900, 80, 20, 0),
+            1000,
+        );
 
         let err = usecase
-            .submit_records("user-789".to_owned(), vec![submission])
+            .submit_records(
+                "user-789".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
             .await
             .expect_err("should propagate error");
 
@@ -575,4 +1074,720 @@ mod tests {
             _ => panic!("unexpected error variant"),
         }
     }
+
+    #[tokio::test]
+    async fn submit_records_rejects_inconsistent_clear_type() {
+        let repositories = MockRepositories {
+            music: sheet_music_repo(),
+            ..Default::default()
+        };
+        let usecase = UserUsecase::new(Arc::new(repositories));
+
+        let submission = UserRecordSubmissionDto::new(
+            "submission-5".to_owned(),
+            "sheet-1".to_owned(),
+            990_000,
+            ClearType::AllPerfect,
+            Judgement::new(990, 10, 0, 0),
+            1000,
+        );
+
+        let err = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
+            .await
+            .expect_err("should reject submission");
+
+        match err {
+            UserUsecaseError::InvalidSubmission { sheet_id, .. } => assert_eq!(sheet_id, "sheet-1"),
+            _ => panic!("unexpected error variant"),
+        }
+    }
+
+    fn usecase_with_sheet() -> UserUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            music: sheet_music_repo(),
+            play: fresh_play_repo(),
+            ..Default::default()
+        };
+        UserUsecase::new(Arc::new(repositories))
+    }
+
+    #[tokio::test]
+    async fn submit_records_rejects_score_above_sheet_maximum() {
+        let usecase = usecase_with_sheet();
+
+        let submission = UserRecordSubmissionDto::new(
+            "submission-6".to_owned(),
+            "sheet-1".to_owned(),
+            1_010_001,
+            ClearType::AllPerfect,
+            Judgement::new(1000, 0, 0, 0),
+            1000,
+        );
+
+        let err = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
+            .await
+            .expect_err("should reject submission");
+
+        match err {
+            UserUsecaseError::ImplausibleSubmission { sheet_id, source } => {
+                assert_eq!(sheet_id, "sheet-1");
+                assert_eq!(
+                    source,
+                    ScoreValidationError::ScoreExceedsMaximum {
+                        score: 1_010_001,
+                        max_score: 1_010_000
+                    }
+                );
+            }
+            _ => panic!("unexpected error variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn submit_records_rejects_judgement_not_matching_note_count() {
+        let usecase = usecase_with_sheet();
+
+        let submission = UserRecordSubmissionDto::new(
+            "submission-7".to_owned(),
+            "sheet-1".to_owned(),
+            900_000,
+            ClearType::Clear,
+            Judgement::new(800, 100, 50, 10),
+            300,
+        );
+
+        let err = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
+            .await
+            .expect_err("should reject submission");
+
+        assert!(matches!(
+            err,
+            UserUsecaseError::ImplausibleSubmission {
+                source: ScoreValidationError::NoteCountMismatch { .. },
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn submit_records_rejects_combo_above_note_count() {
+        let usecase = usecase_with_sheet();
+
+        let submission = UserRecordSubmissionDto::new(
+            "submission-7".to_owned(),
+            "sheet-1".to_owned(),
+            1_010_000,
+            ClearType::AllPerfect,
+            Judgement::new(1000, 0, 0, 0),
+            1001,
+        );
+
+        let err = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
+            .await
+            .expect_err("should reject submission");
+
+        assert!(matches!(
+            err,
+            UserUsecaseError::ImplausibleSubmission {
+                source: ScoreValidationError::ComboExceedsNoteCount {
+                    max_combo: 1001,
+                    note_count: 1000
+                },
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn submit_records_reports_unknown_sheet() {
+        let usecase = usecase_with_sheet();
+
+        let submission = UserRecordSubmissionDto::new(
+            "submission-8".to_owned(),
+            "sheet-404".to_owned(),
+            900_000,
+            ClearType::Clear,
+            Judgement::new(800, 100, 50, 50),
+            300,
+        );
+
+        let err = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
+            .await
+            .expect_err("should reject submission");
+
+        match err {
+            UserUsecaseError::SheetNotFound { sheet_id } => assert_eq!(sheet_id, "sheet-404"),
+            _ => panic!("unexpected error variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn submit_records_replays_legacy_submission_with_the_stored_record() {
+        let mut play_repo = MockPlayRepository::new();
+        play_repo
+            .expect_find_submitted_ids()
+            .withf(|user_id, ids| user_id == "user-123" && ids == ["submission-1".to_owned()])
+            .returning(|_, ids| {
+                let ids = ids.to_vec();
+                Box::pin(async move { Ok(ids) })
+            });
+        play_repo
+            .expect_find_results()
+            .returning(|_, _| Box::pin(async { Ok(Vec::new()) }));
+        play_repo.expect_append().never();
+
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_by_user_id_and_sheet_ids()
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![Record::new(
+                        "record-1".to_owned(),
+                        "user-123".to_owned(),
+                        "sheet-1".to_owned(),
+                        1_000_000,
+                        ClearType::FullCombo,
+                        Judgement::new(900, 80, 20, 0),
+                        1000,
+                        1,
+                        sample_timestamp(),
+                    )])
+                })
+            });
+        record_repo.expect_upsert_many().never();
+
+        let repositories = MockRepositories {
+            user: replaying_user_repo(),
+            record: record_repo,
+            music: sheet_music_repo(),
+            play: play_repo,
+            ..Default::default()
+        };
+        let usecase = UserUsecase::new(Arc::new(repositories));
+
+        let submission = UserRecordSubmissionDto::new(
+            "submission-1".to_owned(),
+            "sheet-1".to_owned(),
+            1_000_000,
+            ClearType::FullCombo,
+            Judgement::new(900, 80, 20, 0),
+            1000,
+        );
+        let result = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
+            .await
+            .expect("replay should succeed");
+
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].id, "record-1");
+        assert_eq!(result.records[0].play_count, 1);
+    }
+
+    /// Replays still lock the user, so that they wait for a concurrent original to commit, but
+    /// never write it.
+    fn replaying_user_repo() -> MockUserRepository {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id_for_update().returning(|_| {
+            let user = User::new(
+                "user-123".to_owned(),
+                "CARD-123".to_owned(),
+                "Alice".to_owned(),
+                Rating::new(1200),
+                0,
+                0,
+                false,
+                false,
+                sample_timestamp(),
+            );
+            Box::pin(async move { Ok(Some(user)) })
+        });
+        user_repo.expect_save().never();
+        user_repo
+    }
+
+    fn stored_result(play_count: u32) -> SubmissionResult {
+        SubmissionResult::new(
+            "submission-1".to_owned(),
+            Record::new(
+                "record-1".to_owned(),
+                "user-123".to_owned(),
+                "sheet-1".to_owned(),
+                990_000,
+                ClearType::Clear,
+                Judgement::new(900, 80, 20, 0),
+                980,
+                play_count,
+                sample_timestamp(),
+            ),
+            Some(LevelUp::new(4, 5)),
+        )
+    }
+
+    fn replay_submission() -> UserRecordSubmissionDto {
+        UserRecordSubmissionDto::new(
+            "submission-1".to_owned(),
+            "sheet-1".to_owned(),
+            990_000,
+            ClearType::Clear,
+            Judgement::new(900, 80, 20, 0),
+            980,
+        )
+    }
+
+    #[tokio::test]
+    async fn submit_records_replays_the_stored_result() {
+        let mut play_repo = MockPlayRepository::new();
+        play_repo.expect_find_submitted_ids().returning(|_, ids| {
+            let ids = ids.to_vec();
+            Box::pin(async move { Ok(ids) })
+        });
+        play_repo
+            .expect_find_results()
+            .withf(|user_id, ids| user_id == "user-123" && ids == ["submission-1".to_owned()])
+            .returning(|_, _| Box::pin(async { Ok(vec![stored_result(3)]) }));
+        play_repo.expect_append().never();
+
+        // Later plays moved the record on; the replay must not see them.
+        let mut record_repo = MockRecordRepository::new();
+        record_repo.expect_find_by_user_id_and_sheet_ids().never();
+        record_repo.expect_upsert_many().never();
+
+        let repositories = MockRepositories {
+            user: replaying_user_repo(),
+            record: record_repo,
+            music: sheet_music_repo(),
+            play: play_repo,
+            ..Default::default()
+        };
+        let usecase = UserUsecase::new(Arc::new(repositories));
+
+        let result = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![replay_submission()],
+            )
+            .await
+            .expect("replay should succeed");
+
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].play_count, 3);
+        assert_eq!(result.records[0].score, 990_000);
+        assert_eq!(result.level_up, Some(LevelUpDto { from: 4, to: 5 }));
+    }
+
+    #[tokio::test]
+    async fn submit_records_replays_a_submission_that_raced_a_concurrent_request() {
+        let lookups = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut play_repo = MockPlayRepository::new();
+        play_repo.expect_find_submitted_ids().returning({
+            let lookups = Arc::clone(&lookups);
+            move |_, ids| {
+                // The concurrent request commits between the two passes.
+                let first = lookups.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0;
+                let ids = if first { Vec::new() } else { ids.to_vec() };
+                Box::pin(async move { Ok(ids) })
+            }
+        });
+        play_repo.expect_append().times(1).returning(|_| {
+            Box::pin(async {
+                Err(PlayRepositoryError::DuplicateSubmission(
+                    "submission-1".to_owned(),
+                ))
+            })
+        });
+        play_repo
+            .expect_find_results()
+            .returning(|_, _| Box::pin(async { Ok(vec![stored_result(1)]) }));
+        play_repo.expect_save_results().never();
+
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_find_by_user_id_and_sheet_ids()
+            .returning(|_, _| Box::pin(async { Ok(Vec::new()) }));
+        record_repo.expect_upsert_many().never();
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id_for_update().returning(|_| {
+            let user = User::new(
+                "user-123".to_owned(),
+                "CARD-123".to_owned(),
+                "Alice".to_owned(),
+                Rating::new(1200),
+                0,
+                0,
+                false,
+                false,
+                sample_timestamp(),
+            );
+            Box::pin(async move { Ok(Some(user)) })
+        });
+        user_repo.expect_save().never();
+
+        let repositories = MockRepositories {
+            user: user_repo,
+            record: record_repo,
+            music: sheet_music_repo(),
+            play: play_repo,
+            ..Default::default()
+        };
+        let usecase = UserUsecase::new(Arc::new(repositories));
+
+        let result = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![replay_submission()],
+            )
+            .await
+            .expect("losing the race should replay");
+
+        assert_eq!(lookups.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(result.records[0].id, "record-1");
+        assert_eq!(result.level_up, Some(LevelUpDto { from: 4, to: 5 }));
+    }
+
+    #[tokio::test]
+    async fn submit_records_rejects_repeated_submission_id() {
+        let usecase = usecase_with_sheet();
+
+        let submission = |score| {
+            UserRecordSubmissionDto::new(
+                "submission-1".to_owned(),
+                "sheet-1".to_owned(),
+                score,
+                ClearType::Clear,
+                Judgement::new(900, 80, 20, 0),
+                1000,
+            )
+        };
+
+        let err = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission(900_000), submission(950_000)],
+            )
+            .await
+            .expect_err("should reject repeated id");
+
+        assert!(matches!(
+            err,
+            UserUsecaseError::InvalidSubmission { reason, .. } if reason.contains("submission-1")
+        ));
+    }
+
+    #[tokio::test]
+    async fn submit_records_folds_repeated_sheets_into_one_upsert() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo
+            .expect_upsert_many()
+            .withf(|upserts| {
+                upserts.len() == 1
+                    && *upserts[0].record.play_count() == 2
+                    && *upserts[0].record.score() == 990_000
+                    && *upserts[0].record.clear_type() == ClearType::FullCombo
+            })
+            .times(1)
+            .returning(|upserts| {
+                let stored = upserts.iter().map(stored_record).collect();
+                Box::pin(async move { Ok(stored) })
+            });
+        record_repo
+            .expect_find_top_sheet_ratings()
+            .returning(|_, _| Box::pin(async { Ok(vec![1460]) }));
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id_for_update().returning(|_| {
+            let user = User::new(
+                "user-123".to_owned(),
+                "CARD-123".to_owned(),
+                "Alice".to_owned(),
+                Rating::new(0),
+                0,
+                0,
+                false,
+                false,
+                sample_timestamp(),
+            );
+            Box::pin(async move { Ok(Some(user)) })
+        });
+        user_repo
+            .expect_save()
+            .withf(|user| user.rating().value() == 1460)
+            .returning(|user| Box::pin(async move { Ok(user) }));
+
+        expect_progress(&mut user_repo);
+
+        let repositories = MockRepositories {
+            user: user_repo,
+            record: record_repo,
+            music: sheet_music_repo(),
+            play: play_repo_expecting(2),
+            xp_campaign: campaign_repo(&[]),
+            ..Default::default()
+        };
+        let usecase = UserUsecase::new(Arc::new(repositories));
+
+        let submissions = vec![
+            UserRecordSubmissionDto::new(
+                "submission-1".to_owned(),
+                "sheet-1".to_owned(),
+                990_000,
+                ClearType::FullCombo,
+                Judgement::new(950, 50, 0, 0),
+                1000,
+            ),
+            UserRecordSubmissionDto::new(
+                "submission-2".to_owned(),
+                "sheet-1".to_owned(),
+                900_000,
+                ClearType::Clear,
+                Judgement::new(900, 50, 30, 20),
+                400,
+            ),
+        ];
+        let result = usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                submissions,
+            )
+            .await
+            .expect("should succeed");
+
+        assert_eq!(result.records.len(), 2);
+        assert!(result.records.iter().all(|record| record.score == 990_000));
+    }
+
+    #[tokio::test]
+    async fn submit_records_averages_recent_plays_when_configured() {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo.expect_upsert_many().returning(|upserts| {
+            let stored = upserts.iter().map(stored_record).collect();
+            Box::pin(async move { Ok(stored) })
+        });
+        record_repo
+            .expect_find_top_sheet_ratings()
+            .withf(|_, limit| *limit == 1)
+            .returning(|_, _| Box::pin(async { Ok(vec![1470]) }));
+
+        let mut play_repo = play_repo_expecting(1);
+        play_repo
+            .expect_find_recent_scores()
+            .withf(|user_id, limit| user_id == "user-123" && *limit == 2)
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![
+                        RecentPlayScore::new(
+                            "sheet-1".to_owned(),
+                            Level::new(13, 7).unwrap(),
+                            1_000_000,
+                        ),
+                        RecentPlayScore::new(
+                            "sheet-2".to_owned(),
+                            Level::new(10, 0).unwrap(),
+                            900_000,
+                        ),
+                    ])
+                })
+            });
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id_for_update().returning(|_| {
+            let user = User::new(
+                "user-123".to_owned(),
+                "CARD-123".to_owned(),
+                "Alice".to_owned(),
+                Rating::new(0),
+                0,
+                0,
+                false,
+                false,
+                sample_timestamp(),
+            );
+            Box::pin(async move { Ok(Some(user)) })
+        });
+        user_repo
+            .expect_save()
+            .withf(|user| user.rating().value() == (1470 + 1470 + 1000) / 3)
+            .times(1)
+            .returning(|user| Box::pin(async move { Ok(user) }));
+
+        expect_progress(&mut user_repo);
+
+        let repositories = MockRepositories {
+            user: user_repo,
+            record: record_repo,
+            music: sheet_music_repo(),
+            play: play_repo,
+            xp_campaign: campaign_repo(&[]),
+            ..Default::default()
+        };
+        let policy = RatingPolicy::new(1, 2, DEFAULT_BONUS_CURVE.to_vec()).unwrap();
+        let usecase = UserUsecase::new(Arc::new(repositories)).with_rating_policy(Arc::new(policy));
+
+        let submission = UserRecordSubmissionDto::new(
+            "submission-1".to_owned(),
+            "sheet-1".to_owned(),
+            1_000_000,
+            ClearType::FullCombo,
+            Judgement::new(900, 80, 20, 0),
+            1000,
+        );
+        usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![submission],
+            )
+            .await
+            .expect("should succeed");
+    }
+
+    /// A user with no XP or rating whose saved XP must equal `expected_xp`.
+    fn user_repo_expecting_xp(expected_xp: u32) -> MockUserRepository {
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id_for_update().returning(|_| {
+            let user = User::new(
+                "user-123".to_owned(),
+                "CARD-123".to_owned(),
+                "Alice".to_owned(),
+                Rating::new(0),
+                0,
+                0,
+                false,
+                false,
+                sample_timestamp(),
+            );
+            Box::pin(async move { Ok(Some(user)) })
+        });
+        user_repo
+            .expect_save()
+            .withf(move |user| *user.xp() == expected_xp)
+            .times(1)
+            .returning(|user| Box::pin(async move { Ok(user) }));
+        expect_progress(&mut user_repo);
+        user_repo
+    }
+
+    fn upserting_record_repo() -> MockRecordRepository {
+        let mut record_repo = MockRecordRepository::new();
+        record_repo.expect_upsert_many().returning(|upserts| {
+            let stored = upserts.iter().map(stored_record).collect();
+            Box::pin(async move { Ok(stored) })
+        });
+        record_repo
+            .expect_find_top_sheet_ratings()
+            .returning(|_, _| Box::pin(async { Ok(vec![1470]) }));
+        record_repo
+    }
+
+    fn full_combo_submission() -> UserRecordSubmissionDto {
+        UserRecordSubmissionDto::new(
+            "submission-1".to_owned(),
+            "sheet-1".to_owned(),
+            1_000_000,
+            ClearType::FullCombo,
+            Judgement::new(900, 80, 20, 0),
+            1000,
+        )
+    }
+
+    #[tokio::test]
+    async fn submit_records_applies_the_largest_active_campaign() {
+        let repositories = MockRepositories {
+            user: user_repo_expecting_xp(200),
+            record: upserting_record_repo(),
+            music: sheet_music_repo(),
+            play: play_repo_expecting(1),
+            xp_campaign: campaign_repo(&[150, 200]),
+            ..Default::default()
+        };
+        let usecase = UserUsecase::new(Arc::new(repositories));
+
+        usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![full_combo_submission()],
+            )
+            .await
+            .expect("should succeed");
+    }
+
+    #[tokio::test]
+    async fn submit_records_grants_bonuses_for_first_clears() {
+        let mut record_repo = upserting_record_repo();
+        record_repo
+            .expect_find_by_user_id_and_sheet_ids()
+            .withf(|_, sheet_ids| sheet_ids == ["sheet-1".to_owned()])
+            .times(1)
+            .returning(|user_id, _| {
+                // Only a failed run is on file, so the sheet has not been cleared yet.
+                let record = Record::new(
+                    "record-1".to_owned(),
+                    user_id.to_owned(),
+                    "sheet-1".to_owned(),
+                    400_000,
+                    ClearType::Fail,
+                    Judgement::new(300, 100, 100, 500),
+                    120,
+                    1,
+                    sample_timestamp(),
+                );
+                Box::pin(async move { Ok(vec![record]) })
+            });
+
+        let repositories = MockRepositories {
+            // 100 for the score, 20 for the full combo, 13 * 2 for the level and 50 for the clear.
+            user: user_repo_expecting_xp(196),
+            record: record_repo,
+            music: sheet_music_repo(),
+            play: play_repo_expecting(1),
+            xp_campaign: campaign_repo(&[]),
+            ..Default::default()
+        };
+        let usecase = UserUsecase::new(Arc::new(repositories))
+            .with_xp_policy(Arc::new(XpPolicy::new(20, 40, 2, 50)));
+
+        usecase
+            .submit_records(
+                "user-123".to_owned(),
+                CABINET_KEY.client_id.to_owned(),
+                vec![full_combo_submission()],
+            )
+            .await
+            .expect("should succeed");
+    }
 }
@@ -23,7 +23,7 @@ impl<R: Repositories> UserUsecase<R> {
         let user = User::new_temporary(raw_user.card, raw_user.display_name, is_public);
         let user = self.repositories.user().create(user).await?;
         info!(user_id = %user.id(), "User persisted by repository");
-        Ok(user.into())
+        Ok(UserDataDto::from_user(user, &self.level_curve))
     }
 }
 
@@ -33,10 +33,7 @@ mod tests {
 
     use domain::{
         entity::rating::Rating,
-        repository::{
-            MockRepositories, music::MockMusicRepository, record::MockRecordRepository,
-            user::UserRepositoryError,
-        },
+        repository::{MockRepositories, user::UserRepositoryError},
         testing::user::{USER1, USER2},
     };
 
@@ -58,8 +55,7 @@ mod tests {
 
         let repositories = MockRepositories {
             user: user_repo,
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
@@ -99,8 +95,7 @@ mod tests {
 
         let repositories = MockRepositories {
             user: user_repo,
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
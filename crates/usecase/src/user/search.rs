@@ -14,7 +14,7 @@ impl<R: Repositories> UserUsecase<R> {
         let user =
             maybe_user.ok_or_else(|| UserUsecaseError::NotFoundByCard { card: card.clone() })?;
         debug!(user_id = %user.id(), "User aggregate resolved");
-        Ok(user.into())
+        Ok(UserDataDto::from_user(user, &self.level_curve))
     }
 }
 
@@ -23,10 +23,7 @@ mod tests {
     use std::sync::Arc;
 
     use domain::{
-        repository::{
-            MockRepositories, music::MockMusicRepository, record::MockRecordRepository,
-            user::UserRepositoryError,
-        },
+        repository::{MockRepositories, user::UserRepositoryError},
         testing::{
             datetime::sample_timestamp,
             user::{USER1, USER2},
@@ -48,8 +45,7 @@ mod tests {
 
         let repositories = MockRepositories {
             user: user_repo,
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
@@ -72,8 +68,7 @@ mod tests {
 
         let repositories = MockRepositories {
             user: user_repo,
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
@@ -101,8 +96,7 @@ mod tests {
 
         let repositories = MockRepositories {
             user: user_repo,
-            record: MockRecordRepository::new(),
-            music: MockMusicRepository::new(),
+            ..Default::default()
         };
         let usecase = UserUsecase::new(Arc::new(repositories));
 
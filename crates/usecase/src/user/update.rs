@@ -25,6 +25,8 @@ impl<R: Repositories> UserUsecase<R> {
         user.set_is_public(update.is_public);
 
         let saved = self.repositories.user().save(user).await?;
-        Ok(saved.into())
+        // Both fields show up on the boards: private users are left out and names are displayed.
+        self.ranking_cache.invalidate();
+        Ok(UserDataDto::from_user(saved, &self.level_curve))
     }
 }
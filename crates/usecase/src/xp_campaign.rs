@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use domain::{
+    entity::xp_campaign::{XpCampaign, XpCampaignError},
+    repository::{
+        Repositories,
+        xp_campaign::{XpCampaignRepository, XpCampaignRepositoryError},
+    },
+};
+use thiserror::Error;
+use tracing::{info, instrument};
+
+use crate::model::xp_campaign::{XpCampaignCreateDto, XpCampaignDto};
+
+#[derive(Debug, Error)]
+pub enum XpCampaignUsecaseError {
+    #[error("Invalid XP campaign: {0}")]
+    InvalidCampaign(#[from] XpCampaignError),
+    #[error("XP campaign not found for id: {campaign_id}")]
+    NotFoundById { campaign_id: String },
+    #[error(transparent)]
+    XpCampaignRepository(XpCampaignRepositoryError),
+}
+
+impl From<XpCampaignRepositoryError> for XpCampaignUsecaseError {
+    fn from(err: XpCampaignRepositoryError) -> Self {
+        match err {
+            XpCampaignRepositoryError::NotFound(campaign_id) => Self::NotFoundById { campaign_id },
+            err => Self::XpCampaignRepository(err),
+        }
+    }
+}
+
+/// Administers the campaigns that multiply the XP of submissions made while they run.
+pub struct XpCampaignUsecase<R: Repositories> {
+    repositories: Arc<R>,
+}
+
+impl<R: Repositories> XpCampaignUsecase<R> {
+    pub fn new(repositories: Arc<R>) -> Self {
+        Self { repositories }
+    }
+
+    #[instrument(skip(self, dto), fields(name = %dto.name, multiplier = dto.multiplier_percent))]
+    pub async fn create(
+        &self,
+        dto: XpCampaignCreateDto,
+    ) -> Result<XpCampaignDto, XpCampaignUsecaseError> {
+        let campaign = XpCampaign::new_temporary(
+            dto.name,
+            dto.multiplier_percent,
+            dto.starts_at,
+            dto.ends_at,
+        )?;
+        let created = self.repositories.xp_campaign().create(campaign).await?;
+        info!(campaign_id = %created.id(), "XP campaign created");
+        Ok(created.into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<XpCampaignDto>, XpCampaignUsecaseError> {
+        let campaigns = self.repositories.xp_campaign().find_all().await?;
+        Ok(campaigns.into_iter().map(XpCampaignDto::from).collect())
+    }
+
+    #[instrument(skip(self), fields(campaign_id = %campaign_id))]
+    pub async fn delete(&self, campaign_id: String) -> Result<(), XpCampaignUsecaseError> {
+        self.repositories.xp_campaign().delete(&campaign_id).await?;
+        info!("XP campaign deleted");
+        Ok(())
+    }
+}
+
+impl<R: Repositories> Clone for XpCampaignUsecase<R> {
+    fn clone(&self) -> Self {
+        Self {
+            repositories: Arc::clone(&self.repositories),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        repository::{MockRepositories, xp_campaign::MockXpCampaignRepository},
+        testing::datetime::timestamp,
+    };
+
+    use super::*;
+
+    fn build_usecase(
+        campaign_repo: MockXpCampaignRepository,
+    ) -> XpCampaignUsecase<MockRepositories> {
+        let repositories = MockRepositories {
+            xp_campaign: campaign_repo,
+            ..Default::default()
+        };
+        XpCampaignUsecase::new(Arc::new(repositories))
+    }
+
+    #[tokio::test]
+    async fn create_rejects_campaigns_that_end_before_they_start() {
+        let usecase = build_usecase(MockXpCampaignRepository::new());
+
+        let err = usecase
+            .create(XpCampaignCreateDto::new(
+                "Double XP".to_owned(),
+                200,
+                timestamp(2025, 11, 24, 0, 0, 0),
+                timestamp(2025, 11, 22, 0, 0, 0),
+            ))
+            .await
+            .expect_err("should reject");
+
+        assert!(matches!(
+            err,
+            XpCampaignUsecaseError::InvalidCampaign(XpCampaignError::EmptyPeriod)
+        ));
+    }
+
+    #[tokio::test]
+    async fn delete_maps_missing_campaigns() {
+        let mut campaign_repo = MockXpCampaignRepository::new();
+        campaign_repo.expect_delete().returning(|id| {
+            let id = id.to_owned();
+            Box::pin(async move { Err(XpCampaignRepositoryError::NotFound(id)) })
+        });
+        let usecase = build_usecase(campaign_repo);
+
+        let err = usecase
+            .delete("missing".to_owned())
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(
+            err,
+            XpCampaignUsecaseError::NotFoundById { campaign_id } if campaign_id == "missing"
+        ));
+    }
+}